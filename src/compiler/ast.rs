@@ -59,6 +59,8 @@ mod sequence_expression;
 pub use sequence_expression::*;
 mod type_expression;
 pub use type_expression::*;
+mod invalid_expression;
+pub use invalid_expression::*;
 
 // Destructuring
 mod destructuring;
@@ -109,6 +111,8 @@ mod import_directive;
 pub use import_directive::*;
 mod use_directive;
 pub use use_directive::*;
+mod invalid_directive;
+pub use invalid_directive::*;
 
 // Miscellaneous
 mod attributes;
@@ -12,4 +12,35 @@ pub struct AssignmentExpression {
     /// and there is no compound assignment, it is a destructuring pattern.
     pub left: Rc<Expression>,
     pub right: Rc<Expression>,
+}
+
+impl AssignmentExpression {
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier) -> Result<Option<Symbol>, DeferVerificationError> {
+        let host = verifier.host.clone();
+
+        // Destructuring assignment
+        if self.compound.is_none() && matches!(self.left.as_ref(), Expression::ArrayLiteral(_) | Expression::ObjectInitializer(_)) {
+            let Some(v) = verifier.verify_expression(&self.right, &default())? else {
+                return Ok(None);
+            };
+            let t = v.static_type(&host);
+            t.throw_if_unresolved()?;
+            verifier.verify_assignment_destructuring(&self.left, &t)?;
+            return Ok(Some(host.factory().create_value(&t)));
+        }
+
+        let Some(target) = verifier.verify_expression(&self.left, &ExpressionVerifyContext { mode: VerifyMode::Write, ..default() })? else {
+            verifier.verify_expression(&self.right, &default())?;
+            return Ok(None);
+        };
+        let t = target.static_type(&host);
+        t.throw_if_unresolved()?;
+        let value = verifier.verify_expression(&self.right, &ExpressionVerifyContext { context_type: Some(t.clone()), ..default() })?;
+        let mut value_type = None;
+        if self.compound.is_none() && verifier.limit_expression_type(&self.right, &t)?.is_some() {
+            value_type = value.map(|v| v.static_type(&host));
+        }
+        verifier.narrow_assignment(&self.left, value_type.as_ref());
+        Ok(Some(host.factory().create_value(&t)))
+    }
 }
\ No newline at end of file
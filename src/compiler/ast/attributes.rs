@@ -66,7 +66,7 @@ impl Attribute {
     pub fn remove_metadata(list: &mut Vec<Attribute>, metadata: &Rc<PlainMetadata>) {
         for i in 0..list.len() {
             if let Attribute::Metadata((metadata_1, _)) = &list[i] {
-                if Rc::ptr_eq(&metadata_1, metadata) {
+                if Rc::ptr_eq(metadata_1, metadata) {
                     list.remove(i);
                     break;
                 }
@@ -77,25 +77,22 @@ impl Attribute {
     pub fn find_metadata(list: &Vec<Attribute>) -> Vec<(Rc<PlainMetadata>, Location)> {
         let mut r = vec![];
         for a in list {
-            match &a {
-                Self::Metadata(e) => {
-                    r.push(e.clone());
-                },
-                _ => {},
+            if let Self::Metadata(e) = &a {
+                r.push(e.clone());
             }
         }
         r
     }
-    pub fn find_public(list: &Vec<Attribute>) -> Option<Location> { for a in list { match &a { Self::Public(l) => return Some(l.clone()), _ => return None } }; None }
-    pub fn find_private(list: &Vec<Attribute>) -> Option<Location> { for a in list { match &a { Self::Private(l) => return Some(l.clone()), _ => return None } }; None }
-    pub fn find_protected(list: &Vec<Attribute>) -> Option<Location> { for a in list { match &a { Self::Protected(l) => return Some(l.clone()), _ => return None } }; None }
-    pub fn find_internal(list: &Vec<Attribute>) -> Option<Location> { for a in list { match &a { Self::Internal(l) => return Some(l.clone()), _ => return None } }; None }
-    pub fn find_proxy(list: &Vec<Attribute>) -> Option<Location> { for a in list { match &a { Self::Proxy(l) => return Some(l.clone()), _ => return None } }; None }
-    pub fn find_final(list: &Vec<Attribute>) -> Option<Location> { for a in list { match &a { Self::Final(l) => return Some(l.clone()), _ => return None } }; None }
-    pub fn find_native(list: &Vec<Attribute>) -> Option<Location> { for a in list { match &a { Self::Native(l) => return Some(l.clone()), _ => return None } }; None }
-    pub fn find_static(list: &Vec<Attribute>) -> Option<Location> { for a in list { match &a { Self::Static(l) => return Some(l.clone()), _ => return None } }; None }
-    pub fn find_abstract(list: &Vec<Attribute>) -> Option<Location> { for a in list { match &a { Self::Abstract(l) => return Some(l.clone()), _ => return None } }; None }
-    pub fn find_override(list: &Vec<Attribute>) -> Option<Location> { for a in list { match &a { Self::Override(l) => return Some(l.clone()), _ => return None } }; None }
+    pub fn find_public(list: &Vec<Attribute>) -> Option<Location> { for a in list { if let Self::Public(l) = &a { return Some(l.clone()) } }; None }
+    pub fn find_private(list: &Vec<Attribute>) -> Option<Location> { for a in list { if let Self::Private(l) = &a { return Some(l.clone()) } }; None }
+    pub fn find_protected(list: &Vec<Attribute>) -> Option<Location> { for a in list { if let Self::Protected(l) = &a { return Some(l.clone()) } }; None }
+    pub fn find_internal(list: &Vec<Attribute>) -> Option<Location> { for a in list { if let Self::Internal(l) = &a { return Some(l.clone()) } }; None }
+    pub fn find_proxy(list: &Vec<Attribute>) -> Option<Location> { for a in list { if let Self::Proxy(l) = &a { return Some(l.clone()) } }; None }
+    pub fn find_final(list: &Vec<Attribute>) -> Option<Location> { for a in list { if let Self::Final(l) = &a { return Some(l.clone()) } }; None }
+    pub fn find_native(list: &Vec<Attribute>) -> Option<Location> { for a in list { if let Self::Native(l) = &a { return Some(l.clone()) } }; None }
+    pub fn find_static(list: &Vec<Attribute>) -> Option<Location> { for a in list { if let Self::Static(l) = &a { return Some(l.clone()) } }; None }
+    pub fn find_abstract(list: &Vec<Attribute>) -> Option<Location> { for a in list { if let Self::Abstract(l) = &a { return Some(l.clone()) } }; None }
+    pub fn find_override(list: &Vec<Attribute>) -> Option<Location> { for a in list { if let Self::Override(l) = &a { return Some(l.clone()) } }; None }
 
     pub fn has(list: &Vec<Attribute>, attribute: &Attribute) -> bool {
         match attribute {
@@ -139,7 +136,7 @@ impl Attribute {
         if location.character_count() != name.chars().count() {
             return None;
         }
-        match name.as_ref() {
+        match name {
             "proxy" => Some(Attribute::Proxy(location.clone())),
             "final" => Some(Attribute::Final(location.clone())),
             "native" => Some(Attribute::Native(location.clone())),
@@ -8,4 +8,474 @@ pub struct BinaryExpression {
     pub operator: Operator,
     pub left: Rc<Expression>,
     pub right: Rc<Expression>,
+}
+
+impl BinaryExpression {
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier, context: &ExpressionVerifyContext) -> Result<Option<Symbol>, DeferVerificationError> {
+        match self.operator {
+            Operator::Is | Operator::IsNot => self.verify_is(verifier),
+            Operator::As => self.verify_as(verifier),
+            Operator::In | Operator::NotIn => self.verify_in(verifier),
+            Operator::NullCoalescing => self.verify_null_coalescing(verifier, context),
+            Operator::LogicalXor => self.verify_logical_xor(verifier),
+            Operator::LogicalAnd | Operator::LogicalOr => self.verify_logical_and_or(verifier, context),
+            Operator::Equals | Operator::NotEquals |
+            Operator::StrictEquals | Operator::StrictNotEquals => self.verify_equality(verifier),
+            Operator::Lt | Operator::Gt | Operator::Le | Operator::Ge => self.verify_relational(verifier),
+            _ => self.verify_arithmetic(verifier, context),
+        }
+    }
+
+    fn is_bitwise_operator(&self) -> bool {
+        [Operator::BitwiseAnd, Operator::BitwiseXor, Operator::BitwiseOr].contains(&self.operator)
+    }
+
+    /// Verifies operators that result into the promoted numeric type
+    /// of the operands, string concatenation or the result type of
+    /// an operator proxy.
+    fn verify_arithmetic(&self, verifier: &mut VerifierVerifier, context: &ExpressionVerifyContext) -> Result<Option<Symbol>, DeferVerificationError> {
+        let host = verifier.host.clone();
+        let left_context_type = context.context_type.as_ref().map(|t| t.non_null_type()).filter(|t| {
+            host.is_numeric_type(t) || (self.is_bitwise_operator() && t.is_enum_type() && t.is_set_enumeration())
+        });
+
+        // A numeric literal on the left is typed against a numeric
+        // right operand, as in `1 + l` where `l` is a `Long`.
+        if left_context_type.is_none() && is_numeric_literal(&self.left) && !is_numeric_literal(&self.right) {
+            let Some(right) = verifier.verify_expression(&self.right, &default())? else {
+                verifier.verify_expression(&self.left, &default())?;
+                return Ok(None);
+            };
+            let right_type = right.static_type(&host);
+            right_type.throw_if_unresolved()?;
+            if host.is_numeric_type(&right_type) {
+                let Some(left) = verifier.verify_expression(&self.left, &ExpressionVerifyContext { context_type: Some(right_type), ..default() })? else {
+                    return Ok(None);
+                };
+                let Some((left, right, result_type)) = self.promote_operands(verifier, &left, &right)? else {
+                    return Ok(None);
+                };
+                return Ok(Some(self.fold(verifier, &left, &right, &result_type)));
+            }
+        }
+        let Some(left) = verifier.verify_expression(&self.left, &ExpressionVerifyContext { context_type: left_context_type, ..default() })? else {
+            verifier.verify_expression(&self.right, &default())?;
+            return Ok(None);
+        };
+        let left_type = left.static_type(&host);
+        left_type.throw_if_unresolved()?;
+
+        // Dynamic operation
+        if left_type.is_any_type() {
+            verifier.verify_expression(&self.right, &default())?;
+            return Ok(Some(host.factory().create_value(&host.any_type())));
+        }
+
+        let string_type = host.string_type();
+
+        // String concatenation
+        if self.operator == Operator::Add && left_type == string_type {
+            let Some(right) = verifier.verify_expression(&self.right, &default())? else {
+                return Ok(None);
+            };
+            return Ok(Some(self.fold(verifier, &left, &right, &string_type)));
+        }
+
+        // Numeric operation
+        if host.is_numeric_type(&left_type) {
+            let Some(right) = verifier.verify_expression(&self.right, &ExpressionVerifyContext { context_type: Some(left_type.clone()), ..default() })? else {
+                return Ok(None);
+            };
+            let right_type = right.static_type(&host);
+            right_type.throw_if_unresolved()?;
+            if self.operator == Operator::Add && right_type == string_type {
+                return Ok(Some(self.fold(verifier, &left, &right, &string_type)));
+            }
+            let Some((left, right, result_type)) = self.promote_operands(verifier, &left, &right)? else {
+                return Ok(None);
+            };
+            return Ok(Some(self.fold(verifier, &left, &right, &result_type)));
+        }
+
+        // Set enumeration operation
+        if self.is_bitwise_operator() && left_type.is_enum_type() && left_type.is_set_enumeration() {
+            let Some(right) = verifier.limit_expression_type(&self.right, &left_type)? else {
+                return Ok(None);
+            };
+            return Ok(Some(self.fold(verifier, &left, &right, &left_type)));
+        }
+
+        // Operator proxy
+        if let Ok(proxy_kind) = ProxyKind::try_from(self.operator) {
+            if left_type.is_class_type() || left_type.is_enum_type() {
+                if let Some(proxy) = left_type.find_proxy(proxy_kind, &host)? {
+                    return BinaryExpression::verify_proxy_call(verifier, &proxy, &self.right);
+                }
+            }
+        }
+
+        let Some(right) = verifier.verify_expression(&self.right, &default())? else {
+            return Ok(None);
+        };
+
+        // String concatenation
+        if self.operator == Operator::Add && right.static_type(&host) == string_type {
+            return Ok(Some(self.fold(verifier, &left, &right, &string_type)));
+        }
+
+        verifier.add_verify_error(&self.location, DiagnosticKind::UnsupportedOperator, diagnostic_arguments![Symbol(left_type)]);
+        Ok(None)
+    }
+
+    /// Verifies the operand of an operator proxy against its last parameter
+    /// and results into a value of the proxy's result type.
+    fn verify_proxy_call(verifier: &mut VerifierVerifier, proxy: &Symbol, operand: &Rc<Expression>) -> Result<Option<Symbol>, DeferVerificationError> {
+        let signature = proxy.signature(&verifier.host);
+        signature.throw_if_unresolved()?;
+        let parameter_type = signature.parameters().iter().last().map(|p| p.static_type.clone()).unwrap_or(verifier.host.any_type());
+        verifier.limit_expression_type(operand, &parameter_type)?;
+        Ok(Some(verifier.host.factory().create_value(&signature.result_type())))
+    }
+
+    /// Folds the operation through `ConstantEvaluator`, resulting into
+    /// a value of `result_type` if the operands are not constants.
+    fn fold(&self, verifier: &mut VerifierVerifier, left: &Symbol, right: &Symbol, result_type: &Symbol) -> Symbol {
+        let host = verifier.host.clone();
+        match ConstantEvaluator(&host).binary(self.operator, left, right) {
+            Ok(Some(v)) => v,
+            Ok(None) => host.factory().create_value(result_type),
+            Err(ConstantEvaluationError::LongOverflow) => {
+                verifier.add_verify_error(&self.location, DiagnosticKind::LongConstantOverflow, diagnostic_arguments![]);
+                host.factory().create_value(result_type)
+            },
+        }
+    }
+
+    /// Converts numeric operands to the numeric type of wider range,
+    /// reporting an error if they are not convertible to each other.
+    ///
+    /// `Single` promotes to `Number` and `Long` to `BigInt`; a `Long`
+    /// operand together with a floating point operand promotes to `Number`.
+    /// `BigInt` does not mix with floating point types.
+    fn promote_operands(&self, verifier: &mut VerifierVerifier, left: &Symbol, right: &Symbol) -> Result<Option<(Symbol, Symbol, Symbol)>, DeferVerificationError> {
+        let host = verifier.host.clone();
+        let left_type = left.static_type(&host);
+        let right_type = right.static_type(&host);
+        if !host.is_numeric_type(&right_type) {
+            verifier.add_verify_error(&self.right.location(), DiagnosticKind::IncompatibleTypes, diagnostic_arguments![Symbol(left_type), Symbol(right_type)]);
+            return Ok(None);
+        }
+        let long_type = host.long_type();
+        let result_type = if left_type == right_type
+            || left_type.is_floating_point_type_of_wider_range_than(&right_type, &host)
+            || left_type.is_integer_type_of_wider_range_than(&right_type, &host) {
+            left_type.clone()
+        } else if right_type.is_floating_point_type_of_wider_range_than(&left_type, &host)
+            || right_type.is_integer_type_of_wider_range_than(&left_type, &host) {
+            right_type.clone()
+        } else if (left_type == long_type && host.is_floating_point_type(&right_type))
+            || (right_type == long_type && host.is_floating_point_type(&left_type)) {
+            host.number_type()
+        } else {
+            verifier.add_verify_error(&self.right.location(), DiagnosticKind::IncompatibleTypes, diagnostic_arguments![Symbol(left_type), Symbol(right_type)]);
+            return Ok(None);
+        };
+        let left = BinaryExpression::convert_operand(verifier, &self.left, left, &result_type);
+        let right = BinaryExpression::convert_operand(verifier, &self.right, right, &result_type);
+        let (Some(left), Some(right)) = (left, right) else {
+            return Ok(None);
+        };
+        Ok(Some((left, right, result_type)))
+    }
+
+    /// Converts an operand to the promoted type, reporting an error
+    /// if there is no such conversion.
+    fn convert_operand(verifier: &mut VerifierVerifier, exp: &Rc<Expression>, value: &Symbol, target_type: &Symbol) -> Option<Symbol> {
+        let host = verifier.host.clone();
+        let from_type = value.static_type(&host);
+        if &from_type == target_type {
+            return Some(value.clone());
+        }
+        // Promotion of a Long operand to Number, which is
+        // not an implicit conversion otherwise.
+        let conversion = if from_type == host.long_type() && target_type == &host.number_type() {
+            Some(match value.is_number_constant().then(|| value.number_value()) {
+                Some(AbstractRangeNumber::Long(v)) => host.factory().create_number_constant(AbstractRangeNumber::Number(v as f64), target_type),
+                _ => host.factory().create_conversion_value(value, TypeConversionRelationship::BetweenNumberTypes, false, target_type),
+            })
+        } else {
+            TypeConversions(&host).implicit_conversion(value, target_type, false)
+        };
+        let Some(conversion) = conversion else {
+            verifier.add_verify_error(&exp.location(), DiagnosticKind::IncompatibleTypes, diagnostic_arguments![Symbol(from_type), Symbol(target_type.clone())]);
+            return None;
+        };
+        verifier.ast_to_symbol.set(exp, Some(conversion.clone()));
+        Some(conversion)
+    }
+
+    fn verify_relational(&self, verifier: &mut VerifierVerifier) -> Result<Option<Symbol>, DeferVerificationError> {
+        let host = verifier.host.clone();
+        let boolean_type = host.boolean_type();
+        boolean_type.throw_if_unresolved()?;
+        let Some(left) = verifier.verify_expression(&self.left, &default())? else {
+            verifier.verify_expression(&self.right, &default())?;
+            return Ok(None);
+        };
+        let left_type = left.static_type(&host);
+        left_type.throw_if_unresolved()?;
+
+        if left_type.is_any_type() {
+            verifier.verify_expression(&self.right, &default())?;
+            return Ok(Some(host.factory().create_value(&boolean_type)));
+        }
+
+        if host.is_numeric_type(&left_type) {
+            let Some(right) = verifier.verify_expression(&self.right, &ExpressionVerifyContext { context_type: Some(left_type.clone()), ..default() })? else {
+                return Ok(None);
+            };
+            right.static_type(&host).throw_if_unresolved()?;
+            let Some((left, right, _)) = self.promote_operands(verifier, &left, &right)? else {
+                return Ok(None);
+            };
+            return Ok(Some(self.fold(verifier, &left, &right, &boolean_type)));
+        }
+        let Some(right) = verifier.limit_expression_type(&self.right, &left_type)? else {
+            return Ok(None);
+        };
+        Ok(Some(self.fold(verifier, &left, &right, &boolean_type)))
+    }
+
+    fn verify_equality(&self, verifier: &mut VerifierVerifier) -> Result<Option<Symbol>, DeferVerificationError> {
+        let host = verifier.host.clone();
+        let boolean_type = host.boolean_type();
+        boolean_type.throw_if_unresolved()?;
+        let Some(left) = verifier.verify_expression(&self.left, &default())? else {
+            verifier.verify_expression(&self.right, &default())?;
+            return Ok(None);
+        };
+        let left_type = left.static_type(&host);
+        left_type.throw_if_unresolved()?;
+        let Some(right) = verifier.verify_expression(&self.right, &ExpressionVerifyContext { context_type: Some(left_type.clone()), ..default() })? else {
+            return Ok(None);
+        };
+        let right_type = right.static_type(&host);
+        right_type.throw_if_unresolved()?;
+
+        let (left, right) = if host.is_numeric_type(&left_type) && host.is_numeric_type(&right_type) {
+            let Some((left, right, _)) = self.promote_operands(verifier, &left, &right)? else {
+                return Ok(None);
+            };
+            (left, right)
+        } else {
+            (left, right)
+        };
+
+        Ok(Some(self.fold(verifier, &left, &right, &boolean_type)))
+    }
+
+    fn verify_logical_and_or(&self, verifier: &mut VerifierVerifier, context: &ExpressionVerifyContext) -> Result<Option<Symbol>, DeferVerificationError> {
+        let host = verifier.host.clone();
+        let Some(left) = verifier.verify_expression(&self.left, &ExpressionVerifyContext { context_type: context.context_type.clone(), ..default() })? else {
+            verifier.verify_expression(&self.right, &default())?;
+            return Ok(None);
+        };
+        let left_type = left.static_type(&host);
+        left_type.throw_if_unresolved()?;
+        let narrowing = verifier.condition_narrowing(&self.left);
+        let narrowings = if self.operator == Operator::LogicalAnd { narrowing.when_true } else { narrowing.when_false };
+        let Some(right) = verifier.verify_narrowed(&narrowings, |verifier| verifier.limit_expression_type(&self.right, &left_type))? else {
+            return Ok(None);
+        };
+        Ok(Some(self.fold(verifier, &left, &right, &left_type)))
+    }
+
+    fn verify_logical_xor(&self, verifier: &mut VerifierVerifier) -> Result<Option<Symbol>, DeferVerificationError> {
+        let host = verifier.host.clone();
+        let boolean_type = host.boolean_type();
+        boolean_type.throw_if_unresolved()?;
+        let left = verifier.limit_expression_type(&self.left, &boolean_type)?;
+        let right = verifier.limit_expression_type(&self.right, &boolean_type)?;
+        let (Some(left), Some(right)) = (left, right) else {
+            return Ok(None);
+        };
+        Ok(Some(self.fold(verifier, &left, &right, &boolean_type)))
+    }
+
+    /// Verifies `x ?? y`. The result is non-nullable if `y` is
+    /// convertible to the non-nullable type of `x`.
+    fn verify_null_coalescing(&self, verifier: &mut VerifierVerifier, context: &ExpressionVerifyContext) -> Result<Option<Symbol>, DeferVerificationError> {
+        let host = verifier.host.clone();
+        let left_context_type = context.context_type.as_ref().map(|t| host.factory().create_nullable_type(t));
+        let Some(left) = verifier.verify_expression(&self.left, &ExpressionVerifyContext { context_type: left_context_type, ..default() })? else {
+            verifier.verify_expression(&self.right, &default())?;
+            return Ok(None);
+        };
+        let left_type = left.static_type(&host);
+        left_type.throw_if_unresolved()?;
+        let left_type_non_null = left_type.non_null_type();
+        let Some(right) = verifier.verify_narrowed(&[], |verifier| verifier.verify_expression(&self.right, &ExpressionVerifyContext { context_type: Some(left_type_non_null.clone()), ..default() }))? else {
+            return Ok(None);
+        };
+        let right_type = right.static_type(&host);
+        right_type.throw_if_unresolved()?;
+
+        let conversions = TypeConversions(&host);
+        let (right, result_type) = if let Some(right) = conversions.implicit_conversion(&right, &left_type_non_null, false) {
+            (right, left_type_non_null)
+        } else if let Some(right) = conversions.implicit_conversion(&right, &left_type, false) {
+            (right, left_type)
+        } else {
+            verifier.add_verify_error(&self.right.location(), DiagnosticKind::IncompatibleTypes, diagnostic_arguments![Symbol(left_type), Symbol(right_type)]);
+            return Ok(None);
+        };
+        verifier.ast_to_symbol.set(&self.right, Some(right.clone()));
+
+        if left.is_null_constant() || left.is_undefined_constant() {
+            if right.is_constant() {
+                return Ok(Some(right));
+            }
+        } else if left.is_constant() {
+            if let Some(left) = TypeConversions(&host).implicit_conversion(&left, &result_type, false) {
+                return Ok(Some(left));
+            }
+        }
+        Ok(Some(host.factory().create_value(&result_type)))
+    }
+
+    fn verify_is(&self, verifier: &mut VerifierVerifier) -> Result<Option<Symbol>, DeferVerificationError> {
+        let boolean_type = verifier.host.boolean_type();
+        boolean_type.throw_if_unresolved()?;
+        verifier.verify_expression(&self.left, &default())?;
+        verifier.verify_type_expression(&self.right)?;
+        Ok(Some(verifier.host.factory().create_value(&boolean_type)))
+    }
+
+    /// Verifies `x as T`, which results into an optional conversion
+    /// to `T?`.
+    fn verify_as(&self, verifier: &mut VerifierVerifier) -> Result<Option<Symbol>, DeferVerificationError> {
+        let host = verifier.host.clone();
+        let left = verifier.verify_expression(&self.left, &default())?;
+        let target_type = verifier.verify_type_expression(&self.right)?;
+        let (Some(left), Some(target_type)) = (left, target_type) else {
+            return Ok(None);
+        };
+        let left_type = left.static_type(&host);
+        left_type.throw_if_unresolved()?;
+        let conversion = TypeConversions(&host).explicit_conversion(&left, &target_type, true);
+        if conversion.is_none() {
+            verifier.add_verify_error(&self.left.location(), DiagnosticKind::IncompatibleTypes, diagnostic_arguments![Symbol(target_type), Symbol(left_type)]);
+            return Ok(None);
+        }
+        Ok(conversion)
+    }
+
+    /// Verifies `k in o` and `k not in o` through the `has` proxy of `o`.
+    fn verify_in(&self, verifier: &mut VerifierVerifier) -> Result<Option<Symbol>, DeferVerificationError> {
+        let host = verifier.host.clone();
+        let boolean_type = host.boolean_type();
+        boolean_type.throw_if_unresolved()?;
+        let Some(right) = verifier.verify_expression(&self.right, &default())? else {
+            verifier.verify_expression(&self.left, &default())?;
+            return Ok(None);
+        };
+        let right_type = right.static_type(&host);
+        right_type.throw_if_unresolved()?;
+
+        if !right_type.is_any_type() {
+            let proxy = if right_type.is_class_type() || right_type.is_enum_type() {
+                right_type.find_proxy(ProxyKind::Has, &host)?
+            } else {
+                None
+            };
+            let Some(proxy) = proxy else {
+                verifier.verify_expression(&self.left, &default())?;
+                verifier.add_verify_error(&self.location, DiagnosticKind::UnsupportedOperator, diagnostic_arguments![Symbol(right_type)]);
+                return Ok(None);
+            };
+            BinaryExpression::verify_proxy_call(verifier, &proxy, &self.left)?;
+        } else {
+            verifier.verify_expression(&self.left, &default())?;
+        }
+        Ok(Some(host.factory().create_value(&boolean_type)))
+    }
+}
+
+/// Whether an expression is a numeric literal, possibly negated.
+fn is_numeric_literal(exp: &Rc<Expression>) -> bool {
+    match exp.as_ref() {
+        Expression::NumericLiteral(_) => true,
+        Expression::Unary(UnaryExpression { operator: Operator::Negative, expression, .. }) => matches!(expression.as_ref(), Expression::NumericLiteral(_)),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ns::*;
+
+    #[test]
+    fn constant_folding() {
+        let host = SymbolHost::new("");
+        declare_jet_lang_classes(&host, &["Object", "Boolean", "String", "Char", "Number", "Single", "Long", "BigInt"]);
+        let verify_with_context_type = |source: &str, context_type: Option<Symbol>| {
+            let compilation_unit = CompilationUnit::new(None, source.to_owned(), &CompilerOptions::new());
+            let exp = ParserFacade::parse_expression(&compilation_unit, &host).unwrap();
+            let v = Verifier::new(&host).verify_expression(&exp, &ExpressionVerifyContext { context_type, ..default() });
+            assert!(!compilation_unit.invalidated(), "{source}");
+            v.unwrap()
+        };
+        let verify = |source: &str| verify_with_context_type(source, None);
+
+        let v = verify("1 + 2 * 3 ** 2");
+        assert!(v.static_type(&host) == host.number_type() && v.number_value() == AbstractRangeNumber::Number(19.0));
+
+        let v = verify_with_context_type("-0x8000_0000_0000_0000 + 1", Some(host.long_type()));
+        assert!(v.static_type(&host) == host.long_type() && v.number_value() == AbstractRangeNumber::Long(i64::MIN + 1));
+
+        let v = verify("(1 << 4 | 1) ^ ~0");
+        assert!(v.number_value() == AbstractRangeNumber::Number(-18.0));
+
+        let v = verify("\"a\" + \"b\" + 1");
+        assert!(v.static_type(&host) == host.string_type() && !v.is_constant());
+
+        let v = verify("\"a\" + \"b\"");
+        assert!(v.string_value() == "ab");
+
+        let v = verify("!(2 < 1) ^^ (1 / 0 === 2 / 0) ^^ (0 / 0 >= 0)");
+        assert!(v.static_type(&host) == host.boolean_type() && !v.boolean_value());
+
+        let v = verify("(null ?? true) ^^ false");
+        assert!(v.static_type(&host) == host.boolean_type() && v.boolean_value());
+
+        let v = verify("1 < 2 && \"a\" != \"b\"");
+        assert!(v.boolean_value());
+    }
+    #[test]
+    fn numeric_promotion() {
+        let host = SymbolHost::new("");
+        declare_jet_lang_classes(&host, &["Object", "Boolean", "String", "Number", "Single", "Long", "BigInt", "Function", "Class", "Array"]);
+
+        let source = r#"
+            package p {
+                public function f(n: Number, s: Single, l: Long, b: BigInt): void {
+                    const a = 1 + l
+                    const a1: Long = a
+                    const c = -2 * s
+                    const c1: Single = c
+                    const d: Number = l * n + s - l
+                    const e: BigInt = b + l
+                    const g = b + n
+                    const h = 0x7FFF_FFFF_FFFF_FFFF / l
+                }
+            }
+        "#;
+        let compilation_unit = CompilationUnit::new(None, source.to_owned(), &CompilerOptions::new());
+        let program = ParserFacade::parse_program(&compilation_unit, &host).unwrap();
+        let mut verifier = Verifier::new(&host);
+        verifier.verify_programs(vec![program.clone()]);
+
+        let diagnostics = compilation_unit.diagnostics().iter().map(|d| (d.id(), d.location().first_line_number())).collect::<Vec<_>>();
+        assert_eq!(diagnostics, [(DiagnosticKind::IncompatibleTypes.id(), 10)]);
+    }
 }
\ No newline at end of file
@@ -7,4 +7,321 @@ pub struct CallExpression {
     pub location: Location,
     pub base: Rc<Expression>,
     pub arguments: Vec<Rc<Expression>>,
+}
+
+impl CallExpression {
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier) -> Result<Option<Symbol>, DeferVerificationError> {
+        let host = verifier.host.clone();
+
+        // Type arguments of a type parameterized function may be inferred.
+        let Some(base) = verifier.verify_expression(&self.base, &ExpressionVerifyContext { followed_by_type_arguments: true, ..default() })? else {
+            for argument in &self.arguments {
+                verifier.verify_expression(argument, &default())?;
+            }
+            return Ok(None);
+        };
+
+        // Conversion, as in `T(v)`
+        if let Ok(target_type) = base.expect_type() {
+            if CallExpression::is_type_parameterized_origin(&target_type) && !matches!(self.base.as_ref(), Expression::WithTypeArguments(_)) {
+                verifier.add_verify_error(&self.base.location(), DiagnosticKind::TypeParameterizedPropertyMustBeArgumented, diagnostic_arguments![]);
+            }
+            if self.arguments.len() != 1 {
+                for argument in &self.arguments {
+                    verifier.verify_expression(argument, &default())?;
+                }
+                verifier.add_verify_error(&self.location, DiagnosticKind::WrongNumberOfArguments, diagnostic_arguments![String("1".into())]);
+                return Ok(None);
+            }
+            let argument = &self.arguments[0];
+            let Some(v) = verifier.verify_expression(argument, &ExpressionVerifyContext { context_type: Some(target_type.clone()), ..default() })? else {
+                return Ok(None);
+            };
+            let v_type = v.static_type(&host);
+            v_type.throw_if_unresolved()?;
+            let Some(v) = TypeConversions(&host).explicit_conversion(&v, &target_type, false) else {
+                verifier.add_verify_error(&argument.location(), DiagnosticKind::IncompatibleTypes, diagnostic_arguments![Symbol(target_type), Symbol(v_type)]);
+                return Ok(None);
+            };
+            return Ok(Some(v));
+        }
+
+        let base_type = base.static_type(&host);
+        base_type.throw_if_unresolved()?;
+
+        // Dynamic call
+        if base_type.is_any_type() || base_type == host.function_type() {
+            for argument in &self.arguments {
+                verifier.verify_expression(argument, &default())?;
+            }
+            return Ok(Some(host.factory().create_value(&host.any_type())));
+        }
+
+        if !base_type.is_function_type() {
+            for argument in &self.arguments {
+                verifier.verify_expression(argument, &default())?;
+            }
+            verifier.add_verify_error(&self.base.location(), DiagnosticKind::NotCallable, diagnostic_arguments![Symbol(base_type)]);
+            return Ok(None);
+        }
+
+        let mut signature = base_type;
+
+        // Infer type arguments
+        if base.is_reference_value() && (base.is_static_reference_value() || base.is_instance_reference_value() || base.is_scope_reference_value() || base.is_package_reference_value()) {
+            let function = base.property();
+            if function.is_origin_function() && function.type_parameters().is_some() {
+                let Some(function) = CallExpression::infer_type_arguments(verifier, &self.location, &function, &self.arguments)? else {
+                    for argument in &self.arguments {
+                        verifier.verify_expression(argument, &default())?;
+                    }
+                    return Ok(None);
+                };
+                verifier.ast_to_symbol.set(&self.base, Some(CallExpression::substitute_referenced_property(&host, &base, &function)));
+                signature = function.signature(&host);
+            }
+        }
+
+        CallExpression::verify_arguments(verifier, &self.location, &self.arguments, &signature)?;
+        Ok(Some(host.factory().create_value(&signature.result_type())))
+    }
+
+    fn is_type_parameterized_origin(symbol: &Symbol) -> bool {
+        (symbol.is_origin_class_type() || symbol.is_origin_interface_type()) && symbol.type_parameters().is_some()
+    }
+
+    /// Verifies arguments against the parameters of a function type,
+    /// naming the parameter in errors.
+    pub(crate) fn verify_arguments(verifier: &mut VerifierVerifier, location: &Location, arguments: &[Rc<Expression>], signature: &Symbol) -> Result<(), DeferVerificationError> {
+        let host = verifier.host.clone();
+        let mut arguments = arguments.iter();
+        for parameter in signature.parameters().iter() {
+            match parameter.kind {
+                ParameterKind::Required | ParameterKind::Optional => {
+                    let Some(argument) = arguments.next() else {
+                        if parameter.kind == ParameterKind::Required {
+                            verifier.add_verify_error(location, DiagnosticKind::MissingArgument, diagnostic_arguments![String(parameter.name.clone())]);
+                        }
+                        break;
+                    };
+                    CallExpression::limit_argument(verifier, argument, &parameter.name, &parameter.static_type)?;
+                },
+                ParameterKind::Rest => {
+                    let element_type = parameter.static_type.array_element_type(&host)?.unwrap_or(host.any_type());
+                    for argument in arguments.by_ref() {
+                        CallExpression::limit_argument(verifier, argument, &parameter.name, &element_type)?;
+                    }
+                },
+            }
+        }
+        let rest = arguments.collect::<Vec<_>>();
+        if !rest.is_empty() {
+            for argument in &rest {
+                verifier.verify_expression(argument, &default())?;
+            }
+            let parameter_count = signature.parameters().length().to_string();
+            verifier.add_verify_error(&rest[0].location(), DiagnosticKind::WrongNumberOfArguments, diagnostic_arguments![String(parameter_count)]);
+        }
+        Ok(())
+    }
+
+    fn limit_argument(verifier: &mut VerifierVerifier, argument: &Rc<Expression>, parameter_name: &str, parameter_type: &Symbol) -> Result<(), DeferVerificationError> {
+        let Some(v) = verifier.verify_expression(argument, &ExpressionVerifyContext { context_type: Some(parameter_type.clone()), ..default() })? else {
+            return Ok(());
+        };
+        let v_type = v.static_type(&verifier.host);
+        v_type.throw_if_unresolved()?;
+        let Some(v) = TypeConversions(&verifier.host).implicit_conversion(&v, parameter_type, false) else {
+            verifier.add_verify_error(&argument.location(), DiagnosticKind::IncompatibleArgument, diagnostic_arguments![String(parameter_name.to_owned()), Symbol(parameter_type.clone()), Symbol(v_type)]);
+            verifier.ast_to_symbol.set(argument, None);
+            return Ok(());
+        };
+        verifier.ast_to_symbol.set(argument, Some(v));
+        Ok(())
+    }
+
+    /// Infers the type arguments of a type parameterized function from
+    /// the static types of the arguments and returns the function after
+    /// type substitution.
+    ///
+    /// Only the arguments whose parameter mentions type parameters not yet
+    /// inferred are verified here, without a context type. The remaining
+    /// arguments are verified once by `verify_arguments` against the
+    /// parameter type after substitution, as in `f(Long(1), 2)`
+    /// where `f` takes two parameters of type `T`.
+    fn infer_type_arguments(verifier: &mut VerifierVerifier, location: &Location, function: &Symbol, arguments: &[Rc<Expression>]) -> Result<Option<Symbol>, DeferVerificationError> {
+        let host = verifier.host.clone();
+        let type_parameters = function.type_parameters().unwrap();
+        let signature = function.signature(&host);
+        signature.throw_if_unresolved()?;
+        let mut inferred: Vec<Option<Symbol>> = type_parameters.iter().map(|_| None).collect();
+
+        let mut arguments_iter = arguments.iter();
+        for parameter in signature.parameters().iter() {
+            let (parameter_type, arguments) = match parameter.kind {
+                ParameterKind::Rest => {
+                    let element_type = parameter.static_type.array_element_type(&host)?.unwrap_or(host.any_type());
+                    (element_type, arguments_iter.by_ref().collect::<Vec<_>>())
+                },
+                _ => (parameter.static_type.clone(), arguments_iter.next().into_iter().collect()),
+            };
+            for argument in arguments {
+                let pending_type_parameters: SharedArray<Symbol> = type_parameters.iter().zip(&inferred)
+                    .filter(|(_, t)| t.is_none())
+                    .map(|(type_parameter, _)| type_parameter)
+                    .collect();
+                if !CallExpression::mentions_type_parameters(&parameter_type, &pending_type_parameters) {
+                    continue;
+                }
+                let Some(v) = verifier.verify_expression(argument, &default())? else {
+                    continue;
+                };
+                let v_type = v.static_type(&host);
+                v_type.throw_if_unresolved()?;
+                CallExpression::unify(&parameter_type, &v_type, &type_parameters, &mut inferred);
+            }
+        }
+
+        let mut substitute_types = vec![];
+        for (type_parameter, t) in type_parameters.iter().zip(inferred) {
+            let Some(t) = t else {
+                verifier.add_verify_error(location, DiagnosticKind::CouldNotInferTypeArgument, diagnostic_arguments![Symbol(type_parameter)]);
+                return Ok(None);
+            };
+            substitute_types.push(t);
+        }
+        let substitute_types: SharedArray<Symbol> = substitute_types.into_iter().collect();
+        Ok(Some(TypeSubstitution(&host).execute(function, &type_parameters, &substitute_types)))
+    }
+
+    fn mentions_type_parameters(t: &Symbol, type_parameters: &SharedArray<Symbol>) -> bool {
+        if t.is_type_parameter_type() {
+            type_parameters.includes(t)
+        } else if t.is_nullable_type() {
+            CallExpression::mentions_type_parameters(&t.base(), type_parameters)
+        } else if t.is_type_after_explicit_type_substitution() {
+            t.substitute_types().iter().any(|t| CallExpression::mentions_type_parameters(&t, type_parameters))
+        } else if t.is_tuple_type() {
+            t.element_types().iter().any(|t| CallExpression::mentions_type_parameters(&t, type_parameters))
+        } else if t.is_function_type() {
+            t.parameters().iter().any(|p| CallExpression::mentions_type_parameters(&p.static_type, type_parameters))
+                || CallExpression::mentions_type_parameters(&t.result_type(), type_parameters)
+        } else {
+            false
+        }
+    }
+
+    /// Matches a parameter type against an argument type, inferring
+    /// the first type found for each type parameter.
+    fn unify(parameter_type: &Symbol, argument_type: &Symbol, type_parameters: &SharedArray<Symbol>, inferred: &mut Vec<Option<Symbol>>) {
+        if parameter_type.is_type_parameter_type() {
+            if let Some(i) = type_parameters.index_of(parameter_type) {
+                if inferred[i].is_none() {
+                    inferred[i] = Some(argument_type.clone());
+                }
+            }
+        } else if parameter_type.is_nullable_type() {
+            CallExpression::unify(&parameter_type.base(), &argument_type.non_null_type(), type_parameters, inferred);
+        } else if parameter_type.is_type_after_explicit_type_substitution() {
+            if argument_type.is_type_after_explicit_type_substitution() && parameter_type.origin() == argument_type.origin() {
+                for (p, a) in parameter_type.substitute_types().iter().zip(argument_type.substitute_types().iter()) {
+                    CallExpression::unify(&p, &a, type_parameters, inferred);
+                }
+            }
+        } else if parameter_type.is_tuple_type() {
+            if argument_type.is_tuple_type() && parameter_type.element_types().length() == argument_type.element_types().length() {
+                for (p, a) in parameter_type.element_types().iter().zip(argument_type.element_types().iter()) {
+                    CallExpression::unify(&p, &a, type_parameters, inferred);
+                }
+            }
+        } else if parameter_type.is_function_type() && argument_type.is_function_type() {
+            for (p, a) in parameter_type.parameters().iter().zip(argument_type.parameters().iter()) {
+                CallExpression::unify(&p.static_type, &a.static_type, type_parameters, inferred);
+            }
+            CallExpression::unify(&parameter_type.result_type(), &argument_type.result_type(), type_parameters, inferred);
+        }
+    }
+
+    /// Returns a reference value equivalent to `reference`, but referring
+    /// to a function after type substitution.
+    pub(crate) fn substitute_referenced_property(host: &SymbolHost, reference: &Symbol, property: &Symbol) -> Symbol {
+        let base = reference.base();
+        if reference.is_static_reference_value() {
+            host.factory().create_static_reference_value(&base, property)
+        } else if reference.is_instance_reference_value() {
+            host.factory().create_instance_reference_value(&base, property)
+        } else if reference.is_scope_reference_value() {
+            host.factory().create_scope_reference_value(&base, property)
+        } else {
+            host.factory().create_package_reference_value(&base, property)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ns::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn signature_checking() {
+        let host = SymbolHost::new("");
+        declare_jet_lang_classes(&host, &["Object", "Boolean", "String", "Char", "Number", "Long", "Function", "Class", "Array"]);
+
+        // function identity.<T>(value: T, label?: String): T
+        let t = host.factory().create_type_parameter_type("T".into());
+        let signature = host.factory().create_function_type(vec![
+            Rc::new(ParameterOfFunctionType { kind: ParameterKind::Required, name: "value".into(), static_type: t.clone() }),
+            Rc::new(ParameterOfFunctionType { kind: ParameterKind::Optional, name: "label".into(), static_type: host.string_type() }),
+        ], t.clone());
+        let identity = host.factory().create_function("identity".into(), &signature);
+        identity.set_type_parameters(Some(&SharedArray::from(vec![t])));
+        identity.set_parent_definition(Some(&host.jet_lang_package()));
+        identity.set_visibility(Visibility::Public);
+        host.jet_lang_package().properties(&host).set("identity".into(), identity);
+
+        let verify = |source: &str| {
+            let compilation_unit = CompilationUnit::new(None, source.to_owned(), &CompilerOptions::new());
+            let exp = ParserFacade::parse_expression(&compilation_unit, &host).unwrap();
+            let v = Verifier::new(&host).verify_expression(&exp, &default());
+            (v, compilation_unit.diagnostics().iter().map(|d| d.kind()).collect::<Vec<_>>())
+        };
+
+        let (v, diagnostics) = verify("identity(10)");
+        assert!(diagnostics.is_empty());
+        assert!(v.unwrap().static_type(&host) == host.number_type());
+
+        let (v, diagnostics) = verify("identity.<String>(\"x\", \"label\")");
+        assert!(diagnostics.is_empty());
+        assert!(v.unwrap().static_type(&host) == host.string_type());
+
+        let (_, diagnostics) = verify("identity.<String>(10)");
+        assert!(diagnostics == [DiagnosticKind::IncompatibleArgument]);
+
+        let (_, diagnostics) = verify("identity.<String>()");
+        assert!(diagnostics == [DiagnosticKind::MissingArgument]);
+
+        let (_, diagnostics) = verify("identity(1, \"a\", 2)");
+        assert!(diagnostics == [DiagnosticKind::WrongNumberOfArguments]);
+
+        // function same.<T>(a: T, b: T): T
+        let t = host.factory().create_type_parameter_type("T".into());
+        let signature = host.factory().create_function_type(vec![
+            Rc::new(ParameterOfFunctionType { kind: ParameterKind::Required, name: "a".into(), static_type: t.clone() }),
+            Rc::new(ParameterOfFunctionType { kind: ParameterKind::Required, name: "b".into(), static_type: t.clone() }),
+        ], t.clone());
+        let same = host.factory().create_function("same".into(), &signature);
+        same.set_type_parameters(Some(&SharedArray::from(vec![t])));
+        same.set_parent_definition(Some(&host.jet_lang_package()));
+        same.set_visibility(Visibility::Public);
+        host.jet_lang_package().properties(&host).set("same".into(), same);
+
+        // The second argument is verified against the inferred type.
+        let (v, diagnostics) = verify("same(Long(1), 2)");
+        assert!(diagnostics.is_empty());
+        assert!(v.unwrap().static_type(&host) == host.long_type());
+
+        let (_, diagnostics) = verify("same(Long(1), \"a\")");
+        assert!(diagnostics == [DiagnosticKind::IncompatibleArgument]);
+    }
 }
\ No newline at end of file
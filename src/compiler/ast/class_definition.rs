@@ -13,4 +13,242 @@ pub struct ClassDefinition {
     pub extends_clause: Option<Rc<Expression>>,
     pub implements_clause: Option<Vec<Rc<Expression>>>,
     pub block: Rc<Block>,
+}
+
+impl ClassDefinition {
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier, drtv: &Rc<Directive>, phase: VerifierPhase) -> Result<(), DeferVerificationError> {
+        if phase == VerifierPhase::Declaration && !verifier.ast_to_symbol.has(drtv) {
+            let class = self.declare(verifier);
+            verifier.ast_to_symbol.set(drtv, class);
+        }
+        let Some(class) = verifier.ast_to_symbol.get(drtv) else {
+            return Ok(());
+        };
+
+        let scope = verifier.ast_to_symbol.get(&self.block).unwrap();
+        verifier.enter_scope(&scope);
+        let result = match phase {
+            VerifierPhase::Heritage => self.verify_heritage(verifier, &class),
+            VerifierPhase::Bodies => self.verify_implementation(verifier, &class),
+            _ => Ok(()),
+        };
+        // The class block is verified once the class itself
+        // is resolved at the given phase.
+        if result.is_ok() {
+            verifier.verify_directives(&self.block.directives, phase);
+        }
+        verifier.exit_scope();
+        result
+    }
+
+    /// Creates the class symbol and its scope and declares it in the
+    /// enclosing definition. Returns `None` if the definition is a duplicate.
+    fn declare(&self, verifier: &mut VerifierVerifier) -> Option<Symbol> {
+        let host = verifier.host.clone();
+        let (name, name_location) = self.name.clone();
+        let (parent, mut properties) = verifier.definition_destination(false);
+        if properties.has(&name) {
+            verifier.add_verify_error(&name_location, DiagnosticKind::DuplicateDefinition, diagnostic_arguments![String(name)]);
+            return None;
+        }
+
+        let class = host.factory().create_class_type(name.clone());
+        class.set_location(Some(name_location));
+        class.set_jetdoc(self.jetdoc.clone());
+        class.set_visibility(Attribute::visibility(&self.attributes, false));
+        class.set_parent_definition(Some(&parent));
+        class.set_is_abstract(Attribute::find_abstract(&self.attributes).is_some());
+        class.set_is_final(Attribute::find_final(&self.attributes).is_some());
+        class.set_is_static(Attribute::find_static(&self.attributes).is_some());
+        class.set_is_limited(Attribute::find_metadata(&self.attributes).iter().any(|(metadata, _)| metadata.name == "Limited"));
+        class.set_allow_literal(self.allow_literal);
+
+        let scope = host.factory().create_class_scope(&class);
+        scope.set_parent_scope(Some(&verifier.scope));
+        if let Some(type_parameters) = &self.type_parameters {
+            let type_parameters: SharedArray<Symbol> = type_parameters.iter().map(|p| host.factory().create_type_parameter_type(p.name.0.clone())).collect();
+            for type_parameter in type_parameters.iter() {
+                scope.properties(&host).set(type_parameter.name(), type_parameter.clone());
+            }
+            class.set_type_parameters(Some(&type_parameters));
+        }
+        verifier.ast_to_symbol.set(&self.block, Some(scope));
+
+        properties.set(name, class.clone());
+        Some(class)
+    }
+
+    /// Returns the origin of a type after explicit type substitution,
+    /// or else the type itself.
+    fn origin_type(t: &Symbol) -> Symbol {
+        if t.is_type_after_explicit_type_substitution() {
+            t.origin()
+        } else {
+            t.clone()
+        }
+    }
+
+    /// Resolves the base class and the implemented interfaces. Every
+    /// type is resolved before anything is reported, so that a deferred
+    /// class does not report the same error twice.
+    fn verify_heritage(&self, verifier: &mut VerifierVerifier, class: &Symbol) -> Result<(), DeferVerificationError> {
+        let host = verifier.host.clone();
+
+        let extends_type = match &self.extends_clause {
+            Some(exp) => verifier.verify_type_expression(exp)?,
+            None => None,
+        };
+        let mut implements_types: Vec<(Rc<Expression>, Symbol)> = vec![];
+        for exp in self.implements_clause.iter().flatten() {
+            if let Some(t) = verifier.verify_type_expression(exp)? {
+                implements_types.push((exp.clone(), t));
+            }
+        }
+
+        // A class extends `Object` by default.
+        if self.extends_clause.is_none() {
+            let object_type = host.object_type();
+            object_type.throw_if_unresolved()?;
+            if &object_type != class {
+                class.set_extends_class(Some(&object_type));
+            }
+        } else if let (Some(t), Some(extends_clause)) = (extends_type, &self.extends_clause) {
+            let location = extends_clause.location();
+            if !t.is_class_type() {
+                verifier.add_verify_error(&location, DiagnosticKind::NotAClass, diagnostic_arguments![Symbol(t)]);
+            } else if t.is_final() {
+                verifier.add_verify_error(&location, DiagnosticKind::ExtendingFinalClass, diagnostic_arguments![Symbol(t)]);
+            } else if t.descending_class_hierarchy(&host).any(|t| &t == class || (t.is_type_after_explicit_type_substitution() && &t.origin() == class)) {
+                verifier.add_verify_error(&location, DiagnosticKind::CyclicInheritance, diagnostic_arguments![Symbol(class.clone())]);
+            } else {
+                class.set_extends_class(Some(&t));
+                if t.is_limited() {
+                    Self::origin_type(&t).limited_subclasses().push(class.clone());
+                }
+            }
+        }
+
+        let mut implements = class.implements(&host);
+        for (exp, t) in implements_types {
+            if !t.is_interface_type() {
+                verifier.add_verify_error(&exp.location(), DiagnosticKind::NotAnInterface, diagnostic_arguments![Symbol(t)]);
+                continue;
+            }
+            if t.is_limited() {
+                Self::origin_type(&t).limited_implementors().push(class.clone());
+            }
+            implements.push(t);
+        }
+
+        Ok(())
+    }
+
+    /// Reports abstract methods that a concrete class leaves unimplemented
+    /// and members missing from implemented interfaces.
+    fn verify_implementation(&self, verifier: &mut VerifierVerifier, class: &Symbol) -> Result<(), DeferVerificationError> {
+        let host = verifier.host.clone();
+
+        let not_overriden = if class.is_abstract() { vec![] } else { MethodOverriding(&host).abstract_methods_not_overriden(class)? };
+        let mut logs: Vec<(Symbol, Vec<InterfaceImplementationLog>)> = vec![];
+        for interface in class.implements(&host).iter() {
+            logs.push((interface.clone(), InterfaceImplementations(&host).verify(class, &interface)?));
+        }
+
+        let location = &self.name.1;
+        for method in not_overriden {
+            verifier.add_verify_error(location, DiagnosticKind::AbstractMethodNotImplemented, diagnostic_arguments![String(method.name())]);
+        }
+        for (interface, log) in logs {
+            for entry in log {
+                let (kind, arguments) = match entry {
+                    InterfaceImplementationLog::UnimplementedMethod { name } =>
+                        (DiagnosticKind::UnimplementedInterfaceMethod, diagnostic_arguments![String(name), Symbol(interface.clone())]),
+                    InterfaceImplementationLog::UnimplementedGetter { name } =>
+                        (DiagnosticKind::UnimplementedInterfaceGetter, diagnostic_arguments![String(name), Symbol(interface.clone())]),
+                    InterfaceImplementationLog::UnimplementedSetter { name } =>
+                        (DiagnosticKind::UnimplementedInterfaceSetter, diagnostic_arguments![String(name), Symbol(interface.clone())]),
+                    InterfaceImplementationLog::PropertyMustBeMethod { name } =>
+                        (DiagnosticKind::InterfacePropertyMustBeMethod, diagnostic_arguments![String(name), Symbol(interface.clone())]),
+                    InterfaceImplementationLog::PropertyMustBeVirtualProperty { name } =>
+                        (DiagnosticKind::InterfacePropertyMustBeVirtualProperty, diagnostic_arguments![String(name), Symbol(interface.clone())]),
+                    InterfaceImplementationLog::WrongMethodSignature { name, expected_signature } =>
+                        (DiagnosticKind::WrongInterfaceMethodSignature, diagnostic_arguments![String(name), Symbol(expected_signature), Symbol(interface.clone())]),
+                    InterfaceImplementationLog::WrongGetterSignature { name, expected_signature } =>
+                        (DiagnosticKind::WrongInterfaceGetterSignature, diagnostic_arguments![String(name), Symbol(expected_signature), Symbol(interface.clone())]),
+                    InterfaceImplementationLog::WrongSetterSignature { name, expected_signature } =>
+                        (DiagnosticKind::WrongInterfaceSetterSignature, diagnostic_arguments![String(name), Symbol(expected_signature), Symbol(interface.clone())]),
+                    InterfaceImplementationLog::WrongVisibility { name, expected_visibility } =>
+                        (DiagnosticKind::WrongInterfaceImplementationVisibility, diagnostic_arguments![String(name), String(expected_visibility.to_string()), Symbol(interface.clone())]),
+                    InterfaceImplementationLog::NonConformingTypeParameters { name } =>
+                        (DiagnosticKind::NonConformingInterfaceTypeParameters, diagnostic_arguments![String(name), Symbol(interface.clone())]),
+                };
+                verifier.add_verify_error(location, kind, arguments);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::ns::*;
+
+    #[test]
+    fn class_definitions() {
+        let host = SymbolHost::new("");
+        declare_jet_lang_classes(&host, &["Object", "Boolean", "String", "Number", "Function", "Class", "Array"]);
+        let interface = host.factory().create_interface_type("I".to_owned());
+        interface.set_parent_definition(Some(&host.jet_lang_package()));
+        interface.set_visibility(Visibility::Public);
+        host.jet_lang_package().properties(&host).set("I".to_owned(), interface.clone());
+        let m = host.factory().create_function("m".to_owned(), &host.factory().create_function_type(vec![], host.void_type()));
+        m.set_parent_definition(Some(&interface));
+        m.set_visibility(Visibility::Public);
+        interface.prototype(&host).set("m".to_owned(), m);
+
+        let source = r#"
+            final class A {}
+            class B extends A {}
+            abstract class C {
+                abstract function f(): void;
+                function g(): Number { return 0 }
+                final function h(): void {}
+            }
+            class D extends C {
+                override function g(): String { return "" }
+                override function h(): void {}
+                override function k(): void {}
+            }
+            class E extends C implements I {
+                override function f(): void {}
+            }
+            class F extends G {}
+            class G extends F {}
+        "#;
+        let compilation_unit = CompilationUnit::new(None, source.to_owned(), &CompilerOptions::new());
+        let program = ParserFacade::parse_program(&compilation_unit, &host).unwrap();
+        let mut verifier = Verifier::new(&host);
+        verifier.verify_programs(vec![program.clone()]);
+
+        let mut diagnostics = compilation_unit.diagnostics().iter().map(|d| d.id()).collect::<Vec<_>>();
+        diagnostics.sort();
+        assert_eq!(diagnostics, [
+            DiagnosticKind::ExtendingFinalClass.id(),
+            DiagnosticKind::CyclicInheritance.id(),
+            DiagnosticKind::MustOverrideAMethod.id(),
+            DiagnosticKind::IncompatibleOverride.id(),
+            DiagnosticKind::OverridingFinalMethod.id(),
+            DiagnosticKind::AbstractMethodNotImplemented.id(),
+            DiagnosticKind::UnimplementedInterfaceMethod.id(),
+        ]);
+
+        let class_c = verifier.ast_to_symbol().get(&program.directives[2]).unwrap();
+        let class_e = verifier.ast_to_symbol().get(&program.directives[4]).unwrap();
+        assert_eq!(class_e.extends_class(&host), Some(class_c.clone()));
+        assert!(class_e.implements(&host).includes(&interface));
+        let f = class_e.prototype(&host).get(&"f".to_owned()).unwrap();
+        assert_eq!(f.overrides_method(&host), class_c.prototype(&host).get(&"f".to_owned()));
+    }
 }
\ No newline at end of file
@@ -7,4 +7,17 @@ pub struct ComputedMemberExpression {
     pub location: Location,
     pub base: Rc<Expression>,
     pub key: Rc<Expression>,
+}
+
+impl ComputedMemberExpression {
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier, context: &ExpressionVerifyContext) -> Result<Option<Symbol>, DeferVerificationError> {
+        let base = verifier.verify_expression(&self.base, &default())?;
+        let key = verifier.verify_expression(&self.key, &default())?;
+        let (Some(base), Some(key)) = (base, key) else {
+            return Ok(None);
+        };
+        key.static_type(&verifier.host).throw_if_unresolved()?;
+        let base = MemberExpression::property_base(&base);
+        QualifiedIdentifier::verify_property_resolution(verifier, &self.location, &base, None, SemanticPropertyKey::Value(key), PropertyDisambiguation::Default, context)
+    }
 }
\ No newline at end of file
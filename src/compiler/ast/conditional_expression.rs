@@ -8,4 +8,97 @@ pub struct ConditionalExpression {
     pub test: Rc<Expression>,
     pub consequent: Rc<Expression>,
     pub alternative: Rc<Expression>,
+}
+impl ConditionalExpression {
+    /// Verifies `test ? consequent : alternative`. Given a context type,
+    /// both branches are converted to it; otherwise the result type is that
+    /// of the consequent if the alternative is implicitly convertible to it,
+    /// otherwise that of the alternative.
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier, context: &ExpressionVerifyContext) -> Result<Option<Symbol>, DeferVerificationError> {
+        let host = verifier.host.clone();
+        let test = verifier.verify_condition(&self.test)?;
+        let narrowing = if test.is_some() { verifier.condition_narrowing(&self.test) } else { default() };
+        let branch_context = ExpressionVerifyContext { context_type: context.context_type.clone(), ..default() };
+        let consequent = verifier.verify_narrowed(&narrowing.when_true, |verifier| verifier.verify_expression(&self.consequent, &branch_context))?;
+        let alternative = verifier.verify_narrowed(&narrowing.when_false, |verifier| verifier.verify_expression(&self.alternative, &branch_context))?;
+        let (Some(consequent), Some(alternative)) = (consequent, alternative) else {
+            return Ok(None);
+        };
+        let consequent_type = consequent.static_type(&host);
+        consequent_type.throw_if_unresolved()?;
+        let alternative_type = alternative.static_type(&host);
+        alternative_type.throw_if_unresolved()?;
+
+        let conversions = TypeConversions(&host);
+        let (consequent, alternative, result_type) = if let Some(context_type) = &context.context_type {
+            let consequent = Self::convert_branch(verifier, &self.consequent, &consequent, context_type);
+            let alternative = Self::convert_branch(verifier, &self.alternative, &alternative, context_type);
+            let (Some(consequent), Some(alternative)) = (consequent, alternative) else {
+                return Ok(None);
+            };
+            (consequent, alternative, context_type.clone())
+        } else if let Some(alternative) = conversions.implicit_conversion(&alternative, &consequent_type, false) {
+            verifier.ast_to_symbol.set(&self.alternative, Some(alternative.clone()));
+            (consequent, alternative, consequent_type)
+        } else if let Some(consequent) = conversions.implicit_conversion(&consequent, &alternative_type, false) {
+            verifier.ast_to_symbol.set(&self.consequent, Some(consequent.clone()));
+            (consequent, alternative, alternative_type)
+        } else {
+            verifier.add_verify_error(&self.alternative.location(), DiagnosticKind::IncompatibleTypes, diagnostic_arguments![Symbol(consequent_type), Symbol(alternative_type)]);
+            return Ok(None);
+        };
+
+        if let Some(test) = test {
+            if let Some(v) = ConstantEvaluator(&host).conditional(&test, &consequent, &alternative) {
+                return Ok(Some(v));
+            }
+        }
+        Ok(Some(host.factory().create_value(&result_type)))
+    }
+
+    /// Converts a verified branch to the context type, reporting
+    /// the branch if it is not implicitly convertible.
+    fn convert_branch(verifier: &mut VerifierVerifier, exp: &Rc<Expression>, v: &Symbol, context_type: &Symbol) -> Option<Symbol> {
+        let Some(v_1) = TypeConversions(&verifier.host).implicit_conversion(v, context_type, false) else {
+            let t = v.static_type(&verifier.host);
+            verifier.add_verify_error(&exp.location(), DiagnosticKind::IncompatibleTypes, diagnostic_arguments![Symbol(context_type.clone()), Symbol(t)]);
+            return None;
+        };
+        verifier.ast_to_symbol.set(exp, Some(v_1.clone()));
+        Some(v_1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ns::*;
+
+    #[test]
+    fn conditional_context_type() {
+        let host = SymbolHost::new("");
+        declare_jet_lang_classes(&host, &["Object", "Boolean", "String", "Number", "Function", "Class", "Array"]);
+
+        let source = r#"
+            package p {
+                public function f(t: Boolean): void {
+                    const a: Number = t ? 1 : "x";
+                    const b: String = t ? 1 : "x";
+                    const c: Number? = t ? 1 : null;
+                    const d: Number = (t ? 1 : null) ?? 0;
+                    const e = t ? 1 : "x";
+                }
+            }
+        "#;
+        let compilation_unit = CompilationUnit::new(None, source.to_owned(), &CompilerOptions::new());
+        let program = ParserFacade::parse_program(&compilation_unit, &host).unwrap();
+        let mut verifier = Verifier::new(&host);
+        verifier.verify_programs(vec![program.clone()]);
+
+        let diagnostics = compilation_unit.diagnostics().iter().map(|d| (d.id(), d.location().first_line_number(), d.location().first_column())).collect::<Vec<_>>();
+        assert_eq!(diagnostics, [
+            (DiagnosticKind::IncompatibleTypes.id(), 4, 46),
+            (DiagnosticKind::IncompatibleTypes.id(), 5, 42),
+            (DiagnosticKind::IncompatibleTypes.id(), 8, 38),
+        ]);
+    }
 }
\ No newline at end of file
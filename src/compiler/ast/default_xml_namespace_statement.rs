@@ -6,4 +6,29 @@ use std::rc::Rc;
 pub struct DefaultXmlNamespaceStatement {
     pub location: Location,
     pub right: Rc<Expression>,
+}
+
+impl DefaultXmlNamespaceStatement {
+    /// Verifies the assigned namespace, which is either a `Namespace` or
+    /// a `String`, and records it in the enclosing activation, so that
+    /// subsequent unqualified element names of `XML` values are qualified by it.
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier) -> Result<(), DeferVerificationError> {
+        let host = verifier.host.clone();
+        let Some(right) = verifier.verify_expression(&self.right, &default())? else {
+            return Ok(());
+        };
+        let right_type = right.static_type(&host);
+        right_type.throw_if_unresolved()?;
+        let namespace_type = host.namespace_type();
+        namespace_type.throw_if_unresolved()?;
+        let t = right_type.non_null_type();
+        if !(t.is_any_type() || t == namespace_type || t == host.string_type()) {
+            verifier.add_verify_error(&self.right.location(), DiagnosticKind::IncompatibleTypes, diagnostic_arguments![Symbol(namespace_type), Symbol(right_type)]);
+            return Ok(());
+        }
+        if let Some(activation) = verifier.scope.find_activation() {
+            activation.set_default_xml_namespace(Some(&right));
+        }
+        Ok(())
+    }
 }
\ No newline at end of file
@@ -7,4 +7,15 @@ pub struct DescendantsExpression {
     pub location: Location,
     pub base: Rc<Expression>,
     pub identifier: QualifiedIdentifier,
+}
+
+impl DescendantsExpression {
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier) -> Result<Option<Symbol>, DeferVerificationError> {
+        let base = verifier.verify_expression(&self.base, &default())?;
+        self.identifier.verify(verifier)?;
+        let Some(base) = base else {
+            return Ok(None);
+        };
+        FilterExpression::verify_xml_base(verifier, &self.base, &base)
+    }
 }
\ No newline at end of file
@@ -32,6 +32,7 @@ pub enum Directive {
     EnumDefinition(EnumDefinition),
     InterfaceDefinition(InterfaceDefinition),
     TypeDefinition(TypeDefinition),
+    Invalid(InvalidDirective),
 }
 
 impl Directive {
@@ -65,6 +66,21 @@ impl Directive {
             Self::EnumDefinition(d) => d.location.clone(),
             Self::InterfaceDefinition(d) => d.location.clone(),
             Self::TypeDefinition(d) => d.location.clone(),
+            Self::Invalid(d) => d.location.clone(),
+        }
+    }
+
+    /// The JetDoc comment attached to the directive, if any.
+    pub fn jetdoc(&self) -> Option<Rc<JetDoc>> {
+        match self {
+            Self::UseDirective(d) => d.jetdoc.clone(),
+            Self::VariableDefinition(d) => d.jetdoc.clone(),
+            Self::FunctionDefinition(d) => d.jetdoc.clone(),
+            Self::ClassDefinition(d) => d.jetdoc.clone(),
+            Self::EnumDefinition(d) => d.jetdoc.clone(),
+            Self::InterfaceDefinition(d) => d.jetdoc.clone(),
+            Self::TypeDefinition(d) => d.jetdoc.clone(),
+            _ => None,
         }
     }
 }
\ No newline at end of file
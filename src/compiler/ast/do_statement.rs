@@ -8,4 +8,19 @@ pub struct DoStatement {
     pub location: Location,
     pub body: Rc<Directive>,
     pub test: Rc<Expression>,
+}
+
+impl DoStatement {
+    /// Verifies the statement. The test is verified after the body,
+    /// which it follows.
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier, phase: VerifierPhase) -> Result<(), DeferVerificationError> {
+        if phase == VerifierPhase::Bodies {
+            verifier.clear_loop_narrowings(&self.body, None);
+        }
+        verifier.verify_directives(std::slice::from_ref(&self.body), phase);
+        if phase == VerifierPhase::Bodies {
+            verifier.verify_condition(&self.test)?;
+        }
+        Ok(())
+    }
 }
\ No newline at end of file
@@ -83,24 +83,56 @@ impl EmbedExpression {
         let value = value.as_ref().unwrap();
         match value.as_ref() {
             Expression::StringLiteral(StringLiteral { value, .. }) => {
-                Some(FlexPath::new_native(&self.location.compilation_unit().file_path().unwrap_or(String::new())).resolve("..").resolve(value).to_string_with_flex_separator())
+                Some(FlexPath::new_native(&self.location.compilation_unit().file_path().unwrap_or_default()).resolve("..").resolve(value).to_string_with_flex_separator())
             },
             Expression::Binary(BinaryExpression { left, operator, right, .. }) => {
                 if *operator != Operator::Add {
                     return None;
                 }
-                let Some(left_id) = left.to_identifier_name_or_asterisk() else {
-                    return None;
-                };
+                let left_id = left.to_identifier_name_or_asterisk()?;
                 if left_id.0 != "output" {
                     return None;
                 }
                 let Expression::StringLiteral(StringLiteral { value: right_val, .. }) = right.as_ref() else {
                     return None;
                 };
-                Some(FlexPath::from_n_native([verifier.host.jetpm_output_directory().as_ref(), right_val.as_ref()]).to_string_with_flex_separator())
+                let jetpm_output_directory = self.location.compilation_unit().compiler_options.jetpm_output_directory.clone()
+                    .unwrap_or_else(|| verifier.host.jetpm_output_directory());
+                Some(FlexPath::from_n_native([jetpm_output_directory.as_ref(), right_val.as_ref()]).to_string_with_flex_separator())
             },
             _ => None,
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ns::*;
+
+    #[test]
+    fn jetpm_output_directory() {
+        let directory = std::env::temp_dir().join(format!("jet-embed-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("a.txt"), "text").unwrap();
+
+        let source = r#"
+            const a: String = embed { source: output + "a.txt" }
+        "#;
+        let verify = |compiler_options: CompilerOptions| {
+            let host = SymbolHost::new("");
+            declare_jet_lang_classes(&host, &["Object", "Boolean", "String", "Number", "Function", "Class", "Array"]);
+            let compilation_unit = CompilationUnit::new(None, source.to_owned(), &Rc::new(compiler_options));
+            let program = ParserFacade::parse_program(&compilation_unit, &host).unwrap();
+            let mut verifier = Verifier::new(&host);
+            verifier.verify_programs(vec![program.clone()]);
+            compilation_unit.diagnostics().iter().map(|d| (d.id(), d.location().first_line_number())).collect::<Vec<_>>()
+        };
+
+        // The host's output directory is only used when the option is unset.
+        assert_eq!(verify(default()), [(DiagnosticKind::FailedLoadingEmbeddedFile.id(), 2)]);
+        let jetpm_output_directory = Some(directory.to_string_lossy().into_owned());
+        assert_eq!(verify(CompilerOptions { jetpm_output_directory, ..default() }), []);
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
 }
\ No newline at end of file
@@ -11,4 +11,364 @@ pub struct EnumDefinition {
     pub name: (String, Location),
     pub as_clause: Option<Rc<Expression>>,
     pub block: Rc<Block>,
+}
+
+impl EnumDefinition {
+    /// Converts the name of an enumeration constant from screaming snake case
+    /// to camel case, as in `"FOO_BAR"` to `"fooBar"`. This is the default
+    /// string of an enumeration member.
+    pub fn member_string(constant: &str) -> String {
+        let mut r = String::new();
+        for (i, part) in constant.split('_').filter(|part| !part.is_empty()).enumerate() {
+            let part = part.to_lowercase();
+            if i == 0 {
+                r.push_str(&part);
+            } else {
+                let mut chars = part.chars();
+                if let Some(first) = chars.next() {
+                    r.extend(first.to_uppercase());
+                    r.push_str(chars.as_str());
+                }
+            }
+        }
+        r
+    }
+
+    /// Returns the members of the enumeration as (constant, location, initializer)
+    /// entries. Members are the non-static variable definitions of the block.
+    fn members(&self) -> Vec<(String, Location, Option<Rc<Expression>>)> {
+        let mut r = vec![];
+        for drtv in &self.block.directives {
+            let Directive::VariableDefinition(defn) = drtv.as_ref() else {
+                continue;
+            };
+            if Attribute::find_static(&defn.attributes).is_some() {
+                continue;
+            }
+            for binding in &defn.bindings {
+                if let Some((name, location)) = binding.destructuring.destructuring.to_identifier_name() {
+                    r.push((name, location, binding.initializer.clone()));
+                }
+            }
+        }
+        r
+    }
+
+    fn is_member_directive(drtv: &Rc<Directive>) -> bool {
+        matches!(drtv.as_ref(), Directive::VariableDefinition(defn) if Attribute::find_static(&defn.attributes).is_none())
+    }
+
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier, drtv: &Rc<Directive>, phase: VerifierPhase) -> Result<(), DeferVerificationError> {
+        if phase == VerifierPhase::Declaration && !verifier.ast_to_symbol.has(drtv) {
+            let enum_type = self.declare(verifier);
+            verifier.ast_to_symbol.set(drtv, enum_type);
+        }
+        let Some(enum_type) = verifier.ast_to_symbol.get(drtv) else {
+            return Ok(());
+        };
+
+        let scope = verifier.ast_to_symbol.get(&self.block).unwrap();
+        verifier.enter_scope(&scope);
+        let result = match phase {
+            VerifierPhase::Heritage => self.verify_representation_type(verifier, &enum_type),
+            VerifierPhase::Signatures => self.declare_special_methods(verifier, &enum_type)
+                .and_then(|_| self.verify_members(verifier, &enum_type)),
+            _ => Ok(()),
+        };
+        // Members are verified above; the remaining directives
+        // of the block are verified as in a class block.
+        if result.is_ok() {
+            let directives: Vec<Rc<Directive>> = self.block.directives.iter().filter(|d| !Self::is_member_directive(d)).cloned().collect();
+            verifier.verify_directives(&directives, phase);
+        }
+        verifier.exit_scope();
+        result
+    }
+
+    /// Creates the enumeration symbol, its scope and its member constants,
+    /// and declares it in the enclosing definition. Returns `None`
+    /// if the definition is a duplicate.
+    fn declare(&self, verifier: &mut VerifierVerifier) -> Option<Symbol> {
+        let host = verifier.host.clone();
+        let (name, name_location) = self.name.clone();
+        let (parent, mut properties) = verifier.definition_destination(false);
+        if properties.has(&name) {
+            verifier.add_verify_error(&name_location, DiagnosticKind::DuplicateDefinition, diagnostic_arguments![String(name)]);
+            return None;
+        }
+
+        let enum_type = host.factory().create_enum_type(name.clone(), self.is_set);
+        enum_type.set_location(Some(name_location));
+        enum_type.set_jetdoc(self.jetdoc.clone());
+        enum_type.set_visibility(Attribute::visibility(&self.attributes, false));
+        enum_type.set_parent_definition(Some(&parent));
+
+        let scope = host.factory().create_enum_scope(&enum_type);
+        scope.set_parent_scope(Some(&verifier.scope));
+        verifier.ast_to_symbol.set(&self.block, Some(scope));
+
+        // Member constants are public unless a visibility is given.
+        let mut static_properties = enum_type.static_properties(&host);
+        for drtv in &self.block.directives {
+            let Directive::VariableDefinition(defn) = drtv.as_ref() else {
+                continue;
+            };
+            if Attribute::find_static(&defn.attributes).is_some() {
+                continue;
+            }
+            let visibility = if Attribute::has_visibility(&defn.attributes) { Attribute::visibility(&defn.attributes, false) } else { Visibility::Public };
+            for binding in &defn.bindings {
+                let Some((name, location)) = binding.destructuring.destructuring.to_identifier_name() else {
+                    continue;
+                };
+                if static_properties.has(&name) {
+                    verifier.add_verify_error(&location, DiagnosticKind::DuplicateDefinition, diagnostic_arguments![String(name)]);
+                    continue;
+                }
+                let constant = host.factory().create_variable_property(name.clone(), true, &enum_type);
+                constant.set_location(Some(location));
+                constant.set_jetdoc(defn.jetdoc.clone());
+                constant.set_visibility(visibility);
+                constant.set_parent_definition(Some(&enum_type));
+                static_properties.set(name, constant);
+            }
+        }
+
+        properties.set(name, enum_type.clone());
+        Some(enum_type)
+    }
+
+    /// Resolves the representation type, which defaults to `Number`.
+    fn verify_representation_type(&self, verifier: &mut VerifierVerifier, enum_type: &Symbol) -> Result<(), DeferVerificationError> {
+        let host = verifier.host.clone();
+        let number_type = host.number_type();
+        number_type.throw_if_unresolved()?;
+
+        let mut representation_type = number_type.clone();
+        if let Some(exp) = &self.as_clause {
+            if let Some(t) = verifier.verify_type_expression(exp)? {
+                if host.is_numeric_type(&t) {
+                    representation_type = t;
+                } else {
+                    verifier.add_verify_error(&exp.location(), DiagnosticKind::UnsupportedEnumRepresentationType, diagnostic_arguments![Symbol(t)]);
+                }
+            }
+        }
+        enum_type.set_enumeration_representation_type(Some(&representation_type));
+        Ok(())
+    }
+
+    /// Assigns values to the member constants. A member takes the value
+    /// of its initializer or the value following the previous member:
+    /// the next integer, or the next power of two for set enumerations.
+    ///
+    /// Every initializer is verified before anything is reported, so that
+    /// a deferred enumeration does not report the same error twice.
+    fn verify_members(&self, verifier: &mut VerifierVerifier, enum_type: &Symbol) -> Result<(), DeferVerificationError> {
+        let host = verifier.host.clone();
+        let representation_type = enum_type.enumeration_representation_type().unwrap();
+
+        let mut members = vec![];
+        for (name, location, initializer) in self.members() {
+            let mut string: Option<String> = None;
+            let mut value: Option<Rc<Expression>> = None;
+            match initializer.as_ref().map(|exp| exp.as_ref()) {
+                Some(Expression::StringLiteral(literal)) => {
+                    string = Some(literal.value.clone());
+                },
+                Some(Expression::ArrayLiteral(literal)) => {
+                    for element in &literal.elements {
+                        let Element::Expression(exp) = element else {
+                            continue;
+                        };
+                        if let Expression::StringLiteral(literal) = exp.as_ref() {
+                            string = Some(literal.value.clone());
+                        } else {
+                            value = Some(exp.clone());
+                        }
+                    }
+                },
+                Some(_) => {
+                    value = initializer.clone();
+                },
+                None => {},
+            }
+            let value = match value {
+                Some(exp) => {
+                    let v = verifier.limit_expression_type(&exp, &representation_type)?;
+                    Some((exp, v))
+                },
+                None => None,
+            };
+            members.push((name, location, string, value));
+        }
+
+        let static_properties = enum_type.static_properties(&host);
+        let mut enumeration_members = enum_type.enumeration_members();
+        let mut values: Vec<(AbstractRangeNumber, String)> = vec![];
+        let mut counter = Some(if self.is_set { AbstractRangeNumber::one(&representation_type, &host) } else { AbstractRangeNumber::zero(&representation_type, &host) });
+        for (name, location, string, value) in members {
+            let Some(constant) = static_properties.get(&name) else {
+                continue;
+            };
+            if constant.constant_initializer().is_some() {
+                continue;
+            }
+            let string = string.unwrap_or_else(|| Self::member_string(&name));
+            let value = match value {
+                Some((_, Some(v))) if v.is_number_constant() => Some(v.number_value()),
+                Some((exp, Some(_))) => {
+                    verifier.add_verify_error(&exp.location(), DiagnosticKind::ConstantExpressionExpected, diagnostic_arguments![]);
+                    counter.clone()
+                },
+                _ => counter.clone(),
+            };
+            let Some(value) = value else {
+                verifier.add_verify_error(&location, DiagnosticKind::EnumMemberValueOutOfRange, diagnostic_arguments![String(name), Symbol(representation_type.clone())]);
+                continue;
+            };
+            counter = Self::next_member_value(&value, self.is_set);
+
+            if enumeration_members.has(&string) {
+                verifier.add_verify_error(&location, DiagnosticKind::DuplicateDefinition, diagnostic_arguments![String(string)]);
+                continue;
+            }
+            if let Some((_, name_1)) = values.iter().find(|(value_1, _)| value_1 == &value) {
+                verifier.add_verify_error(&location, DiagnosticKind::DuplicateEnumMemberValue, diagnostic_arguments![String(name), String(name_1.clone())]);
+                continue;
+            }
+            values.push((value.clone(), name));
+            constant.set_constant_initializer(Some(&host.factory().create_enum_constant(value.clone(), enum_type)));
+            enumeration_members.set(string, value);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the value following a member value, or `None`
+    /// if it exceeds the range of the representation type.
+    fn next_member_value(value: &AbstractRangeNumber, is_set: bool) -> Option<AbstractRangeNumber> {
+        if is_set && value.is_zero() {
+            return Some(value.increase_by_one());
+        }
+        match value {
+            AbstractRangeNumber::Long(v) => {
+                let v = if is_set { v.checked_mul(2) } else { v.checked_add(1) };
+                v.map(AbstractRangeNumber::Long)
+            },
+            _ => {
+                let v = if is_set { value.multiply_per_two() } else { value.increase_by_one() };
+                if v.is_positive_infinity() { None } else { Some(v) }
+            },
+        }
+    }
+
+    /// Creates the special methods of the enumeration with resolved
+    /// signatures, unless the block defines methods of the same name.
+    fn declare_special_methods(&self, verifier: &mut VerifierVerifier, enum_type: &Symbol) -> Result<(), DeferVerificationError> {
+        let host = verifier.host.clone();
+        let string_type = host.string_type();
+        string_type.throw_if_unresolved()?;
+        let boolean_type = host.boolean_type();
+        boolean_type.throw_if_unresolved()?;
+        let representation_type = enum_type.enumeration_representation_type().unwrap();
+
+        let value_parameter = |name: &str, static_type: &Symbol| Rc::new(ParameterOfFunctionType {
+            kind: ParameterKind::Required,
+            name: name.to_owned(),
+            static_type: static_type.clone(),
+        });
+        let mut methods = vec![
+            ("toString", host.factory().create_function_type(vec![], string_type)),
+            ("valueOf", host.factory().create_function_type(vec![], representation_type)),
+        ];
+        if self.is_set {
+            let callback_type = host.factory().create_function_type(vec![value_parameter("value", enum_type)], boolean_type);
+            methods.push(("include", host.factory().create_function_type(vec![value_parameter("value", enum_type)], enum_type.clone())));
+            methods.push(("exclude", host.factory().create_function_type(vec![value_parameter("value", enum_type)], enum_type.clone())));
+            methods.push(("filter", host.factory().create_function_type(vec![value_parameter("callback", &callback_type)], enum_type.clone())));
+        }
+
+        let mut prototype = enum_type.prototype(&host);
+        for (name, signature) in methods {
+            if prototype.has(&name.to_owned()) {
+                continue;
+            }
+            let method = host.factory().create_function(name.to_owned(), &signature);
+            method.set_visibility(Visibility::Public);
+            method.set_parent_definition(Some(enum_type));
+            method.set_is_native(true);
+            prototype.set(name.to_owned(), method);
+        }
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::ns::*;
+
+    #[test]
+    fn enum_definitions() {
+        let host = SymbolHost::new("");
+        declare_jet_lang_classes(&host, &["Object", "Boolean", "String", "Number", "Long", "Function", "Class", "Array"]);
+
+        let source = r#"
+            enum Color {
+                const RED
+                const DARK_GREEN = "verde"
+                const BLUE = 5
+                const CYAN = ["ciano", 7]
+                const MAGENTA
+            }
+            [Set] enum Flags {
+                const A
+                const B
+                const C = 16
+                const D
+            }
+            enum Text as String {}
+            enum Duplicates {
+                const X = 1
+                const Y = 1
+                const X
+            }
+            enum Big as Long {
+                const MAX = 0x7FFF_FFFF_FFFF_FFFF
+                const OVER
+            }
+        "#;
+        let compilation_unit = CompilationUnit::new(None, source.to_owned(), &CompilerOptions::new());
+        let program = ParserFacade::parse_program(&compilation_unit, &host).unwrap();
+        let mut verifier = Verifier::new(&host);
+        verifier.verify_programs(vec![program.clone()]);
+
+        let mut diagnostics = compilation_unit.diagnostics().iter().map(|d| d.id()).collect::<Vec<_>>();
+        diagnostics.sort();
+        assert_eq!(diagnostics, [
+            DiagnosticKind::DuplicateDefinition.id(),
+            DiagnosticKind::UnsupportedEnumRepresentationType.id(),
+            DiagnosticKind::EnumMemberValueOutOfRange.id(),
+            DiagnosticKind::DuplicateEnumMemberValue.id(),
+        ]);
+
+        let members = |i: usize| {
+            let enum_type = verifier.ast_to_symbol().get(&program.directives[i]).unwrap();
+            enum_type.enumeration_members().borrow().iter().map(|(name, v)| (name.clone(), v.clone())).collect::<Vec<_>>()
+        };
+        let mut color = members(0);
+        color.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+        assert_eq!(color.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>(), ["red", "verde", "blue", "ciano", "magenta"]);
+        assert!(color.iter().map(|(_, v)| v.clone()).eq([0.0, 1.0, 5.0, 7.0, 8.0].map(AbstractRangeNumber::Number)));
+        let mut flags = members(1);
+        flags.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+        assert!(flags.iter().map(|(_, v)| v.clone()).eq([1.0, 2.0, 16.0, 32.0].map(AbstractRangeNumber::Number)));
+
+        let flags = verifier.ast_to_symbol().get(&program.directives[1]).unwrap();
+        let filter = flags.prototype(&host).get(&"filter".to_owned()).unwrap();
+        assert_eq!(filter.signature(&host).to_string(), "function(callback: function(value: Flags): jet.lang.Boolean): Flags");
+        let c = flags.static_properties(&host).get(&"C".to_owned()).unwrap();
+        assert!(c.constant_initializer().is_some_and(|c| c.is_enum_constant()));
+    }
 }
\ No newline at end of file
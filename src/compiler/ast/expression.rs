@@ -42,6 +42,7 @@ pub enum Expression {
     ArrayType(ArrayTypeExpression),
     TupleType(TupleTypeExpression),
     FunctionType(FunctionTypeExpression),
+    Invalid(InvalidExpression),
 }
 
 impl Expression {
@@ -85,6 +86,7 @@ impl Expression {
             Self::ArrayType(e) => e.location.clone(),
             Self::TupleType(e) => e.location.clone(),
             Self::FunctionType(e) => e.location.clone(),
+            Self::Invalid(e) => e.location.clone(),
         }
     }
 
@@ -129,4 +131,45 @@ impl Expression {
             _ => None,
         }
     }
+
+    /// Collects the names bound by a destructuring pattern.
+    pub fn pattern_names(&self, names: &mut Vec<String>) {
+        match self {
+            Self::QualifiedIdentifier(id) => {
+                if let Some((name, _)) = id.to_identifier_name() {
+                    names.push(name);
+                }
+            },
+            Self::Unary(UnaryExpression { operator: Operator::NonNull, expression, .. }) => {
+                expression.pattern_names(names);
+            },
+            Self::ArrayLiteral(ArrayLiteral { elements, .. }) => {
+                for element in elements {
+                    match element {
+                        Element::Expression(exp) => exp.pattern_names(names),
+                        Element::Rest((exp, _)) => exp.pattern_names(names),
+                        Element::Elision => {},
+                    }
+                }
+            },
+            Self::ObjectInitializer(ObjectInitializer { fields, .. }) => {
+                for field in fields {
+                    match field.as_ref() {
+                        InitializerField::Field { name, value, .. } => {
+                            if let Some(value) = value {
+                                value.pattern_names(names);
+                            } else if let FieldName::Identifier(name) = &name.0 {
+                                names.push(name.clone());
+                            }
+                        },
+                        InitializerField::Rest((exp, _)) => exp.pattern_names(names),
+                    }
+                }
+            },
+            Self::Assignment(AssignmentExpression { left, compound: None, .. }) => {
+                left.pattern_names(names);
+            },
+            _ => {},
+        }
+    }
 }
\ No newline at end of file
@@ -8,4 +8,54 @@ pub struct ExpressionWithTypeArguments {
     pub location: Location,
     pub base: Rc<Expression>,
     pub arguments: Vec<Rc<Expression>>,
+}
+
+impl ExpressionWithTypeArguments {
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier) -> Result<Option<Symbol>, DeferVerificationError> {
+        let host = verifier.host.clone();
+        let base = verifier.verify_expression(&self.base, &ExpressionVerifyContext { followed_by_type_arguments: true, ..default() })?;
+        let mut substitute_types = vec![];
+        for argument in &self.arguments {
+            substitute_types.push(verifier.verify_type_expression(argument)?);
+        }
+        let Some(base) = base else {
+            return Ok(None);
+        };
+        let Some(substitute_types) = substitute_types.into_iter().collect::<Option<SharedArray<Symbol>>>() else {
+            return Ok(None);
+        };
+
+        // Type parameterized type
+        if let Ok(t) = base.expect_type() {
+            let type_parameters = if t.is_origin_class_type() || t.is_origin_interface_type() { t.type_parameters() } else { None };
+            let Some(type_parameters) = type_parameters else {
+                verifier.add_verify_error(&self.base.location(), DiagnosticKind::WrongNumberOfTypeArguments, diagnostic_arguments![String("0".into())]);
+                return Ok(None);
+            };
+            if type_parameters.length() != substitute_types.length() {
+                verifier.add_verify_error(&self.location, DiagnosticKind::WrongNumberOfTypeArguments, diagnostic_arguments![String(type_parameters.length().to_string())]);
+                return Ok(None);
+            }
+            let t = host.factory().create_type_after_explicit_type_substitution(&t, &substitute_types);
+            return Ok(Some(host.factory().create_type_as_reference_value(&t)));
+        }
+
+        // Type parameterized function
+        if base.is_static_reference_value() || base.is_instance_reference_value() || base.is_scope_reference_value() || base.is_package_reference_value() {
+            let function = base.property();
+            if function.is_origin_function() {
+                if let Some(type_parameters) = function.type_parameters() {
+                    if type_parameters.length() != substitute_types.length() {
+                        verifier.add_verify_error(&self.location, DiagnosticKind::WrongNumberOfTypeArguments, diagnostic_arguments![String(type_parameters.length().to_string())]);
+                        return Ok(None);
+                    }
+                    let function = TypeSubstitution(&host).execute(&function, &type_parameters, &substitute_types);
+                    return Ok(Some(CallExpression::substitute_referenced_property(&host, &base, &function)));
+                }
+            }
+        }
+
+        verifier.add_verify_error(&self.base.location(), DiagnosticKind::WrongNumberOfTypeArguments, diagnostic_arguments![String("0".into())]);
+        Ok(None)
+    }
 }
\ No newline at end of file
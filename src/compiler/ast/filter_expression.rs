@@ -8,4 +8,42 @@ pub struct FilterExpression {
     pub location: Location,
     pub base: Rc<Expression>,
     pub test: Rc<Expression>,
+}
+
+impl FilterExpression {
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier) -> Result<Option<Symbol>, DeferVerificationError> {
+        let host = verifier.host.clone();
+        let Some(base) = verifier.verify_expression(&self.base, &default())? else {
+            verifier.verify_expression(&self.test, &default())?;
+            return Ok(None);
+        };
+
+        // The test expression is verified within a scope
+        // where names resolve dynamically against each element.
+        let scope = host.factory().create_filter_operator_scope(&base);
+        verifier.enter_scope(&scope);
+        let test = verifier.verify_expression(&self.test, &default());
+        verifier.exit_scope();
+        test?;
+
+        FilterExpression::verify_xml_base(verifier, &self.base, &base)
+    }
+
+    /// Checks that the base of an E4X operator is of type `*`, `XML` or `XMLList`,
+    /// returning a value of the operator's result type.
+    pub(crate) fn verify_xml_base(verifier: &mut VerifierVerifier, base_exp: &Rc<Expression>, base: &Symbol) -> Result<Option<Symbol>, DeferVerificationError> {
+        let host = verifier.host.clone();
+        let base_type = base.static_type(&host);
+        base_type.throw_if_unresolved()?;
+        if base_type.is_any_type() {
+            return Ok(Some(host.factory().create_value(&base_type)));
+        }
+        let xml_list_type = host.xml_list_type();
+        xml_list_type.throw_if_unresolved()?;
+        if base_type != host.xml_type() && base_type != xml_list_type {
+            verifier.add_verify_error(&base_exp.location(), DiagnosticKind::UnsupportedOperator, diagnostic_arguments![Symbol(base_type)]);
+            return Ok(None);
+        }
+        Ok(Some(host.factory().create_value(&xml_list_type)))
+    }
 }
\ No newline at end of file
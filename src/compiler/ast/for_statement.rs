@@ -30,4 +30,205 @@ pub struct ForInStatement {
 pub enum ForInBinding {
     Expression(Rc<Expression>),
     VariableDefinition(Rc<SimpleVariableDefinition>),
+}
+
+impl ForStatement {
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier, phase: VerifierPhase) -> Result<(), DeferVerificationError> {
+        let scope = if let Some(ForInitializer::VariableDefinition(defn)) = &self.init {
+            Some(verifier.node_scope(defn))
+        } else {
+            None
+        };
+        if let Some(scope) = &scope {
+            verifier.enter_scope(scope);
+        }
+        let result = self.verify_1(verifier, phase);
+        if scope.is_some() {
+            verifier.exit_scope();
+        }
+        result
+    }
+
+    fn verify_1(&self, verifier: &mut VerifierVerifier, phase: VerifierPhase) -> Result<(), DeferVerificationError> {
+        let mut narrowing = ConditionNarrowing::default();
+        if phase == VerifierPhase::Bodies {
+            match &self.init {
+                Some(ForInitializer::Expression(exp)) => {
+                    verifier.verify_expression(exp, &default())?;
+                },
+                Some(ForInitializer::VariableDefinition(defn)) => {
+                    let read_only = defn.kind.0 == VariableDefinitionKind::Const;
+                    let scope = verifier.scope.clone();
+                    let properties = scope.properties(&verifier.host);
+                    for binding in &defn.bindings {
+                        binding.declare(verifier, read_only, &properties, &scope, Visibility::Internal)?;
+                        binding.verify_initializer(verifier, read_only)?;
+                    }
+                },
+                None => {},
+            }
+            verifier.clear_loop_narrowings(&self.body, self.update.as_ref());
+            if let Some(test) = &self.test {
+                verifier.verify_condition(test)?;
+                narrowing = verifier.condition_narrowing(test);
+            }
+            // The update is not evaluated before the first iteration.
+            if let Some(update) = &self.update {
+                verifier.verify_narrowed(&[], |verifier| verifier.verify_expression(update, &default()))?;
+            }
+        }
+        verifier.verify_narrowed_substatement(&self.body, &narrowing.when_true, phase);
+        Ok(())
+    }
+}
+
+impl ForInStatement {
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier, phase: VerifierPhase) -> Result<(), DeferVerificationError> {
+        // Narrowings from assigning a binding expression
+        // do not hold after the loop.
+        let scope = if let ForInBinding::VariableDefinition(defn) = &self.left {
+            verifier.node_scope(defn)
+        } else {
+            verifier.host.factory().create_scope()
+        };
+        verifier.enter_scope(&scope);
+        let result = self.verify_1(verifier, phase);
+        verifier.exit_scope();
+        result
+    }
+
+    fn verify_1(&self, verifier: &mut VerifierVerifier, phase: VerifierPhase) -> Result<(), DeferVerificationError> {
+        if phase == VerifierPhase::Bodies {
+            let host = verifier.host.clone();
+            let mut iteration_type = host.any_type();
+            if let Some(right) = verifier.verify_expression(&self.right, &default())? {
+                let right_type = right.static_type(&host);
+                right_type.throw_if_unresolved()?;
+                if let Some(t) = self.iteration_type(verifier, &right_type)? {
+                    iteration_type = t;
+                } else {
+                    verifier.add_verify_error(&self.right.location(), DiagnosticKind::NotIterable, diagnostic_arguments![Symbol(right_type)]);
+                }
+            }
+            iteration_type.throw_if_unresolved()?;
+            verifier.clear_loop_narrowings(&self.body, None);
+            match &self.left {
+                ForInBinding::Expression(exp) => {
+                    verifier.verify_assignment_destructuring(exp, &iteration_type)?;
+                },
+                ForInBinding::VariableDefinition(defn) => {
+                    let read_only = defn.kind.0 == VariableDefinitionKind::Const;
+                    let scope = verifier.scope.clone();
+                    let properties = scope.properties(&host);
+                    for binding in &defn.bindings {
+                        verifier.verify_typed_destructuring(&binding.destructuring, read_only, &properties, &scope, Visibility::Internal, Some(&iteration_type))?;
+                    }
+                },
+            }
+        }
+        verifier.verify_directives(std::slice::from_ref(&self.body), phase);
+        Ok(())
+    }
+
+    /// Returns the type iterated over a value of type `t`, or `None`
+    /// if `t` is not iterable.
+    ///
+    /// `for..in` iterates the indices of an `Array` and the keys of a `Map`,
+    /// whereas `for each` iterates their elements and values, as well as
+    /// the items of an `Iterator`. Other classes are iterated through
+    /// their `keys` or `values` proxy, which returns an `Iterator`.
+    fn iteration_type(&self, verifier: &mut VerifierVerifier, t: &Symbol) -> Result<Option<Symbol>, DeferVerificationError> {
+        let host = verifier.host.clone();
+        let t = t.non_null_type();
+        if t.is_any_type() || t == host.object_type() || t.is_tuple_type() {
+            return Ok(Some(host.any_type()));
+        }
+        if let Some(element_type) = t.array_element_type(&host)? {
+            return Ok(Some(if self.each { element_type } else { host.number_type() }));
+        }
+        if let Some((key_type, value_type)) = t.map_key_value_types(&host)? {
+            return Ok(Some(if self.each { value_type } else { key_type }));
+        }
+        let iterator_type = host.iterator_type();
+        iterator_type.throw_if_unresolved()?;
+        if self.each && t.type_after_substitution_has_origin(&iterator_type) {
+            return Ok(Some(t.substitute_types().get(0).unwrap()));
+        }
+        if t.is_class_type() || t.is_enum_type() {
+            let proxy_kind = if self.each { ProxyKind::Values } else { ProxyKind::Keys };
+            if let Some(proxy) = t.find_proxy(proxy_kind, &host)? {
+                let signature = proxy.signature(&host);
+                signature.throw_if_unresolved()?;
+                let result_type = signature.result_type();
+                if result_type.type_after_substitution_has_origin(&iterator_type) {
+                    return Ok(Some(result_type.substitute_types().get(0).unwrap()));
+                }
+                return Ok(Some(host.any_type()));
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ns::*;
+
+    #[test]
+    fn statements() {
+        let host = SymbolHost::new("");
+        declare_jet_lang_classes(&host, &["Object", "Boolean", "String", "Number", "Function", "Class"]);
+
+        let source = r#"
+            package jet.lang {
+                public class Array.<T> {}
+                public class Map.<K, V> {}
+                public class Iterator.<T> {}
+                public class Error {}
+                public class RangeError extends Error {}
+            }
+            package p {
+                public function f(a: [String], m: Map.<String, Number>, n: Number): void {
+                    for each (var s in a) {
+                        const s1: String = s;
+                    }
+                    for (var i in a) {
+                        const i1: String = i;
+                    }
+                    for each (var v in m) {
+                        const v1: Number = v;
+                    }
+                    for (var k in n) {}
+                    while (n) {}
+                    switch (n) {
+                        case "x":
+                            break;
+                    }
+                    try {
+                    } catch (e: RangeError) {
+                        const e1: Error = e;
+                    } catch (e: String) {
+                    }
+                    switch type (n) {
+                        case (x: Number) {
+                            const x1: Number = x;
+                        }
+                    }
+                }
+            }
+        "#;
+        let compilation_unit = CompilationUnit::new(None, source.to_owned(), &CompilerOptions::new());
+        let program = ParserFacade::parse_program(&compilation_unit, &host).unwrap();
+        let mut verifier = Verifier::new(&host);
+        verifier.verify_programs(vec![program.clone()]);
+
+        let diagnostics = compilation_unit.diagnostics().iter().map(|d| (d.id(), d.location().first_line_number())).collect::<Vec<_>>();
+        assert_eq!(diagnostics, [
+            (DiagnosticKind::IncompatibleTypes.id(), 15),
+            (DiagnosticKind::NotIterable.id(), 20),
+            (DiagnosticKind::IncompatibleTypes.id(), 21),
+            (DiagnosticKind::IncompatibleTypes.id(), 23),
+            (DiagnosticKind::NotAnErrorType.id(), 29),
+        ]);
+    }
 }
\ No newline at end of file
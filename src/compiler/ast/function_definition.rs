@@ -39,6 +39,195 @@ impl FunctionDefinition {
             FunctionName::Proxy(_, name) => name.clone(),
         }
     }
+
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier, drtv: &Rc<Directive>, phase: VerifierPhase) -> Result<(), DeferVerificationError> {
+        match phase {
+            VerifierPhase::Declaration => {
+                if !verifier.ast_to_symbol.has(drtv) {
+                    let function = self.declare(verifier);
+                    verifier.ast_to_symbol.set(drtv, function);
+                }
+                Ok(())
+            },
+            VerifierPhase::Signatures => {
+                let host = verifier.host.clone();
+                let Some(function) = verifier.ast_to_symbol.get(drtv) else {
+                    return Ok(());
+                };
+                if !function.signature(&host).is_unresolved() {
+                    return Ok(());
+                }
+                let default_result_type = if self.is_constructor() || self.is_setter() { host.void_type() } else { host.any_type() };
+                let scope = FunctionCommon::signature_scope(verifier, &function);
+                let signature = self.common.verify_signature(verifier, &scope, &default_result_type, None)?;
+                if let Some(activation) = function.activation_scope() {
+                    self.common.declare_parameters(verifier, &activation, &signature)?;
+                }
+                function.set_signature(&signature);
+                Ok(())
+            },
+            VerifierPhase::Bodies => {
+                let host = verifier.host.clone();
+                let Some(function) = verifier.ast_to_symbol.get(drtv) else {
+                    return Ok(());
+                };
+                if function.is_overriding() && function.overrides_method(&host).is_none() {
+                    self.verify_override(verifier, &function)?;
+                }
+                if let Some(activation) = function.activation_scope() {
+                    verifier.defer_function_common(phase, &activation, &self.common);
+                }
+                Ok(())
+            },
+            _ => Ok(()),
+        }
+    }
+
+    /// Creates the function symbol and declares it in the enclosing
+    /// definition. Returns `None` if the definition is a duplicate.
+    fn declare(&self, verifier: &mut VerifierVerifier) -> Option<Symbol> {
+        let host = verifier.host.clone();
+        let (name, name_location) = self.name_identifier();
+        let is_static = Attribute::find_static(&self.attributes).is_some();
+        let at_interface_block = verifier.scope.is_interface_scope();
+        let (parent, mut properties) = verifier.definition_destination(is_static);
+
+        let function = host.factory().create_function(name.clone(), &host.unresolved());
+        function.set_location(Some(name_location.clone()));
+        function.set_jetdoc(self.jetdoc.clone());
+        function.set_visibility(Attribute::visibility(&self.attributes, at_interface_block));
+        function.set_parent_definition(Some(&parent));
+        function.set_is_abstract(Attribute::find_abstract(&self.attributes).is_some());
+        function.set_is_final(Attribute::find_final(&self.attributes).is_some());
+        function.set_is_native(Attribute::find_native(&self.attributes).is_some());
+        function.set_is_overriding(Attribute::find_override(&self.attributes).is_some());
+        function.set_is_generator(self.common.contains_yield);
+        function.set_is_async(self.common.contains_await);
+
+        // Interface methods marked with the `[Optional]` meta-data
+        // need not be implemented.
+        if at_interface_block {
+            let optional = Attribute::find_metadata(&self.attributes).iter().any(|(metadata, _)| metadata.name == "Optional");
+            function.set_is_optional_interface_method(optional);
+        }
+
+        if let Some(type_parameters) = &self.type_parameters {
+            let type_parameters: SharedArray<Symbol> = type_parameters.iter().map(|p| host.factory().create_type_parameter_type(p.name.0.clone())).collect();
+            function.set_type_parameters(Some(&type_parameters));
+        }
+
+        // Abstract, native and interface functions have no activation.
+        if !(function.is_abstract() || function.is_native() || at_interface_block) {
+            let activation = host.factory().create_activation_scope(&function);
+            activation.set_parent_scope(Some(&verifier.scope));
+            if !is_static && (parent.is_class_type() || parent.is_enum_type()) {
+                activation.set_this(Some(&host.factory().create_this_value(&parent)));
+            }
+            if let Some(type_parameters) = function.type_parameters() {
+                for type_parameter in type_parameters.iter() {
+                    activation.properties(&host).set(type_parameter.name(), type_parameter.clone());
+                }
+            }
+            function.set_activation_scope(Some(activation));
+        }
+        verifier.ast_to_symbol.set(&self.common, Some(function.clone()));
+
+        match &self.name {
+            FunctionName::Identifier(_) => {
+                if properties.has(&name) {
+                    verifier.add_verify_error(&name_location, DiagnosticKind::DuplicateDefinition, diagnostic_arguments![String(name)]);
+                    return None;
+                }
+                properties.set(name, function.clone());
+            },
+            FunctionName::Getter(_) | FunctionName::Setter(_) => {
+                let virtual_property = match properties.get(&name) {
+                    Some(p) if p.is_virtual_property() => p,
+                    Some(_) => {
+                        verifier.add_verify_error(&name_location, DiagnosticKind::DuplicateDefinition, diagnostic_arguments![String(name)]);
+                        return None;
+                    },
+                    None => {
+                        let p = host.factory().create_virtual_property(name.clone());
+                        p.set_location(Some(name_location.clone()));
+                        p.set_jetdoc(self.jetdoc.clone());
+                        p.set_visibility(function.visibility());
+                        p.set_parent_definition(Some(&parent));
+                        properties.set(name.clone(), p.clone());
+                        p
+                    },
+                };
+                let accessor = if self.is_getter() { virtual_property.getter(&host) } else { virtual_property.setter(&host) };
+                if accessor.is_some() {
+                    verifier.add_verify_error(&name_location, DiagnosticKind::DuplicateDefinition, diagnostic_arguments![String(name)]);
+                    return None;
+                }
+                if self.is_getter() {
+                    virtual_property.set_getter(Some(&function));
+                } else {
+                    virtual_property.set_setter(Some(&function));
+                }
+                function.set_of_virtual_property(Some(&virtual_property));
+            },
+            FunctionName::Constructor(_) => {
+                function.set_is_constructor(true);
+                if parent.is_class_type() {
+                    if parent.constructor_function(&host).is_some() {
+                        verifier.add_verify_error(&name_location, DiagnosticKind::DuplicateDefinition, diagnostic_arguments![String(name)]);
+                        return None;
+                    }
+                    parent.set_constructor_function(Some(&function));
+                }
+            },
+            FunctionName::Proxy(kind, _) => {
+                if parent.is_class_type() || parent.is_enum_type() {
+                    let mut proxies = parent.proxies(&host);
+                    if proxies.has(kind) {
+                        verifier.add_verify_error(&name_location, DiagnosticKind::DuplicateDefinition, diagnostic_arguments![String(name)]);
+                        return None;
+                    }
+                    proxies.set(*kind, function.clone());
+                }
+            },
+        }
+
+        Some(function)
+    }
+
+    /// Overrides a method of the base class, reporting
+    /// an error if the method cannot be overriden.
+    fn verify_override(&self, verifier: &mut VerifierVerifier, function: &Symbol) -> Result<(), DeferVerificationError> {
+        let host = verifier.host.clone();
+        let (name, name_location) = self.name_identifier();
+        let parent = function.parent_definition().unwrap();
+        let is_static = Attribute::find_static(&self.attributes).is_some();
+        if is_static || !(parent.is_class_type() || parent.is_enum_type()) {
+            verifier.add_verify_error(&name_location, DiagnosticKind::MustOverrideAMethod, diagnostic_arguments![String(name)]);
+            return Ok(());
+        }
+        match MethodOverriding(&host).override_method(function) {
+            Ok(()) => {},
+            Err(MethodOverridingError::DeferVerification) => {
+                return Err(DeferVerificationError);
+            },
+            Err(MethodOverridingError::MustOverrideAMethod) => {
+                verifier.add_verify_error(&name_location, DiagnosticKind::MustOverrideAMethod, diagnostic_arguments![String(name)]);
+            },
+            Err(MethodOverridingError::CannotOverrideTypeParameterizedMethod) => {
+                verifier.add_verify_error(&name_location, DiagnosticKind::CannotOverrideTypeParameterizedMethod, diagnostic_arguments![String(name)]);
+            },
+            Err(MethodOverridingError::CannotIntroduceTypeParameters) => {
+                verifier.add_verify_error(&name_location, DiagnosticKind::CannotIntroduceTypeParameters, diagnostic_arguments![String(name)]);
+            },
+            Err(MethodOverridingError::IncompatibleSignature { expected_signature, actual_signature }) => {
+                verifier.add_verify_error(&name_location, DiagnosticKind::IncompatibleOverride, diagnostic_arguments![Symbol(expected_signature), Symbol(actual_signature)]);
+            },
+            Err(MethodOverridingError::OverridingFinalMethod) => {
+                verifier.add_verify_error(&name_location, DiagnosticKind::OverridingFinalMethod, diagnostic_arguments![String(name)]);
+            },
+        }
+        Ok(())
+    }
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -115,6 +304,31 @@ impl TryFrom<ProxyKind> for Operator {
     }
 }
 
+impl TryFrom<Operator> for ProxyKind {
+    type Error = ();
+    fn try_from(value: Operator) -> Result<Self, Self::Error> {
+        match value {
+            Operator::Positive => Ok(ProxyKind::Positive),
+            Operator::Negative => Ok(ProxyKind::Negate),
+            Operator::BitwiseNot => Ok(ProxyKind::BitwiseNot),
+            Operator::Add => Ok(ProxyKind::Add),
+            Operator::Subtract => Ok(ProxyKind::Subtract),
+            Operator::Multiply => Ok(ProxyKind::Multiply),
+            Operator::Divide => Ok(ProxyKind::Divide),
+            Operator::Remainder => Ok(ProxyKind::Remainder),
+            Operator::Power => Ok(ProxyKind::Power),
+            Operator::BitwiseAnd => Ok(ProxyKind::BitwiseAnd),
+            Operator::BitwiseXor => Ok(ProxyKind::BitwiseXor),
+            Operator::BitwiseOr => Ok(ProxyKind::BitwiseOr),
+            Operator::ShiftLeft => Ok(ProxyKind::ShiftLeft),
+            Operator::ShiftRight => Ok(ProxyKind::ShiftRight),
+            Operator::ShiftRightUnsigned => Ok(ProxyKind::ShiftRightUnsigned),
+            Operator::In | Operator::NotIn => Ok(ProxyKind::Has),
+            _ => Err(()),
+        }
+    }
+}
+
 impl FromStr for ProxyKind {
     type Err = ();
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -163,6 +377,94 @@ impl FunctionCommon {
     pub(crate) fn has_block_body(&self) -> bool {
         if let Some(ref body) = self.body { matches!(body, FunctionBody::Block(_)) } else { false }
     }
+
+    /// Returns the scope in which the signature of a function is resolved:
+    /// its activation, or a scope declaring its type parameters
+    /// if it has no activation.
+    pub(crate) fn signature_scope(verifier: &mut VerifierVerifier, function: &Symbol) -> Symbol {
+        if let Some(activation) = function.activation_scope() {
+            return activation;
+        }
+        let scope = verifier.host.factory().create_scope();
+        scope.set_parent_scope(Some(&verifier.scope));
+        if let Some(type_parameters) = function.type_parameters() {
+            for type_parameter in type_parameters.iter() {
+                scope.properties(&verifier.host).set(type_parameter.name(), type_parameter.clone());
+            }
+        }
+        scope
+    }
+
+    /// Resolves the function type from the parameter and result annotations
+    /// within `scope`. Missing annotations are taken from `inferred_type`,
+    /// the function type expected by the context, if any.
+    pub(crate) fn verify_signature(&self, verifier: &mut VerifierVerifier, scope: &Symbol, default_result_type: &Symbol, inferred_type: Option<&Symbol>) -> Result<Symbol, DeferVerificationError> {
+        verifier.enter_scope(scope);
+        let signature = self.verify_signature_1(verifier, default_result_type, inferred_type);
+        verifier.exit_scope();
+        signature
+    }
+
+    fn verify_signature_1(&self, verifier: &mut VerifierVerifier, default_result_type: &Symbol, inferred_type: Option<&Symbol>) -> Result<Symbol, DeferVerificationError> {
+        let host = verifier.host.clone();
+        let inferred_parameters = inferred_type.map(|t| t.parameters());
+
+        let mut parameters = vec![];
+        for (i, parameter) in self.signature.parameters.iter().enumerate() {
+            let inferred = inferred_parameters.as_ref()
+                .and_then(|p| p.get(i))
+                .filter(|p| p.kind == parameter.kind)
+                .map(|p| p.static_type.clone());
+            let static_type = if let Some(annotation) = &parameter.destructuring.type_annotation {
+                verifier.verify_type_expression(annotation)?.unwrap_or(host.any_type())
+            } else if let Some(t) = inferred {
+                t
+            } else if parameter.kind == ParameterKind::Rest {
+                host.array_type_of_any()
+            } else {
+                host.any_type()
+            };
+            static_type.throw_if_unresolved()?;
+
+            if parameter.kind == ParameterKind::Rest && !static_type.is_any_type() && static_type.array_element_type(&host)?.is_none() {
+                verifier.add_verify_error(&parameter.location, DiagnosticKind::CannotUseTypeInRest, diagnostic_arguments![Symbol(static_type.clone())]);
+            }
+
+            let name = parameter.destructuring.destructuring.to_identifier_name().map(|name| name.0).unwrap_or_default();
+            parameters.push(Rc::new(ParameterOfFunctionType {
+                kind: parameter.kind,
+                name,
+                static_type,
+            }));
+        }
+
+        let result_type = if let Some(annotation) = &self.signature.result_type {
+            verifier.verify_type_expression(annotation)?.unwrap_or(host.any_type())
+        } else if let Some(t) = inferred_type {
+            t.result_type()
+        } else {
+            default_result_type.clone()
+        };
+        result_type.throw_if_unresolved()?;
+
+        Ok(host.factory().create_function_type(parameters, result_type))
+    }
+
+    /// Declares the variables bound by the parameters
+    /// in the activation.
+    pub(crate) fn declare_parameters(&self, verifier: &mut VerifierVerifier, activation: &Symbol, signature: &Symbol) -> Result<(), DeferVerificationError> {
+        let host = verifier.host.clone();
+        let properties = activation.properties(&host);
+        verifier.enter_scope(activation);
+        for (parameter, parameter_1) in self.signature.parameters.iter().zip(signature.parameters().iter()) {
+            if let Err(error) = verifier.verify_typed_destructuring(&parameter.destructuring, false, &properties, activation, Visibility::Internal, Some(&parameter_1.static_type)) {
+                verifier.exit_scope();
+                return Err(error);
+            }
+        }
+        verifier.exit_scope();
+        Ok(())
+    }
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -198,4 +500,86 @@ impl ParameterKind {
 pub enum FunctionBody {
     Expression(Rc<Expression>),
     Block(Rc<Block>),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ns::*;
+
+    #[test]
+    fn function_definitions() {
+        let host = SymbolHost::new("");
+        declare_jet_lang_classes(&host, &["Object", "Boolean", "String", "Number", "Function", "Class", "Array"]);
+
+        let source = r#"
+            function add(a: Number, b: Number = 1): Number {
+                return a + b
+            }
+            function f(): void {
+                return 1
+            }
+            function g(): Number {
+                return
+            }
+            function add() {}
+            add(1)
+            add("x")
+        "#;
+        let compilation_unit = CompilationUnit::new(None, source.to_owned(), &CompilerOptions::new());
+        let program = ParserFacade::parse_program(&compilation_unit, &host).unwrap();
+        let mut verifier = Verifier::new(&host);
+        verifier.verify_programs(vec![program.clone()]);
+
+        let mut diagnostics = compilation_unit.diagnostics().iter().map(|d| d.id()).collect::<Vec<_>>();
+        diagnostics.sort();
+        assert_eq!(diagnostics, [
+            DiagnosticKind::IncompatibleArgument.id(),
+            DiagnosticKind::DuplicateDefinition.id(),
+            DiagnosticKind::VoidFunctionMustNotReturnValue.id(),
+            DiagnosticKind::ReturnValueExpected.id(),
+        ]);
+
+        let Directive::FunctionDefinition(add) = program.directives[0].as_ref() else {
+            panic!();
+        };
+        let function = verifier.ast_to_symbol().get(&add.common).unwrap();
+        assert_eq!(function.signature(&host).to_string(), "function(a: jet.lang.Number, b?: jet.lang.Number): jet.lang.Number");
+        assert!(function.activation_scope().is_some());
+    }
+
+    #[test]
+    fn generators_and_asynchronous_functions() {
+        let host = SymbolHost::new("");
+        declare_jet_lang_classes(&host, &["Object", "Boolean", "String", "Number", "Function", "Class", "Array", "Iterator"]);
+
+        let source = r#"
+            function g1(): Iterator {
+                yield 1
+            }
+            function g2() {
+                yield 1
+            }
+            function g3(): Number {
+                yield 1
+            }
+            function f1(): void {
+                const f = function(x: Number = await 0): void { await 1 }
+                await 0
+            }
+            function f2(): Iterator {
+                const f = function(x: Number = yield 0): Iterator { yield 1 }
+            }
+        "#;
+        let compilation_unit = CompilationUnit::new(None, source.to_owned(), &CompilerOptions::new());
+        let program = ParserFacade::parse_program(&compilation_unit, &host).unwrap();
+        let mut verifier = Verifier::new(&host);
+        verifier.verify_programs(vec![program.clone()]);
+
+        let diagnostics = compilation_unit.diagnostics().iter().map(|d| (d.id(), d.location().first_line_number())).collect::<Vec<_>>();
+        assert_eq!(diagnostics, [
+            (DiagnosticKind::GeneratorResultTypeMustBeIterator.id(), 8),
+            (DiagnosticKind::AwaitOutsideAsynchronousFunction.id(), 12),
+            (DiagnosticKind::YieldOutsideGenerator.id(), 16),
+        ]);
+    }
 }
\ No newline at end of file
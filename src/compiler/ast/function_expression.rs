@@ -7,4 +7,50 @@ pub struct FunctionExpression {
     pub location: Location,
     pub name: Option<(String, Location)>,
     pub common: Rc<FunctionCommon>,
+}
+
+impl FunctionExpression {
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier, context: &ExpressionVerifyContext) -> Result<Option<Symbol>, DeferVerificationError> {
+        let host = verifier.host.clone();
+
+        // The function and its activation are cached before verifying
+        // the signature so that a deferred verification does not
+        // declare them twice.
+        let function = if let Some(function) = verifier.ast_to_symbol.get(&self.common) {
+            function
+        } else {
+            let name = self.name.as_ref().map(|name| name.0.clone()).unwrap_or_default();
+            let function = host.factory().create_function(name, &host.unresolved());
+            function.set_location(Some(self.location.clone()));
+            function.set_is_generator(self.common.contains_yield);
+            function.set_is_async(self.common.contains_await);
+
+            let activation = host.factory().create_activation_scope(&function);
+            activation.set_parent_scope(Some(&verifier.scope));
+
+            // Function expressions preserve `this`.
+            if let Some(enclosing_activation) = verifier.scope.find_activation() {
+                activation.set_this(enclosing_activation.this().as_ref());
+            }
+
+            if let Some((name, _)) = &self.name {
+                function.set_parent_definition(Some(&activation));
+                activation.properties(&host).set(name.clone(), function.clone());
+            }
+            function.set_activation_scope(Some(activation));
+            verifier.ast_to_symbol.set(&self.common, Some(function.clone()));
+            function
+        };
+        let activation = function.activation_scope().unwrap();
+
+        if function.signature(&host).is_unresolved() {
+            let inferred_type = context.context_type.as_ref().map(|t| t.non_null_type()).filter(|t| t.is_function_type());
+            let signature = self.common.verify_signature(verifier, &activation, &host.any_type(), inferred_type.as_ref())?;
+            self.common.declare_parameters(verifier, &activation, &signature)?;
+            function.set_signature(&signature);
+        }
+
+        verifier.defer_function_common(VerifierPhase::Bodies, &activation, &self.common);
+        Ok(Some(host.factory().create_function_value(&activation)))
+    }
 }
\ No newline at end of file
@@ -8,4 +8,38 @@ pub struct IfStatement {
     pub test: Rc<Expression>,
     pub consequent: Rc<Directive>,
     pub alternative: Option<Rc<Directive>>,
+}
+
+impl IfStatement {
+    /// Verifies the statement, narrowing local variables within
+    /// each branch. If a branch cannot complete normally, the narrowing
+    /// of the other branch also holds after the statement, as in:
+    ///
+    /// ```jet
+    /// if (x == null) {
+    ///     return;
+    /// }
+    /// // x is non-nullable here
+    /// ```
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier, phase: VerifierPhase) -> Result<(), DeferVerificationError> {
+        let mut narrowing = ConditionNarrowing::default();
+        if phase == VerifierPhase::Bodies {
+            verifier.verify_condition(&self.test)?;
+            narrowing = verifier.condition_narrowing(&self.test);
+        }
+        verifier.verify_narrowed_substatement(&self.consequent, &narrowing.when_true, phase);
+        if let Some(alternative) = &self.alternative {
+            verifier.verify_narrowed_substatement(alternative, &narrowing.when_false, phase);
+        }
+        if phase == VerifierPhase::Bodies {
+            let consequent_completes = verifier.completes_normally(&self.consequent);
+            let alternative_completes = self.alternative.as_ref().is_none_or(|alternative| verifier.completes_normally(alternative));
+            if !consequent_completes && alternative_completes {
+                VerifierVerifier::narrow_scope(&verifier.scope, &narrowing.when_false);
+            } else if consequent_completes && !alternative_completes {
+                VerifierVerifier::narrow_scope(&verifier.scope, &narrowing.when_true);
+            }
+        }
+        Ok(())
+    }
 }
\ No newline at end of file
@@ -9,6 +9,38 @@ pub struct ImportDirective {
     pub import_specifier: ImportSpecifier,
 }
 
+impl ImportDirective {
+    /// Opens or aliases a package at the Declaration phase and imports
+    /// a single definition at the Heritage phase, after every
+    /// package has declared its definitions.
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier, phase: VerifierPhase) {
+        match (&self.import_specifier, phase) {
+            (ImportSpecifier::Wildcard(_), VerifierPhase::Declaration) => {
+                let Some(package) = verifier.resolve_package_name(&self.package_name) else {
+                    return;
+                };
+                let scope = verifier.scope.clone();
+                if let Some((alias, _)) = &self.alias {
+                    scope.package_aliases().set(alias.clone(), package);
+                } else if !scope.open_packages().includes(&package) {
+                    scope.open_packages().push(package);
+                }
+            },
+            (ImportSpecifier::Identifier(name), VerifierPhase::Heritage) => {
+                let Some(package) = verifier.resolve_package_name(&self.package_name) else {
+                    return;
+                };
+                let Some(property) = verifier.resolve_package_property(&package, name) else {
+                    return;
+                };
+                let alias = self.alias.as_ref().unwrap_or(name).0.clone();
+                verifier.scope.imports().set(alias, property);
+            },
+            _ => {},
+        }
+    }
+}
+
 #[derive(Clone, Serialize, Deserialize)]
 pub enum ImportSpecifier {
     Wildcard(Location),
@@ -22,4 +54,51 @@ impl ImportSpecifier {
             Self::Identifier((_, l)) => l.clone(),
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ns::*;
+
+    #[test]
+    fn imports() {
+        let host = SymbolHost::new("");
+        declare_jet_lang_classes(&host, &["Object", "Boolean", "String", "Number", "Function", "Class"]);
+
+        let source = r#"
+            package p {
+                public class C {}
+                public class D {}
+            }
+            package q {
+                import p.C;
+                import E = p.D;
+                public class F extends C {}
+                public var e: ?E = null;
+            }
+            package r {
+                public use p.C;
+                public use G = p.D;
+            }
+            import p.*;
+            import s = q.*;
+            import r.G;
+            var c: ?C = null;
+            var f: ?s.F = null;
+            var g: ?G = null;
+            type T = ?C;
+            var t: T = c;
+            import x.*;
+            import p.H;
+        "#;
+        let compilation_unit = CompilationUnit::new(None, source.to_owned(), &CompilerOptions::new());
+        let program = ParserFacade::parse_program(&compilation_unit, &host).unwrap();
+        Verifier::new(&host).verify_programs(vec![program.clone()]);
+
+        let diagnostics = compilation_unit.diagnostics().iter().map(|d| (d.id(), d.location().first_line_number())).collect::<Vec<_>>();
+        assert_eq!(diagnostics, [
+            (DiagnosticKind::UndefinedPackage.id(), 24),
+            (DiagnosticKind::UndefinedProperty.id(), 25),
+        ]);
+    }
 }
\ No newline at end of file
@@ -11,4 +11,162 @@ pub struct InterfaceDefinition {
     pub type_parameters: Option<Vec<Rc<TypeParameter>>>,
     pub extends_clause: Option<Vec<Rc<Expression>>>,
     pub block: Rc<Block>,
+}
+
+impl InterfaceDefinition {
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier, drtv: &Rc<Directive>, phase: VerifierPhase) -> Result<(), DeferVerificationError> {
+        if phase == VerifierPhase::Declaration && !verifier.ast_to_symbol.has(drtv) {
+            let interface = self.declare(verifier);
+            verifier.ast_to_symbol.set(drtv, interface);
+        }
+        let Some(interface) = verifier.ast_to_symbol.get(drtv) else {
+            return Ok(());
+        };
+
+        let scope = verifier.ast_to_symbol.get(&self.block).unwrap();
+        verifier.enter_scope(&scope);
+        let result = match phase {
+            VerifierPhase::Heritage => self.verify_heritage(verifier, &interface),
+            _ => Ok(()),
+        };
+        if result.is_ok() {
+            verifier.verify_directives(&self.block.directives, phase);
+        }
+        verifier.exit_scope();
+        result
+    }
+
+    /// Creates the interface symbol and its scope and declares it in the
+    /// enclosing definition. Returns `None` if the definition is a duplicate.
+    fn declare(&self, verifier: &mut VerifierVerifier) -> Option<Symbol> {
+        let host = verifier.host.clone();
+        let (name, name_location) = self.name.clone();
+        let (parent, mut properties) = verifier.definition_destination(false);
+        if properties.has(&name) {
+            verifier.add_verify_error(&name_location, DiagnosticKind::DuplicateDefinition, diagnostic_arguments![String(name)]);
+            return None;
+        }
+
+        let interface = host.factory().create_interface_type(name.clone());
+        interface.set_location(Some(name_location));
+        interface.set_jetdoc(self.jetdoc.clone());
+        interface.set_visibility(Attribute::visibility(&self.attributes, false));
+        interface.set_parent_definition(Some(&parent));
+        interface.set_is_limited(Attribute::find_metadata(&self.attributes).iter().any(|(metadata, _)| metadata.name == "Limited"));
+
+        let scope = host.factory().create_interface_scope(&interface);
+        scope.set_parent_scope(Some(&verifier.scope));
+        if let Some(type_parameters) = &self.type_parameters {
+            let type_parameters: SharedArray<Symbol> = type_parameters.iter().map(|p| host.factory().create_type_parameter_type(p.name.0.clone())).collect();
+            for type_parameter in type_parameters.iter() {
+                scope.properties(&host).set(type_parameter.name(), type_parameter.clone());
+            }
+            interface.set_type_parameters(Some(&type_parameters));
+        }
+        verifier.ast_to_symbol.set(&self.block, Some(scope));
+
+        // Interface methods are public, without a body, and
+        // carry no attributes other than meta-data.
+        for drtv in &self.block.directives {
+            let Directive::FunctionDefinition(defn) = drtv.as_ref() else {
+                continue;
+            };
+            for a in &defn.attributes {
+                if !(a.is_metadata() || matches!(a, Attribute::Public(_))) {
+                    verifier.add_verify_error(&a.location(), DiagnosticKind::UnallowedAttribute, diagnostic_arguments![]);
+                }
+            }
+            if defn.common.body.is_some() {
+                verifier.add_verify_error(&defn.name.location(), DiagnosticKind::FunctionMustNotContainBody, diagnostic_arguments![]);
+            }
+        }
+
+        properties.set(name, interface.clone());
+        Some(interface)
+    }
+
+    /// Resolves the extended interfaces. Every type is resolved before
+    /// anything is reported, so that a deferred interface does not
+    /// report the same error twice.
+    fn verify_heritage(&self, verifier: &mut VerifierVerifier, interface: &Symbol) -> Result<(), DeferVerificationError> {
+        let host = verifier.host.clone();
+
+        let mut extends_types: Vec<(Rc<Expression>, Symbol)> = vec![];
+        for exp in self.extends_clause.iter().flatten() {
+            if let Some(t) = verifier.verify_type_expression(exp)? {
+                extends_types.push((exp.clone(), t));
+            }
+        }
+
+        let mut extends_interfaces = interface.extends_interfaces(&host);
+        for (exp, t) in extends_types {
+            if !t.is_interface_type() {
+                verifier.add_verify_error(&exp.location(), DiagnosticKind::NotAnInterface, diagnostic_arguments![Symbol(t)]);
+                continue;
+            }
+            let is_cyclic = |t: &Symbol| t == interface || (t.is_type_after_explicit_type_substitution() && &t.origin() == interface);
+            if is_cyclic(&t) || t.all_ascending_types(&host).iter().any(is_cyclic) {
+                verifier.add_verify_error(&exp.location(), DiagnosticKind::CyclicInheritance, diagnostic_arguments![Symbol(interface.clone())]);
+                continue;
+            }
+            extends_interfaces.push(t);
+        }
+
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::ns::*;
+
+    #[test]
+    fn interface_definitions() {
+        let host = SymbolHost::new("");
+        declare_jet_lang_classes(&host, &["Object", "Boolean", "String", "Number", "Function", "Class", "Array"]);
+
+        let source = r#"
+            package p {
+                public interface A extends B {}
+                public interface B extends A {}
+                public interface Readable {
+                    function read(): Number;
+                    [Optional] function close(): void;
+                    function get size(): Number;
+                    [Optional] function write(): void {}
+                }
+                public interface Stream extends Readable {}
+                public class Base {
+                    public function read(): Number { return 0 }
+                }
+                public class File extends Base implements Stream {
+                    public function get size(): Number { return 0 }
+                }
+                public class Broken implements Stream {}
+            }
+        "#;
+        let compilation_unit = CompilationUnit::new(None, source.to_owned(), &CompilerOptions::new());
+        let program = ParserFacade::parse_program(&compilation_unit, &host).unwrap();
+        let mut verifier = Verifier::new(&host);
+        verifier.verify_programs(vec![program.clone()]);
+
+        let mut diagnostics = compilation_unit.diagnostics().iter().map(|d| d.id()).collect::<Vec<_>>();
+        diagnostics.sort();
+        assert_eq!(diagnostics, [
+            DiagnosticKind::FunctionMustNotContainBody.id(),
+            DiagnosticKind::CyclicInheritance.id(),
+            DiagnosticKind::UnimplementedInterfaceMethod.id(),
+            DiagnosticKind::UnimplementedInterfaceGetter.id(),
+        ]);
+
+        let directives = &program.packages[0].block.directives;
+        let readable = verifier.ast_to_symbol().get(&directives[2]).unwrap();
+        let stream = verifier.ast_to_symbol().get(&directives[3]).unwrap();
+        assert!(stream.extends_interfaces(&host).includes(&readable));
+        let close = readable.prototype(&host).get(&"close".to_owned()).unwrap();
+        assert!(close.is_optional_interface_method());
+        assert!(close.activation_scope().is_none());
+        assert!(close.visibility() == Visibility::Public);
+    }
 }
\ No newline at end of file
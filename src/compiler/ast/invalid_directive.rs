@@ -0,0 +1,10 @@
+use crate::ns::*;
+use serde::{Serialize, Deserialize};
+
+/// Placeholder for a directive that failed to parse. Only produced
+/// by the parser in error-tolerant mode, covering the tokens skipped
+/// while recovering.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct InvalidDirective {
+    pub location: Location,
+}
@@ -0,0 +1,9 @@
+use crate::ns::*;
+use serde::{Serialize, Deserialize};
+
+/// Placeholder for an expression that failed to parse. Only produced
+/// by the parser in error-tolerant mode.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct InvalidExpression {
+    pub location: Location,
+}
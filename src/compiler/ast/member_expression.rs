@@ -7,4 +7,73 @@ pub struct MemberExpression {
     pub location: Location,
     pub base: Rc<Expression>,
     pub identifier: QualifiedIdentifier,
+}
+
+impl MemberExpression {
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier, context: &ExpressionVerifyContext) -> Result<Option<Symbol>, DeferVerificationError> {
+        let Some(base) = verifier.verify_expression(&self.base, &default())? else {
+            self.identifier.verify(verifier)?;
+            return Ok(None);
+        };
+        let base = MemberExpression::property_base(&base);
+        self.identifier.verify_as_property_of(verifier, &base, context)
+    }
+
+    /// Returns the symbol whose properties are accessed by a member
+    /// operator: the referenced class or enumeration for a type
+    /// reference, otherwise the value itself.
+    pub(crate) fn property_base(base: &Symbol) -> Symbol {
+        match base.expect_type() {
+            Ok(t) if t.is_class_type() || t.is_enum_type() => t,
+            _ => base.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ns::*;
+
+    #[test]
+    fn property_resolution() {
+        let host = SymbolHost::new("");
+        let jet_lang = host.jet_lang_package();
+        let define = |name: &str, symbol: Symbol| {
+            symbol.set_parent_definition(Some(&jet_lang));
+            symbol.set_visibility(Visibility::Public);
+            jet_lang.properties(&host).set(name.to_owned(), symbol);
+        };
+        declare_jet_lang_classes(&host, &["Object", "Boolean", "String", "Number", "Class", "C"]);
+
+        // class C { static var x: Number }
+        let c = jet_lang.properties(&host).get(&"C".to_owned()).unwrap();
+        let x = host.factory().create_variable_property("x".into(), false, &host.number_type());
+        x.set_parent_definition(Some(&c));
+        x.set_visibility(Visibility::Public);
+        c.static_properties(&host).set("x".into(), x);
+
+        define("t", host.factory().create_variable_property("t".into(), false, &host.factory().create_tuple_type(vec![host.number_type(), host.string_type()])));
+        define("n", host.factory().create_variable_property("n".into(), false, &host.factory().create_nullable_type(&c)));
+
+        let verify = |source: &str| {
+            let compilation_unit = CompilationUnit::new(None, source.to_owned(), &CompilerOptions::new());
+            let exp = ParserFacade::parse_expression(&compilation_unit, &host).unwrap();
+            let v = Verifier::new(&host).verify_expression(&exp, &default());
+            (v, compilation_unit.diagnostics().iter().map(|d| d.kind()).collect::<Vec<_>>())
+        };
+
+        let (v, diagnostics) = verify("C.x");
+        let v = v.unwrap();
+        assert!(diagnostics.is_empty() && v.is_static_reference_value() && v.static_type(&host) == host.number_type());
+
+        let (v, diagnostics) = verify("t[1]");
+        let v = v.unwrap();
+        assert!(diagnostics.is_empty() && v.is_tuple_reference_value() && v.static_type(&host) == host.string_type());
+
+        let (_, diagnostics) = verify("C.y");
+        assert!(diagnostics == [DiagnosticKind::UndefinedProperty]);
+
+        let (_, diagnostics) = verify("n.x");
+        assert!(diagnostics == [DiagnosticKind::AccessingPropertyOfNullableBase]);
+    }
 }
\ No newline at end of file
@@ -7,4 +7,55 @@ pub struct NewExpression {
     pub location: Location,
     pub base: Rc<Expression>,
     pub arguments: Option<Vec<Rc<Expression>>>,
+}
+
+impl NewExpression {
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier) -> Result<Option<Symbol>, DeferVerificationError> {
+        let host = verifier.host.clone();
+        let arguments = self.arguments.clone().unwrap_or(vec![]);
+
+        let Some(base) = verifier.verify_expression(&self.base, &default())? else {
+            for argument in &arguments {
+                verifier.verify_expression(argument, &default())?;
+            }
+            return Ok(None);
+        };
+
+        let Ok(t) = base.expect_type() else {
+            let base_type = base.static_type(&host);
+            base_type.throw_if_unresolved()?;
+            for argument in &arguments {
+                verifier.verify_expression(argument, &default())?;
+            }
+
+            // Dynamic instantiation
+            if base_type.is_any_type() || base_type == host.class_type() {
+                return Ok(Some(host.factory().create_value(&host.any_type())));
+            }
+
+            verifier.add_verify_error(&self.base.location(), DiagnosticKind::MustResolveToType, diagnostic_arguments![]);
+            return Ok(None);
+        };
+
+        if !t.is_class_type() || t.is_abstract() {
+            for argument in &arguments {
+                verifier.verify_expression(argument, &default())?;
+            }
+            verifier.add_verify_error(&self.base.location(), DiagnosticKind::CannotInstantiate, diagnostic_arguments![Symbol(t)]);
+            return Ok(None);
+        }
+
+        if let Some(constructor) = t.constructor_function(&host) {
+            let signature = constructor.signature(&host);
+            signature.throw_if_unresolved()?;
+            CallExpression::verify_arguments(verifier, &self.location, &arguments, &signature)?;
+        } else if !arguments.is_empty() {
+            for argument in &arguments {
+                verifier.verify_expression(argument, &default())?;
+            }
+            verifier.add_verify_error(&arguments[0].location(), DiagnosticKind::WrongNumberOfArguments, diagnostic_arguments![String("0".into())]);
+        }
+
+        Ok(Some(host.factory().create_value(&t)))
+    }
 }
\ No newline at end of file
@@ -18,12 +18,12 @@ impl NumericLiteral {
     /// decimal, binary (`0b`) or hexadecimal (`0x`) notation.
     pub fn parse_double(&self, negative: bool) -> Result<f64, ParsingFailure> {
         let s = self.value.replace('_', "");
-        if s.starts_with('0') {
-            if s[1..].starts_with('x') || s[1..].starts_with('X') {
+        if let Some(s_1) = s.strip_prefix('0') {
+            if s_1.starts_with(['x', 'X']) {
                 let n = u64::from_str_radix(&(if negative { "-" } else { "" }.to_owned() + &s[2..]), 16);
                 return n.map_err(|_| ParsingFailure)
                     .and_then(|n| f64::value_from(n).map_err(|_| ParsingFailure));
-            } else if s[1..].starts_with('b') || s[1..].starts_with('B') {
+            } else if s_1.starts_with(['b', 'B']) {
                 let n = u64::from_str_radix(&(if negative { "-" } else { "" }.to_owned() + &s[2..]), 2);
                 return n.map_err(|_| ParsingFailure)
                     .and_then(|n| f64::value_from(n).map_err(|_| ParsingFailure));
@@ -36,12 +36,12 @@ impl NumericLiteral {
     /// decimal, binary (`0b`) or hexadecimal (`0x`) notation.
     pub fn parse_single(&self, negative: bool) -> Result<f32, ParsingFailure> {
         let s = self.value.replace('_', "");
-        if s.starts_with('0') {
-            if s[1..].starts_with('x') || s[1..].starts_with('X') {
+        if let Some(s_1) = s.strip_prefix('0') {
+            if s_1.starts_with(['x', 'X']) {
                 let n = u64::from_str_radix(&(if negative { "-" } else { "" }.to_owned() + &s[2..]), 16);
                 return n.map_err(|_| ParsingFailure)
                     .and_then(|n| f32::value_from(n).map_err(|_| ParsingFailure));
-            } else if s[1..].starts_with('b') || s[1..].starts_with('B') {
+            } else if s_1.starts_with(['b', 'B']) {
                 let n = u64::from_str_radix(&(if negative { "-" } else { "" }.to_owned() + &s[2..]), 2);
                 return n.map_err(|_| ParsingFailure)
                     .and_then(|n| f32::value_from(n).map_err(|_| ParsingFailure));
@@ -54,37 +54,37 @@ impl NumericLiteral {
     /// decimal, binary (`0b`) or hexadecimal (`0x`) notation.
     pub fn parse_long(&self, negative: bool) -> Result<i64, ParsingFailure> {
         let s = self.value.replace('_', "");
-        if s.starts_with('0') {
-            if s[1..].starts_with('x') || s[1..].starts_with('X') {
+        if let Some(s_1) = s.strip_prefix('0') {
+            if s_1.starts_with(['x', 'X']) {
                 let n = i64::from_str_radix(&(if negative { "-" } else { "" }.to_owned() + &s[2..]), 16);
                 return n.map_err(|_| ParsingFailure);
-            } else if s[1..].starts_with('b') || s[1..].starts_with('B') {
+            } else if s_1.starts_with(['b', 'B']) {
                 let n = i64::from_str_radix(&(if negative { "-" } else { "" }.to_owned() + &s[2..]), 2);
                 return n.map_err(|_| ParsingFailure);
             }
         }
-        i64::from_str(&s).map_err(|_| ParsingFailure)
+        i64::from_str(&(if negative { "-" } else { "" }.to_owned() + &s)).map_err(|_| ParsingFailure)
     }
 
     /// Parses a big integer either in
     /// decimal, binary (`0b`) or hexadecimal (`0x`) notation.
     pub fn parse_big_int(&self, negative: bool) -> Result<BigInt, ParsingFailure> {
         let s = self.value.replace('_', "");
-        if s.starts_with('0') {
-            if s[1..].starts_with('x') || s[1..].starts_with('X') {
+        if let Some(s_1) = s.strip_prefix('0') {
+            if s_1.starts_with(['x', 'X']) {
                 let mut digits: Vec<u8> = vec![];
                 for ch in s[2..].chars() {
                     digits.push(CharacterValidator::hex_digit_mv(ch).unwrap().to_u8().unwrap());
                 }
                 let n = BigInt::from_radix_be(if negative { num_bigint::Sign::Minus } else { num_bigint::Sign::Plus }, &digits, 16);
-                return n.map_or(Err(ParsingFailure), |n| Ok(n));
-            } else if s[1..].starts_with('b') || s[1..].starts_with('B') {
+                return n.ok_or(ParsingFailure);
+            } else if s_1.starts_with(['b', 'B']) {
                 let mut digits: Vec<u8> = vec![];
                 for ch in s[2..].chars() {
                     digits.push(CharacterValidator::bin_digit_mv(ch).unwrap().to_u8().unwrap());
                 }
                 let n = BigInt::from_radix_be(if negative { num_bigint::Sign::Minus } else { num_bigint::Sign::Plus }, &digits, 2);
-                return n.map_or(Err(ParsingFailure), |n| Ok(n));
+                return n.ok_or(ParsingFailure);
             }
         }
         BigInt::from_str(&s).map_err(|_| ParsingFailure)
@@ -108,7 +108,7 @@ impl NumericLiteral {
             verifier.add_syntax_error(&self.location, DiagnosticKind::FailedParsingNumericLiteral, diagnostic_arguments![]);
             return Ok(None);
         }
-        return Ok(Some(verifier.host.factory().create_number_constant(n.unwrap(), &t)));
+        Ok(Some(verifier.host.factory().create_number_constant(n.unwrap(), &t)))
     }
 
     fn parse_with_static_type(&self, host: &Rc<SymbolHost>, static_type: &Symbol, context: &ExpressionVerifyContext) -> Result<AbstractRangeNumber, ParsingFailure> {
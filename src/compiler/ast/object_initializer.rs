@@ -76,7 +76,7 @@ impl ObjectInitializer {
                                 verifier.add_verify_error(&name.1, DiagnosticKind::IncompatibleTypes, diagnostic_arguments![Symbol(v_t.clone()), Symbol(short_ref_1.static_type(&verifier.host))]);
                             }
                         }
-                        if ![verifier.host.any_type(), verifier.host.object_type(), verifier.host.string_type()].contains(&k_t) {
+                        if ![verifier.host.any_type(), verifier.host.object_type(), verifier.host.string_type()].contains(k_t) {
                             verifier.add_verify_error(&name.1, DiagnosticKind::IncompatibleFieldKey, diagnostic_arguments![]);
                         }
                     } else {
@@ -85,12 +85,12 @@ impl ObjectInitializer {
                                 verifier.limit_expression_type(exp, k_t)?;
                             },
                             FieldName::Identifier(_) | FieldName::StringLiteral(_) => {
-                                if ![verifier.host.any_type(), verifier.host.object_type(), verifier.host.string_type()].contains(&k_t) {
+                                if ![verifier.host.any_type(), verifier.host.object_type(), verifier.host.string_type()].contains(k_t) {
                                     verifier.add_verify_error(&name.1, DiagnosticKind::IncompatibleFieldKey, diagnostic_arguments![]);
                                 }
                             },
                             FieldName::NumericLiteral(_) => {
-                                if ![verifier.host.any_type(), verifier.host.object_type(), verifier.host.number_type()].contains(&k_t) {
+                                if ![verifier.host.any_type(), verifier.host.object_type(), verifier.host.number_type()].contains(k_t) {
                                     verifier.add_verify_error(&name.1, DiagnosticKind::IncompatibleFieldKey, diagnostic_arguments![]);
                                 }
                             },
@@ -156,7 +156,7 @@ impl ObjectInitializer {
         }
 
         if is_const {
-            return Ok(Some(verifier.host.factory().create_enum_constant(c, &context_type)));
+            return Ok(Some(verifier.host.factory().create_enum_constant(c, context_type)));
         }
 
         Ok(Some(verifier.host.factory().create_value(context_type)))
@@ -167,10 +167,10 @@ impl ObjectInitializer {
             panic!();
         };
         let value_exp = value.as_ref().unwrap();
-        let c1 = verifier.limit_expression_type(value_exp, &boolean_type)?;
+        let c1 = verifier.limit_expression_type(value_exp, boolean_type)?;
         match &name.0 {
             FieldName::Brackets(exp) => {
-                verifier.limit_expression_type(exp, &string_type)?;
+                verifier.limit_expression_type(exp, string_type)?;
                 is_const = false;
             },
             FieldName::Identifier(name_1) => {
@@ -213,7 +213,7 @@ impl ObjectInitializer {
         let c = context_type.non_null_type();
         
         let mut missing = HashSet::<Symbol>::new();
-        for (_, prop) in c.prototype(&verifier.host).borrow().iter() {
+        for prop in c.prototype(&verifier.host).borrow().values() {
             if prop.is_variable_property() && !prop.is_optional_variable(&verifier.host)? {
                 missing.insert(prop.clone());
             }
@@ -266,11 +266,11 @@ impl ObjectInitializer {
         match &name.0 {
             FieldName::Brackets(exp) => {
                 verifier.limit_expression_type(exp, &verifier.host.string_type())?;
-                verifier.verify_expression(value_exp, &default());
+                verifier.verify_expression(value_exp, &default())?;
                 missing.clear();
             },
             FieldName::Identifier(name_1) => {
-                let variable = Self::resolve_instance_variable(verifier, &c, &(name_1.clone(), name.1.clone()))?;
+                let variable = Self::resolve_instance_variable(verifier, c, &(name_1.clone(), name.1.clone()))?;
                 if let Some(variable) = variable.clone() {
                     missing.remove(&variable);
                 }
@@ -279,7 +279,7 @@ impl ObjectInitializer {
                     if variable_data_type.is_unresolved() {
                         verifier.verify_expression(value_exp, &default())?;
                     } else {
-                        verifier.limit_expression_type(value_exp, &variable_data_type);
+                        verifier.limit_expression_type(value_exp, &variable_data_type)?;
                     }
                 } else {
                     verifier.verify_expression(value_exp, &default())?;
@@ -287,7 +287,7 @@ impl ObjectInitializer {
             },
             FieldName::StringLiteral(sl) => {
                 let name_1 = verifier.verify_expression(sl, &default())?.unwrap().string_value();
-                let variable = Self::resolve_instance_variable(verifier, &c, &(name_1, name.1.clone()))?;
+                let variable = Self::resolve_instance_variable(verifier, c, &(name_1, name.1.clone()))?;
                 if let Some(variable) = variable.clone() {
                     missing.remove(&variable);
                 }
@@ -296,14 +296,14 @@ impl ObjectInitializer {
                     if variable_data_type.is_unresolved() {
                         verifier.verify_expression(value_exp, &default())?;
                     } else {
-                        verifier.limit_expression_type(value_exp, &variable_data_type);
+                        verifier.limit_expression_type(value_exp, &variable_data_type)?;
                     }
                 } else {
                     verifier.verify_expression(value_exp, &default())?;
                 }
             },
             FieldName::NumericLiteral(_) => {
-                verifier.verify_expression(value_exp, &default());
+                verifier.verify_expression(value_exp, &default())?;
                 verifier.add_verify_error(&name.1, DiagnosticKind::IncompatibleFieldKey, diagnostic_arguments![]);
             },
         }
@@ -335,8 +335,9 @@ impl ObjectInitializer {
     fn resolve_shorthand(verifier: &mut VerifierVerifier, name: &(String, Location)) -> Result<Option<Symbol>, DeferVerificationError> {
         let name_str = &name.0;
         let r = verifier.scope.resolve_property(None, SemanticPropertyKey::String(name_str.clone()), &verifier.host);
-        if r.is_err() {
-            match r.unwrap_err() {
+        let r = match r {
+            Ok(r) => r,
+            Err(e) => match e {
                 PropertyResolutionError::AmbiguousReference { name: amb_name } => {
                     verifier.add_verify_error(&name.1, DiagnosticKind::AmbiguousReference, diagnostic_arguments![String(amb_name.clone())]);
                     return Ok(None);
@@ -352,9 +353,8 @@ impl ObjectInitializer {
                     verifier.add_verify_error(&name.1, DiagnosticKind::AccessingPropertyOfNullableBase, diagnostic_arguments![Symbol(nullable_type)]);
                     return Ok(None);
                 },
-            }
-        }
-        let r = r.unwrap();
+            },
+        };
         if r.is_none() {
             verifier.add_verify_error(&name.1, DiagnosticKind::UndefinedProperty, diagnostic_arguments![String(name_str.clone())]);
             return Ok(None);
@@ -378,7 +378,7 @@ impl ObjectInitializer {
     }
 
     fn resolve_instance_variable(verifier: &mut VerifierVerifier, c: &Symbol, name: &(String, Location)) -> Result<Option<Symbol>, DeferVerificationError> {
-        let variable = c.prototype(&verifier.host).get(&name.0).and_then(|v| if v.is_variable_property() { Some(v) } else { None });
+        let variable = c.prototype(&verifier.host).get(&name.0).filter(|v| v.is_variable_property());
         if variable.is_none() {
             verifier.add_verify_error(&name.1, DiagnosticKind::UndefinedProperty, diagnostic_arguments![String(name.0.clone())]);
             return Ok(None);
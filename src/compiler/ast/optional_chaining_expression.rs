@@ -14,6 +14,26 @@ pub struct OptionalChainingExpression {
     pub expression: Rc<Expression>,
 }
 
+impl OptionalChainingExpression {
+    /// Verifies the optional chaining operations against the non-null
+    /// base, resulting into a nullable value.
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier) -> Result<Option<Symbol>, DeferVerificationError> {
+        let host = verifier.host.clone();
+        let Some(base) = verifier.verify_expression(&self.base, &default())? else {
+            return Ok(None);
+        };
+        let base_type = base.static_type(&host).non_null_type();
+        verifier.optional_chaining_bases.push(host.factory().create_value(&base_type));
+        let result = verifier.verify_narrowed(&[], |verifier| verifier.verify_expression(&self.expression, &default()));
+        verifier.optional_chaining_bases.pop();
+        let Some(result) = result? else {
+            return Ok(None);
+        };
+        let result_type = result.static_type(&host);
+        Ok(Some(host.factory().create_value(&host.factory().create_nullable_type(&result_type))))
+    }
+}
+
 /// Internal expression used as the topmost expression
 /// of a sequence of optional chaining operations.
 #[derive(Clone, Serialize, Deserialize)]
@@ -68,40 +68,101 @@ impl QualifiedIdentifier {
     }
 
     pub(crate) fn verify_as_exp(&self, verifier: &mut VerifierVerifier, context: &ExpressionVerifyContext) -> Result<Option<Symbol>, DeferVerificationError> {
+        let scope = verifier.scope.clone();
+        let r = self.verify_as_property_of(verifier, &scope, context)?;
+
+        // Local variables may be narrowed by enclosing conditions
+        // or assignments. Assignment targets are of the declared type.
+        if let Some(r) = &r {
+            if context.mode == VerifyMode::Read && r.is_scope_reference_value() && r.property().is_variable_property() {
+                let variable = r.property();
+                if let Some(t) = scope.find_narrowed_type(&variable) {
+                    let r = verifier.host.factory().create_scope_reference_value(&r.base(), &variable);
+                    r.set_static_type(&t);
+                    return Ok(Some(r));
+                }
+            }
+        }
+
+        Ok(r)
+    }
+
+    /// Resolves the qualified identifier as a property of `base`, which is
+    /// either a scope, a type or a value.
+    pub(crate) fn verify_as_property_of(&self, verifier: &mut VerifierVerifier, base: &Symbol, context: &ExpressionVerifyContext) -> Result<Option<Symbol>, DeferVerificationError> {
         let qn = self.verify(verifier)?;
         if qn.is_none() {
             return Ok(None);
         }
         let (qual, key, disamb) = qn.unwrap();
-        let r = verifier.scope.resolve_property_with_disambiguation(qual, key.clone(), &verifier.host, disamb);
-        if r.is_err() {
-            match r.unwrap_err() {
+        let Some(r) = QualifiedIdentifier::verify_property_resolution(verifier, &self.location, base, qual, key, disamb, context)? else {
+            return Ok(None);
+        };
+        if r.is_xml_reference_value() {
+            return Ok(Some(self.verify_xml_reference(verifier, &r)?));
+        }
+        // Attributes are only accessible dynamically.
+        if self.attribute && !(r.is_dynamic_reference_value() || r.is_dynamic_scope_reference_value()) {
+            verifier.add_verify_error(&self.location, DiagnosticKind::UnsupportedAttributeAccess, diagnostic_arguments![]);
+            return Ok(None);
+        }
+        Ok(Some(r))
+    }
+
+    /// Types an access to an element or attribute of an `XML` or `XMLList`
+    /// value. Attributes yield an `XMLList`; unqualified element names are
+    /// qualified by the innermost `default xml namespace`, if any.
+    fn verify_xml_reference(&self, verifier: &mut VerifierVerifier, r: &Symbol) -> Result<Symbol, DeferVerificationError> {
+        let host = verifier.host.clone();
+        if self.attribute {
+            let xml_list_type = host.xml_list_type();
+            xml_list_type.throw_if_unresolved()?;
+            r.set_static_type(&xml_list_type);
+            return Ok(r.clone());
+        }
+        if r.qualifier().is_none() {
+            let default_namespace = verifier.scope.descending_scope_hierarchy()
+                .filter(|scope| scope.is_activation_scope())
+                .find_map(|activation| activation.default_xml_namespace());
+            if let Some(namespace) = default_namespace {
+                return Ok(host.factory().create_xml_reference_value(&r.base(), Some(namespace), &r.key(), r.disambiguation()));
+            }
+        }
+        Ok(r.clone())
+    }
+
+    /// Resolves a property through `PropertyResolution`, reporting
+    /// resolution errors, undefined properties and inaccessible properties.
+    pub(crate) fn verify_property_resolution(verifier: &mut VerifierVerifier, location: &Location, base: &Symbol, qual: Option<Symbol>, key: SemanticPropertyKey, disamb: PropertyDisambiguation, context: &ExpressionVerifyContext) -> Result<Option<Symbol>, DeferVerificationError> {
+        let r = base.resolve_property_with_disambiguation(qual, key.clone(), &verifier.host, disamb);
+        let r = match r {
+            Ok(r) => r,
+            Err(e) => match e {
                 PropertyResolutionError::AmbiguousReference { name } => {
-                    verifier.add_verify_error(&self.location, DiagnosticKind::AmbiguousReference, diagnostic_arguments![String(name.clone())]);
+                    verifier.add_verify_error(location, DiagnosticKind::AmbiguousReference, diagnostic_arguments![String(name.clone())]);
                     return Ok(None);
                 },
                 PropertyResolutionError::DeferVerification => {
                     return Err(DeferVerificationError);
                 },
                 PropertyResolutionError::VoidBase => {
-                    verifier.add_verify_error(&self.location, DiagnosticKind::AccessingPropertyOfVoidBase, diagnostic_arguments![]);
+                    verifier.add_verify_error(location, DiagnosticKind::AccessingPropertyOfVoidBase, diagnostic_arguments![]);
                     return Ok(None);
                 },
                 PropertyResolutionError::NullableBase { nullable_type } => {
-                    verifier.add_verify_error(&self.location, DiagnosticKind::AccessingPropertyOfNullableBase, diagnostic_arguments![Symbol(nullable_type)]);
+                    verifier.add_verify_error(location, DiagnosticKind::AccessingPropertyOfNullableBase, diagnostic_arguments![Symbol(nullable_type)]);
                     return Ok(None);
                 },
-            }
-        }
-        let r = r.unwrap();
+            },
+        };
         if r.is_none() {
-            verifier.add_verify_error(&self.location, DiagnosticKind::UndefinedProperty, diagnostic_arguments![String(key.string_value().unwrap_or(key.number_value().unwrap().to_string()))]);
+            verifier.add_verify_error(location, DiagnosticKind::UndefinedProperty, diagnostic_arguments![String(key.string_value().unwrap_or_else(|| key.number_value().unwrap().to_string()))]);
             return Ok(None);
         }
         let r = r.unwrap();
 
         if !r.property_is_visible(&verifier.scope, &verifier.host) {
-            verifier.add_verify_error(&self.location, DiagnosticKind::InaccessibleProperty, diagnostic_arguments![String(key.string_value().unwrap())]);
+            verifier.add_verify_error(location, DiagnosticKind::InaccessibleProperty, diagnostic_arguments![String(key.string_value().unwrap())]);
         }
 
         if r.is_reference_value() && (r.is_static_reference_value() || r.is_instance_reference_value() || r.is_scope_reference_value() || r.is_package_reference_value()) {
@@ -109,12 +170,11 @@ impl QualifiedIdentifier {
 
             // Require type arguments
             if (p.is_origin_function() || p.is_origin_class_type() || p.is_origin_interface_type()) && p.type_parameters().is_some() && !context.followed_by_type_arguments {
-                verifier.add_verify_error(&self.location, DiagnosticKind::TypeParameterizedPropertyMustBeArgumented, diagnostic_arguments![]);
+                verifier.add_verify_error(location, DiagnosticKind::TypeParameterizedPropertyMustBeArgumented, diagnostic_arguments![]);
             }
 
             // Compile-time constant
-            if p.is_origin_variable_property() && p.read_only(&verifier.host) && p.constant_initializer().is_some() {
-                let r = p.constant_initializer().unwrap();
+            if let Some(r) = ConstantEvaluator(&verifier.host).constant_of(&r) {
                 return Ok(Some(r));
             }
         }
@@ -10,6 +10,44 @@ pub struct RegExpLiteral {
 
 impl RegExpLiteral {
     pub(crate) fn verify(&self, verifier: &mut VerifierVerifier) -> Result<Option<Symbol>, DeferVerificationError> {
+        let compilation_unit = self.location.compilation_unit();
+        for issue in RegExpValidator::validate(&self.body, &self.flags) {
+            let location = Location::with_offsets(&compilation_unit, self.location.first_offset() + issue.range.start, self.location.first_offset() + issue.range.end);
+            verifier.add_verify_error(&location, issue.kind, issue.arguments);
+        }
         Ok(Some(verifier.host.factory().create_value(&verifier.host.reg_exp_type())))
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ns::*;
+
+    #[test]
+    fn regexp_literals() {
+        let host = SymbolHost::new("");
+        declare_jet_lang_classes(&host, &["Object", "Boolean", "String", "Number", "Function", "Class", "Array", "RegExp"]);
+
+        let source = r#"
+            package p {
+                public function f(): void {
+                    const a: RegExp = /(ab/g;
+                    const b: RegExp = /ab/gx;
+                    const c: RegExp = /=a{2,1}/;
+                    const n: Number = 10 / 2 / 5;
+                }
+            }
+        "#;
+        let compilation_unit = CompilationUnit::new(None, source.to_owned(), &CompilerOptions::new());
+        let program = ParserFacade::parse_program(&compilation_unit, &host).unwrap();
+        let mut verifier = Verifier::new(&host);
+        verifier.verify_programs(vec![program.clone()]);
+
+        let diagnostics = compilation_unit.diagnostics().iter().map(|d| (d.id(), d.location().first_line_number(), d.location().text())).collect::<Vec<_>>();
+        assert_eq!(diagnostics, [
+            (DiagnosticKind::UnterminatedRegExpGroup.id(), 4, "(".to_owned()),
+            (DiagnosticKind::UnknownRegExpFlag.id(), 5, "x".to_owned()),
+            (DiagnosticKind::RegExpQuantifierOutOfOrder.id(), 6, "{2,1}".to_owned()),
+        ]);
+    }
 }
\ No newline at end of file
@@ -6,4 +6,43 @@ use std::rc::Rc;
 pub struct ReturnStatement {
     pub location: Location,
     pub expression: Option<Rc<Expression>>,
+}
+
+impl ReturnStatement {
+    /// Verifies the returned value against the result type
+    /// of the enclosing function.
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier) -> Result<(), DeferVerificationError> {
+        let host = verifier.host.clone();
+        let Some(function) = verifier.scope.find_activation().map(|activation| activation.function()) else {
+            if let Some(exp) = &self.expression {
+                verifier.verify_expression(exp, &default())?;
+            }
+            return Ok(());
+        };
+        let signature = function.signature(&host);
+        signature.throw_if_unresolved()?;
+        let result_type = signature.result_type();
+
+        if function.is_generator() || function.is_async() || result_type.is_any_type() {
+            if let Some(exp) = &self.expression {
+                verifier.verify_expression(exp, &default())?;
+            }
+            return Ok(());
+        }
+
+        if result_type.is_void_type() {
+            if let Some(exp) = &self.expression {
+                verifier.verify_expression(exp, &default())?;
+                verifier.add_verify_error(&exp.location(), DiagnosticKind::VoidFunctionMustNotReturnValue, diagnostic_arguments![]);
+            }
+            return Ok(());
+        }
+
+        let Some(exp) = &self.expression else {
+            verifier.add_verify_error(&self.location, DiagnosticKind::ReturnValueExpected, diagnostic_arguments![Symbol(result_type)]);
+            return Ok(());
+        };
+        verifier.limit_expression_type(exp, &result_type)?;
+        Ok(())
+    }
 }
\ No newline at end of file
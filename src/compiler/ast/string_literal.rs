@@ -17,19 +17,19 @@ impl StringLiteral {
                     verifier.add_verify_error(&self.location, DiagnosticKind::StringLiteralMustBeASingleCharacter, diagnostic_arguments![]);
                     return Ok(None);
                 }
-                return Ok(Some(verifier.host.factory().create_char_constant(ch[0], &t)));
+                return Ok(Some(verifier.host.factory().create_char_constant(ch[0], t)));
             }
             if t_non_null.is_enum_type() {
                 let et = t_non_null;
                 let m = et.enumeration_members().get(&self.value);
                 if let Some(m) = m {
-                    return Ok(Some(verifier.host.factory().create_enum_constant(m, &t)));
+                    return Ok(Some(verifier.host.factory().create_enum_constant(m, t)));
                 } else {
                     verifier.add_verify_error(&self.location, DiagnosticKind::EnumerationHasNoMember, diagnostic_arguments![Symbol(et), String(self.value.clone())]);
                     return Ok(None);
                 }
             }
         }
-        return Ok(Some(verifier.host.factory().create_string_constant(self.value.clone(), &verifier.host.string_type())));
+        Ok(Some(verifier.host.factory().create_string_constant(self.value.clone(), &verifier.host.string_type())))
     }
 }
\ No newline at end of file
@@ -9,11 +9,37 @@ use std::rc::Rc;
 /// following property operator is limited to access a property from the base class
 /// or invoke a method of the base class.
 /// 
-/// ```
+/// ```jet
 /// super.f()
 /// ```
 #[derive(Clone, Serialize, Deserialize)]
 pub struct SuperExpression {
     pub location: Location,
     pub object: Option<Vec<Rc<Expression>>>,
+}
+
+impl SuperExpression {
+    /// Verifies the super expression, resulting into the object
+    /// (`this` by default) typed as the base class.
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier) -> Result<Option<Symbol>, DeferVerificationError> {
+        let host = verifier.host.clone();
+        let mut object: Option<Symbol> = None;
+        if let Some(exps) = &self.object {
+            for exp in exps {
+                object = verifier.verify_expression(exp, &default())?;
+            }
+            if object.is_none() {
+                return Ok(None);
+            }
+        } else {
+            object = verifier.scope.find_activation().and_then(|activation| activation.this());
+        }
+        let base_class = object.map(|object| object.static_type(&host)).filter(|t| t.is_class_type()).and_then(|t| t.extends_class(&host));
+        let Some(base_class) = base_class else {
+            verifier.add_verify_error(&self.location, DiagnosticKind::IllegalSuperExpression, diagnostic_arguments![]);
+            return Ok(None);
+        };
+        base_class.throw_if_unresolved()?;
+        Ok(Some(host.factory().create_value(&base_class)))
+    }
 }
\ No newline at end of file
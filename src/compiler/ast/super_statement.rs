@@ -6,4 +6,27 @@ use std::rc::Rc;
 pub struct SuperStatement {
     pub location: Location,
     pub arguments: Vec<Rc<Expression>>,
+}
+
+impl SuperStatement {
+    /// Verifies the arguments against the constructor
+    /// of the base class.
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier) -> Result<(), DeferVerificationError> {
+        let host = verifier.host.clone();
+        let base_class = verifier.scope.find_activation()
+            .and_then(|activation| activation.function().parent_definition())
+            .filter(|class| class.is_class_type())
+            .and_then(|class| class.extends_class(&host));
+        let constructor = base_class.and_then(|class| class.constructor_function(&host));
+        if let Some(constructor) = constructor {
+            let signature = constructor.signature(&host);
+            signature.throw_if_unresolved()?;
+            CallExpression::verify_arguments(verifier, &self.location, &self.arguments, &signature)?;
+        } else {
+            for argument in &self.arguments {
+                verifier.verify_expression(argument, &default())?;
+            }
+        }
+        Ok(())
+    }
 }
\ No newline at end of file
@@ -44,4 +44,267 @@ pub struct TypeCase {
     /// Case parameter. If `None`, designates a `default {}` case.
     pub parameter: Option<TypedDestructuring>,
     pub block: Rc<Block>,
+}
+
+impl SwitchStatement {
+    /// Verifies the statement. Case labels are limited to the type
+    /// of the discriminant and the cases share a single scope.
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier, drtv: &Rc<Directive>, phase: VerifierPhase) -> Result<(), DeferVerificationError> {
+        if phase == VerifierPhase::Bodies {
+            let host = verifier.host.clone();
+            let discriminant_type = verifier.verify_expression(&self.discriminant, &default())?.map(|d| d.static_type(&host));
+            if let Some(t) = &discriminant_type {
+                t.throw_if_unresolved()?;
+            }
+            for case in &self.cases {
+                for label in &case.labels {
+                    let CaseLabel::Case((exp, _)) = label else {
+                        continue;
+                    };
+                    if let Some(t) = &discriminant_type {
+                        verifier.limit_expression_type(exp, t)?;
+                    } else {
+                        verifier.verify_expression(exp, &default())?;
+                    }
+                }
+            }
+            self.verify_exhaustiveness(verifier, discriminant_type.as_ref());
+        }
+        let scope = verifier.node_scope(drtv);
+        verifier.enter_scope(&scope);
+        for case in &self.cases {
+            // A case may be reached by a jump, past the
+            // assignments of the preceding cases.
+            VerifierVerifier::clear_scope_narrowings(&scope, |_| true);
+            verifier.verify_directives(&case.directives, phase);
+        }
+        verifier.exit_scope();
+        Ok(())
+    }
+
+    /// Reports duplicate constant labels and, in the absence of
+    /// a `default` label, the members of an enumeration discriminant
+    /// that no case covers.
+    fn verify_exhaustiveness(&self, verifier: &mut VerifierVerifier, discriminant_type: Option<&Symbol>) {
+        let mut constants: Vec<Symbol> = vec![];
+        let mut has_default = false;
+        for case in &self.cases {
+            for label in &case.labels {
+                let CaseLabel::Case((exp, location)) = label else {
+                    has_default = true;
+                    continue;
+                };
+                let Some(v) = verifier.ast_to_symbol.get(exp).filter(|v| v.is_constant()) else {
+                    continue;
+                };
+                if constants.iter().any(|v_1| Self::same_constant(v_1, &v)) {
+                    verifier.add_warning(location, DiagnosticKind::UnreachableCase, diagnostic_arguments![]);
+                    continue;
+                }
+                constants.push(v);
+            }
+        }
+
+        let Some(t) = discriminant_type else {
+            return;
+        };
+        let enum_type = t.non_null_type();
+        if has_default || !enum_type.is_enum_type() || enum_type.is_set_enumeration() {
+            return;
+        }
+        let mut missing: Vec<(AbstractRangeNumber, String)> = enum_type.static_properties(&verifier.host).borrow().iter()
+            .filter(|(_, p)| p.is_variable_property())
+            .filter_map(|(name, p)| Some((p.constant_initializer().filter(|v| v.is_enum_constant())?.number_value(), name.clone())))
+            .filter(|(value, _)| !constants.iter().any(|v| v.is_enum_constant() && &v.number_value() == value))
+            .collect();
+        missing.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap());
+        let mut missing: Vec<String> = missing.into_iter().map(|(_, name)| name).collect();
+        if t.is_nullable_type() && !constants.iter().any(|v| v.is_null_constant()) {
+            missing.push("null".into());
+        }
+        if !missing.is_empty() {
+            verifier.add_warning(&self.location, DiagnosticKind::NonExhaustiveSwitch, diagnostic_arguments![String(missing.join(", "))]);
+        }
+    }
+
+    fn same_constant(a: &Symbol, b: &Symbol) -> bool {
+        if (a.is_number_constant() && b.is_number_constant()) || (a.is_enum_constant() && b.is_enum_constant()) {
+            a.number_value() == b.number_value()
+        } else if a.is_string_constant() && b.is_string_constant() {
+            a.string_value() == b.string_value()
+        } else if a.is_char_constant() && b.is_char_constant() {
+            a.char_value() == b.char_value()
+        } else if a.is_boolean_constant() && b.is_boolean_constant() {
+            a.boolean_value() == b.boolean_value()
+        } else {
+            (a.is_null_constant() && b.is_null_constant()) || (a.is_undefined_constant() && b.is_undefined_constant())
+        }
+    }
+}
+
+impl SwitchTypeStatement {
+    /// Verifies the statement, narrowing a local variable discriminant
+    /// to the type of each case within the case block. A case parameter
+    /// is declared as a variable of the case block.
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier, phase: VerifierPhase) -> Result<(), DeferVerificationError> {
+        let host = verifier.host.clone();
+        let scopes: Vec<Symbol> = self.cases.iter().map(|case| verifier.node_scope(&case.block)).collect();
+        let mut variable: Option<Symbol> = None;
+        if phase == VerifierPhase::Bodies {
+            let discriminant = verifier.verify_expression(&self.discriminant, &default())?;
+            variable = verifier.narrowable_variable(&self.discriminant).map(|(variable, _)| variable);
+            for (case, scope) in self.cases.iter().zip(scopes.iter()) {
+                if let Some(parameter) = &case.parameter {
+                    verifier.verify_typed_destructuring(parameter, false, &scope.properties(&host), scope, Visibility::Internal, None)?;
+                }
+            }
+            if let Some(discriminant) = discriminant {
+                self.verify_exhaustiveness(verifier, &discriminant.static_type(&host));
+            }
+        }
+        for (case, scope) in self.cases.iter().zip(scopes.iter()) {
+            if let Some(variable) = &variable {
+                let t = case.parameter.as_ref()
+                    .and_then(|parameter| parameter.type_annotation.as_ref())
+                    .and_then(|t| verifier.ast_to_symbol.get(t));
+                if let Some(t) = t {
+                    VerifierVerifier::narrow_scope(scope, &[(variable.clone(), t)]);
+                }
+            }
+            verifier.enter_scope(scope);
+            verifier.verify_directives(&case.block.directives, phase);
+            verifier.exit_scope();
+        }
+        Ok(())
+    }
+
+    /// Reports cases whose type is covered by an earlier case and, in
+    /// the absence of a `default` case, the subtypes of a limited class
+    /// or interface discriminant that no case covers.
+    fn verify_exhaustiveness(&self, verifier: &mut VerifierVerifier, discriminant_type: &Symbol) {
+        let host = verifier.host.clone();
+        let mut case_types: Vec<Symbol> = vec![];
+        let mut has_default = false;
+        for case in &self.cases {
+            let Some(parameter) = &case.parameter else {
+                has_default = true;
+                continue;
+            };
+            let case_type = match &parameter.type_annotation {
+                Some(annotation) => {
+                    let Some(t) = verifier.ast_to_symbol.get(annotation) else {
+                        continue;
+                    };
+                    t
+                },
+                None => host.any_type(),
+            };
+            if case_types.iter().any(|t| Self::covers(t, &case_type, &host)) {
+                verifier.add_warning(&parameter.location, DiagnosticKind::UnreachableCase, diagnostic_arguments![]);
+                continue;
+            }
+            case_types.push(case_type);
+        }
+        if has_default {
+            return;
+        }
+
+        let t = discriminant_type.non_null_type();
+        let t = if t.is_type_after_explicit_type_substitution() {
+            t.origin()
+        } else {
+            t
+        };
+        let mut required: Vec<Symbol> = if t.is_class_type() && t.is_limited() {
+            let mut required: Vec<Symbol> = t.limited_subclasses().iter().collect();
+            if !t.is_abstract() {
+                required.insert(0, t.clone());
+            }
+            required
+        } else if t.is_interface_type() && t.is_limited() {
+            t.limited_implementors().iter().collect()
+        } else {
+            return;
+        };
+        required.retain(|t| !case_types.iter().any(|case_type| Self::covers(case_type, t, &host)));
+        let mut missing: Vec<String> = required.iter().map(|t| t.to_string()).collect();
+        if discriminant_type.is_nullable_type() {
+            missing.push("null".into());
+        }
+        if !missing.is_empty() {
+            verifier.add_warning(&self.location, DiagnosticKind::NonExhaustiveSwitch, diagnostic_arguments![String(missing.join(", "))]);
+        }
+    }
+
+    /// Indicates whether a case of type `case_type` matches
+    /// every value of type `t`.
+    fn covers(case_type: &Symbol, t: &Symbol, host: &SymbolHost) -> bool {
+        t == case_type || t.is_subtype_of(case_type, host)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ns::*;
+
+    #[test]
+    fn exhaustiveness() {
+        let source = r#"
+            package p {
+                public enum Color {
+                    const RED
+                    const GREEN
+                    const BLUE = "azure"
+                }
+                [Limited] public abstract class Shape {}
+                public class Circle extends Shape {}
+                public class Square extends Shape {}
+                public function f(c: Color, s: Shape): void {
+                    switch (c) {
+                        case Color.RED:
+                            break;
+                        case Color.RED:
+                            break;
+                    }
+                    switch (c) {
+                        case Color.RED:
+                        case Color.GREEN:
+                        case Color.BLUE:
+                            break;
+                    }
+                    switch type (s) {
+                        case (c: Circle) {}
+                    }
+                    switch type (s) {
+                        case (c: Circle) {}
+                        case (s: Square) {}
+                        case (c: Circle) {}
+                    }
+                    switch type (s) {
+                        case (c: Circle) {}
+                        default {}
+                    }
+                }
+            }
+        "#;
+        for warnings_as_errors in [false, true] {
+            let host = SymbolHost::new("");
+            declare_jet_lang_classes(&host, &["Object", "Boolean", "String", "Number", "Function", "Class", "Array"]);
+            let compiler_options = Rc::new(CompilerOptions { warnings_as_errors, ..default() });
+            let compilation_unit = CompilationUnit::new(None, source.to_owned(), &compiler_options);
+            let program = ParserFacade::parse_program(&compilation_unit, &host).unwrap();
+            let mut verifier = Verifier::new(&host);
+            verifier.verify_programs(vec![program.clone()]);
+
+            let diagnostics = compilation_unit.diagnostics().iter().map(|d| (d.id(), d.location().first_line_number(), d.is_warning())).collect::<Vec<_>>();
+            let is_warning = !warnings_as_errors;
+            assert_eq!(diagnostics, [
+                (DiagnosticKind::UnreachableCase.id(), 15, is_warning),
+                (DiagnosticKind::NonExhaustiveSwitch.id(), 12, is_warning),
+                (DiagnosticKind::NonExhaustiveSwitch.id(), 24, is_warning),
+                (DiagnosticKind::UnreachableCase.id(), 30, is_warning),
+            ]);
+            assert_eq!(compilation_unit.diagnostics()[1].format_message_english(), "Switch is not exhaustive; missing: GREEN, BLUE");
+        }
+    }
 }
\ No newline at end of file
@@ -21,4 +21,41 @@ pub struct CatchClause {
 pub struct FinallyClause {
     pub location: Location,
     pub block: Rc<Block>,
+}
+
+impl TryStatement {
+    /// Verifies the statement. The type of a catch parameter
+    /// must be `jet.lang.Error` or a subclass of it.
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier, phase: VerifierPhase) -> Result<(), DeferVerificationError> {
+        let host = verifier.host.clone();
+        let scopes: Vec<Symbol> = self.catch_clauses.iter().map(|clause| verifier.node_scope(&clause.block)).collect();
+        if phase == VerifierPhase::Bodies {
+            let error_type = host.error_type();
+            if self.catch_clauses.iter().any(|clause| clause.parameter.type_annotation.is_some()) {
+                error_type.throw_if_unresolved()?;
+            }
+            for (clause, scope) in self.catch_clauses.iter().zip(scopes.iter()) {
+                verifier.verify_typed_destructuring(&clause.parameter, false, &scope.properties(&host), scope, Visibility::Internal, None)?;
+            }
+            for clause in &self.catch_clauses {
+                let Some(annotation) = &clause.parameter.type_annotation else {
+                    continue;
+                };
+                let Some(t) = verifier.ast_to_symbol.get(annotation) else {
+                    continue;
+                };
+                if !(t.is_any_type() || t == error_type || t.is_subtype_of(&error_type, &host)) {
+                    verifier.add_verify_error(&annotation.location(), DiagnosticKind::NotAnErrorType, diagnostic_arguments![Symbol(t)]);
+                }
+            }
+        }
+        verifier.verify_block(&self.block, phase);
+        for clause in &self.catch_clauses {
+            verifier.verify_block(&clause.block, phase);
+        }
+        if let Some(finally_clause) = &self.finally_clause {
+            verifier.verify_block(&finally_clause.block, phase);
+        }
+        Ok(())
+    }
 }
\ No newline at end of file
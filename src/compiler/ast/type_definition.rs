@@ -9,4 +9,45 @@ pub struct TypeDefinition {
     pub attributes: Vec<Attribute>,
     pub left: (String, Location),
     pub right: Rc<Expression>,
+}
+
+impl TypeDefinition {
+    /// Declares the type alias at the Declaration phase and
+    /// resolves the aliased type at the Heritage phase.
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier, drtv: &Rc<Directive>, phase: VerifierPhase) -> Result<(), DeferVerificationError> {
+        let host = verifier.host.clone();
+        match phase {
+            VerifierPhase::Declaration => {
+                if verifier.ast_to_symbol.has(drtv) {
+                    return Ok(());
+                }
+                let (name, name_location) = self.left.clone();
+                let (parent, mut properties) = verifier.definition_destination(false);
+                if properties.has(&name) {
+                    verifier.add_verify_error(&name_location, DiagnosticKind::DuplicateDefinition, diagnostic_arguments![String(name)]);
+                    verifier.ast_to_symbol.set(drtv, None);
+                    return Ok(());
+                }
+                let alias = host.factory().create_alias(name.clone(), &host.unresolved());
+                alias.set_location(Some(name_location));
+                alias.set_jetdoc(self.jetdoc.clone());
+                alias.set_visibility(Attribute::visibility(&self.attributes, false));
+                alias.set_parent_definition(Some(&parent));
+                properties.set(name, alias.clone());
+                verifier.ast_to_symbol.set(drtv, Some(alias));
+            },
+            VerifierPhase::Heritage => {
+                let Some(alias) = verifier.ast_to_symbol.get(drtv) else {
+                    return Ok(());
+                };
+                if alias.alias_of().is_unresolved() {
+                    // An invalid type resolves to `*` so that it is reported once.
+                    let t = verifier.verify_type_expression(&self.right)?;
+                    alias.set_alias_of(&t.unwrap_or(host.any_type()));
+                }
+            },
+            _ => {},
+        }
+        Ok(())
+    }
 }
\ No newline at end of file
@@ -41,4 +41,80 @@ pub struct TupleTypeExpression {
 pub struct FunctionTypeExpression {
     pub location: Location,
     pub signature: FunctionSignature,
+}
+
+impl NullableTypeExpression {
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier) -> Result<Option<Symbol>, DeferVerificationError> {
+        let Some(t) = verifier.verify_type_expression(&self.base)? else {
+            return Ok(None);
+        };
+        let t = verifier.host.factory().create_nullable_type(&t);
+        Ok(Some(verifier.host.factory().create_type_as_reference_value(&t)))
+    }
+}
+
+impl NonNullableTypeExpression {
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier) -> Result<Option<Symbol>, DeferVerificationError> {
+        let Some(t) = verifier.verify_type_expression(&self.base)? else {
+            return Ok(None);
+        };
+        Ok(Some(verifier.host.factory().create_type_as_reference_value(&t.non_null_type())))
+    }
+}
+
+impl ArrayTypeExpression {
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier) -> Result<Option<Symbol>, DeferVerificationError> {
+        let host = verifier.host.clone();
+        let Some(element_type) = verifier.verify_type_expression(&self.expression)? else {
+            return Ok(None);
+        };
+        let array_type = host.array_type();
+        array_type.throw_if_unresolved()?;
+        let t = host.factory().create_type_after_explicit_type_substitution(&array_type, &SharedArray::from(vec![element_type]));
+        Ok(Some(host.factory().create_type_as_reference_value(&t)))
+    }
+}
+
+impl TupleTypeExpression {
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier) -> Result<Option<Symbol>, DeferVerificationError> {
+        let mut element_types = vec![];
+        for exp in &self.expressions {
+            element_types.push(verifier.verify_type_expression(exp)?);
+        }
+        let Some(element_types) = element_types.into_iter().collect::<Option<Vec<_>>>() else {
+            return Ok(None);
+        };
+        let t = verifier.host.factory().create_tuple_type(element_types);
+        Ok(Some(verifier.host.factory().create_type_as_reference_value(&t)))
+    }
+}
+
+impl FunctionTypeExpression {
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier) -> Result<Option<Symbol>, DeferVerificationError> {
+        let host = verifier.host.clone();
+        let mut parameters = vec![];
+        for parameter in &self.signature.parameters {
+            let static_type = if let Some(annotation) = &parameter.destructuring.type_annotation {
+                verifier.verify_type_expression(annotation)?
+            } else {
+                Some(host.any_type())
+            };
+            let name = parameter.destructuring.destructuring.to_identifier_name().map(|name| name.0).unwrap_or_default();
+            parameters.push(static_type.map(|static_type| Rc::new(ParameterOfFunctionType {
+                kind: parameter.kind,
+                name,
+                static_type,
+            })));
+        }
+        let result_type = if let Some(annotation) = &self.signature.result_type {
+            verifier.verify_type_expression(annotation)?
+        } else {
+            Some(host.any_type())
+        };
+        let (Some(parameters), Some(result_type)) = (parameters.into_iter().collect::<Option<Vec<_>>>(), result_type) else {
+            return Ok(None);
+        };
+        let t = host.factory().create_function_type(parameters, result_type);
+        Ok(Some(host.factory().create_type_as_reference_value(&t)))
+    }
 }
\ No newline at end of file
@@ -7,4 +7,133 @@ pub struct UnaryExpression {
     pub location: Location,
     pub operator: Operator,
     pub expression: Rc<Expression>,
+}
+
+impl UnaryExpression {
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier, context: &ExpressionVerifyContext) -> Result<Option<Symbol>, DeferVerificationError> {
+        let host = verifier.host.clone();
+        match self.operator {
+            Operator::Positive | Operator::Negative | Operator::BitwiseNot => self.verify_numeric(verifier, context),
+            Operator::LogicalNot => {
+                let boolean_type = host.boolean_type();
+                boolean_type.throw_if_unresolved()?;
+                let Some(v) = verifier.verify_expression(&self.expression, &default())? else {
+                    return Ok(None);
+                };
+                Ok(Some(self.fold(verifier, &v, &boolean_type)))
+            },
+            Operator::Void => {
+                verifier.verify_expression(&self.expression, &default())?;
+                Ok(Some(host.factory().create_undefined_constant(&host.any_type())))
+            },
+            Operator::Typeof => {
+                let string_type = host.string_type();
+                string_type.throw_if_unresolved()?;
+                verifier.verify_expression(&self.expression, &default())?;
+                Ok(Some(host.factory().create_value(&string_type)))
+            },
+            Operator::Delete => {
+                let boolean_type = host.boolean_type();
+                boolean_type.throw_if_unresolved()?;
+                verifier.verify_expression(&self.expression, &ExpressionVerifyContext { mode: VerifyMode::Delete, ..default() })?;
+                Ok(Some(host.factory().create_value(&boolean_type)))
+            },
+            Operator::NonNull => {
+                let Some(v) = verifier.verify_expression(&self.expression, &default())? else {
+                    return Ok(None);
+                };
+                let t = v.static_type(&host);
+                t.throw_if_unresolved()?;
+                if v.is_constant() && !(v.is_null_constant() || v.is_undefined_constant()) {
+                    return Ok(Some(v));
+                }
+                Ok(Some(host.factory().create_value(&t.non_null_type())))
+            },
+            Operator::PreIncrement | Operator::PreDecrement |
+            Operator::PostIncrement | Operator::PostDecrement => {
+                let Some(v) = verifier.verify_expression(&self.expression, &ExpressionVerifyContext { mode: VerifyMode::Write, ..default() })? else {
+                    return Ok(None);
+                };
+                let t = v.static_type(&host);
+                t.throw_if_unresolved()?;
+                if !(t.is_any_type() || host.is_numeric_type(&t)) {
+                    verifier.add_verify_error(&self.location, DiagnosticKind::UnsupportedOperator, diagnostic_arguments![Symbol(t)]);
+                    return Ok(None);
+                }
+                Ok(Some(host.factory().create_value(&t)))
+            },
+            // Await and yield
+            _ => {
+                // Parameter default values belong to no function body.
+                let function = verifier.scope.find_activation()
+                    .filter(|_| !verifier.verifying_parameter_defaults)
+                    .map(|activation| activation.function());
+                if self.operator == Operator::Yield && !function.as_ref().is_some_and(|f| f.is_generator()) {
+                    verifier.add_verify_error(&self.location, DiagnosticKind::YieldOutsideGenerator, diagnostic_arguments![]);
+                }
+                if self.operator == Operator::Await && !function.as_ref().is_some_and(|f| f.is_async()) {
+                    verifier.add_verify_error(&self.location, DiagnosticKind::AwaitOutsideAsynchronousFunction, diagnostic_arguments![]);
+                }
+                verifier.verify_expression(&self.expression, &default())?;
+                Ok(Some(host.factory().create_value(&host.any_type())))
+            },
+        }
+    }
+
+    /// Verifies the positive, negative and bitwise NOT operators,
+    /// either over a numeric type or through an operator proxy.
+    fn verify_numeric(&self, verifier: &mut VerifierVerifier, context: &ExpressionVerifyContext) -> Result<Option<Symbol>, DeferVerificationError> {
+        let host = verifier.host.clone();
+        let context_type = context.context_type.as_ref().map(|t| t.non_null_type()).filter(|t| host.is_numeric_type(t));
+
+        // A negated numeric literal is parsed as a negative number
+        // so that minimum values such as `-0x8000_0000_0000_0000`
+        // are in range.
+        if self.operator == Operator::Negative && matches!(self.expression.as_ref(), Expression::NumericLiteral(_)) {
+            return verifier.verify_expression(&self.expression, &ExpressionVerifyContext {
+                context_type,
+                preceded_by_negative: true,
+                ..default()
+            });
+        }
+
+        let Some(v) = verifier.verify_expression(&self.expression, &ExpressionVerifyContext { context_type, ..default() })? else {
+            return Ok(None);
+        };
+        let t = v.static_type(&host);
+        t.throw_if_unresolved()?;
+
+        if t.is_any_type() {
+            return Ok(Some(host.factory().create_value(&t)));
+        }
+
+        if host.is_numeric_type(&t) {
+            return Ok(Some(self.fold(verifier, &v, &t)));
+        }
+
+        if t.is_class_type() || t.is_enum_type() {
+            if let Some(proxy) = t.find_proxy(ProxyKind::try_from(self.operator).unwrap(), &host)? {
+                let signature = proxy.signature(&host);
+                signature.throw_if_unresolved()?;
+                return Ok(Some(host.factory().create_value(&signature.result_type())));
+            }
+        }
+
+        verifier.add_verify_error(&self.location, DiagnosticKind::UnsupportedOperator, diagnostic_arguments![Symbol(t)]);
+        Ok(None)
+    }
+
+    /// Folds the operation through `ConstantEvaluator`, resulting into
+    /// a value of `result_type` if the operand is not a constant.
+    fn fold(&self, verifier: &mut VerifierVerifier, operand: &Symbol, result_type: &Symbol) -> Symbol {
+        let host = verifier.host.clone();
+        match ConstantEvaluator(&host).unary(self.operator, operand) {
+            Ok(Some(v)) => v,
+            Ok(None) => host.factory().create_value(result_type),
+            Err(ConstantEvaluationError::LongOverflow) => {
+                verifier.add_verify_error(&self.location, DiagnosticKind::LongConstantOverflow, diagnostic_arguments![]);
+                host.factory().create_value(result_type)
+            },
+        }
+    }
 }
\ No newline at end of file
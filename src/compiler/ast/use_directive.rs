@@ -10,4 +10,53 @@ pub struct UseDirective {
     pub alias: Option<(String, Location)>,
     pub package_name: Vec<(String, Location)>,
     pub import_specifier: ImportSpecifier,
+}
+
+impl UseDirective {
+    /// Redirects the enclosing package to another package, or declares
+    /// an alias to a definition of another package. Aliases are declared
+    /// at the Declaration phase and resolved at the Heritage phase.
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier, drtv: &Rc<Directive>, phase: VerifierPhase) {
+        // A syntax error has already been reported outside of a package.
+        if !verifier.scope.is_package_scope() {
+            return;
+        }
+        let host = verifier.host.clone();
+        let package = verifier.scope.package();
+        match (&self.import_specifier, phase) {
+            (ImportSpecifier::Wildcard(_), VerifierPhase::Declaration) => {
+                let Some(package_1) = verifier.resolve_package_name(&self.package_name) else {
+                    return;
+                };
+                if package_1 != package && !package.redirect_packages().includes(&package_1) {
+                    package.redirect_packages().push(package_1);
+                }
+            },
+            (ImportSpecifier::Identifier(name), VerifierPhase::Declaration) => {
+                let (alias_name, alias_location) = self.alias.clone().unwrap_or(name.clone());
+                let mut properties = package.properties(&host);
+                if properties.has(&alias_name) {
+                    verifier.add_verify_error(&alias_location, DiagnosticKind::DuplicateDefinition, diagnostic_arguments![String(alias_name)]);
+                    return;
+                }
+                let alias = host.factory().create_alias(alias_name.clone(), &host.unresolved());
+                alias.set_location(Some(alias_location));
+                alias.set_jetdoc(self.jetdoc.clone());
+                alias.set_visibility(Attribute::visibility(&self.attributes, false));
+                alias.set_parent_definition(Some(&package));
+                properties.set(alias_name, alias.clone());
+                verifier.ast_to_symbol.set(drtv, Some(alias));
+            },
+            (ImportSpecifier::Identifier(name), VerifierPhase::Heritage) => {
+                let Some(alias) = verifier.ast_to_symbol.get(drtv) else {
+                    return;
+                };
+                let property = verifier.resolve_package_name(&self.package_name)
+                    .and_then(|package_1| verifier.resolve_package_property(&package_1, name));
+                // An invalid alias resolves to `*` so that it is reported once.
+                alias.set_alias_of(&property.unwrap_or(host.any_type()));
+            },
+            _ => {},
+        }
+    }
 }
\ No newline at end of file
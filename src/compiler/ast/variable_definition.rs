@@ -34,4 +34,72 @@ impl VariableBinding {
     pub fn location(&self) -> Location {
         self.initializer.as_ref().map_or(self.destructuring.location.clone(), |init| self.destructuring.location.combine_with(init.location()))
     }
+}
+
+impl VariableDefinition {
+    /// Declares the variables of the definition and verifies
+    /// their initializers.
+    ///
+    /// Variables of a package or type are declared at the signatures
+    /// phase, whereas local variables are declared in statement order
+    /// at the bodies phase, after their initializers are verified.
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier, phase: VerifierPhase) -> Result<(), DeferVerificationError> {
+        let is_static = Attribute::find_static(&self.attributes).is_some();
+        let (parent, properties) = verifier.definition_destination(is_static);
+        let local = parent.is_scope();
+        let read_only = self.kind.0 == VariableDefinitionKind::Const;
+        let visibility = Attribute::visibility(&self.attributes, verifier.scope.is_interface_scope());
+        match phase {
+            VerifierPhase::Signatures if !local => {
+                for binding in &self.bindings {
+                    binding.declare(verifier, read_only, &properties, &parent, visibility)?;
+                }
+            },
+            VerifierPhase::Bodies => {
+                for binding in &self.bindings {
+                    if local {
+                        binding.declare(verifier, read_only, &properties, &parent, visibility)?;
+                    }
+                    binding.verify_initializer(verifier, read_only)?;
+                }
+            },
+            _ => {},
+        }
+        Ok(())
+    }
+}
+
+impl VariableBinding {
+    pub(crate) fn declare(&self, verifier: &mut VerifierVerifier, read_only: bool, properties: &SharedMap<String, Symbol>, parent: &Symbol, visibility: Visibility) -> Result<(), DeferVerificationError> {
+        let host = verifier.host.clone();
+
+        // An unannotated binding takes the type of its initializer.
+        let mut inferred_type: Option<Symbol> = None;
+        if self.destructuring.type_annotation.is_none() {
+            if let Some(init) = &self.initializer {
+                inferred_type = verifier.verify_expression(init, &default())?.map(|v| v.static_type(&host));
+            }
+        }
+
+        verifier.verify_typed_destructuring(&self.destructuring, read_only, properties, parent, visibility, inferred_type.as_ref())
+    }
+
+    pub(crate) fn verify_initializer(&self, verifier: &mut VerifierVerifier, read_only: bool) -> Result<(), DeferVerificationError> {
+        let host = verifier.host.clone();
+        let Some(init) = &self.initializer else {
+            return Ok(());
+        };
+        let Some(symbol) = verifier.ast_to_symbol.get(&self.destructuring.destructuring) else {
+            verifier.verify_expression(init, &default())?;
+            return Ok(());
+        };
+        let t = symbol.static_type(&host);
+        let Some(v) = verifier.limit_expression_type(init, &t)? else {
+            return Ok(());
+        };
+        if read_only && symbol.is_variable_property() && v.is_constant() {
+            symbol.set_constant_initializer(Some(&v));
+        }
+        Ok(())
+    }
 }
\ No newline at end of file
@@ -7,4 +7,19 @@ pub struct WhileStatement {
     pub location: Location,
     pub test: Rc<Expression>,
     pub body: Rc<Directive>,
+}
+
+impl WhileStatement {
+    /// Verifies the statement, narrowing local variables
+    /// within the body as the test holds.
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier, phase: VerifierPhase) -> Result<(), DeferVerificationError> {
+        let mut narrowing = ConditionNarrowing::default();
+        if phase == VerifierPhase::Bodies {
+            verifier.clear_loop_narrowings(&self.body, None);
+            verifier.verify_condition(&self.test)?;
+            narrowing = verifier.condition_narrowing(&self.test);
+        }
+        verifier.verify_narrowed_substatement(&self.body, &narrowing.when_true, phase);
+        Ok(())
+    }
 }
\ No newline at end of file
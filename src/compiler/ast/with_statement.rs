@@ -7,4 +7,31 @@ pub struct WithStatement {
     pub location: Location,
     pub object: Rc<Expression>,
     pub body: Rc<Directive>,
+}
+
+impl WithStatement {
+    /// Verifies the statement at the bodies phase. As the scope of the
+    /// body depends on the object, the body is verified through every
+    /// phase at once within a `WithScope`.
+    pub(crate) fn verify(&self, verifier: &mut VerifierVerifier, drtv: &Rc<Directive>, phase: VerifierPhase) -> Result<(), DeferVerificationError> {
+        if phase != VerifierPhase::Bodies {
+            return Ok(());
+        }
+        let host = verifier.host.clone();
+        let scope = if let Some(scope) = verifier.ast_to_symbol.get(drtv) {
+            scope
+        } else {
+            let object = verifier.verify_expression(&self.object, &default())?.unwrap_or(host.factory().create_value(&host.any_type()));
+            object.static_type(&host).throw_if_unresolved()?;
+            let scope = host.factory().create_with_scope(&object);
+            verifier.ast_to_symbol.set(drtv, Some(scope.clone()));
+            scope
+        };
+        verifier.enter_scope(&scope);
+        for phase in VerifierPhase::LIST {
+            verifier.verify_directives(std::slice::from_ref(&self.body), phase);
+        }
+        verifier.exit_scope();
+        Ok(())
+    }
 }
\ No newline at end of file
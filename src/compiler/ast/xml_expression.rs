@@ -53,6 +53,66 @@ impl XmlElement {
         }
         Ok(())
     }
+
+    /// Reports mismatched closing tags, duplicate attributes and undeclared
+    /// namespace prefixes in an element whose embedded expressions have
+    /// already been verified.
+    ///
+    /// `namespace_prefixes` lists the prefixes declared by the enclosing
+    /// elements, or is `None` if they are not statically known, as
+    /// within an element with an attribute expression.
+    pub(crate) fn verify_markup(&self, verifier: &mut VerifierVerifier, namespace_prefixes: Option<&[String]>) {
+        if let (XmlTagName::Name((name, _)), Some(XmlTagName::Name((closing_name, closing_location)))) = (&self.name, &self.closing_name) {
+            if name != closing_name {
+                verifier.add_verify_error(closing_location, DiagnosticKind::XmlClosingTagMismatch, diagnostic_arguments![String(closing_name.clone()), String(name.clone())]);
+            }
+        }
+
+        let mut attribute_names: Vec<&str> = vec![];
+        for attr in &self.attributes {
+            if attribute_names.contains(&attr.name.0.as_str()) {
+                verifier.add_verify_error(&attr.name.1, DiagnosticKind::DuplicateXmlAttribute, diagnostic_arguments![String(attr.name.0.clone())]);
+            } else {
+                attribute_names.push(&attr.name.0);
+            }
+        }
+
+        let namespace_prefixes = namespace_prefixes.filter(|_| self.attribute_expression.is_none()).map(|inherited| {
+            let mut prefixes = inherited.to_vec();
+            for attr in &self.attributes {
+                if let Some(prefix) = attr.name.0.strip_prefix("xmlns:") {
+                    prefixes.push(prefix.to_owned());
+                }
+            }
+            prefixes
+        });
+        if let Some(prefixes) = &namespace_prefixes {
+            if let XmlTagName::Name(name) = &self.name {
+                XmlElement::verify_namespace_prefix(verifier, name, prefixes, false);
+            }
+            for attr in &self.attributes {
+                XmlElement::verify_namespace_prefix(verifier, &attr.name, prefixes, true);
+            }
+        }
+
+        if let Some(content_list) = &self.content {
+            for content in content_list {
+                if let XmlElementContent::XmlElement(el) = content.as_ref() {
+                    el.verify_markup(verifier, namespace_prefixes.as_deref());
+                }
+            }
+        }
+    }
+
+    fn verify_namespace_prefix(verifier: &mut VerifierVerifier, (name, location): &(String, Location), prefixes: &[String], attribute: bool) {
+        let Some((prefix, _)) = name.split_once(':') else {
+            return;
+        };
+        if prefix == "xml" || (attribute && prefix == "xmlns") || prefixes.iter().any(|p| p == prefix) {
+            return;
+        }
+        verifier.add_verify_error(location, DiagnosticKind::UndeclaredXmlNamespacePrefix, diagnostic_arguments![String(prefix.to_owned())]);
+    }
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -96,4 +156,47 @@ impl XmlElementContent {
             _ => Ok(()),
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ns::*;
+
+    #[test]
+    fn xml_verification() {
+        let host = SymbolHost::new("");
+        declare_jet_lang_classes(&host, &["Object", "Boolean", "String", "Number", "Function", "Class", "Array", "Namespace", "XML", "XMLList"]);
+
+        let source = r#"
+            package p {
+                public class C {
+                    public var v: Number = 0;
+                }
+                public function f(x: XML, c: C): void {
+                    const a = <a><b></c></a>;
+                    const b = <a id="1" id="2"/>;
+                    const d = <p:a xmlns:q="u"><q:b xml:lang="en"/></p:a>;
+                    const l: XMLList = x.@id;
+                    const n: Number = x.@id;
+                    c.@v;
+                    default xml namespace = 10;
+                    const e: XMLList = x..item.(@id == "1");
+                }
+            }
+        "#;
+        let compilation_unit = CompilationUnit::new(None, source.to_owned(), &CompilerOptions::new());
+        let program = ParserFacade::parse_program(&compilation_unit, &host).unwrap();
+        let mut verifier = Verifier::new(&host);
+        verifier.verify_programs(vec![program.clone()]);
+
+        let diagnostics = compilation_unit.diagnostics().iter().map(|d| (d.id(), d.location().first_line_number())).collect::<Vec<_>>();
+        assert_eq!(diagnostics, [
+            (DiagnosticKind::XmlClosingTagMismatch.id(), 7),
+            (DiagnosticKind::DuplicateXmlAttribute.id(), 8),
+            (DiagnosticKind::UndeclaredXmlNamespacePrefix.id(), 9),
+            (DiagnosticKind::IncompatibleTypes.id(), 11),
+            (DiagnosticKind::UnsupportedAttributeAccess.id(), 12),
+            (DiagnosticKind::IncompatibleTypes.id(), 13),
+        ]);
+    }
 }
\ No newline at end of file
@@ -37,6 +37,33 @@ impl AstToSymbol {
             compilation_units: RefCell::new(HashMap::new()),
         })
     }
+
+    /// Returns the innermost expression of a compilation unit that
+    /// has been assigned a symbol and whose location contains
+    /// the given UTF-8 offset.
+    pub fn expression_at(&self, compilation_unit: &Rc<CompilationUnit>, offset: usize) -> Option<(Rc<Expression>, Symbol)> {
+        let compilation_units = self.compilation_units.borrow();
+        let m1 = compilation_units.get(&ByAddress(compilation_unit.clone()))?;
+        let mut result: Option<(Rc<Expression>, Symbol)> = None;
+        for (exp, symbol) in m1.expressions.iter() {
+            let Some(symbol) = symbol else {
+                continue;
+            };
+            let location = exp.location();
+            if offset < location.first_offset() || offset > location.last_offset() {
+                continue;
+            }
+            let span = location.last_offset() - location.first_offset();
+            if let Some((exp_1, _)) = &result {
+                let location_1 = exp_1.location();
+                if span >= location_1.last_offset() - location_1.first_offset() {
+                    continue;
+                }
+            }
+            result = Some((exp.0.clone(), symbol.clone()));
+        }
+        result
+    }
 }
 
 pub trait AstToSymbolAccessor<T> {
@@ -44,9 +71,9 @@ pub trait AstToSymbolAccessor<T> {
     fn set(&self, node: &Rc<T>, symbol: Option<Symbol>);
     fn delete(&self, node: &Rc<T>) -> bool;
 
-    fn has(&self, node: &Rc<T>) -> bool {
-        self.get(node).is_some()
-    }
+    /// Indicates whether a node has been assigned either
+    /// a symbol or `None`.
+    fn has(&self, node: &Rc<T>) -> bool;
 }
 
 impl AstToSymbolAccessor<Expression> for AstToSymbol {
@@ -54,12 +81,22 @@ impl AstToSymbolAccessor<Expression> for AstToSymbol {
         let compilation_units = self.compilation_units.borrow();
         let m1 = compilation_units.get(&ByAddress(node.location().compilation_unit()));
         if let Some(m1) = m1 {
-            m1.expressions.get(&AstAsKey(node.clone())).map(|v| v.clone().unwrap())
+            m1.expressions.get(&AstAsKey(node.clone())).and_then(|v| v.clone())
         } else {
             None
         }
     }
 
+    fn has(&self, node: &Rc<Expression>) -> bool {
+        let compilation_units = self.compilation_units.borrow();
+        let m1 = compilation_units.get(&ByAddress(node.location().compilation_unit()));
+        if let Some(m1) = m1 {
+            m1.expressions.contains_key(&AstAsKey(node.clone()))
+        } else {
+            false
+        }
+    }
+
     fn set(&self, node: &Rc<Expression>, symbol: Option<Symbol>) {
         let compilation_unit = node.location().compilation_unit();
         let mut compilation_units = self.compilation_units.borrow_mut();
@@ -90,12 +127,22 @@ impl AstToSymbolAccessor<Directive> for AstToSymbol {
         let compilation_units = self.compilation_units.borrow();
         let m1 = compilation_units.get(&ByAddress(node.location().compilation_unit()));
         if let Some(m1) = m1 {
-            m1.directives.get(&AstAsKey(node.clone())).map(|v| v.clone().unwrap())
+            m1.directives.get(&AstAsKey(node.clone())).and_then(|v| v.clone())
         } else {
             None
         }
     }
 
+    fn has(&self, node: &Rc<Directive>) -> bool {
+        let compilation_units = self.compilation_units.borrow();
+        let m1 = compilation_units.get(&ByAddress(node.location().compilation_unit()));
+        if let Some(m1) = m1 {
+            m1.directives.contains_key(&AstAsKey(node.clone()))
+        } else {
+            false
+        }
+    }
+
     fn set(&self, node: &Rc<Directive>, symbol: Option<Symbol>) {
         let compilation_unit = node.location().compilation_unit();
         let mut compilation_units = self.compilation_units.borrow_mut();
@@ -126,12 +173,22 @@ impl AstToSymbolAccessor<SimpleVariableDefinition> for AstToSymbol {
         let compilation_units = self.compilation_units.borrow();
         let m1 = compilation_units.get(&ByAddress(node.location.compilation_unit()));
         if let Some(m1) = m1 {
-            m1.simple_variable_definitions.get(&AstAsKey(node.clone())).map(|v| v.clone().unwrap())
+            m1.simple_variable_definitions.get(&AstAsKey(node.clone())).and_then(|v| v.clone())
         } else {
             None
         }
     }
 
+    fn has(&self, node: &Rc<SimpleVariableDefinition>) -> bool {
+        let compilation_units = self.compilation_units.borrow();
+        let m1 = compilation_units.get(&ByAddress(node.location.compilation_unit()));
+        if let Some(m1) = m1 {
+            m1.simple_variable_definitions.contains_key(&AstAsKey(node.clone()))
+        } else {
+            false
+        }
+    }
+
     fn set(&self, node: &Rc<SimpleVariableDefinition>, symbol: Option<Symbol>) {
         let compilation_unit = node.location.compilation_unit();
         let mut compilation_units = self.compilation_units.borrow_mut();
@@ -162,12 +219,22 @@ impl AstToSymbolAccessor<Block> for AstToSymbol {
         let compilation_units = self.compilation_units.borrow();
         let m1 = compilation_units.get(&ByAddress(node.location.compilation_unit()));
         if let Some(m1) = m1 {
-            m1.blocks.get(&AstAsKey(node.clone())).map(|v| v.clone().unwrap())
+            m1.blocks.get(&AstAsKey(node.clone())).and_then(|v| v.clone())
         } else {
             None
         }
     }
 
+    fn has(&self, node: &Rc<Block>) -> bool {
+        let compilation_units = self.compilation_units.borrow();
+        let m1 = compilation_units.get(&ByAddress(node.location.compilation_unit()));
+        if let Some(m1) = m1 {
+            m1.blocks.contains_key(&AstAsKey(node.clone()))
+        } else {
+            false
+        }
+    }
+
     fn set(&self, node: &Rc<Block>, symbol: Option<Symbol>) {
         let compilation_unit = node.location.compilation_unit();
         let mut compilation_units = self.compilation_units.borrow_mut();
@@ -198,12 +265,22 @@ impl AstToSymbolAccessor<Program> for AstToSymbol {
         let compilation_units = self.compilation_units.borrow();
         let m1 = compilation_units.get(&ByAddress(node.location.compilation_unit()));
         if let Some(m1) = m1 {
-            m1.programs.get(&AstAsKey(node.clone())).map(|v| v.clone().unwrap())
+            m1.programs.get(&AstAsKey(node.clone())).and_then(|v| v.clone())
         } else {
             None
         }
     }
 
+    fn has(&self, node: &Rc<Program>) -> bool {
+        let compilation_units = self.compilation_units.borrow();
+        let m1 = compilation_units.get(&ByAddress(node.location.compilation_unit()));
+        if let Some(m1) = m1 {
+            m1.programs.contains_key(&AstAsKey(node.clone()))
+        } else {
+            false
+        }
+    }
+
     fn set(&self, node: &Rc<Program>, symbol: Option<Symbol>) {
         let compilation_unit = node.location.compilation_unit();
         let mut compilation_units = self.compilation_units.borrow_mut();
@@ -234,12 +311,22 @@ impl AstToSymbolAccessor<FunctionCommon> for AstToSymbol {
         let compilation_units = self.compilation_units.borrow();
         let m1 = compilation_units.get(&ByAddress(node.location.compilation_unit()));
         if let Some(m1) = m1 {
-            m1.function_commons.get(&AstAsKey(node.clone())).map(|v| v.clone().unwrap())
+            m1.function_commons.get(&AstAsKey(node.clone())).and_then(|v| v.clone())
         } else {
             None
         }
     }
 
+    fn has(&self, node: &Rc<FunctionCommon>) -> bool {
+        let compilation_units = self.compilation_units.borrow();
+        let m1 = compilation_units.get(&ByAddress(node.location.compilation_unit()));
+        if let Some(m1) = m1 {
+            m1.function_commons.contains_key(&AstAsKey(node.clone()))
+        } else {
+            false
+        }
+    }
+
     fn set(&self, node: &Rc<FunctionCommon>, symbol: Option<Symbol>) {
         let compilation_unit = node.location.compilation_unit();
         let mut compilation_units = self.compilation_units.borrow_mut();
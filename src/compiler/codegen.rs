@@ -0,0 +1,5 @@
+//! Code generation backends.
+
+pub mod js;
+mod source_map_builder;
+pub use source_map_builder::*;
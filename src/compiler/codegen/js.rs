@@ -0,0 +1,9 @@
+//! JavaScript (ES2020) backend, generating ES modules with source maps.
+
+mod js_generator;
+pub use js_generator::*;
+mod js_writer;
+pub(crate) use js_writer::*;
+
+/// Source of the runtime module imported by the generated modules.
+pub const JS_RUNTIME: &str = include_str!("js/runtime.js");
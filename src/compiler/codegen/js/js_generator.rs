@@ -0,0 +1,2724 @@
+use crate::ns::*;
+use file_paths::FlexPath;
+use std::collections::{BTreeMap, HashMap};
+use std::rc::Rc;
+
+/// Types of the `jet.lang` package that are represented by
+/// type descriptors of the runtime (`$jet.types`).
+const RUNTIME_TYPES: [&str; 8] = ["Boolean", "String", "Char", "Number", "Single", "Long", "BigInt", "Function"];
+
+/// Options for the [`JsGenerator`].
+#[derive(Clone)]
+pub struct JsGeneratorOptions {
+    /// Directory that the output file names are relative to. If `None`,
+    /// each output file is named after the base name of its source file.
+    pub source_directory: Option<String>,
+    /// File name of the runtime module ([`JS_RUNTIME`]), relative
+    /// to the output directory.
+    pub runtime_file_name: String,
+}
+
+impl Default for JsGeneratorOptions {
+    fn default() -> Self {
+        Self {
+            source_directory: None,
+            runtime_file_name: "jet.runtime.js".into(),
+        }
+    }
+}
+
+/// An ES module generated from a program.
+pub struct JsOutput {
+    /// File name relative to the output directory.
+    pub file_name: String,
+    pub code: String,
+    /// Version 3 source map, to be written to the file name
+    /// followed by `.map`.
+    pub source_map: String,
+}
+
+/// JavaScript (ES2020) code generator.
+///
+/// Each verified `Program` is emitted as an ES module. Definitions
+/// in packages are exported from their module and imported by the modules
+/// that refer to them; the runtime module, whose contents are [`JS_RUNTIME`],
+/// is imported as `$jet` when needed.
+///
+/// Symbols assigned by the verifier are consulted where available:
+/// compile-time constants, as given by [`ConstantEvaluator`], are emitted as literals, `ConversionValue` symbols are
+/// lowered according to their relationship and operators on classes that
+/// define proxies invoke the proxy methods.
+///
+/// ```ignore
+/// let generator = JsGenerator::new(&host, verifier.ast_to_symbol(), JsGeneratorOptions::default());
+/// for output in generator.generate(&programs) {
+///     // Write output.code and output.source_map
+/// }
+/// ```
+pub struct JsGenerator {
+    host: Rc<SymbolHost>,
+    ast_to_symbol: Rc<AstToSymbol>,
+    options: JsGeneratorOptions,
+}
+
+impl JsGenerator {
+    pub fn new(host: &Rc<SymbolHost>, ast_to_symbol: &Rc<AstToSymbol>, options: JsGeneratorOptions) -> Self {
+        Self {
+            host: host.clone(),
+            ast_to_symbol: ast_to_symbol.clone(),
+            options,
+        }
+    }
+
+    /// Generates one ES module per program whose compilation
+    /// unit targets [`CompilerTarget::JavaScript`].
+    pub fn generate(&self, programs: &[Rc<Program>]) -> Vec<JsOutput> {
+        let programs: Vec<Rc<Program>> = programs.iter()
+            .filter(|program| program.location.compilation_unit().compiler_options().target == CompilerTarget::JavaScript)
+            .cloned().collect();
+        let programs = &programs[..];
+        let file_names: Vec<String> = programs.iter().enumerate().map(|(i, program)| self.output_file_name(program, i)).collect();
+        let index = JsPackageIndex::new(&self.host, programs);
+        programs.iter().enumerate().map(|(i, program)| {
+            JsModuleGenerator::new(self, &index, &file_names, i).generate(program)
+        }).collect()
+    }
+
+    fn output_file_name(&self, program: &Rc<Program>, index: usize) -> String {
+        let Some(file_path) = program.location.compilation_unit().file_path() else {
+            return format!("module{}.js", index);
+        };
+        let file_name = if let Some(source_directory) = &self.options.source_directory {
+            FlexPath::new_native(source_directory).relative(&file_path)
+        } else {
+            FlexPath::new_native(&file_path).base_name()
+        };
+        FlexPath::new_common(&file_name.replace('\\', "/")).change_last_extension(".js").to_string_with_flex_separator()
+    }
+}
+
+/// Definitions of the packages of all programs, used for
+/// resolving imports between the generated modules.
+struct JsPackageIndex {
+    packages: HashMap<String, HashMap<String, JsIndexedDefinition>>,
+}
+
+#[derive(Clone)]
+struct JsIndexedDefinition {
+    module: usize,
+    package: String,
+    directive: Rc<Directive>,
+}
+
+impl JsPackageIndex {
+    fn new(host: &SymbolHost, programs: &[Rc<Program>]) -> Self {
+        let mut packages: HashMap<String, HashMap<String, JsIndexedDefinition>> = HashMap::new();
+        for (module, program) in programs.iter().enumerate() {
+            for pckg in &program.packages {
+                let package = pckg.name.iter().map(|name| name.0.clone()).collect::<Vec<_>>().join(".");
+                let definitions = packages.entry(package.clone()).or_default();
+                for drtv in ConfigurationEvaluator(host).expand_directives(&pckg.block.directives) {
+                    for name in definition_names(&drtv) {
+                        definitions.insert(name, JsIndexedDefinition { module, package: package.clone(), directive: drtv.clone() });
+                    }
+                }
+            }
+        }
+        Self { packages }
+    }
+
+    fn get(&self, package: &str, name: &str) -> Option<&JsIndexedDefinition> {
+        self.packages.get(package).and_then(|definitions| definitions.get(name))
+    }
+
+    fn modules_of(&self, package: &str) -> Vec<usize> {
+        let mut modules: Vec<usize> = self.packages.get(package).map_or(vec![], |definitions| definitions.values().map(|d| d.module).collect());
+        modules.sort();
+        modules.dedup();
+        modules
+    }
+}
+
+/// Names declared by a directive in its enclosing block,
+/// excluding native definitions, which are not emitted.
+fn definition_names(drtv: &Rc<Directive>) -> Vec<String> {
+    match drtv.as_ref() {
+        Directive::VariableDefinition(defn) => {
+            if Attribute::find_native(&defn.attributes).is_some() {
+                return vec![];
+            }
+            let mut names = vec![];
+            for binding in &defn.bindings {
+                binding.destructuring.destructuring.pattern_names(&mut names);
+            }
+            names
+        },
+        Directive::FunctionDefinition(defn) => {
+            if Attribute::find_native(&defn.attributes).is_some() || !defn.is_normal() {
+                return vec![];
+            }
+            vec![defn.name_identifier().0]
+        },
+        Directive::ClassDefinition(defn) => vec![defn.name.0.clone()],
+        Directive::EnumDefinition(defn) => vec![defn.name.0.clone()],
+        Directive::InterfaceDefinition(defn) => vec![defn.name.0.clone()],
+        Directive::TypeDefinition(defn) => vec![defn.left.0.clone()],
+        _ => vec![],
+    }
+}
+
+fn proxy_kind_name(kind: ProxyKind) -> &'static str {
+    match kind {
+        ProxyKind::Positive => "positive",
+        ProxyKind::Negate => "negate",
+        ProxyKind::BitwiseNot => "bitwiseNot",
+        ProxyKind::Add => "add",
+        ProxyKind::Subtract => "subtract",
+        ProxyKind::Multiply => "multiply",
+        ProxyKind::Divide => "divide",
+        ProxyKind::Remainder => "remainder",
+        ProxyKind::Power => "power",
+        ProxyKind::BitwiseAnd => "bitwiseAnd",
+        ProxyKind::BitwiseXor => "bitwiseXor",
+        ProxyKind::BitwiseOr => "bitwiseOr",
+        ProxyKind::ShiftLeft => "shiftLeft",
+        ProxyKind::ShiftRight => "shiftRight",
+        ProxyKind::ShiftRightUnsigned => "shiftRightUnsigned",
+        ProxyKind::To => "to",
+        ProxyKind::GetProperty => "getProperty",
+        ProxyKind::SetProperty => "setProperty",
+        ProxyKind::DeleteProperty => "deleteProperty",
+        ProxyKind::Has => "has",
+        ProxyKind::Keys => "keys",
+        ProxyKind::Values => "values",
+    }
+}
+
+fn proxy_kind_of_operator(operator: Operator) -> Option<ProxyKind> {
+    match operator {
+        Operator::Positive => Some(ProxyKind::Positive),
+        Operator::Negative => Some(ProxyKind::Negate),
+        Operator::BitwiseNot => Some(ProxyKind::BitwiseNot),
+        Operator::Add => Some(ProxyKind::Add),
+        Operator::Subtract => Some(ProxyKind::Subtract),
+        Operator::Multiply => Some(ProxyKind::Multiply),
+        Operator::Divide => Some(ProxyKind::Divide),
+        Operator::Remainder => Some(ProxyKind::Remainder),
+        Operator::Power => Some(ProxyKind::Power),
+        Operator::BitwiseAnd => Some(ProxyKind::BitwiseAnd),
+        Operator::BitwiseXor => Some(ProxyKind::BitwiseXor),
+        Operator::BitwiseOr => Some(ProxyKind::BitwiseOr),
+        Operator::ShiftLeft => Some(ProxyKind::ShiftLeft),
+        Operator::ShiftRight => Some(ProxyKind::ShiftRight),
+        Operator::ShiftRightUnsigned => Some(ProxyKind::ShiftRightUnsigned),
+        _ => None,
+    }
+}
+
+fn binary_operator_code(operator: Operator) -> &'static str {
+    match operator {
+        Operator::Power => "**",
+        Operator::Multiply => "*",
+        Operator::Divide => "/",
+        Operator::Remainder => "%",
+        Operator::Add => "+",
+        Operator::Subtract => "-",
+        Operator::ShiftLeft => "<<",
+        Operator::ShiftRight => ">>",
+        Operator::ShiftRightUnsigned => ">>>",
+        Operator::Lt => "<",
+        Operator::Gt => ">",
+        Operator::Le => "<=",
+        Operator::Ge => ">=",
+        Operator::In => "in",
+        Operator::Equals => "==",
+        Operator::NotEquals => "!=",
+        Operator::StrictEquals => "===",
+        Operator::StrictNotEquals => "!==",
+        Operator::BitwiseAnd => "&",
+        Operator::BitwiseXor => "^",
+        Operator::BitwiseOr => "|",
+        Operator::LogicalAnd => "&&",
+        Operator::LogicalOr => "||",
+        Operator::NullCoalescing => "??",
+        _ => unreachable!("{operator:?} has no JavaScript binary operator"),
+    }
+}
+
+fn float_code(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".into()
+    } else if value.is_infinite() {
+        if value < 0.0 { "(-Infinity)".into() } else { "Infinity".into() }
+    } else if value < 0.0 {
+        format!("({:?})", value)
+    } else {
+        format!("{:?}", value)
+    }
+}
+
+fn number_code(value: &AbstractRangeNumber) -> String {
+    match value {
+        AbstractRangeNumber::Number(v) => float_code(*v),
+        AbstractRangeNumber::Single(v) => float_code(*v as f64),
+        AbstractRangeNumber::Long(v) => if *v < 0 { format!("({}n)", v) } else { format!("{}n", v) },
+        AbstractRangeNumber::BigInt(v) => if v.sign() == num_bigint::Sign::Minus { format!("({}n)", v) } else { format!("{}n", v) },
+    }
+}
+
+fn string_code(value: &str) -> String {
+    serde_json::to_string(value).unwrap()
+}
+
+/// Escapes text for inclusion in a template literal.
+fn template_text(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('`', "\\`").replace("${", "\\${")
+}
+
+/// Returns a module specifier for importing a module from another,
+/// given their file names relative to the output directory.
+fn module_specifier(from_file_name: &str, to_file_name: &str) -> String {
+    let from: Vec<&str> = from_file_name.split('/').collect();
+    let to: Vec<&str> = to_file_name.split('/').collect();
+    let from_directory = &from[..from.len() - 1];
+    let common = from_directory.iter().zip(to.iter()).take_while(|(a, b)| a == b).count();
+    let mut r = if from_directory.len() == common { "./".to_owned() } else { "../".repeat(from_directory.len() - common) };
+    r.push_str(&to[common..].join("/"));
+    r
+}
+
+#[derive(Clone)]
+enum JsBinding {
+    /// A lexical variable, function or local definition.
+    Local,
+    /// An instance member of the enclosing class.
+    Instance,
+    /// An instance member of the enclosing enumeration, whose
+    /// `this` value is the enumeration number.
+    EnumInstance(String),
+    /// A static member of the enclosing class or enumeration.
+    Static(String),
+    /// A package definition, possibly imported from another module.
+    Definition { package: String, name: String },
+    /// An alias to a package, as in `import q = p.q.*`.
+    PackageAlias(String),
+}
+
+#[derive(Default)]
+struct JsFrame {
+    names: HashMap<String, JsBinding>,
+    open_packages: Vec<String>,
+    /// Object whose properties are referred to by identifiers that are
+    /// not lexically bound, as inside a `with` statement or a filter
+    /// operation, and whether it is an XML filter operation.
+    dynamic_object: Option<(String, bool)>,
+}
+
+/// Generates the module of a single program.
+struct JsModuleGenerator<'a> {
+    generator: &'a JsGenerator,
+    index: &'a JsPackageIndex,
+    file_names: &'a [String],
+    module: usize,
+    writer: JsWriter,
+    frames: Vec<JsFrame>,
+    /// Imports of definitions by module, from local name to exported name.
+    imports: BTreeMap<usize, BTreeMap<String, String>>,
+    uses_runtime: bool,
+    optional_chaining_bases: Vec<Rc<Expression>>,
+    /// Enumeration code when `this` is an enumeration value.
+    enum_this: Option<String>,
+    temporary_counter: usize,
+}
+
+impl<'a> JsModuleGenerator<'a> {
+    fn new(generator: &'a JsGenerator, index: &'a JsPackageIndex, file_names: &'a [String], module: usize) -> Self {
+        Self {
+            generator,
+            index,
+            file_names,
+            module,
+            writer: JsWriter::new(),
+            frames: vec![],
+            imports: BTreeMap::new(),
+            uses_runtime: false,
+            optional_chaining_bases: vec![],
+            enum_this: None,
+            temporary_counter: 0,
+        }
+    }
+
+    fn generate(mut self, program: &Rc<Program>) -> JsOutput {
+        let file_name = self.file_names[self.module].clone();
+
+        for pckg in &program.packages {
+            let package = pckg.name.iter().map(|name| name.0.clone()).collect::<Vec<_>>().join(".");
+            let mut frame = JsFrame::default();
+            frame.open_packages.push(package.clone());
+            self.frames.push(frame);
+            self.declare_block_names(&pckg.block.directives, Some(&package));
+            self.package_directives(&pckg.block.directives);
+            self.frames.pop();
+        }
+
+        self.frames.push(JsFrame::default());
+        self.declare_block_names(&program.directives, None);
+        self.statements(&program.directives);
+        self.frames.pop();
+
+        let base_name = FlexPath::new_common(&file_name).base_name();
+        self.writer.write(&format!("//# sourceMappingURL={}.map", base_name));
+        self.writer.line();
+
+        // Prepend the imports
+        let mut header = JsWriter::new();
+        if self.uses_runtime {
+            header.write(&format!("import * as $jet from {};", string_code(&module_specifier(&file_name, &self.generator.options.runtime_file_name))));
+            header.line();
+        }
+        for (module, names) in &self.imports {
+            let specifiers = names.iter().map(|(local, name)| if local == name { name.clone() } else { format!("{} as {}", name, local) }).collect::<Vec<_>>().join(", ");
+            header.write(&format!("import {{ {} }} from {};", specifiers, string_code(&module_specifier(&file_name, &self.file_names[*module]))));
+            header.line();
+        }
+        header.append(self.writer);
+        let (code, mappings) = header.into_parts();
+
+        let mut source_map = SourceMapBuilder::new(Some(&base_name));
+        source_map.add_source(&program.location.compilation_unit());
+        for mapping in &mappings {
+            source_map.add_mapping(mapping.generated_line, mapping.generated_column, &mapping.location);
+        }
+        let source_map = source_map.build();
+
+        JsOutput { file_name, code, source_map }
+    }
+
+    fn runtime(&mut self, name: &str) -> String {
+        self.uses_runtime = true;
+        format!("$jet.{}", name)
+    }
+
+    fn temporary(&mut self) -> String {
+        self.temporary_counter += 1;
+        format!("$t{}", self.temporary_counter)
+    }
+
+    fn symbol_of(&self, exp: &Rc<Expression>) -> Option<Symbol> {
+        self.generator.ast_to_symbol.get(exp)
+    }
+
+    fn static_type_of(&self, exp: &Rc<Expression>) -> Option<Symbol> {
+        let symbol = self.symbol_of(exp)?;
+        if !symbol.is_value() {
+            return None;
+        }
+        let static_type = symbol.static_type(&self.generator.host);
+        if static_type.is_unresolved() { None } else { Some(static_type.non_null_type()) }
+    }
+
+    /// Writes the result of a closure into a separate string, discarding
+    /// its source mappings.
+    fn capture(&mut self, f: impl FnOnce(&mut Self)) -> String {
+        let writer = std::mem::replace(&mut self.writer, JsWriter::new());
+        f(self);
+        let captured = std::mem::replace(&mut self.writer, writer);
+        captured.into_parts().0
+    }
+
+    /// Replaces configuration directives by the directives
+    /// of their selected blocks.
+    fn expand_directives(&self, list: &[Rc<Directive>]) -> Vec<Rc<Directive>> {
+        ConfigurationEvaluator(&self.generator.host).expand_directives(list)
+    }
+
+    // Names
+
+    fn declare(&mut self, name: &str, binding: JsBinding) {
+        self.frames.last_mut().unwrap().names.insert(name.to_owned(), binding);
+    }
+
+    fn declare_pattern(&mut self, exp: &Rc<Expression>) {
+        let mut names = vec![];
+        exp.pattern_names(&mut names);
+        for name in names {
+            self.declare(&name, JsBinding::Local);
+        }
+    }
+
+    /// Declares the names of the definitions and imports of a block
+    /// before the block is emitted, so that they may be referred to
+    /// regardless of the order in which they appear.
+    fn declare_block_names(&mut self, list: &[Rc<Directive>], package: Option<&str>) {
+        for drtv in &self.expand_directives(list) {
+            match drtv.as_ref() {
+                Directive::ImportDirective(ImportDirective { alias, package_name, import_specifier, .. }) |
+                Directive::UseDirective(UseDirective { alias, package_name, import_specifier, .. }) => {
+                    let package_name = package_name.iter().map(|name| name.0.clone()).collect::<Vec<_>>().join(".");
+                    match (alias, import_specifier) {
+                        (Some(alias), ImportSpecifier::Wildcard(_)) => {
+                            self.declare(&alias.0, JsBinding::PackageAlias(package_name));
+                        },
+                        (Some(alias), ImportSpecifier::Identifier(name)) => {
+                            self.declare(&alias.0, JsBinding::Definition { package: package_name, name: name.0.clone() });
+                        },
+                        (None, ImportSpecifier::Wildcard(_)) => {
+                            self.frames.last_mut().unwrap().open_packages.push(package_name);
+                        },
+                        (None, ImportSpecifier::Identifier(name)) => {
+                            self.declare(&name.0, JsBinding::Definition { package: package_name, name: name.0.clone() });
+                        },
+                    }
+                },
+                _ => {
+                    for name in definition_names(drtv) {
+                        if let Some(package) = package {
+                            self.declare(&name, JsBinding::Definition { package: package.to_owned(), name: name.clone() });
+                        } else {
+                            self.declare(&name, JsBinding::Local);
+                        }
+                    }
+                },
+            }
+        }
+    }
+
+    fn lookup_binding(&self, name: &str) -> Option<JsBinding> {
+        self.frames.iter().rev().find_map(|frame| frame.names.get(name).cloned())
+    }
+
+    /// Resolves a name to an open package that defines it.
+    fn lookup_open_package(&self, name: &str) -> Option<String> {
+        for frame in self.frames.iter().rev() {
+            for package in frame.open_packages.iter().rev() {
+                if self.index.get(package, name).is_some() {
+                    return Some(package.clone());
+                }
+            }
+        }
+        self.index.get("", name).map(|_| String::new())
+    }
+
+    /// Returns code referring to a package definition, importing it
+    /// from its module if necessary. Definitions that are not part of
+    /// the generated modules are referred to by their name.
+    fn definition_reference(&mut self, package: &str, name: &str, local: &str) -> String {
+        let Some(definition) = self.index.get(package, name) else {
+            return name.to_owned();
+        };
+        if definition.module == self.module {
+            return name.to_owned();
+        }
+        self.imports.entry(definition.module).or_default().insert(local.to_owned(), name.to_owned());
+        local.to_owned()
+    }
+
+    fn binding_code(&mut self, name: &str, binding: JsBinding) -> String {
+        match binding {
+            JsBinding::Local | JsBinding::PackageAlias(_) => name.to_owned(),
+            JsBinding::Instance => format!("this.{}", name),
+            JsBinding::EnumInstance(enum_code) => format!("Reflect.get({}.prototype, {}, this)", enum_code, string_code(name)),
+            JsBinding::Static(class_code) => format!("{}.{}", class_code, name),
+            JsBinding::Definition { package, name: name_1 } => self.definition_reference(&package, &name_1, name),
+        }
+    }
+
+    /// Resolves an identifier that is lexically bound or that names
+    /// a definition of an open package.
+    fn resolve_bound_identifier(&mut self, name: &str) -> Option<String> {
+        if let Some(binding) = self.lookup_binding(name) {
+            return Some(self.binding_code(name, binding));
+        }
+        let package = self.lookup_open_package(name)?;
+        Some(self.definition_reference(&package, name, name))
+    }
+
+    fn resolve_identifier(&mut self, name: &str) -> String {
+        if let Some(code) = self.resolve_bound_identifier(name) {
+            return code;
+        }
+        let dynamic_object = self.frames.iter().rev().find_map(|frame| frame.dynamic_object.clone());
+        if let Some((object, filter)) = dynamic_object {
+            return if filter {
+                format!("{}({}, {})", self.runtime("child"), object, string_code(name))
+            } else {
+                format!("{}.{}", object, name)
+            };
+        }
+        if name == "trace" {
+            return self.runtime("trace");
+        }
+        name.to_owned()
+    }
+
+    /// Returns the package name designated by a sequence of identifiers
+    /// that are not lexically bound, as in `p.q`.
+    fn package_name_of(&self, exp: &Rc<Expression>) -> Option<String> {
+        match exp.as_ref() {
+            Expression::QualifiedIdentifier(id) => {
+                let (name, _) = id.to_identifier_name()?;
+                match self.lookup_binding(&name) {
+                    Some(JsBinding::PackageAlias(package)) => Some(package),
+                    Some(_) => None,
+                    None => Some(name),
+                }
+            },
+            Expression::Member(MemberExpression { base, identifier, .. }) => {
+                let (name, _) = identifier.to_identifier_name()?;
+                Some(format!("{}.{}", self.package_name_of(base)?, name))
+            },
+            _ => None,
+        }
+    }
+
+    /// Resolves a member expression designating a package definition,
+    /// as in `p.q.C`.
+    fn package_member(&mut self, member: &MemberExpression) -> Option<String> {
+        let (name, _) = member.identifier.to_identifier_name()?;
+        let package = self.package_name_of(&member.base)?;
+        self.index.get(&package, &name)?;
+        Some(self.definition_reference(&package, &name, &name))
+    }
+
+    // Types
+
+    /// Returns code referring to the runtime representation of
+    /// a type expression.
+    fn type_reference(&mut self, exp: &Rc<Expression>) -> String {
+        if let Some(symbol) = self.symbol_of(exp) {
+            if symbol.is_type() && !symbol.is_unresolved() {
+                return self.type_symbol_reference(&symbol);
+            }
+        }
+        match exp.as_ref() {
+            Expression::Paren(ParenExpression { expression, .. }) |
+            Expression::NullableType(NullableTypeExpression { base: expression, .. }) |
+            Expression::NonNullableType(NonNullableTypeExpression { base: expression, .. }) |
+            Expression::WithTypeArguments(ExpressionWithTypeArguments { base: expression, .. }) => self.type_reference(expression),
+            Expression::AnyType(_) => self.runtime("types.Any"),
+            Expression::VoidType(_) => "undefined".into(),
+            Expression::ArrayType(_) | Expression::TupleType(_) => "Array".into(),
+            Expression::FunctionType(_) => self.runtime("types.Function"),
+            Expression::QualifiedIdentifier(id) if id.to_identifier_name().is_some() => {
+                let (name, _) = id.to_identifier_name().unwrap();
+                if let Some(code) = self.resolve_bound_identifier(&name) {
+                    code
+                } else if RUNTIME_TYPES.contains(&name.as_str()) {
+                    self.runtime(&format!("types.{}", name))
+                } else {
+                    name
+                }
+            },
+            Expression::Member(member) => {
+                if let Some(code) = self.package_member(member) {
+                    return code;
+                }
+                let name = member.identifier.to_identifier_name();
+                if let (Some(package), Some((name, _))) = (self.package_name_of(&member.base), name) {
+                    if package == "jet.lang" && RUNTIME_TYPES.contains(&name.as_str()) {
+                        return self.runtime(&format!("types.{}", name));
+                    }
+                }
+                self.capture(|this| this.expression(exp))
+            },
+            _ => self.capture(|this| this.expression(exp)),
+        }
+    }
+
+    /// Returns code referring to the runtime representation of a type symbol.
+    fn type_symbol_reference(&mut self, type_symbol: &Symbol) -> String {
+        if type_symbol.is_any_type() || type_symbol.is_type_parameter_type() {
+            return self.runtime("types.Any");
+        }
+        if type_symbol.is_nullable_type() {
+            return self.type_symbol_reference(&type_symbol.base());
+        }
+        if type_symbol.is_void_type() {
+            return "undefined".into();
+        }
+        if type_symbol.is_function_type() {
+            return self.runtime("types.Function");
+        }
+        if type_symbol.is_tuple_type() {
+            return "Array".into();
+        }
+        let mut names = type_symbol.fully_qualified_name_list();
+        let name = names.pop().unwrap_or_default();
+        let package = names.join(".");
+        if package == "jet.lang" && RUNTIME_TYPES.contains(&name.as_str()) {
+            return self.runtime(&format!("types.{}", name));
+        }
+        self.definition_reference(&package, &name, &name)
+    }
+
+    /// Returns the default value of a variable, given its type annotation.
+    fn default_value(&self, type_annotation: &Option<Rc<Expression>>) -> String {
+        if let Some(exp) = type_annotation {
+            if let Some(symbol) = self.symbol_of(exp) {
+                if symbol.is_type() && !symbol.is_unresolved() {
+                    let host = &self.generator.host;
+                    return if symbol == host.number_type() || symbol == host.single_type() || symbol == host.char_type() {
+                        "0".into()
+                    } else if symbol == host.long_type() || symbol == host.big_int_type() {
+                        "0n".into()
+                    } else if symbol == host.boolean_type() {
+                        "false".into()
+                    } else if symbol == host.string_type() {
+                        "\"\"".into()
+                    } else {
+                        "null".into()
+                    };
+                }
+            }
+            if let Some((name, _)) = exp.to_identifier_name() {
+                if self.lookup_binding(&name).is_none() {
+                    match name.as_str() {
+                        "Number" | "Single" | "Char" => return "0".into(),
+                        "Long" | "BigInt" => return "0n".into(),
+                        "Boolean" => return "false".into(),
+                        "String" => return "\"\"".into(),
+                        _ => {},
+                    }
+                }
+            }
+        }
+        "null".into()
+    }
+
+    // Directives
+
+    /// Emits the directives of a package block, exporting definitions.
+    fn package_directives(&mut self, list: &[Rc<Directive>]) {
+        let mut type_definitions = vec![];
+        for drtv in &self.expand_directives(list) {
+            match drtv.as_ref() {
+                Directive::TypeDefinition(_) => type_definitions.push(drtv.clone()),
+                Directive::VariableDefinition(_) |
+                Directive::FunctionDefinition(_) |
+                Directive::ClassDefinition(_) |
+                Directive::EnumDefinition(_) |
+                Directive::InterfaceDefinition(_) => self.definition(drtv, true),
+                _ => self.statement(drtv),
+            }
+        }
+        // Type aliases are initialized after the types they may refer to.
+        for drtv in type_definitions {
+            self.definition(&drtv, true);
+        }
+    }
+
+    fn statements(&mut self, list: &[Rc<Directive>]) {
+        for drtv in &self.expand_directives(list) {
+            self.statement(drtv);
+        }
+    }
+
+    fn definition(&mut self, drtv: &Rc<Directive>, export: bool) {
+        self.writer.map(&drtv.location());
+        let export = if export { "export " } else { "" };
+        match drtv.as_ref() {
+            Directive::VariableDefinition(defn) => {
+                if Attribute::find_native(&defn.attributes).is_some() {
+                    return;
+                }
+                self.writer.write(export);
+                self.variable_bindings(defn.kind.0, &defn.bindings);
+                self.writer.write(";");
+                self.writer.line();
+            },
+            Directive::FunctionDefinition(defn) => {
+                if Attribute::find_native(&defn.attributes).is_some() || defn.common.body.is_none() {
+                    return;
+                }
+                self.writer.write(export);
+                self.function_keyword(&defn.common);
+                self.writer.write(&defn.name_identifier().0);
+                self.function_rest(&defn.common, false);
+                self.writer.line();
+            },
+            Directive::ClassDefinition(defn) => self.class_definition(defn, export),
+            Directive::EnumDefinition(defn) => self.enum_definition(drtv, defn, export),
+            Directive::InterfaceDefinition(defn) => {
+                let bases = defn.extends_clause.clone().unwrap_or_default().iter().map(|base| self.type_reference(base)).collect::<Vec<_>>().join(", ");
+                let define_interface = self.runtime("defineInterface");
+                let package = self.frames.iter().rev().find_map(|frame| frame.open_packages.first().cloned()).unwrap_or_default();
+                let name = if package.is_empty() { defn.name.0.clone() } else { format!("{}.{}", package, defn.name.0) };
+                self.writer.write(&format!("{}const {} = {}({}, [{}]);", export, defn.name.0, define_interface, string_code(&name), bases));
+                self.writer.line();
+            },
+            Directive::TypeDefinition(defn) => {
+                let right = self.type_reference(&defn.right);
+                self.writer.write(&format!("{}const {} = {};", export, defn.left.0, right));
+                self.writer.line();
+            },
+            _ => unreachable!("only definitions are emitted by JsGenerator::definition"),
+        }
+    }
+
+    fn variable_bindings(&mut self, kind: VariableDefinitionKind, bindings: &[Rc<VariableBinding>]) {
+        self.writer.write(if kind == VariableDefinitionKind::Const { "const " } else { "let " });
+        for (i, binding) in bindings.iter().enumerate() {
+            if i != 0 {
+                self.writer.write(", ");
+            }
+            self.pattern(&binding.destructuring.destructuring);
+            self.writer.write(" = ");
+            if let Some(init) = &binding.initializer {
+                self.list_item(init);
+            } else {
+                let default_value = self.default_value(&binding.destructuring.type_annotation);
+                self.writer.write(&default_value);
+            }
+        }
+    }
+
+    fn statement(&mut self, drtv: &Rc<Directive>) {
+        self.writer.map(&drtv.location());
+        match drtv.as_ref() {
+            Directive::EmptyStatement(_) => {
+                self.writer.write(";");
+                self.writer.line();
+            },
+            Directive::ExpressionStatement(ExpressionStatement { expression, .. }) => {
+                let code = self.capture(|this| this.expression(expression));
+                let wrap = code.starts_with('{') || code.starts_with("function") || code.starts_with("class") || code.starts_with("let ");
+                if wrap {
+                    self.writer.write("(");
+                }
+                self.expression(expression);
+                if wrap {
+                    self.writer.write(")");
+                }
+                self.writer.write(";");
+                self.writer.line();
+            },
+            Directive::SuperStatement(SuperStatement { arguments, .. }) => {
+                self.writer.write("super(");
+                self.arguments(arguments);
+                self.writer.write(");");
+                self.writer.line();
+            },
+            Directive::Block(block) => {
+                self.block(block);
+                self.writer.line();
+            },
+            Directive::LabeledStatement(LabeledStatement { label, substatement, .. }) => {
+                self.writer.write(&format!("{}: ", label.0));
+                self.statement(substatement);
+            },
+            Directive::IfStatement(IfStatement { test, consequent, alternative, .. }) => {
+                self.writer.write("if (");
+                self.expression(test);
+                self.writer.write(") ");
+                self.substatement(consequent);
+                if let Some(alternative) = alternative {
+                    self.writer.write(" else ");
+                    self.substatement(alternative);
+                }
+                self.writer.line();
+            },
+            Directive::SwitchStatement(stmt) => self.switch_statement(stmt),
+            Directive::SwitchTypeStatement(stmt) => self.switch_type_statement(stmt),
+            Directive::DoStatement(DoStatement { body, test, .. }) => {
+                self.writer.write("do ");
+                self.substatement(body);
+                self.writer.write(" while (");
+                self.expression(test);
+                self.writer.write(");");
+                self.writer.line();
+            },
+            Directive::WhileStatement(WhileStatement { test, body, .. }) => {
+                self.writer.write("while (");
+                self.expression(test);
+                self.writer.write(") ");
+                self.substatement(body);
+                self.writer.line();
+            },
+            Directive::ForStatement(stmt) => self.for_statement(stmt),
+            Directive::ForInStatement(stmt) => self.for_in_statement(stmt),
+            Directive::BreakStatement(BreakStatement { label, .. }) => {
+                self.writer.write(&label.as_ref().map_or("break;".into(), |label| format!("break {};", label.0)));
+                self.writer.line();
+            },
+            Directive::ContinueStatement(ContinueStatement { label, .. }) => {
+                self.writer.write(&label.as_ref().map_or("continue;".into(), |label| format!("continue {};", label.0)));
+                self.writer.line();
+            },
+            Directive::WithStatement(WithStatement { object, body, .. }) => {
+                let object_code = self.temporary();
+                self.writer.write("{");
+                self.writer.line();
+                self.writer.indent();
+                self.writer.write(&format!("const {} = ", object_code));
+                self.expression(object);
+                self.writer.write(";");
+                self.writer.line();
+                self.frames.push(JsFrame { dynamic_object: Some((object_code, false)), ..default() });
+                self.statement(body);
+                self.frames.pop();
+                self.writer.unindent();
+                self.writer.write("}");
+                self.writer.line();
+            },
+            Directive::ReturnStatement(ReturnStatement { expression, .. }) => {
+                if let Some(expression) = expression {
+                    self.writer.write("return ");
+                    self.list_item(expression);
+                    self.writer.write(";");
+                } else {
+                    self.writer.write("return;");
+                }
+                self.writer.line();
+            },
+            Directive::ThrowStatement(ThrowStatement { expression, .. }) => {
+                self.writer.write("throw ");
+                self.expression(expression);
+                self.writer.write(";");
+                self.writer.line();
+            },
+            Directive::DefaultXmlNamespaceStatement(DefaultXmlNamespaceStatement { right, .. }) => {
+                let set_default_xml_namespace = self.runtime("setDefaultXmlNamespace");
+                self.writer.write(&format!("{}(", set_default_xml_namespace));
+                self.list_item(right);
+                self.writer.write(");");
+                self.writer.line();
+            },
+            Directive::TryStatement(stmt) => self.try_statement(stmt),
+            Directive::UseDirective(drtv) => self.use_directive(drtv),
+            Directive::ConfigurationDirective(_) => self.statements(std::slice::from_ref(drtv)),
+            Directive::ImportDirective(_) |
+            Directive::Invalid(_) => {},
+            Directive::VariableDefinition(_) |
+            Directive::FunctionDefinition(_) |
+            Directive::ClassDefinition(_) |
+            Directive::EnumDefinition(_) |
+            Directive::InterfaceDefinition(_) |
+            Directive::TypeDefinition(_) => self.definition(drtv, false),
+        }
+    }
+
+    /// Emits a statement that is the body of another statement,
+    /// without terminating the line.
+    fn substatement(&mut self, drtv: &Rc<Directive>) {
+        if let Directive::Block(block) = drtv.as_ref() {
+            self.writer.map(&drtv.location());
+            self.block(block);
+        } else {
+            self.writer.write("{");
+            self.writer.line();
+            self.writer.indent();
+            self.frames.push(JsFrame::default());
+            self.declare_block_names(std::slice::from_ref(drtv), None);
+            self.statement(drtv);
+            self.frames.pop();
+            self.writer.unindent();
+            self.writer.write("}");
+        }
+    }
+
+    /// Emits a block without terminating the line.
+    fn block(&mut self, block: &Block) {
+        self.block_with(block, |_| {});
+    }
+
+    /// Emits a block, invoking a closure after its scope has been
+    /// entered, without terminating the line.
+    fn block_with(&mut self, block: &Block, f: impl FnOnce(&mut Self)) {
+        self.writer.write("{");
+        self.writer.line();
+        self.writer.indent();
+        self.frames.push(JsFrame::default());
+        f(self);
+        self.declare_block_names(&block.directives, None);
+        self.statements(&block.directives);
+        self.frames.pop();
+        self.writer.unindent();
+        self.writer.write("}");
+    }
+
+    fn switch_statement(&mut self, stmt: &SwitchStatement) {
+        self.writer.write("switch (");
+        self.expression(&stmt.discriminant);
+        self.writer.write(") {");
+        self.writer.line();
+        self.writer.indent();
+        self.frames.push(JsFrame::default());
+        for case in &stmt.cases {
+            self.writer.map(&case.location);
+            for (i, label) in case.labels.iter().enumerate() {
+                if i != 0 {
+                    self.writer.line();
+                }
+                match label {
+                    CaseLabel::Case((exp, _)) => {
+                        self.writer.write("case ");
+                        self.expression(exp);
+                        self.writer.write(":");
+                    },
+                    CaseLabel::Default(_) => {
+                        self.writer.write("default:");
+                    },
+                }
+            }
+            self.writer.write(" {");
+            self.writer.line();
+            self.writer.indent();
+            self.frames.push(JsFrame::default());
+            self.declare_block_names(&case.directives, None);
+            self.statements(&case.directives);
+            self.frames.pop();
+            // Cases do not fall through.
+            let terminated = matches!(case.directives.last().map(|d| d.as_ref()), Some(Directive::BreakStatement(_) | Directive::ContinueStatement(_) | Directive::ReturnStatement(_) | Directive::ThrowStatement(_)));
+            if !terminated {
+                self.writer.write("break;");
+                self.writer.line();
+            }
+            self.writer.unindent();
+            self.writer.write("}");
+            self.writer.line();
+        }
+        self.frames.pop();
+        self.writer.unindent();
+        self.writer.write("}");
+        self.writer.line();
+    }
+
+    fn switch_type_statement(&mut self, stmt: &SwitchTypeStatement) {
+        let discriminant = self.temporary();
+        self.writer.write("{");
+        self.writer.line();
+        self.writer.indent();
+        self.writer.write(&format!("const {} = ", discriminant));
+        self.expression(&stmt.discriminant);
+        self.writer.write(";");
+        self.writer.line();
+        self.type_cases(&discriminant, stmt.cases.iter().map(|case| (case.parameter.as_ref(), case.block.clone())).collect(), false);
+        self.writer.unindent();
+        self.writer.write("}");
+        self.writer.line();
+    }
+
+    /// Emits a chain of `if` statements testing the type of a value
+    /// against typed bindings. If `rethrow` is true, the value is rethrown
+    /// when no binding matches.
+    fn type_cases(&mut self, value: &str, cases: Vec<(Option<&TypedDestructuring>, Rc<Block>)>, rethrow: bool) {
+        let mut has_default = false;
+        for (i, (parameter, block)) in cases.iter().enumerate() {
+            if i != 0 {
+                self.writer.write(" else ");
+            }
+            let type_annotation = parameter.and_then(|parameter| parameter.type_annotation.clone())
+                .filter(|t| !matches!(t.as_ref(), Expression::AnyType(_)));
+            if let Some(type_annotation) = &type_annotation {
+                let type_code = self.type_reference(type_annotation);
+                let is = self.runtime("is");
+                self.writer.write(&format!("if ({}({}, {})) ", is, value, type_code));
+            } else {
+                has_default = true;
+            }
+            self.block_with(block, |this| {
+                if let Some(parameter) = parameter {
+                    this.declare_pattern(&parameter.destructuring);
+                    this.writer.write("const ");
+                    this.pattern(&parameter.destructuring);
+                    this.writer.write(&format!(" = {};", value));
+                    this.writer.line();
+                }
+            });
+            if has_default {
+                break;
+            }
+        }
+        if rethrow && !has_default {
+            self.writer.write(&format!(" else {{ throw {}; }}", value));
+        }
+        self.writer.line();
+    }
+
+    fn for_statement(&mut self, stmt: &ForStatement) {
+        self.frames.push(JsFrame::default());
+        self.writer.write("for (");
+        match &stmt.init {
+            Some(ForInitializer::Expression(exp)) => self.expression(exp),
+            Some(ForInitializer::VariableDefinition(defn)) => {
+                for binding in &defn.bindings {
+                    self.declare_pattern(&binding.destructuring.destructuring);
+                }
+                self.variable_bindings(defn.kind.0, &defn.bindings);
+            },
+            None => {},
+        }
+        self.writer.write("; ");
+        if let Some(test) = &stmt.test {
+            self.expression(test);
+        }
+        self.writer.write("; ");
+        if let Some(update) = &stmt.update {
+            self.expression(update);
+        }
+        self.writer.write(") ");
+        self.substatement(&stmt.body);
+        self.frames.pop();
+        self.writer.line();
+    }
+
+    fn for_in_statement(&mut self, stmt: &ForInStatement) {
+        self.frames.push(JsFrame::default());
+        self.writer.write("for (");
+        match &stmt.left {
+            ForInBinding::Expression(exp) => self.pattern(exp),
+            ForInBinding::VariableDefinition(defn) => {
+                let binding = &defn.bindings[0];
+                self.declare_pattern(&binding.destructuring.destructuring);
+                self.writer.write(if defn.kind.0 == VariableDefinitionKind::Const { "const " } else { "let " });
+                self.pattern(&binding.destructuring.destructuring);
+            },
+        }
+        let iterate = self.runtime(if stmt.each { "values" } else { "keys" });
+        self.writer.write(&format!(" of {}(", iterate));
+        self.expression(&stmt.right);
+        self.writer.write(")) ");
+        self.substatement(&stmt.body);
+        self.frames.pop();
+        self.writer.line();
+    }
+
+    fn try_statement(&mut self, stmt: &TryStatement) {
+        self.writer.write("try ");
+        self.block(&stmt.block);
+        let single_untyped = stmt.catch_clauses.len() == 1 && stmt.catch_clauses[0].parameter.type_annotation.as_ref().is_none_or(|t| matches!(t.as_ref(), Expression::AnyType(_)));
+        if single_untyped {
+            let clause = &stmt.catch_clauses[0];
+            self.writer.write(" catch (");
+            self.pattern(&clause.parameter.destructuring);
+            self.writer.write(") ");
+            self.block_with(&clause.block, |this| this.declare_pattern(&clause.parameter.destructuring));
+        } else if !stmt.catch_clauses.is_empty() {
+            let error = self.temporary();
+            self.writer.write(&format!(" catch ({}) {{", error));
+            self.writer.line();
+            self.writer.indent();
+            self.type_cases(&error, stmt.catch_clauses.iter().map(|clause| (Some(&clause.parameter), clause.block.clone())).collect(), true);
+            self.writer.unindent();
+            self.writer.write("}");
+        }
+        if let Some(finally_clause) = &stmt.finally_clause {
+            self.writer.write(" finally ");
+            self.block(&finally_clause.block);
+        }
+        self.writer.line();
+    }
+
+    /// Emits a `use` directive, which re-exports a definition or
+    /// the definitions of a package.
+    fn use_directive(&mut self, drtv: &UseDirective) {
+        let package = drtv.package_name.iter().map(|name| name.0.clone()).collect::<Vec<_>>().join(".");
+        let from_file_name = self.file_names[self.module].clone();
+        match &drtv.import_specifier {
+            ImportSpecifier::Identifier(name) => {
+                let Some(definition) = self.index.get(&package, &name.0).cloned() else {
+                    return;
+                };
+                let alias = drtv.alias.as_ref().map_or(name.0.clone(), |alias| alias.0.clone());
+                let specifier = if alias == name.0 { name.0.clone() } else { format!("{} as {}", name.0, alias) };
+                if definition.module == self.module {
+                    self.writer.write(&format!("export {{ {} }};", specifier));
+                } else {
+                    self.writer.write(&format!("export {{ {} }} from {};", specifier, string_code(&module_specifier(&from_file_name, &self.file_names[definition.module]))));
+                }
+                self.writer.line();
+            },
+            ImportSpecifier::Wildcard(_) => {
+                for module in self.index.modules_of(&package) {
+                    if module == self.module {
+                        continue;
+                    }
+                    self.writer.write(&format!("export * from {};", string_code(&module_specifier(&from_file_name, &self.file_names[module]))));
+                    self.writer.line();
+                }
+            },
+        }
+    }
+
+    // Classes and enumerations
+
+    /// Collects the instance member names of a class definition
+    /// and of the classes it inherits from the generated modules.
+    fn inherited_instance_names(&self, extends_clause: &Option<Rc<Expression>>, package: &str, names: &mut Vec<String>, depth: usize) {
+        let Some(exp) = extends_clause else {
+            return;
+        };
+        if depth > 32 {
+            return;
+        }
+        let definition = if let Some((name, _)) = exp.to_identifier_name() {
+            match self.lookup_binding(&name) {
+                Some(JsBinding::Definition { package, name }) => self.index.get(&package, &name),
+                Some(_) => None,
+                None => self.lookup_open_package(&name).and_then(|p| self.index.get(&p, &name)).or_else(|| self.index.get(package, &name)),
+            }
+        } else if let Expression::Member(MemberExpression { base, identifier, .. }) = exp.as_ref() {
+            identifier.to_identifier_name().and_then(|(name, _)| self.package_name_of(base).and_then(|p| self.index.get(&p, &name)))
+        } else {
+            None
+        };
+        let Some(definition) = definition else {
+            return;
+        };
+        if let Directive::ClassDefinition(defn) = definition.directive.as_ref() {
+            for drtv in &self.expand_directives(&defn.block.directives) {
+                if let Some((name, is_static)) = member_name(drtv) {
+                    if !is_static {
+                        names.push(name);
+                    }
+                }
+            }
+            self.inherited_instance_names(&defn.extends_clause, &definition.package, names, depth + 1);
+        }
+    }
+
+    fn class_definition(&mut self, defn: &ClassDefinition, export: &str) {
+        let name = defn.name.0.clone();
+        let package = self.frames.iter().rev().find_map(|frame| frame.open_packages.first().cloned()).unwrap_or_default();
+
+        let mut frame = JsFrame::default();
+        let mut inherited = vec![];
+        self.inherited_instance_names(&defn.extends_clause, &package, &mut inherited, 0);
+        for member in inherited {
+            frame.names.insert(member, JsBinding::Instance);
+        }
+        for drtv in &self.expand_directives(&defn.block.directives) {
+            if let Some((member, is_static)) = member_name(drtv) {
+                frame.names.insert(member, if is_static { JsBinding::Static(name.clone()) } else { JsBinding::Instance });
+            }
+        }
+
+        let extends_code = defn.extends_clause.as_ref().map(|exp| self.type_reference(exp));
+        self.frames.push(frame);
+
+        let mut instance_fields: Vec<Rc<VariableBinding>> = vec![];
+        let mut static_fields: Vec<Rc<VariableBinding>> = vec![];
+        let mut constructor: Option<&FunctionDefinition> = None;
+        let mut methods: Vec<&FunctionDefinition> = vec![];
+        let mut statements: Vec<Rc<Directive>> = vec![];
+        let directives = self.expand_directives(&defn.block.directives);
+        for drtv in &directives {
+            match drtv.as_ref() {
+                Directive::VariableDefinition(var_defn) => {
+                    if Attribute::find_native(&var_defn.attributes).is_some() {
+                        continue;
+                    }
+                    if Attribute::find_static(&var_defn.attributes).is_some() {
+                        static_fields.extend(var_defn.bindings.iter().cloned());
+                    } else {
+                        instance_fields.extend(var_defn.bindings.iter().cloned());
+                    }
+                },
+                Directive::FunctionDefinition(fn_defn) => {
+                    if fn_defn.is_constructor() {
+                        constructor = Some(fn_defn);
+                    } else {
+                        methods.push(fn_defn);
+                    }
+                },
+                _ => statements.push(drtv.clone()),
+            }
+        }
+
+        self.writer.write(&format!("{}class {}", export, name));
+        if let Some(extends_code) = &extends_code {
+            self.writer.write(&format!(" extends {}", extends_code));
+        }
+        self.writer.write(" {");
+        self.writer.line();
+        self.writer.indent();
+
+        // Instance fields are initialized by the constructor.
+        if constructor.is_some() || !instance_fields.is_empty() {
+            self.constructor(constructor, &instance_fields, extends_code.is_some());
+        }
+        let mut to_proxy_index = 0;
+        for method in methods {
+            self.method(method, &mut to_proxy_index);
+        }
+
+        self.writer.unindent();
+        self.writer.write("}");
+        self.writer.line();
+
+        for binding in &static_fields {
+            self.field_initializer(&name, binding);
+        }
+        if let Some(implements_clause) = &defn.implements_clause {
+            let interfaces = implements_clause.iter().map(|exp| self.type_reference(exp)).collect::<Vec<_>>().join(", ");
+            let implement = self.runtime("implement");
+            self.writer.write(&format!("{}({}, [{}]);", implement, name, interfaces));
+            self.writer.line();
+        }
+        self.statements(&statements);
+
+        self.frames.pop();
+    }
+
+    /// Emits the assignment of a field to an object.
+    fn field_initializer(&mut self, object: &str, binding: &Rc<VariableBinding>) {
+        let Some((field_name, _)) = binding.destructuring.destructuring.to_identifier_name() else {
+            return;
+        };
+        self.writer.map(&binding.location());
+        self.writer.write(&format!("{}.{} = ", object, field_name));
+        if let Some(init) = &binding.initializer {
+            self.list_item(init);
+        } else {
+            let default_value = self.default_value(&binding.destructuring.type_annotation);
+            self.writer.write(&default_value);
+        }
+        self.writer.write(";");
+        self.writer.line();
+    }
+
+    fn constructor(&mut self, defn: Option<&FunctionDefinition>, instance_fields: &[Rc<VariableBinding>], derived: bool) {
+        let Some(defn) = defn else {
+            self.writer.write("constructor() {");
+            self.writer.line();
+            self.writer.indent();
+            if derived {
+                self.writer.write("super();");
+                self.writer.line();
+            }
+            for binding in instance_fields {
+                self.field_initializer("this", binding);
+            }
+            self.writer.unindent();
+            self.writer.write("}");
+            self.writer.line();
+            return;
+        };
+        self.writer.map(&defn.location);
+        self.writer.write("constructor");
+        self.frames.push(JsFrame::default());
+        self.parameters(&defn.common.signature);
+        self.writer.write(" {");
+        self.writer.line();
+        self.writer.indent();
+        let directives = match &defn.common.body {
+            Some(FunctionBody::Block(block)) => block.directives.clone(),
+            _ => vec![],
+        };
+        self.declare_block_names(&directives, None);
+        let super_statement = directives.iter().position(|drtv| matches!(drtv.as_ref(), Directive::SuperStatement(_)));
+        let after_super = if let Some(i) = super_statement {
+            self.statements(&directives[..=i]);
+            i + 1
+        } else {
+            if derived {
+                self.writer.write("super();");
+                self.writer.line();
+            }
+            0
+        };
+        for binding in instance_fields {
+            self.field_initializer("this", binding);
+        }
+        self.statements(&directives[after_super..]);
+        self.frames.pop();
+        self.writer.unindent();
+        self.writer.write("}");
+        self.writer.line();
+    }
+
+    /// Emits a method, getter, setter or proxy of a class or enumeration.
+    fn method(&mut self, defn: &FunctionDefinition, to_proxy_index: &mut usize) {
+        let name = match &defn.name {
+            FunctionName::Identifier(name) => name.0.clone(),
+            FunctionName::Getter(name) => format!("get {}", name.0),
+            FunctionName::Setter(name) => format!("set {}", name.0),
+            FunctionName::Proxy(ProxyKind::To, _) => {
+                *to_proxy_index += 1;
+                format!("$proxy$to${}", *to_proxy_index - 1)
+            },
+            FunctionName::Proxy(kind, _) => format!("$proxy${}", proxy_kind_name(*kind)),
+            FunctionName::Constructor(_) => return,
+        };
+        if Attribute::find_native(&defn.attributes).is_some() || defn.common.body.is_none() {
+            return;
+        }
+        self.writer.map(&defn.location);
+        if Attribute::find_static(&defn.attributes).is_some() {
+            self.writer.write("static ");
+        }
+        if defn.common.contains_await {
+            self.writer.write("async ");
+        }
+        if defn.common.contains_yield {
+            self.writer.write("*");
+        }
+        self.writer.write(&name);
+        self.function_rest(&defn.common, false);
+        self.writer.line();
+    }
+
+    fn enum_definition(&mut self, drtv: &Rc<Directive>, defn: &EnumDefinition, export: &str) {
+        let name = defn.name.0.clone();
+        let enum_type = self.generator.ast_to_symbol.get(drtv);
+
+        let mut frame = JsFrame::default();
+        let mut members: Vec<(String, String, String)> = vec![];
+        let mut methods: Vec<&FunctionDefinition> = vec![];
+        let mut statements: Vec<Rc<Directive>> = vec![];
+        let directives = self.expand_directives(&defn.block.directives);
+        for drtv in &directives {
+            match drtv.as_ref() {
+                Directive::VariableDefinition(var_defn) if var_defn.kind.0 == VariableDefinitionKind::Const && Attribute::find_static(&var_defn.attributes).is_none() => {
+                    for binding in &var_defn.bindings {
+                        let Some((constant, _)) = binding.destructuring.destructuring.to_identifier_name() else {
+                            continue;
+                        };
+                        frame.names.insert(constant.clone(), JsBinding::Static(name.clone()));
+
+                        // Members take the values assigned by the verifier.
+                        let Some(enum_type) = &enum_type else {
+                            continue;
+                        };
+                        let Some(value) = enum_type.static_properties(&self.generator.host).get(&constant)
+                            .and_then(|constant| constant.constant_initializer())
+                            .map(|constant| constant.number_value()) else {
+                            continue;
+                        };
+                        let string = enum_type.enumeration_members().borrow().iter()
+                            .find(|(_, value_1)| **value_1 == value)
+                            .map(|(string, _)| string.clone())
+                            .unwrap_or_else(|| EnumDefinition::member_string(&constant));
+                        members.push((constant, string, number_code(&value)));
+                    }
+                },
+                Directive::FunctionDefinition(fn_defn) => {
+                    if let Some((member, is_static)) = member_name(drtv) {
+                        frame.names.insert(member, if is_static { JsBinding::Static(name.clone()) } else { JsBinding::EnumInstance(name.clone()) });
+                    }
+                    methods.push(fn_defn);
+                },
+                _ => {
+                    if let Some((member, _)) = member_name(drtv) {
+                        frame.names.insert(member, JsBinding::Static(name.clone()));
+                    }
+                    statements.push(drtv.clone());
+                },
+            }
+        }
+
+        self.frames.push(frame);
+        self.writer.write(&format!("{}class {} {{", export, name));
+        self.writer.line();
+        self.writer.indent();
+        let mut to_proxy_index = 0;
+        for method in methods {
+            let enum_this = if Attribute::find_static(&method.attributes).is_some() { None } else { Some(name.clone()) };
+            let enum_this = std::mem::replace(&mut self.enum_this, enum_this);
+            self.method(method, &mut to_proxy_index);
+            self.enum_this = enum_this;
+        }
+        self.writer.unindent();
+        self.writer.write("}");
+        self.writer.line();
+
+        let define_enum = self.runtime("defineEnum");
+        self.writer.write(&format!("{}({}, [", define_enum, name));
+        self.writer.line();
+        self.writer.indent();
+        for (constant, string, value) in members {
+            self.writer.write(&format!("[{}, {}, {}],", string_code(&constant), string_code(&string), value));
+            self.writer.line();
+        }
+        self.writer.unindent();
+        self.writer.write(&format!("], {});", defn.is_set));
+        self.writer.line();
+        for drtv in statements {
+            if let Directive::VariableDefinition(var_defn) = drtv.as_ref() {
+                for binding in &var_defn.bindings {
+                    self.field_initializer(&name, binding);
+                }
+            } else {
+                self.statement(&drtv);
+            }
+        }
+        self.frames.pop();
+    }
+
+    // Functions
+
+    fn function_keyword(&mut self, common: &FunctionCommon) {
+        if common.contains_await {
+            self.writer.write("async ");
+        }
+        self.writer.write(if common.contains_yield { "function* " } else { "function " });
+    }
+
+    fn parameters(&mut self, signature: &FunctionSignature) {
+        self.writer.write("(");
+        for (i, param) in signature.parameters.iter().enumerate() {
+            if i != 0 {
+                self.writer.write(", ");
+            }
+            self.writer.map(&param.location);
+            self.declare_pattern(&param.destructuring.destructuring);
+            if param.kind == ParameterKind::Rest {
+                self.writer.write("...");
+            }
+            self.pattern(&param.destructuring.destructuring);
+            if let Some(default_value) = &param.default_value {
+                self.writer.write(" = ");
+                self.list_item(default_value);
+            }
+        }
+        self.writer.write(")");
+    }
+
+    /// Emits the parameters and body of a function. If `arrow` is true,
+    /// the function is emitted as an arrow function.
+    fn function_rest(&mut self, common: &FunctionCommon, arrow: bool) {
+        self.frames.push(JsFrame::default());
+        self.parameters(&common.signature);
+        if arrow {
+            self.writer.write(" =>");
+        }
+        self.writer.write(" ");
+        match &common.body {
+            Some(FunctionBody::Block(block)) => {
+                self.block(block);
+            },
+            Some(FunctionBody::Expression(exp)) => {
+                if arrow && !matches!(exp.as_ref(), Expression::ObjectInitializer(_)) {
+                    self.list_item(exp);
+                } else {
+                    self.writer.write("{ return ");
+                    self.list_item(exp);
+                    self.writer.write("; }");
+                }
+            },
+            None => {
+                self.writer.write("{}");
+            },
+        }
+        self.frames.pop();
+    }
+
+    fn function_expression(&mut self, exp: &FunctionExpression) {
+        // Arrow functions preserve `this`, which is required for
+        // accessing members of the enclosing class without qualification.
+        if exp.name.is_none() && !exp.common.contains_yield {
+            self.writer.write("(");
+            if exp.common.contains_await {
+                self.writer.write("async ");
+            }
+            self.function_rest(&exp.common, true);
+            self.writer.write(")");
+            return;
+        }
+        self.writer.write("(");
+        self.function_keyword(&exp.common);
+        self.frames.push(JsFrame::default());
+        if let Some(name) = &exp.name {
+            self.declare(&name.0, JsBinding::Local);
+            self.writer.write(&name.0);
+        }
+        self.function_rest(&exp.common, false);
+        self.frames.pop();
+        self.writer.write(").bind(this)");
+    }
+
+    // Expressions
+
+    /// Emits an expression that is an element of a list, such as an
+    /// argument, an initializer or a returned value.
+    fn list_item(&mut self, exp: &Rc<Expression>) {
+        if let Expression::Sequence(_) = exp.as_ref() {
+            self.writer.write("(");
+            self.expression(exp);
+            self.writer.write(")");
+        } else {
+            self.expression(exp);
+        }
+    }
+
+    fn arguments(&mut self, arguments: &[Rc<Expression>]) {
+        for (i, argument) in arguments.iter().enumerate() {
+            if i != 0 {
+                self.writer.write(", ");
+            }
+            self.list_item(argument);
+        }
+    }
+
+    fn expression(&mut self, exp: &Rc<Expression>) {
+        self.writer.map(&exp.location());
+        if let Some(symbol) = self.symbol_of(exp) {
+            if symbol.is_conversion_value() {
+                self.conversion(exp, &symbol);
+                return;
+            }
+            let constant = ConstantEvaluator(&self.generator.host).constant_of(&symbol);
+            if let Some(constant) = constant.filter(|_| !matches!(exp.as_ref(), Expression::NumericLiteral(_) | Expression::NullLiteral(_) | Expression::BooleanLiteral(_))) {
+                let code = self.constant(&constant);
+                self.writer.write(&code);
+                return;
+            }
+            if symbol.is_embed_value() {
+                if let Some(string) = symbol.embedded_string() {
+                    self.writer.write(&string_code(&string));
+                } else if let Some(bytes) = symbol.embedded_byte_array() {
+                    self.writer.write(&format!("new Uint8Array([{}])", bytes.iter().map(|b| b.to_string()).collect::<Vec<_>>().join(", ")));
+                }
+                return;
+            }
+        }
+        self.expression_1(exp);
+    }
+
+    fn constant(&mut self, constant: &Symbol) -> String {
+        if constant.is_undefined_constant() {
+            "undefined".into()
+        } else if constant.is_null_constant() {
+            "null".into()
+        } else if constant.is_string_constant() {
+            string_code(&constant.string_value())
+        } else if constant.is_char_constant() {
+            (constant.char_value() as u32).to_string()
+        } else if constant.is_boolean_constant() {
+            constant.boolean_value().to_string()
+        } else {
+            number_code(&constant.number_value())
+        }
+    }
+
+    /// Emits the conversion of a value, whose symbol is a `ConversionValue`.
+    fn conversion(&mut self, exp: &Rc<Expression>, conversion: &Symbol) {
+        let host = self.generator.host.clone();
+        let explicit_operand = match exp.as_ref() {
+            Expression::Call(CallExpression { base, arguments, .. }) if arguments.len() == 1 && self.symbol_of(base).is_some_and(|s| s.is_type() || s.is_type_as_reference_value()) => {
+                Some(arguments[0].clone())
+            },
+            Expression::Binary(BinaryExpression { operator: Operator::As, left, .. }) if !conversion.base().is_conversion_value() => {
+                Some(left.clone())
+            },
+            _ => None,
+        };
+        let explicit = explicit_operand.is_some();
+        let target = conversion.conversion_target().non_null_type();
+        let optional = conversion.conversion_is_optional();
+
+        type R = TypeConversionRelationship;
+        let (prefix, suffix) = match conversion.conversion_relationship() {
+            R::BetweenNumberTypes => {
+                let f = if target == host.single_type() {
+                    "toSingle"
+                } else if target == host.long_type() {
+                    "toLong"
+                } else if target == host.big_int_type() {
+                    "toBigInt"
+                } else {
+                    "toNumber"
+                };
+                (format!("{}(", self.runtime(f)), ")".to_owned())
+            },
+            R::FromAny if !explicit => (String::new(), String::new()),
+            R::FromAny |
+            R::FromNonNullableToContravariantType |
+            R::FromNullableToNonNullableContravariantType |
+            R::ArrayToContravariantArray |
+            R::FromTypeParameter => {
+                let type_code = self.type_symbol_reference(&target);
+                (format!("{}(", self.runtime(if optional { "as" } else { "cast" })), format!(", {})", type_code))
+            },
+            R::ThroughToProxy => {
+                let from_type = conversion.base().static_type(&host).non_null_type();
+                let index = from_type.list_of_to_proxies(&host).iter().position(|proxy| {
+                    let signature = proxy.signature(&host);
+                    !signature.is_unresolved() && signature.result_type().is_equals_or_subtype_of(&conversion.conversion_target(), &host)
+                });
+                let native = index.and_then(|i| from_type.list_of_to_proxies(&host).get(i)).is_none_or(|proxy| proxy.is_native());
+                if native {
+                    (String::new(), String::new())
+                } else if from_type.is_enum_type() {
+                    let enum_code = self.type_symbol_reference(&from_type);
+                    (format!("{}.prototype.$proxy$to${}.call(", enum_code, index.unwrap()), ")".to_owned())
+                } else {
+                    ("(".to_owned(), format!(").$proxy$to${}()", index.unwrap()))
+                }
+            },
+            R::FromStringToEnum | R::FromNumberToEnum => {
+                let enum_code = self.type_symbol_reference(&target);
+                let f = if conversion.conversion_relationship() == R::FromStringToEnum { "enumFromString" } else { "enumFromNumber" };
+                (format!("{}({}, ", self.runtime(f), enum_code), format!(", {})", optional))
+            },
+            R::FromStringToChar => (format!("{}(", self.runtime("charFromString")), format!(", {})", optional)),
+            R::FromCharToString => (format!("{}(", self.runtime("charToString")), ")".to_owned()),
+            R::FromNumberToChar => (format!("{}(", self.runtime("charFromNumber")), ")".to_owned()),
+            R::FromMapToLiteralClass => {
+                let class_code = self.type_symbol_reference(&target);
+                (format!("{}(", self.runtime("fromMap")), format!(", {})", class_code))
+            },
+            R::FromLiteralClassToMap => (format!("{}(", self.runtime("toMap")), ")".to_owned()),
+            _ => (String::new(), String::new()),
+        };
+
+        self.writer.write(&prefix);
+        if let Some(operand) = explicit_operand {
+            if prefix.is_empty() {
+                self.writer.write("(");
+                self.expression(&operand);
+                self.writer.write(")");
+            } else {
+                self.list_item(&operand);
+            }
+        } else if prefix.is_empty() {
+            self.expression_1(exp);
+        } else {
+            self.writer.write("(");
+            self.expression_1(exp);
+            self.writer.write(")");
+        }
+        self.writer.write(&suffix);
+    }
+
+    /// Returns the enumeration code if an expression is an enumeration
+    /// value, in which case its instance members are accessed through
+    /// the prototype of the enumeration.
+    fn enum_receiver(&mut self, exp: &Rc<Expression>) -> Option<String> {
+        if let Expression::ThisLiteral(_) = exp.as_ref() {
+            if let Some(enum_code) = &self.enum_this {
+                return Some(enum_code.clone());
+            }
+        }
+        let static_type = self.static_type_of(exp)?;
+        if static_type.is_enum_type() {
+            Some(self.type_symbol_reference(&static_type))
+        } else {
+            None
+        }
+    }
+
+    /// Finds a proxy defined by the static type of an expression, excluding
+    /// native proxies, which are implemented by the runtime.
+    fn find_proxy(&self, exp: &Rc<Expression>, kind: ProxyKind) -> Option<Symbol> {
+        let static_type = self.static_type_of(exp)?;
+        if !(static_type.is_class_type() || static_type.is_enum_type()) {
+            return None;
+        }
+        let proxy = static_type.find_proxy(kind, &self.generator.host).ok()??;
+        if proxy.is_native() { None } else { Some(proxy) }
+    }
+
+    /// Emits the invocation of a proxy on operands. The first operand is
+    /// the receiver unless the proxy takes as many parameters as operands.
+    fn proxy_call(&mut self, proxy: &Symbol, kind: ProxyKind, operands: &[&Rc<Expression>]) {
+        let host = self.generator.host.clone();
+        let method = format!("$proxy${}", proxy_kind_name(kind));
+        let owner = proxy.parent_definition().unwrap();
+        let owner_code = self.type_symbol_reference(&owner);
+        let is_static = proxy.signature(&host).parameters().length() == operands.len();
+        if is_static {
+            self.writer.write(&format!("{}.{}(", owner_code, method));
+            for (i, operand) in operands.iter().enumerate() {
+                if i != 0 {
+                    self.writer.write(", ");
+                }
+                self.list_item(operand);
+            }
+        } else if owner.is_enum_type() {
+            self.writer.write(&format!("{}.prototype.{}.call(", owner_code, method));
+            for (i, operand) in operands.iter().enumerate() {
+                if i != 0 {
+                    self.writer.write(", ");
+                }
+                self.list_item(operand);
+            }
+        } else {
+            self.writer.write("(");
+            self.expression(operands[0]);
+            self.writer.write(&format!(").{}(", method));
+            for (i, operand) in operands[1..].iter().enumerate() {
+                if i != 0 {
+                    self.writer.write(", ");
+                }
+                self.list_item(operand);
+            }
+        }
+        self.writer.write(")");
+    }
+
+    fn is_proxy_reference(&self, exp: &Rc<Expression>) -> bool {
+        self.symbol_of(exp).is_some_and(|s| s.is_proxy_reference_value())
+    }
+
+    /// Emits a computed member whose symbol is a `ProxyReferenceValue`
+    /// using the given proxy, or a runtime function for native proxies.
+    fn proxy_property(&mut self, base: &Rc<Expression>, kind: ProxyKind, operands: &[&Rc<Expression>]) {
+        let proxy = self.find_proxy(base, kind);
+        let mut all = vec![base];
+        all.extend_from_slice(operands);
+        if let Some(proxy) = proxy {
+            self.proxy_call(&proxy, kind, &all);
+        } else {
+            let f = self.runtime(proxy_kind_name(kind));
+            self.writer.write(&format!("{}(", f));
+            for (i, operand) in all.iter().enumerate() {
+                if i != 0 {
+                    self.writer.write(", ");
+                }
+                self.list_item(operand);
+            }
+            self.writer.write(")");
+        }
+    }
+
+    fn expression_1(&mut self, exp: &Rc<Expression>) {
+        match exp.as_ref() {
+            Expression::QualifiedIdentifier(id) => self.qualified_identifier(id),
+            Expression::Embed(_) | Expression::Invalid(_) => {
+                self.writer.write("undefined");
+            },
+            Expression::Paren(ParenExpression { expression, .. }) => {
+                self.writer.write("(");
+                self.expression(expression);
+                self.writer.write(")");
+            },
+            Expression::NullLiteral(_) => self.writer.write("null"),
+            Expression::BooleanLiteral(BooleanLiteral { value, .. }) => self.writer.write(&value.to_string()),
+            Expression::NumericLiteral(literal) => self.numeric_literal(exp, literal),
+            Expression::StringLiteral(StringLiteral { value, .. }) => self.writer.write(&string_code(value)),
+            Expression::ThisLiteral(_) => self.writer.write("this"),
+            Expression::RegExpLiteral(RegExpLiteral { body, flags, .. }) => {
+                self.writer.write(&format!("/{}/{}", body, flags));
+            },
+            Expression::Xml(XmlExpression { element, .. }) => {
+                let f = self.runtime("xml");
+                self.writer.write(&format!("{}(`", f));
+                self.xml_element(element);
+                self.writer.write("`)");
+            },
+            Expression::XmlMarkup(XmlMarkupExpression { markup, .. }) => {
+                let f = self.runtime("xml");
+                self.writer.write(&format!("{}(`{}`)", f, template_text(markup)));
+            },
+            Expression::XmlList(XmlListExpression { content, .. }) => {
+                let f = self.runtime("xmlList");
+                self.writer.write(&format!("{}(`", f));
+                for content in content {
+                    self.xml_content(content);
+                }
+                self.writer.write("`)");
+            },
+            Expression::ArrayLiteral(literal) => self.array_literal(exp, literal),
+            Expression::ObjectInitializer(initializer) => self.object_initializer(exp, initializer),
+            Expression::Function(function) => self.function_expression(function),
+            Expression::ImportMeta(_) => self.writer.write("import.meta"),
+            Expression::New(NewExpression { base, arguments, .. }) => {
+                self.writer.write("new ");
+                let base = if let Expression::WithTypeArguments(ExpressionWithTypeArguments { base, .. }) = base.as_ref() { base } else { base };
+                let wrap = !matches!(base.as_ref(), Expression::QualifiedIdentifier(_) | Expression::Member(_) | Expression::Paren(_));
+                if wrap {
+                    self.writer.write("(");
+                }
+                self.expression(base);
+                if wrap {
+                    self.writer.write(")");
+                }
+                self.writer.write("(");
+                self.arguments(arguments.as_deref().unwrap_or(&[]));
+                self.writer.write(")");
+            },
+            Expression::Member(member) => self.member_expression(member),
+            Expression::ComputedMember(ComputedMemberExpression { base, key, .. }) => {
+                if self.is_proxy_reference(exp) {
+                    self.proxy_property(base, ProxyKind::GetProperty, &[key]);
+                    return;
+                }
+                self.member_base(base);
+                self.writer.write(if self.is_optional_chaining_placeholder(base) { "?.[" } else { "[" });
+                self.list_item(key);
+                self.writer.write("]");
+            },
+            Expression::Descendants(DescendantsExpression { base, identifier, .. }) => {
+                let f = self.runtime("descendants");
+                self.writer.write(&format!("{}(", f));
+                self.expression(base);
+                self.writer.write(", ");
+                self.identifier_key(identifier);
+                self.writer.write(")");
+            },
+            Expression::Filter(FilterExpression { base, test, .. }) => {
+                let f = self.runtime("filter");
+                let element = self.temporary();
+                self.writer.write(&format!("{}(", f));
+                self.expression(base);
+                self.writer.write(&format!(", {} => ", element));
+                self.frames.push(JsFrame { dynamic_object: Some((element, true)), ..default() });
+                self.list_item(test);
+                self.frames.pop();
+                self.writer.write(")");
+            },
+            Expression::Super(_) => self.writer.write("super"),
+            Expression::Call(call) => self.call_expression(call),
+            Expression::WithTypeArguments(ExpressionWithTypeArguments { base, .. }) => self.expression(base),
+            Expression::Unary(unary) => self.unary_expression(unary),
+            Expression::OptionalChaining(OptionalChainingExpression { base, expression, .. }) => {
+                self.optional_chaining_bases.push(base.clone());
+                self.expression(expression);
+                self.optional_chaining_bases.pop();
+            },
+            Expression::OptionalChainingPlaceholder(_) => {
+                let base = self.optional_chaining_bases.pop().unwrap();
+                self.member_base(&base);
+                self.optional_chaining_bases.push(base);
+            },
+            Expression::Binary(binary) => self.binary_expression(binary),
+            Expression::Conditional(ConditionalExpression { test, consequent, alternative, .. }) => {
+                self.expression(test);
+                self.writer.write(" ? ");
+                self.list_item(consequent);
+                self.writer.write(" : ");
+                self.list_item(alternative);
+            },
+            Expression::Assignment(assignment) => self.assignment_expression(assignment),
+            Expression::Sequence(SequenceExpression { left, right, .. }) => {
+                self.expression(left);
+                self.writer.write(", ");
+                self.expression(right);
+            },
+            Expression::NullableType(_) |
+            Expression::NonNullableType(_) |
+            Expression::AnyType(_) |
+            Expression::VoidType(_) |
+            Expression::ArrayType(_) |
+            Expression::TupleType(_) |
+            Expression::FunctionType(_) => {
+                let code = self.type_reference(exp);
+                self.writer.write(&code);
+            },
+        }
+    }
+
+    fn is_optional_chaining_placeholder(&self, exp: &Rc<Expression>) -> bool {
+        matches!(exp.as_ref(), Expression::OptionalChainingPlaceholder(_))
+    }
+
+    /// Emits the base of a property operation, parenthesizing
+    /// numeric literals.
+    fn member_base(&mut self, base: &Rc<Expression>) {
+        if let Expression::NumericLiteral(_) = base.as_ref() {
+            self.writer.write("(");
+            self.expression(base);
+            self.writer.write(")");
+        } else {
+            self.expression(base);
+        }
+    }
+
+    fn numeric_literal(&mut self, exp: &Rc<Expression>, literal: &NumericLiteral) {
+        let text = literal.value.replace('_', "");
+        let value = self.symbol_of(exp).filter(|s| s.is_number_constant() || s.is_enum_constant()).map(|s| s.number_value());
+        match value {
+            Some(AbstractRangeNumber::Long(_) | AbstractRangeNumber::BigInt(_)) => {
+                self.writer.write(&format!("{}n", text));
+            },
+            Some(AbstractRangeNumber::Single(_)) => {
+                self.writer.write(&format!("Math.fround({})", text));
+            },
+            _ => self.writer.write(&text),
+        }
+    }
+
+    fn qualified_identifier(&mut self, id: &QualifiedIdentifier) {
+        if id.attribute {
+            let object = self.frames.iter().rev().find_map(|frame| frame.dynamic_object.clone().filter(|(_, filter)| *filter));
+            let f = self.runtime("attribute");
+            self.writer.write(&format!("{}({}, ", f, object.map_or("this".to_owned(), |(object, _)| object)));
+            self.identifier_key(id);
+            self.writer.write(")");
+            return;
+        }
+        match &id.id {
+            QualifiedIdentifierIdentifier::Id((name, _)) => {
+                let code = self.resolve_identifier(name);
+                self.writer.write(&code);
+            },
+            QualifiedIdentifierIdentifier::Brackets(exp) => {
+                self.writer.write("this[");
+                self.list_item(exp);
+                self.writer.write("]");
+            },
+        }
+    }
+
+    /// Emits the name of a qualified identifier as a string.
+    /// Namespace qualifiers are not represented at runtime.
+    fn identifier_key(&mut self, id: &QualifiedIdentifier) {
+        match &id.id {
+            QualifiedIdentifierIdentifier::Id((name, _)) => self.writer.write(&string_code(name)),
+            QualifiedIdentifierIdentifier::Brackets(exp) => self.list_item(exp),
+        }
+    }
+
+    fn member_expression(&mut self, member: &MemberExpression) {
+        let MemberExpression { base, identifier, .. } = member;
+
+        if let Some(code) = self.package_member(member) {
+            self.writer.write(&code);
+            return;
+        }
+
+        // import.meta.output and import.meta.env
+        if let (Expression::ImportMeta(_), Some((name, _))) = (base.as_ref(), identifier.to_identifier_name()) {
+            if name == "output" {
+                let depth = self.file_names[self.module].matches('/').count();
+                let f = self.runtime("outputDirectory");
+                self.writer.write(&format!("{}(import.meta.url, {})", f, depth));
+                return;
+            }
+            if name == "env" {
+                let code = self.runtime("env");
+                self.writer.write(&code);
+                return;
+            }
+        }
+
+        if identifier.attribute {
+            let f = self.runtime("attribute");
+            self.writer.write(&format!("{}(", f));
+            self.expression(base);
+            self.writer.write(", ");
+            self.identifier_key(identifier);
+            self.writer.write(")");
+            return;
+        }
+
+        if let QualifiedIdentifierIdentifier::Id((name, _)) = &identifier.id {
+            if name == "*" {
+                let f = self.runtime("children");
+                self.writer.write(&format!("{}(", f));
+                self.expression(base);
+                self.writer.write(")");
+                return;
+            }
+            if let Some(enum_code) = self.enum_receiver(base) {
+                self.writer.write(&format!("Reflect.get({}.prototype, {}, ", enum_code, string_code(name)));
+                self.expression(base);
+                self.writer.write(")");
+                return;
+            }
+        }
+
+        self.member_base(base);
+        let optional = self.is_optional_chaining_placeholder(base);
+        match &identifier.id {
+            QualifiedIdentifierIdentifier::Id((name, _)) => {
+                self.writer.write(if optional { "?." } else { "." });
+                self.writer.write(name);
+            },
+            QualifiedIdentifierIdentifier::Brackets(key) => {
+                self.writer.write(if optional { "?.[" } else { "[" });
+                self.list_item(key);
+                self.writer.write("]");
+            },
+        }
+    }
+
+    fn call_expression(&mut self, call: &CallExpression) {
+        let CallExpression { base, arguments, .. } = call;
+
+        // Calls to types without a conversion symbol
+        if let Some(type_name) = base.to_identifier_name().filter(|(name, _)| self.lookup_binding(name).is_none()).map(|(name, _)| name) {
+            let f = match type_name.as_str() {
+                "Single" => Some("toSingle"),
+                "Long" => Some("toLong"),
+                "BigInt" => Some("toBigInt"),
+                _ => None,
+            };
+            if let (Some(f), 1) = (f, arguments.len()) {
+                let f = self.runtime(f);
+                self.writer.write(&format!("{}(", f));
+                self.list_item(&arguments[0]);
+                self.writer.write(")");
+                return;
+            }
+        }
+
+        // Instance methods of enumerations
+        if let Expression::Member(MemberExpression { base: object, identifier, .. }) = base.as_ref() {
+            if let (Some((name, _)), false) = (identifier.to_identifier_name(), identifier.attribute) {
+                if let Some(enum_code) = self.enum_receiver(object) {
+                    self.writer.write(&format!("{}.prototype.{}.call(", enum_code, name));
+                    self.expression(object);
+                    for argument in arguments {
+                        self.writer.write(", ");
+                        self.list_item(argument);
+                    }
+                    self.writer.write(")");
+                    return;
+                }
+            }
+        }
+        if let Some((name, _)) = base.to_identifier_name() {
+            if let Some(JsBinding::EnumInstance(enum_code)) = self.lookup_binding(&name) {
+                self.writer.write(&format!("{}.prototype.{}.call(this", enum_code, name));
+                for argument in arguments {
+                    self.writer.write(", ");
+                    self.list_item(argument);
+                }
+                self.writer.write(")");
+                return;
+            }
+        }
+
+        let wrap = matches!(base.as_ref(), Expression::Function(_) | Expression::Unary(_) | Expression::Binary(_) | Expression::Conditional(_) | Expression::Assignment(_) | Expression::New(_));
+        if wrap {
+            self.writer.write("(");
+        }
+        self.member_base(base);
+        if wrap {
+            self.writer.write(")");
+        }
+        self.writer.write(if self.is_optional_chaining_placeholder(base) { "?.(" } else { "(" });
+        self.arguments(arguments);
+        self.writer.write(")");
+    }
+
+    fn unary_expression(&mut self, unary: &UnaryExpression) {
+        let UnaryExpression { operator, expression, .. } = unary;
+
+        if let Some(kind) = proxy_kind_of_operator(*operator) {
+            if let Some(proxy) = self.find_proxy(expression, kind) {
+                self.proxy_call(&proxy, kind, &[expression]);
+                return;
+            }
+        }
+
+        match operator {
+            Operator::NonNull => self.expression(expression),
+            Operator::Positive => {
+                self.writer.write("(");
+                self.expression(expression);
+                self.writer.write(")");
+            },
+            Operator::Delete => {
+                if let (Expression::ComputedMember(ComputedMemberExpression { base, key, .. }), true) = (expression.as_ref(), self.is_proxy_reference(expression)) {
+                    self.proxy_property(base, ProxyKind::DeleteProperty, &[key]);
+                    return;
+                }
+                self.writer.write("delete ");
+                self.expression(expression);
+            },
+            Operator::PostIncrement | Operator::PostDecrement | Operator::PreIncrement | Operator::PreDecrement
+            if matches!(expression.as_ref(), Expression::ComputedMember(_)) && self.is_proxy_reference(expression) => {
+                let delta = if matches!(operator, Operator::PostIncrement | Operator::PreIncrement) { "+" } else { "-" };
+                let postfix = matches!(operator, Operator::PostIncrement | Operator::PostDecrement);
+                self.proxy_update(expression, |this, value| {
+                    format!("{} {} {}", value, delta, this.one_of(expression))
+                }, postfix);
+            },
+            Operator::PostIncrement => {
+                self.expression(expression);
+                self.writer.write("++");
+            },
+            Operator::PostDecrement => {
+                self.expression(expression);
+                self.writer.write("--");
+            },
+            _ => {
+                let prefix = match operator {
+                    Operator::Void => "void ",
+                    Operator::Typeof => "typeof ",
+                    Operator::Await => "await ",
+                    Operator::Yield => "yield ",
+                    Operator::PreIncrement => "++",
+                    Operator::PreDecrement => "--",
+                    Operator::Negative => "-",
+                    Operator::BitwiseNot => "~",
+                    Operator::LogicalNot => "!",
+                    _ => unreachable!("{operator:?} is not an unary operator"),
+                };
+                self.writer.write(prefix);
+                let wrap = matches!(expression.as_ref(), Expression::Unary(UnaryExpression { operator: Operator::Negative | Operator::Positive | Operator::PreIncrement | Operator::PreDecrement, .. }));
+                if wrap {
+                    self.writer.write("(");
+                }
+                self.expression(expression);
+                if wrap {
+                    self.writer.write(")");
+                }
+            },
+        }
+    }
+
+    /// Returns the code of the number one for the static type of
+    /// an expression.
+    fn one_of(&self, exp: &Rc<Expression>) -> String {
+        let host = &self.generator.host;
+        match self.static_type_of(exp) {
+            Some(t) if t == host.long_type() || t == host.big_int_type() => "1n".into(),
+            _ => "1".into(),
+        }
+    }
+
+    /// Emits an update of a computed member whose symbol is a
+    /// `ProxyReferenceValue`, evaluating its base and key once.
+    fn proxy_update(&mut self, target: &Rc<Expression>, new_value: impl FnOnce(&mut Self, &str) -> String, postfix: bool) {
+        let Expression::ComputedMember(ComputedMemberExpression { base, key, .. }) = target.as_ref() else {
+            unreachable!("proxy updates are only emitted for computed members");
+        };
+        let object = self.temporary();
+        let key_code = self.temporary();
+        let value = self.temporary();
+        let get = self.proxy_method_code(base, ProxyKind::GetProperty, &object, &[&key_code]);
+        let new_value_code = new_value(self, &value);
+        let result = self.temporary();
+        let set = self.proxy_method_code(base, ProxyKind::SetProperty, &object, &[&key_code, &result]);
+        self.writer.write(&format!("(({}, {}) => {{ const {} = {}; const {} = {}; {}; return {}; }})(", object, key_code, value, get, result, new_value_code, set, if postfix { &value } else { &result }));
+        self.expression(base);
+        self.writer.write(", ");
+        self.list_item(key);
+        self.writer.write(")");
+    }
+
+    /// Returns code invoking a property proxy on an object
+    /// that has already been evaluated.
+    fn proxy_method_code(&mut self, base: &Rc<Expression>, kind: ProxyKind, object: &str, arguments: &[&str]) -> String {
+        let arguments = arguments.join(", ");
+        if let Some(proxy) = self.find_proxy(base, kind) {
+            let owner = proxy.parent_definition().unwrap();
+            let owner_code = self.type_symbol_reference(&owner);
+            let method = format!("$proxy${}", proxy_kind_name(kind));
+            if owner.is_enum_type() {
+                format!("{}.prototype.{}.call({}, {})", owner_code, method, object, arguments)
+            } else {
+                format!("{}.{}({})", object, method, arguments)
+            }
+        } else {
+            format!("{}({}, {})", self.runtime(proxy_kind_name(kind)), object, arguments)
+        }
+    }
+
+    fn binary_expression(&mut self, binary: &BinaryExpression) {
+        let BinaryExpression { operator, left, right, .. } = binary;
+
+        if let Some(kind) = proxy_kind_of_operator(*operator) {
+            if let Some(proxy) = self.find_proxy(left, kind) {
+                self.proxy_call(&proxy, kind, &[left, right]);
+                return;
+            }
+        }
+        if matches!(operator, Operator::In | Operator::NotIn) {
+            let proxy = self.find_proxy(right, ProxyKind::Has);
+            let native = proxy.is_none() && self.static_type_of(right).is_some_and(|t| t.find_proxy(ProxyKind::Has, &self.generator.host).ok().flatten().is_some());
+            if proxy.is_some() || native {
+                if *operator == Operator::NotIn {
+                    self.writer.write("!");
+                }
+                if let Some(proxy) = proxy {
+                    self.proxy_call(&proxy, ProxyKind::Has, &[right, left]);
+                } else {
+                    let f = self.runtime("has");
+                    self.writer.write(&format!("{}(", f));
+                    self.list_item(right);
+                    self.writer.write(", ");
+                    self.list_item(left);
+                    self.writer.write(")");
+                }
+                return;
+            }
+        }
+
+        match operator {
+            Operator::Is | Operator::IsNot | Operator::As => {
+                if *operator == Operator::IsNot {
+                    self.writer.write("!");
+                }
+                let f = self.runtime(if *operator == Operator::As { "as" } else { "is" });
+                self.writer.write(&format!("{}(", f));
+                self.list_item(left);
+                let type_code = self.type_reference(right);
+                self.writer.write(&format!(", {})", type_code));
+            },
+            Operator::NotIn => {
+                self.writer.write("!(");
+                self.expression(left);
+                self.writer.write(" in ");
+                self.expression(right);
+                self.writer.write(")");
+            },
+            Operator::LogicalXor => {
+                self.writer.write("(!(");
+                self.expression(left);
+                self.writer.write(") !== !(");
+                self.expression(right);
+                self.writer.write("))");
+            },
+            _ => {
+                // Nullish coalescing cannot be mixed with logical operators
+                // without parentheses; the left operand of exponentiation
+                // cannot be an unary expression.
+                let wrap = |exp: &Rc<Expression>| -> bool {
+                    match exp.as_ref() {
+                        Expression::Binary(BinaryExpression { operator: operator_1, .. }) => {
+                            (*operator == Operator::NullCoalescing && matches!(operator_1, Operator::LogicalAnd | Operator::LogicalOr))
+                            || (matches!(operator, Operator::LogicalAnd | Operator::LogicalOr) && *operator_1 == Operator::NullCoalescing)
+                        },
+                        Expression::Unary(_) => *operator == Operator::Power,
+                        _ => false,
+                    }
+                };
+                let wrap_left = wrap(left);
+                let wrap_right = wrap(right) && *operator != Operator::Power;
+                if wrap_left {
+                    self.writer.write("(");
+                }
+                self.expression(left);
+                if wrap_left {
+                    self.writer.write(")");
+                }
+                self.writer.write(&format!(" {} ", binary_operator_code(*operator)));
+                if wrap_right {
+                    self.writer.write("(");
+                }
+                self.expression(right);
+                if wrap_right {
+                    self.writer.write(")");
+                }
+            },
+        }
+    }
+
+    fn assignment_expression(&mut self, assignment: &AssignmentExpression) {
+        let AssignmentExpression { compound, left, right, .. } = assignment;
+
+        if self.is_proxy_reference(left) {
+            if let Expression::ComputedMember(ComputedMemberExpression { base, key, .. }) = left.as_ref() {
+                if let Some(compound) = compound {
+                    let right = right.clone();
+                    let compound = *compound;
+                    self.proxy_update(left, move |this, value| {
+                        let right_code = this.capture(|this| this.expression(&right));
+                        if compound == Operator::LogicalXor {
+                            format!("!({}) !== !({})", value, right_code)
+                        } else {
+                            format!("{} {} ({})", value, binary_operator_code(compound), right_code)
+                        }
+                    }, false);
+                } else {
+                    self.proxy_property(base, ProxyKind::SetProperty, &[key, right]);
+                }
+                return;
+            }
+        }
+
+        match compound {
+            None => {
+                self.pattern(left);
+                self.writer.write(" = ");
+                self.list_item(right);
+            },
+            // Logical assignments are not part of ES2020.
+            Some(Operator::LogicalAnd | Operator::LogicalOr | Operator::NullCoalescing) => {
+                self.expression(left);
+                self.writer.write(&format!(" {} (", binary_operator_code(compound.unwrap())));
+                self.expression(left);
+                self.writer.write(" = ");
+                self.list_item(right);
+                self.writer.write(")");
+            },
+            Some(Operator::LogicalXor) => {
+                self.expression(left);
+                self.writer.write(" = (!(");
+                self.expression(left);
+                self.writer.write(") !== !(");
+                self.expression(right);
+                self.writer.write("))");
+            },
+            Some(operator) => {
+                self.expression(left);
+                self.writer.write(&format!(" {}= ", binary_operator_code(*operator)));
+                self.list_item(right);
+            },
+        }
+    }
+
+    /// Emits a destructuring pattern or an assignment target.
+    fn pattern(&mut self, exp: &Rc<Expression>) {
+        self.writer.map(&exp.location());
+        match exp.as_ref() {
+            Expression::Unary(UnaryExpression { operator: Operator::NonNull, expression, .. }) => {
+                self.pattern(expression);
+            },
+            Expression::ArrayLiteral(ArrayLiteral { elements, .. }) => {
+                self.writer.write("[");
+                for (i, element) in elements.iter().enumerate() {
+                    if i != 0 {
+                        self.writer.write(", ");
+                    }
+                    match element {
+                        Element::Elision => {},
+                        Element::Expression(exp) => self.pattern(exp),
+                        Element::Rest((exp, _)) => {
+                            self.writer.write("...");
+                            self.pattern(exp);
+                        },
+                    }
+                }
+                if matches!(elements.last(), Some(Element::Elision)) {
+                    self.writer.write(",");
+                }
+                self.writer.write("]");
+            },
+            Expression::ObjectInitializer(ObjectInitializer { fields, .. }) => {
+                self.writer.write("{ ");
+                for (i, field) in fields.iter().enumerate() {
+                    if i != 0 {
+                        self.writer.write(", ");
+                    }
+                    match field.as_ref() {
+                        InitializerField::Field { name, value, .. } => {
+                            self.field_name(&name.0);
+                            if let Some(value) = value {
+                                self.writer.write(": ");
+                                self.pattern(value);
+                            }
+                        },
+                        InitializerField::Rest((exp, _)) => {
+                            self.writer.write("...");
+                            self.pattern(exp);
+                        },
+                    }
+                }
+                self.writer.write(" }");
+            },
+            Expression::Assignment(AssignmentExpression { left, right, compound: None, .. }) => {
+                self.pattern(left);
+                self.writer.write(" = ");
+                self.list_item(right);
+            },
+            _ => self.expression(exp),
+        }
+    }
+
+    fn field_name(&mut self, name: &FieldName) {
+        match name {
+            FieldName::Identifier(name) => self.writer.write(name),
+            FieldName::Brackets(exp) => {
+                self.writer.write("[");
+                self.list_item(exp);
+                self.writer.write("]");
+            },
+            FieldName::StringLiteral(exp) | FieldName::NumericLiteral(exp) => self.expression_1(exp),
+        }
+    }
+
+    /// Emits a field name as a Map key.
+    fn field_key(&mut self, name: &FieldName) {
+        match name {
+            FieldName::Identifier(name) => self.writer.write(&string_code(name)),
+            FieldName::Brackets(exp) => self.list_item(exp),
+            FieldName::StringLiteral(exp) | FieldName::NumericLiteral(exp) => self.expression(exp),
+        }
+    }
+
+    fn array_literal(&mut self, exp: &Rc<Expression>, literal: &ArrayLiteral) {
+        let set_enum = self.static_type_of(exp).filter(|t| t.is_enum_type() && t.is_set_enumeration());
+        if let Some(set_enum) = &set_enum {
+            let f = self.runtime("enumFromArray");
+            let enum_code = self.type_symbol_reference(set_enum);
+            self.writer.write(&format!("{}({}, ", f, enum_code));
+        }
+        self.writer.write("[");
+        for (i, element) in literal.elements.iter().enumerate() {
+            if i != 0 {
+                self.writer.write(", ");
+            }
+            match element {
+                Element::Elision => {},
+                Element::Expression(exp) => self.list_item(exp),
+                Element::Rest((exp, _)) => {
+                    self.writer.write("...");
+                    self.list_item(exp);
+                },
+            }
+        }
+        if matches!(literal.elements.last(), Some(Element::Elision)) {
+            self.writer.write(",");
+        }
+        self.writer.write("]");
+        if set_enum.is_some() {
+            self.writer.write(")");
+        }
+    }
+
+    fn object_initializer(&mut self, exp: &Rc<Expression>, initializer: &ObjectInitializer) {
+        let host = self.generator.host.clone();
+        let static_type = self.static_type_of(exp);
+
+        // Map
+        if static_type.as_ref().is_some_and(|t| t.type_after_substitution_has_origin(&host.map_type())) {
+            self.writer.write("new Map([");
+            for (i, field) in initializer.fields.iter().enumerate() {
+                if i != 0 {
+                    self.writer.write(", ");
+                }
+                match field.as_ref() {
+                    InitializerField::Field { name, value, .. } => {
+                        self.writer.write("[");
+                        self.field_key(&name.0);
+                        self.writer.write(", ");
+                        if let Some(value) = value {
+                            self.list_item(value);
+                        } else if let FieldName::Identifier(name) = &name.0 {
+                            let code = self.resolve_identifier(name);
+                            self.writer.write(&code);
+                        }
+                        self.writer.write("]");
+                    },
+                    InitializerField::Rest((exp, _)) => {
+                        self.writer.write("...");
+                        self.list_item(exp);
+                    },
+                }
+            }
+            self.writer.write("])");
+            return;
+        }
+
+        let mut suffix = String::new();
+        if let Some(static_type) = &static_type {
+            if static_type.is_enum_type() && static_type.is_set_enumeration() {
+                let f = self.runtime("enumFromFlags");
+                let enum_code = self.type_symbol_reference(static_type);
+                self.writer.write(&format!("{}({}, ", f, enum_code));
+                suffix = ")".into();
+            } else if static_type.is_class_type() && static_type.allow_literal() {
+                let class_code = self.type_symbol_reference(static_type);
+                self.writer.write(&format!("Object.assign(new {}(), ", class_code));
+                suffix = ")".into();
+            }
+        }
+
+        if initializer.fields.is_empty() {
+            self.writer.write("{}");
+        } else {
+            self.writer.write("{ ");
+            for (i, field) in initializer.fields.iter().enumerate() {
+                if i != 0 {
+                    self.writer.write(", ");
+                }
+                match field.as_ref() {
+                    InitializerField::Field { name, value, .. } => {
+                        self.field_name(&name.0);
+                        self.writer.write(": ");
+                        if let Some(value) = value {
+                            self.list_item(value);
+                        } else if let FieldName::Identifier(name) = &name.0 {
+                            let code = self.resolve_identifier(name);
+                            self.writer.write(&code);
+                        }
+                    },
+                    InitializerField::Rest((exp, _)) => {
+                        self.writer.write("...");
+                        self.list_item(exp);
+                    },
+                }
+            }
+            self.writer.write(" }");
+        }
+        self.writer.write(&suffix);
+    }
+
+    // XML
+
+    fn xml_element(&mut self, element: &XmlElement) {
+        self.writer.write("<");
+        self.xml_tag_name(&element.name);
+        for attribute in &element.attributes {
+            self.writer.write(&format!(" {}=\"", attribute.name.0));
+            match &attribute.value {
+                XmlAttributeValue::Value((value, _)) => self.writer.write(&template_text(value)),
+                XmlAttributeValue::Expression(exp) => self.xml_interpolation("xmlText", exp),
+            }
+            self.writer.write("\"");
+        }
+        if let Some(exp) = &element.attribute_expression {
+            self.writer.write(" ");
+            self.xml_interpolation("xmlAttributes", exp);
+        }
+        if let Some(content) = &element.content {
+            self.writer.write(">");
+            for content in content {
+                self.xml_content(content);
+            }
+            self.writer.write("</");
+            self.xml_tag_name(element.closing_name.as_ref().unwrap_or(&element.name));
+            self.writer.write(">");
+        } else {
+            self.writer.write("/>");
+        }
+    }
+
+    fn xml_tag_name(&mut self, name: &XmlTagName) {
+        match name {
+            XmlTagName::Name((name, _)) => self.writer.write(name),
+            XmlTagName::Expression(exp) => {
+                self.writer.write("${");
+                self.list_item(exp);
+                self.writer.write("}");
+            },
+        }
+    }
+
+    fn xml_content(&mut self, content: &XmlElementContent) {
+        match content {
+            XmlElementContent::XmlText((text, _)) | XmlElementContent::XmlMarkup((text, _)) => {
+                self.writer.write(&template_text(text));
+            },
+            XmlElementContent::XmlElement(element) => self.xml_element(element),
+            XmlElementContent::Expression(exp) => self.xml_interpolation("xmlText", exp),
+        }
+    }
+
+    fn xml_interpolation(&mut self, function_name: &str, exp: &Rc<Expression>) {
+        let f = self.runtime(function_name);
+        self.writer.write(&format!("${{{}(", f));
+        self.list_item(exp);
+        self.writer.write(")}");
+    }
+}
+
+/// Returns the name of a member defined by a directive of a class
+/// or enumeration block and whether it is static.
+fn member_name(drtv: &Rc<Directive>) -> Option<(String, bool)> {
+    match drtv.as_ref() {
+        Directive::VariableDefinition(defn) => {
+            let name = defn.bindings.first()?.destructuring.destructuring.to_identifier_name()?.0;
+            Some((name, Attribute::find_static(&defn.attributes).is_some()))
+        },
+        Directive::FunctionDefinition(defn) => {
+            if defn.is_constructor() || defn.is_proxy() {
+                return None;
+            }
+            Some((defn.name_identifier().0, Attribute::find_static(&defn.attributes).is_some()))
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn generate(sources: &[(&str, &str)]) -> Vec<JsOutput> {
+        let host = SymbolHost::new("");
+        declare_jet_lang_classes(&host, &["Object", "Boolean", "String", "Number", "Long", "Function", "Class"]);
+        let compiler_options = CompilerOptions::new();
+        let trace = CompilationUnit::new(None, "package jet.lang { public final class Array.<T> {} public native function trace(...arguments: [*]): void; }".into(), &compiler_options);
+        let trace = ParserFacade::parse_program(&trace, &host).unwrap();
+        let programs: Vec<Rc<Program>> = sources.iter().map(|(file_path, text)| {
+            let compilation_unit = CompilationUnit::new(Some(file_path.to_string()), text.to_string(), &compiler_options);
+            ParserFacade::parse_program(&compilation_unit, &host).unwrap()
+        }).collect();
+        let mut verifier = Verifier::new(&host);
+        verifier.verify_programs([trace.clone()].into_iter().chain(programs.iter().cloned()).collect());
+        for program in [&trace].into_iter().chain(&programs) {
+            let diagnostics: Vec<String> = program.location.compilation_unit().diagnostics().iter().map(|d| d.format_english()).collect();
+            assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+        }
+        let options = JsGeneratorOptions { source_directory: Some("/src".into()), ..default() };
+        JsGenerator::new(&host, verifier.ast_to_symbol(), options).generate(&programs)
+    }
+
+    #[test]
+    fn modules() {
+        let outputs = generate(&[
+            ("/src/a/A.jet", "package a { public interface I {} public class A implements I { public var x: Number; function f() { return function() (x); } } [Set] public enum E { const FOO; const BAR_QUX; } }"),
+            ("/src/Main.jet", "import a.*; const v = new A(); trace(v?.x, E.BAR_QUX, import.meta.output);"),
+        ]);
+        assert_eq!(outputs[0].file_name, "a/A.js");
+        assert!(outputs[0].code.contains("export const I = $jet.defineInterface(\"a.I\", []);"));
+        assert!(outputs[0].code.contains("        this.x = 0;\n"));
+        assert!(outputs[0].code.contains("return (() => (this.x));"));
+        assert!(outputs[0].code.contains("$jet.implement(A, [I]);"));
+        assert!(outputs[0].code.contains("[\"BAR_QUX\", \"barQux\", 2.0],"));
+        assert!(outputs[0].code.contains("], true);"));
+
+        assert_eq!(outputs[1].file_name, "Main.js");
+        assert!(outputs[1].code.starts_with("import * as $jet from \"./jet.runtime.js\";\nimport { A, E } from \"./a/A.js\";\n"));
+        assert!(outputs[1].code.contains("$jet.trace(v?.x, E.BAR_QUX, $jet.outputDirectory(import.meta.url, 0));"));
+        assert!(outputs[1].code.ends_with("//# sourceMappingURL=Main.js.map\n"));
+        assert!(outputs[1].source_map.contains("\"sources\":[\"/src/Main.jet\"]"));
+        assert!(outputs[1].source_map.contains("\"sourcesContent\":[\"import a.*;"));
+    }
+
+    #[test]
+    fn enums() {
+        let outputs = generate(&[
+            ("/src/Main.jet", "[Set] enum E { const A = 1 << 3; const B; const C = [\"ce\", 64]; }\nenum F as Long { const X = -2; const Y; }\n"),
+        ]);
+        assert!(outputs[0].code.contains("    [\"A\", \"a\", 8.0],\n    [\"B\", \"b\", 16.0],\n    [\"C\", \"ce\", 64.0],\n], true);"));
+        assert!(outputs[0].code.contains("    [\"X\", \"x\", (-2n)],\n    [\"Y\", \"y\", (-1n)],\n], false);"));
+    }
+
+    #[test]
+    fn classes() {
+        let outputs = generate(&[
+            ("/src/a/B.jet", "package a { public class B { public var y: Number = 1; public function B(y: Number) { this.y = y; } public function get z(): Number { return y; } public static function s(): void {} } public class C extends B { public function C() { super(2); } override public function get z(): Number { return super.z + 1; } } }"),
+        ]);
+        assert_eq!(outputs[0].code, concat!(
+            "export class B {\n",
+            "    constructor(y) {\n",
+            "        this.y = 1;\n",
+            "        this.y = y;\n",
+            "    }\n",
+            "    get z() {\n",
+            "        return this.y;\n",
+            "    }\n",
+            "    static s() {\n",
+            "    }\n",
+            "}\n",
+            "export class C extends B {\n",
+            "    constructor() {\n",
+            "        super(2);\n",
+            "    }\n",
+            "    get z() {\n",
+            "        return super.z + 1;\n",
+            "    }\n",
+            "}\n",
+            "//# sourceMappingURL=B.js.map\n",
+        ));
+    }
+
+    #[test]
+    fn closures_and_destructuring() {
+        let outputs = generate(&[
+            ("/src/Main.jet", "function f(list: *, o: *): Number {\n    const [p, q] = list;\n    const { x, y: w } = o;\n    var n = 0;\n    const add = function(k: Number): Number { n += k; return n; };\n    return add(p + q);\n}\n"),
+        ]);
+        assert_eq!(outputs[0].code, concat!(
+            "function f(list, o) {\n",
+            "    const [p, q] = list;\n",
+            "    const { x, y: w } = o;\n",
+            "    let n = 0;\n",
+            "    const add = ((k) => {\n",
+            "        n += k;\n",
+            "        return n;\n",
+            "    });\n",
+            "    return add(p + q);\n",
+            "}\n",
+            "//# sourceMappingURL=Main.js.map\n",
+        ));
+    }
+
+    #[test]
+    fn source_maps() {
+        let outputs = generate(&[
+            ("/src/Main.jet", "\nvar x = 1;\n    trace(x);"),
+        ]);
+        assert_eq!(outputs[0].code, "import * as $jet from \"./jet.runtime.js\";\nlet x = 1;\n$jet.trace(x);\n//# sourceMappingURL=Main.js.map\n");
+        // The runtime import is not mapped; `let x` maps to the second
+        // source line and `$jet.trace` to the fifth column of the third.
+        assert!(outputs[0].source_map.contains("\"file\":\"Main.js\",\"mappings\":\";AACA,IAAI,IAAI;AACJ,WAAM\""));
+    }
+}
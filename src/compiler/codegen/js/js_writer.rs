@@ -0,0 +1,112 @@
+use crate::ns::*;
+
+/// Code buffer used by the JavaScript generator, tracking generated
+/// positions for source mappings.
+pub(crate) struct JsWriter {
+    code: String,
+    indentation: usize,
+    at_line_start: bool,
+    /// Zero based generated line.
+    line: usize,
+    /// Zero based generated column in UTF-16 code units.
+    column: usize,
+    mappings: Vec<JsMapping>,
+}
+
+/// Mapping from a generated position to a source location.
+#[derive(Clone)]
+pub(crate) struct JsMapping {
+    pub generated_line: usize,
+    pub generated_column: usize,
+    pub location: Location,
+}
+
+impl JsWriter {
+    pub fn new() -> Self {
+        Self {
+            code: String::new(),
+            indentation: 0,
+            at_line_start: true,
+            line: 0,
+            column: 0,
+            mappings: vec![],
+        }
+    }
+
+    pub fn write(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        self.write_indentation();
+        for ch in text.chars() {
+            if ch == '\n' {
+                self.line += 1;
+                self.column = 0;
+            } else {
+                self.column += ch.len_utf16();
+            }
+        }
+        self.code.push_str(text);
+    }
+
+    /// Terminates the current line. The indentation of the next line
+    /// is written lazily.
+    pub fn line(&mut self) {
+        self.code.push('\n');
+        self.line += 1;
+        self.column = 0;
+        self.at_line_start = true;
+    }
+
+    pub fn indent(&mut self) {
+        self.indentation += 1;
+    }
+
+    pub fn unindent(&mut self) {
+        self.indentation -= 1;
+    }
+
+    /// Maps the current generated position to a source location.
+    pub fn map(&mut self, location: &Location) {
+        self.write_indentation();
+        if let Some(last) = self.mappings.last() {
+            if last.generated_line == self.line && last.generated_column == self.column {
+                self.mappings.pop();
+            }
+        }
+        self.mappings.push(JsMapping {
+            generated_line: self.line,
+            generated_column: self.column,
+            location: location.clone(),
+        });
+    }
+
+    fn write_indentation(&mut self) {
+        if self.at_line_start {
+            self.at_line_start = false;
+            let indentation = "    ".repeat(self.indentation);
+            self.column += indentation.len();
+            self.code.push_str(&indentation);
+        }
+    }
+
+    /// Moves the contents of `other` to the end of this writer.
+    pub fn append(&mut self, other: JsWriter) {
+        if !other.code.is_empty() && !self.at_line_start {
+            self.line();
+        }
+        let line_offset = self.line;
+        self.code.push_str(&other.code);
+        self.line += other.line;
+        self.column = other.column;
+        self.at_line_start = other.at_line_start;
+        for mut mapping in other.mappings {
+            mapping.generated_line += line_offset;
+            self.mappings.push(mapping);
+        }
+    }
+
+    pub fn into_parts(self) -> (String, Vec<JsMapping>) {
+        (self.code, self.mappings)
+    }
+}
@@ -0,0 +1,319 @@
+// Runtime support for JavaScript modules generated from Jet programs.
+// Generated modules import this module as `$jet`.
+
+const IMPLEMENTS = Symbol("jet.implements");
+const ENUM = Symbol("jet.enum");
+
+function primitive(name, test) {
+    return { name, test };
+}
+
+export const types = {
+    Any: primitive("*", () => true),
+    Boolean: primitive("Boolean", v => typeof v === "boolean"),
+    String: primitive("String", v => typeof v === "string"),
+    Char: primitive("Char", v => Number.isInteger(v) && v >= 0 && v <= 0x10FFFF),
+    Number: primitive("Number", v => typeof v === "number"),
+    Single: primitive("Single", v => typeof v === "number"),
+    Long: primitive("Long", v => typeof v === "bigint" && BigInt.asIntN(64, v) === v),
+    BigInt: primitive("BigInt", v => typeof v === "bigint"),
+    Function: primitive("Function", v => typeof v === "function"),
+};
+
+// Interfaces
+
+export function defineInterface(name, bases) {
+    const itrfc = { name, bases, test: v => implementsInterface(v, itrfc) };
+    return itrfc;
+}
+
+export function implement(type, interfaces) {
+    Object.defineProperty(type, IMPLEMENTS, { value: interfaces });
+}
+
+function implementsInterface(v, itrfc) {
+    if (v === null || v === undefined) {
+        return false;
+    }
+    for (let c = v.constructor; c && c !== Function.prototype; c = Object.getPrototypeOf(c)) {
+        const list = Object.prototype.hasOwnProperty.call(c, IMPLEMENTS) ? c[IMPLEMENTS] : [];
+        if (list.some(i => extendsInterface(i, itrfc))) {
+            return true;
+        }
+    }
+    return false;
+}
+
+function extendsInterface(itrfc, target) {
+    return itrfc === target || itrfc.bases.some(b => extendsInterface(b, target));
+}
+
+// Enumerations
+
+export function defineEnum(type, members, isSet) {
+    const byName = new Map();
+    const byValue = new Map();
+    const zero = members.length !== 0 && typeof members[0][2] === "bigint" ? 0n : 0;
+    let mask = zero;
+    for (const [constant, string, value] of members) {
+        type[constant] = value;
+        byName.set(string, value);
+        byValue.set(value, string);
+        mask |= value;
+    }
+    Object.defineProperty(type, ENUM, { value: { byName, byValue, isSet, mask, zero } });
+}
+
+function isEnumValue(type, v) {
+    const e = type[ENUM];
+    if (typeof v !== typeof e.zero) {
+        return false;
+    }
+    return e.isSet ? (v & ~e.mask) === e.zero : e.byValue.has(v);
+}
+
+function fail(optional, message) {
+    if (optional) {
+        return null;
+    }
+    throw new TypeError(message);
+}
+
+export function enumFromString(type, s, optional) {
+    const e = type[ENUM];
+    if (!e.isSet) {
+        return e.byName.has(s) ? e.byName.get(s) : fail(optional, `Invalid enumeration member: ${s}`);
+    }
+    let v = e.zero;
+    for (const name of s.split(",").map(name => name.trim()).filter(name => name !== "")) {
+        if (!e.byName.has(name)) {
+            return fail(optional, `Invalid enumeration member: ${name}`);
+        }
+        v |= e.byName.get(name);
+    }
+    return v;
+}
+
+export function enumFromNumber(type, n, optional) {
+    return isEnumValue(type, n) ? n : fail(optional, `Invalid enumeration value: ${n}`);
+}
+
+export function enumToString(type, v) {
+    const e = type[ENUM];
+    if (!e.isSet) {
+        return e.byValue.get(v);
+    }
+    return [...e.byValue].filter(([value]) => value !== e.zero && (v & value) === value).map(([, name]) => name).join(",");
+}
+
+export function enumFromFlags(type, flags) {
+    const e = type[ENUM];
+    let v = e.zero;
+    for (const [name, value] of e.byName) {
+        if (flags[name]) {
+            v |= value;
+        }
+    }
+    return v;
+}
+
+export function enumFromArray(type, array) {
+    return array.reduce((v, value) => v | value, type[ENUM].zero);
+}
+
+// Type tests and conversions
+
+export function is(v, type) {
+    if (type === null || type === undefined) {
+        return false;
+    }
+    if (typeof type === "function") {
+        return Object.prototype.hasOwnProperty.call(type, ENUM) ? isEnumValue(type, v) : v instanceof type;
+    }
+    return type.test(v);
+}
+
+export function as(v, type) {
+    return is(v, type) ? v : null;
+}
+
+export function cast(v, type) {
+    if (v === null || v === undefined || is(v, type)) {
+        return v;
+    }
+    throw new TypeError(`Cannot convert value to ${type.name}`);
+}
+
+export function toNumber(v) {
+    return Number(v);
+}
+
+export function toSingle(v) {
+    return Math.fround(Number(v));
+}
+
+export function toBigInt(v) {
+    if (typeof v === "bigint") {
+        return v;
+    }
+    return Number.isFinite(v) ? BigInt(Math.trunc(v)) : 0n;
+}
+
+export function toLong(v) {
+    return BigInt.asIntN(64, toBigInt(v));
+}
+
+export function charFromString(s, optional) {
+    const c = s.codePointAt(0);
+    if (c === undefined || String.fromCodePoint(c) !== s) {
+        return fail(optional, "String must contain exactly one character");
+    }
+    return c;
+}
+
+export function charToString(c) {
+    return String.fromCodePoint(c);
+}
+
+export function charFromNumber(n) {
+    return Math.trunc(Number(n));
+}
+
+export function fromMap(map, type) {
+    return Object.assign(new type(), Object.fromEntries(map));
+}
+
+export function toMap(o) {
+    return new Map(Object.entries(o));
+}
+
+// Iteration
+
+export function keys(o) {
+    if (o !== null && o !== undefined && typeof o.$proxy$keys === "function") {
+        return o.$proxy$keys();
+    }
+    if (Array.isArray(o) || o instanceof Map) {
+        return o.keys();
+    }
+    return Object.keys(o);
+}
+
+export function values(o) {
+    if (o !== null && o !== undefined && typeof o.$proxy$values === "function") {
+        return o.$proxy$values();
+    }
+    if (Array.isArray(o) || o instanceof Map) {
+        return o.values();
+    }
+    return Object.values(o);
+}
+
+// Native proxies
+
+export function has(o, key) {
+    if (o instanceof Map) {
+        return o.has(key);
+    }
+    if (Array.isArray(o)) {
+        return o.includes(key);
+    }
+    return key in o;
+}
+
+export function getProperty(o, key) {
+    return o instanceof Map ? o.get(key) : o[key];
+}
+
+export function setProperty(o, key, value) {
+    if (o instanceof Map) {
+        o.set(key, value);
+    } else {
+        o[key] = value;
+    }
+}
+
+export function deleteProperty(o, key) {
+    return o instanceof Map ? o.delete(key) : delete o[key];
+}
+
+export function trace(...args) {
+    console.log(...args);
+}
+
+// import.meta
+
+export function outputDirectory(moduleUrl, depth) {
+    return new URL("../".repeat(depth) || "./", moduleUrl).href.replace(/\/$/, "");
+}
+
+export const env = typeof process !== "undefined" && process.env ? process.env : {};
+
+// E4X
+
+let defaultXmlNamespace = "";
+
+export function setDefaultXmlNamespace(ns) {
+    defaultXmlNamespace = String(ns);
+}
+
+function parseXml(markup) {
+    if (typeof DOMParser === "undefined") {
+        throw new Error("XML is not supported in this environment");
+    }
+    const xmlns = defaultXmlNamespace === "" ? "" : ` xmlns="${defaultXmlNamespace}"`;
+    const document = new DOMParser().parseFromString(`<jet${xmlns}>${markup}</jet>`, "application/xml");
+    return [...document.documentElement.childNodes];
+}
+
+export function xml(markup) {
+    return parseXml(markup).find(node => node.nodeType === 1);
+}
+
+export function xmlList(markup) {
+    return parseXml(markup);
+}
+
+function list(base) {
+    return Array.isArray(base) ? base : [base];
+}
+
+export function attribute(base, name) {
+    const values = list(base).map(element => element.getAttribute(name)).filter(v => v !== null);
+    return values.length === 1 ? values[0] : values;
+}
+
+export function descendants(base, name) {
+    return list(base).flatMap(element => [...element.getElementsByTagName(name)]);
+}
+
+export function filter(base, test) {
+    return list(base).filter(element => test(element));
+}
+
+export function child(base, name) {
+    return list(base).flatMap(element => [...element.children].filter(child => child.localName === name));
+}
+
+export function children(base) {
+    return list(base).flatMap(element => [...element.children]);
+}
+
+function escapeXml(s, quote) {
+    s = s.replace(/&/g, "&amp;").replace(/</g, "&lt;").replace(/>/g, "&gt;");
+    return quote ? s.replace(/"/g, "&quot;") : s;
+}
+
+export function xmlText(v) {
+    if (typeof Node !== "undefined" && v instanceof Node) {
+        return new XMLSerializer().serializeToString(v);
+    }
+    if (Array.isArray(v)) {
+        return v.map(xmlText).join("");
+    }
+    return escapeXml(String(v), true);
+}
+
+export function xmlAttributes(o) {
+    return Object.entries(o).map(([name, value]) => `${name}="${escapeXml(String(value), true)}"`).join(" ");
+}
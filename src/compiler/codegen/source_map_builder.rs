@@ -0,0 +1,225 @@
+use crate::ns::*;
+use serde_json::json;
+use std::rc::Rc;
+
+const BASE64_DIGITS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Builder of version 3 source maps.
+///
+/// Code generators feed the builder with generated positions and the
+/// source `Location`s they correspond to. Generated lines are counted
+/// from zero and generated columns are counted in UTF-16 code units, as
+/// required by the source map format.
+///
+/// ```ignore
+/// let mut builder = SourceMapBuilder::new(Some("Main.js"));
+/// builder.add_mapping(0, 0, &directive.location());
+/// let json = builder.build();
+/// ```
+///
+/// # Interpolated sources
+///
+/// A compilation unit whose text is prefixed with line breaks and whitespace,
+/// as described in `docs/interpolation.md`, already has line numbers and
+/// columns respective to the file it is interpolated in. Such compilation unit
+/// may be added through `add_interpolated_source()` so that the source map
+/// refers to that file instead.
+pub struct SourceMapBuilder {
+    file: Option<String>,
+    source_root: Option<String>,
+    sources: Vec<SourceMapSource>,
+    names: Vec<String>,
+    mappings: Vec<SourceMapping>,
+}
+
+struct SourceMapSource {
+    compilation_unit: Rc<CompilationUnit>,
+    name: String,
+    content: Option<String>,
+}
+
+#[derive(Copy, Clone)]
+struct SourceMapping {
+    generated_line: usize,
+    generated_column: usize,
+    source: usize,
+    source_line: usize,
+    source_column: usize,
+    name: Option<usize>,
+}
+
+impl SourceMapBuilder {
+    /// Constructs a builder for the given generated file name.
+    pub fn new(file: Option<&str>) -> Self {
+        Self {
+            file: file.map(|file| file.to_owned()),
+            source_root: None,
+            sources: vec![],
+            names: vec![],
+            mappings: vec![],
+        }
+    }
+
+    pub fn set_source_root(&mut self, source_root: Option<&str>) {
+        self.source_root = source_root.map(|root| root.to_owned());
+    }
+
+    /// Adds a compilation unit to the sources, returning its index.
+    /// The source is named after the compilation unit's file path
+    /// and its content is the compilation unit's text.
+    ///
+    /// Compilation units are added implicitly by the mapping methods.
+    pub fn add_source(&mut self, compilation_unit: &Rc<CompilationUnit>) -> usize {
+        if let Some(i) = self.source_index(compilation_unit) {
+            return i;
+        }
+        let name = compilation_unit.file_path().unwrap_or_default().replace('\\', "/");
+        let content = Some(compilation_unit.text().to_string());
+        self.sources.push(SourceMapSource { compilation_unit: compilation_unit.clone(), name, content });
+        self.sources.len() - 1
+    }
+
+    /// Adds a compilation unit interpolated in another file, returning
+    /// its index. `source_content` is the content of that file; if `None`,
+    /// the compilation unit's text, whose prefix preserves the positions of
+    /// the interpolated source, is used.
+    pub fn add_interpolated_source(&mut self, compilation_unit: &Rc<CompilationUnit>, source_name: &str, source_content: Option<String>) -> usize {
+        let content = source_content.or_else(|| Some(compilation_unit.text().to_string()));
+        if let Some(i) = self.source_index(compilation_unit) {
+            self.sources[i].name = source_name.to_owned();
+            self.sources[i].content = content;
+            return i;
+        }
+        self.sources.push(SourceMapSource { compilation_unit: compilation_unit.clone(), name: source_name.to_owned(), content });
+        self.sources.len() - 1
+    }
+
+    fn source_index(&self, compilation_unit: &Rc<CompilationUnit>) -> Option<usize> {
+        self.sources.iter().position(|source| Rc::ptr_eq(&source.compilation_unit, compilation_unit))
+    }
+
+    /// Maps a generated position to the start of a location.
+    pub fn add_mapping(&mut self, generated_line: usize, generated_column: usize, location: &Location) {
+        self.add_offset_mapping(generated_line, generated_column, &location.compilation_unit(), location.first_offset(), None);
+    }
+
+    /// Maps a generated position to the end of a location.
+    pub fn add_end_mapping(&mut self, generated_line: usize, generated_column: usize, location: &Location) {
+        self.add_offset_mapping(generated_line, generated_column, &location.compilation_unit(), location.last_offset(), None);
+    }
+
+    /// Maps a generated position to the start of a location,
+    /// associating the original name of an identifier.
+    pub fn add_named_mapping(&mut self, generated_line: usize, generated_column: usize, location: &Location, name: &str) {
+        self.add_offset_mapping(generated_line, generated_column, &location.compilation_unit(), location.first_offset(), Some(name));
+    }
+
+    /// Maps a generated position to an offset of a compilation unit.
+    pub fn add_offset_mapping(&mut self, generated_line: usize, generated_column: usize, compilation_unit: &Rc<CompilationUnit>, offset: usize, name: Option<&str>) {
+        let source = self.add_source(compilation_unit);
+        let source_line = compilation_unit.get_line_number(offset) - 1;
+        let line_offset = compilation_unit.get_line_offset_from_offset(offset);
+        let source_column = compilation_unit.text()[line_offset..offset].encode_utf16().count();
+        let name = name.map(|name| {
+            if let Some(i) = self.names.iter().position(|name_1| name_1 == name) {
+                i
+            } else {
+                self.names.push(name.to_owned());
+                self.names.len() - 1
+            }
+        });
+        self.mappings.push(SourceMapping { generated_line, generated_column, source, source_line, source_column, name });
+    }
+
+    /// Builds the source map as JSON.
+    pub fn build(&self) -> String {
+        let mut mappings = self.mappings.clone();
+        mappings.sort_by_key(|m| (m.generated_line, m.generated_column));
+
+        let mut encoded = String::new();
+        let mut line = 0;
+        let mut previous_generated_column = 0;
+        let mut previous_source = 0;
+        let mut previous_source_line = 0;
+        let mut previous_source_column = 0;
+        let mut previous_name = 0;
+        let mut first_in_line = true;
+
+        for mapping in &mappings {
+            while line < mapping.generated_line {
+                encoded.push(';');
+                line += 1;
+                previous_generated_column = 0;
+                first_in_line = true;
+            }
+            if !first_in_line {
+                encoded.push(',');
+            }
+            first_in_line = false;
+
+            encode_vlq(&mut encoded, mapping.generated_column as i64 - previous_generated_column);
+            encode_vlq(&mut encoded, mapping.source as i64 - previous_source);
+            encode_vlq(&mut encoded, mapping.source_line as i64 - previous_source_line);
+            encode_vlq(&mut encoded, mapping.source_column as i64 - previous_source_column);
+            if let Some(name) = mapping.name {
+                encode_vlq(&mut encoded, name as i64 - previous_name);
+                previous_name = name as i64;
+            }
+
+            previous_generated_column = mapping.generated_column as i64;
+            previous_source = mapping.source as i64;
+            previous_source_line = mapping.source_line as i64;
+            previous_source_column = mapping.source_column as i64;
+        }
+
+        let mut source_map = json!({
+            "version": 3,
+            "sources": self.sources.iter().map(|source| source.name.clone()).collect::<Vec<_>>(),
+            "sourcesContent": self.sources.iter().map(|source| source.content.clone()).collect::<Vec<_>>(),
+            "names": self.names,
+            "mappings": encoded,
+        });
+        if let Some(file) = &self.file {
+            source_map["file"] = json!(file);
+        }
+        if let Some(source_root) = &self.source_root {
+            source_map["sourceRoot"] = json!(source_root);
+        }
+        source_map.to_string()
+    }
+}
+
+fn encode_vlq(output: &mut String, value: i64) {
+    let mut value = if value < 0 { ((-value) << 1) | 1 } else { value << 1 };
+    loop {
+        let mut digit = value & 0b11111;
+        value >>= 5;
+        if value > 0 {
+            digit |= 0b100000;
+        }
+        output.push(BASE64_DIGITS[digit as usize] as char);
+        if value == 0 {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolated_source() {
+        // Source interpolated at the third line and fifth column of a file.
+        let compilation_unit = CompilationUnit::new(None, "\n\n    x + y".into(), &CompilerOptions::new());
+        let mut builder = SourceMapBuilder::new(Some("Main.js"));
+        builder.add_interpolated_source(&compilation_unit, "Main.xml", None);
+        builder.add_mapping(0, 0, &Location::with_offset(&compilation_unit, 6));
+        builder.add_named_mapping(0, 4, &Location::with_offset(&compilation_unit, 10), "y");
+        let source_map: serde_json::Value = serde_json::from_str(&builder.build()).unwrap();
+        assert_eq!(source_map["sources"], json!(["Main.xml"]));
+        assert_eq!(source_map["sourcesContent"], json!(["\n\n    x + y"]));
+        assert_eq!(source_map["names"], json!(["y"]));
+        assert_eq!(source_map["mappings"], json!("AAEI,IAAIA"));
+    }
+}
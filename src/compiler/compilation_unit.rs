@@ -1,6 +1,9 @@
+#[allow(clippy::module_inception)]
 mod compilation_unit;
 pub use compilation_unit::*;
 mod comment;
 pub use comment::*;
 mod location;
-pub use location::*;
\ No newline at end of file
+pub use location::*;
+mod location_serialization;
+pub use location_serialization::*;
\ No newline at end of file
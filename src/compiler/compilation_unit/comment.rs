@@ -23,7 +23,7 @@ impl Comment {
     /// The content of the comment.
     /// * If it is a multi-line comment, it includes all the characters after `/*` until `*/` (exclusive).
     /// * If it is a single-line comment, it includes all the characters after `//`
-    /// until the next line terminator (exclusive) or end of program.
+    ///   until the next line terminator (exclusive) or end of program.
     pub fn content(&self) -> String {
         self.content.borrow().clone()
     }
@@ -44,15 +44,15 @@ impl Comment {
     /// a specific location.
     pub fn is_jetdoc(&self, location_to_precede: &Location) -> bool {
         if self.multiline && self.content.borrow().starts_with('*') {
-            let mut i: usize = self.location.borrow().last_offset;
-            for (i_1, ch) in self.location.borrow().compilation_unit().text[i..].char_indices() {
+            let mut i: usize = self.location.borrow().last_offset();
+            for (i_1, ch) in self.location.borrow().compilation_unit().text()[i..].char_indices() {
                 i = i_1;
                 if !(CharacterValidator::is_whitespace(ch) || CharacterValidator::is_line_terminator(ch)) {
                     break;
                 }
             }
-            i += self.location.borrow().last_offset;
-            location_to_precede.first_offset == i
+            i += self.location.borrow().last_offset();
+            location_to_precede.first_offset() == i
         } else {
             false
         }
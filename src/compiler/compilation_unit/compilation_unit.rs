@@ -1,5 +1,6 @@
 use std::cell::{RefCell, Cell, RefMut};
-use std::rc::Rc;
+use std::ops::Range;
+use std::rc::{Rc, Weak};
 use crate::ns::*;
 
 const LINE_SKIP_THRESOLD: usize = 10;
@@ -10,7 +11,12 @@ const EXTRA_HIGHER_LINE_SKIP_THRESOLD: usize = 1_000;
 /// a source text.
 pub struct CompilationUnit {
     pub(crate) file_path: Option<String>,
-    pub(crate) text: String,
+    pub(crate) text: RefCell<Rc<String>>,
+
+    /// Version of the text that new locations refer to.
+    pub(crate) version: RefCell<Rc<TextVersion>>,
+    /// Earlier versions of the text still referred to by locations.
+    previous_versions: RefCell<Vec<Weak<TextVersion>>>,
 
     /// Collection of ascending line number *skips* used
     /// for optimizing retrieval of line numbers or line offsets.
@@ -44,6 +50,115 @@ pub(crate) struct LineSkip {
     pub line_number: usize,
 }
 
+#[derive(Copy, Clone)]
+struct TextEdit {
+    /// First offset of the replaced range.
+    pub start: usize,
+    /// End offset of the replaced range before the edit.
+    pub old_end: usize,
+    /// End offset of the replacement after the edit.
+    pub new_end: usize,
+}
+
+impl TextEdit {
+    /// Maps an offset from before the edit into an offset after the edit.
+    /// Offsets inside the replaced range map to the end of the replacement.
+    fn map_offset(&self, offset: usize) -> usize {
+        if offset <= self.start {
+            offset
+        } else if offset >= self.old_end {
+            offset - self.old_end + self.new_end
+        } else {
+            self.new_end
+        }
+    }
+}
+
+/// A version of the text of a compilation unit, shared by the locations
+/// built while it was current. Each edit eagerly composes into the
+/// offset mapping of the versions that are still referred to, so
+/// that mapping an offset does not depend on the number of edits.
+pub(crate) struct TextVersion {
+    /// Pieces of the mapping from offsets of this version into offsets
+    /// of the current text, each applying from its first offset
+    /// up to the next piece.
+    pieces: RefCell<Vec<(usize, OffsetPiece)>>,
+    /// Range of the current text affected by the edits
+    /// applied since this version.
+    dirty_range: RefCell<Option<Range<usize>>>,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum OffsetPiece {
+    Shift(isize),
+    Fixed(usize),
+}
+
+impl TextVersion {
+    fn new() -> Self {
+        Self {
+            pieces: RefCell::new(vec![(0, OffsetPiece::Shift(0))]),
+            dirty_range: RefCell::new(None),
+        }
+    }
+
+    /// Maps an offset of this version into an offset of the current text.
+    pub fn map_offset(&self, offset: usize) -> usize {
+        let pieces = self.pieces.borrow();
+        let i = pieces.partition_point(|(start, _)| *start <= offset) - 1;
+        match pieces[i].1 {
+            OffsetPiece::Shift(delta) => offset.saturating_add_signed(delta),
+            OffsetPiece::Fixed(offset) => offset,
+        }
+    }
+
+    /// Range of the current text affected by the edits
+    /// applied since this version, if any.
+    pub fn dirty_range(&self) -> Option<Range<usize>> {
+        self.dirty_range.borrow().clone()
+    }
+
+    fn apply_edit(&self, edit: &TextEdit) {
+        let mut dirty_range = self.dirty_range.borrow_mut();
+        *dirty_range = Some(match dirty_range.take() {
+            None => edit.start..edit.new_end,
+            Some(Range { start, end }) => edit.start.min(edit.map_offset(start))..edit.new_end.max(edit.map_offset(end)),
+        });
+
+        // Within a shifted piece, the offsets from `replaced_start` map
+        // into the replaced range and those from `shifted_start` past it.
+        let delta = edit.new_end as isize - edit.old_end as isize;
+        let mut pieces = self.pieces.borrow_mut();
+        let mut result: Vec<(usize, OffsetPiece)> = vec![];
+        let mut push = |start: usize, piece: OffsetPiece| {
+            if result.last().is_some_and(|(_, last)| *last == piece) {
+                return;
+            }
+            result.push((start, piece));
+        };
+        for (i, &(start, piece)) in pieces.iter().enumerate() {
+            let end = pieces.get(i + 1).map_or(usize::MAX, |(start, _)| *start);
+            match piece {
+                OffsetPiece::Fixed(offset) => push(start, OffsetPiece::Fixed(edit.map_offset(offset))),
+                OffsetPiece::Shift(d) => {
+                    let replaced_start = (edit.start as isize + 1 - d).max(0) as usize;
+                    let shifted_start = (edit.old_end as isize - d).max(replaced_start as isize) as usize;
+                    if start < replaced_start {
+                        push(start, OffsetPiece::Shift(d));
+                    }
+                    if replaced_start < shifted_start && start.max(replaced_start) < end.min(shifted_start) {
+                        push(start.max(replaced_start), OffsetPiece::Fixed(edit.new_end));
+                    }
+                    if shifted_start < end {
+                        push(start.max(shifted_start), OffsetPiece::Shift(d + delta));
+                    }
+                },
+            }
+        }
+        *pieces = result;
+    }
+}
+
 #[derive(Copy, Clone)]
 pub(crate) struct HigherLineSkip {
     /// Index to a `LineSkip`, or another `HigherLineSkip` in the case
@@ -59,7 +174,9 @@ impl Default for CompilationUnit {
     fn default() -> Self {
         Self {
             file_path: None,
-            text: "".into(),
+            text: RefCell::new(Rc::new("".into())),
+            version: RefCell::new(Rc::new(TextVersion::new())),
+            previous_versions: RefCell::new(vec![]),
             line_skips: RefCell::new(vec![LineSkip { offset: 0, line_number: 1 }]),
             line_skips_counter: Cell::new(0),
             higher_line_skips: RefCell::new(vec![HigherLineSkip { skip_index: 0, offset: 0, line_number: 1 }]),
@@ -82,7 +199,9 @@ impl CompilationUnit {
     pub fn new(file_path: Option<String>, text: String, compiler_options: &Rc<CompilerOptions>) -> Rc<Self> {
         Rc::new(Self {
             file_path,
-            text,
+            text: RefCell::new(Rc::new(text)),
+            version: RefCell::new(Rc::new(TextVersion::new())),
+            previous_versions: RefCell::new(vec![]),
             line_skips: RefCell::new(vec![LineSkip { offset: 0, line_number: 1 }]),
             line_skips_counter: Cell::new(0),
             higher_line_skips: RefCell::new(vec![HigherLineSkip { skip_index: 0, offset: 0, line_number: 1 }]),
@@ -104,9 +223,82 @@ impl CompilationUnit {
         self.file_path.clone()
     }
 
-    /// Source text.
-    pub fn text(&self) -> &String {
-        &self.text
+    /// Options the compilation unit was constructed with.
+    pub fn compiler_options(&self) -> Rc<CompilerOptions> {
+        self.compiler_options.clone()
+    }
+
+    /// Source text. The text is shared with the tokenizers and
+    /// is not affected by later edits.
+    pub fn text(&self) -> Rc<String> {
+        self.text.borrow().clone()
+    }
+
+    /// Replaces the text in `range` by `replacement`. The line skips are
+    /// updated from the start of the range, and the offsets of existing
+    /// locations past the range are shifted, so that nodes
+    /// parsed before the edit remain valid as long as they do not intersect
+    /// the range.
+    ///
+    /// Following edits, use `ParserFacade::reparse_program()` or
+    /// `ParserFacade::reparse_program_error_tolerant()` to update
+    /// a previously parsed program.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds or does not lie on character boundaries.
+    pub fn edit(&self, range: Range<usize>, replacement: &str) {
+        Rc::make_mut(&mut *self.text.borrow_mut()).replace_range(range.clone(), replacement);
+        let edit = TextEdit {
+            start: range.start,
+            old_end: range.end,
+            new_end: range.start + replacement.len(),
+        };
+
+        // Versions no longer referred to by any location are discarded.
+        let version = self.version.replace(Rc::new(TextVersion::new()));
+        let mut previous_versions = self.previous_versions.borrow_mut();
+        previous_versions.push(Rc::downgrade(&version));
+        drop(version);
+        previous_versions.retain(|version| {
+            let Some(version) = version.upgrade() else {
+                return false;
+            };
+            version.apply_edit(&edit);
+            true
+        });
+        drop(previous_versions);
+
+        self.update_line_skips(range.start);
+    }
+
+    /// Recomputes the line skips located after `offset`.
+    fn update_line_skips(&self, offset: usize) {
+        let mut line_skips = self.line_skips.borrow_mut();
+        while line_skips.len() > 1 && line_skips.last().unwrap().offset >= offset {
+            line_skips.pop();
+        }
+        let LineSkip { mut line_number, offset } = *line_skips.last().unwrap();
+        drop(line_skips);
+        self.higher_line_skips.borrow_mut().retain(|skip| skip.line_number <= line_number);
+        self.extra_higher_line_skips.borrow_mut().retain(|skip| skip.line_number <= line_number);
+
+        // Each counter holds the number of lines pushed since the last skip
+        self.line_skips_counter.set((line_number - 1) % (LINE_SKIP_THRESOLD + 1));
+        self.higher_line_skips_counter.set((line_number - 1) % (HIGHER_LINE_SKIP_THRESOLD + 1));
+        self.extra_higher_line_skips_counter.set((line_number - 1) % (EXTRA_HIGHER_LINE_SKIP_THRESOLD + 1));
+
+        let text = self.text.borrow();
+        let mut characters = CharacterReader::from(&text[offset..]);
+        while let Some(ch) = characters.next() {
+            if CharacterValidator::is_line_terminator(ch) {
+                if ch == '\r' && characters.peek_or_zero() == '\n' {
+                    characters.next();
+                }
+                line_number += 1;
+                self.push_line_skip(line_number, offset + characters.index());
+            }
+        }
     }
 
     /// Whether the source contains any errors after parsing
@@ -126,7 +318,7 @@ impl CompilationUnit {
     }
 
     /// The comments present in the source file, as a mutable collection.
-    pub fn comments_mut(&self) -> RefMut<Vec<Rc<Comment>>> {
+    pub fn comments_mut(&self) -> RefMut<'_, Vec<Rc<Comment>>> {
         self.comments.borrow_mut()
     }
 
@@ -141,7 +333,19 @@ impl CompilationUnit {
         self.diagnostics.borrow_mut().sort();
     }
 
-    pub fn add_diagnostic(&self, diagnostic: Diagnostic) {
+    /// Adds a diagnostic to the compilation unit. Warnings that are suppressed
+    /// by the compiler options are ignored and, in strict mode, warnings
+    /// are added as errors.
+    pub fn add_diagnostic(&self, mut diagnostic: Diagnostic) {
+        if diagnostic.is_warning() {
+            if !self.compiler_options.reports_warning(diagnostic.kind()) {
+                return;
+            }
+            if self.compiler_options.warnings_as_errors {
+                diagnostic.is_warning = false;
+                diagnostic.is_verify_error = true;
+            }
+        }
         if diagnostic.is_warning() {
             self.warning_count.set(self.warning_count.get() + 1);
         } else {
@@ -151,6 +355,17 @@ impl CompilationUnit {
         self.diagnostics.borrow_mut().push(diagnostic);
     }
 
+    /// Retains only the diagnostics satisfying `predicate`,
+    /// recounting errors and warnings.
+    pub(crate) fn retain_diagnostics(&self, predicate: impl FnMut(&Diagnostic) -> bool) {
+        let mut diagnostics = self.diagnostics.borrow_mut();
+        diagnostics.retain(predicate);
+        let warning_count = diagnostics.iter().filter(|d| d.is_warning()).count() as u32;
+        self.warning_count.set(warning_count);
+        self.error_count.set(diagnostics.len() as u32 - warning_count);
+        self.invalidated.set(diagnostics.len() as u32 != warning_count);
+    }
+
     pub fn error_count(&self) -> u32 {
         self.error_count.get()
     }
@@ -191,8 +406,7 @@ impl CompilationUnit {
         // Extra higher line skips
         let mut last_skip = HigherLineSkip { skip_index: 0, offset: 0, line_number: 1 };
         let skips = self.extra_higher_line_skips.borrow();
-        let mut skips = skips.iter();
-        while let Some(skip_1) = skips.next() {
+        for skip_1 in skips.iter() {
             if offset < skip_1.offset {
                 break;
             }
@@ -203,7 +417,7 @@ impl CompilationUnit {
         let skips = self.higher_line_skips.borrow();
         let mut skips = skips[last_skip.skip_index..].iter();
         let mut last_skip = skips.next().unwrap();
-        while let Some(skip_1) = skips.next() {
+        for skip_1 in skips {
             if offset < skip_1.offset {
                 break;
             }
@@ -214,7 +428,7 @@ impl CompilationUnit {
         let skips = self.line_skips.borrow();
         let mut skips = skips[last_skip.skip_index..].iter();
         let mut last_skip = skips.next().unwrap();
-        while let Some(skip_1) = skips.next() {
+        for skip_1 in skips {
             if offset < skip_1.offset {
                 break;
             }
@@ -222,7 +436,8 @@ impl CompilationUnit {
         }
 
         let mut current_line = last_skip.line_number;
-        let mut characters = CharacterReader::from(&self.text[last_skip.offset..]);
+        let text = self.text.borrow();
+        let mut characters = CharacterReader::from(&text[last_skip.offset..]);
         while last_skip.offset + characters.index() < offset {
             let ch_1 = characters.next();
             if let Some(ch_1) = ch_1 {
@@ -244,8 +459,7 @@ impl CompilationUnit {
         // Extra higher line skips
         let mut last_skip = HigherLineSkip { skip_index: 0, offset: 0, line_number: 1 };
         let skips = self.extra_higher_line_skips.borrow();
-        let mut skips = skips.iter();
-        while let Some(skip_1) = skips.next() {
+        for skip_1 in skips.iter() {
             if line < skip_1.line_number {
                 break;
             }
@@ -256,7 +470,7 @@ impl CompilationUnit {
         let skips = self.higher_line_skips.borrow();
         let mut skips = skips[last_skip.skip_index..].iter();
         let mut last_skip = skips.next().unwrap();
-        while let Some(skip_1) = skips.next() {
+        for skip_1 in skips {
             if line < skip_1.line_number {
                 break;
             }
@@ -267,7 +481,7 @@ impl CompilationUnit {
         let skips = self.line_skips.borrow();
         let mut skips = skips[last_skip.skip_index..].iter();
         let mut last_skip = skips.next().unwrap();
-        while let Some(skip_1) = skips.next() {
+        for skip_1 in skips {
             if line < skip_1.line_number {
                 break;
             }
@@ -275,18 +489,15 @@ impl CompilationUnit {
         }
 
         let mut current_line = last_skip.line_number;
-        let mut characters = CharacterReader::from(&self.text[last_skip.offset..]);
+        let text = self.text.borrow();
+        let mut characters = CharacterReader::from(&text[last_skip.offset..]);
         while current_line != line {
-            let ch_1 = characters.next();
-            if let Some(ch_1) = ch_1 {
-                if CharacterValidator::is_line_terminator(ch_1) {
-                    if ch_1 == '\r' && characters.peek_or_zero() == '\n' {
-                        characters.next();
-                    }
-                    current_line += 1;
+            let ch_1 = characters.next()?;
+            if CharacterValidator::is_line_terminator(ch_1) {
+                if ch_1 == '\r' && characters.peek_or_zero() == '\n' {
+                    characters.next();
                 }
-            } else {
-                return None;
+                current_line += 1;
             }
         }
         Some(last_skip.offset + characters.index())
@@ -297,8 +508,7 @@ impl CompilationUnit {
         // Extra higher line skips
         let mut last_skip = HigherLineSkip { skip_index: 0, offset: 0, line_number: 1 };
         let skips = self.extra_higher_line_skips.borrow();
-        let mut skips = skips.iter();
-        while let Some(skip_1) = skips.next() {
+        for skip_1 in skips.iter() {
             if offset < skip_1.offset {
                 break;
             }
@@ -309,7 +519,7 @@ impl CompilationUnit {
         let skips = self.higher_line_skips.borrow();
         let mut skips = skips[last_skip.skip_index..].iter();
         let mut last_skip = skips.next().unwrap();
-        while let Some(skip_1) = skips.next() {
+        for skip_1 in skips {
             if offset < skip_1.offset {
                 break;
             }
@@ -320,7 +530,7 @@ impl CompilationUnit {
         let skips = self.line_skips.borrow();
         let mut skips = skips[last_skip.skip_index..].iter();
         let mut last_skip = skips.next().unwrap();
-        while let Some(skip_1) = skips.next() {
+        for skip_1 in skips {
             if offset < skip_1.offset {
                 break;
             }
@@ -328,7 +538,8 @@ impl CompilationUnit {
         }
 
         let mut current_line_offset = last_skip.offset;
-        let mut characters = CharacterReader::from(&self.text[last_skip.offset..]);
+        let text = self.text.borrow();
+        let mut characters = CharacterReader::from(&text[last_skip.offset..]);
         while last_skip.offset + characters.index() < offset {
             let ch_1 = characters.next();
             if let Some(ch_1) = ch_1 {
@@ -347,7 +558,38 @@ impl CompilationUnit {
 
     pub fn get_line_indent(&self, line: usize) -> usize {
         let line_offset = self.get_line_offset(line).unwrap();
-        let indent = CharacterValidator::indent_count(&self.text[line_offset..]);
+        let indent = CharacterValidator::indent_count(&self.text.borrow()[line_offset..]);
         indent - line_offset
     }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edited_locations() {
+        let compilation_unit = CompilationUnit::new(None, "abc def ghi".into(), &CompilerOptions::new());
+        let bc = Location::with_offsets(&compilation_unit, 1, 3);
+        let def = Location::with_offsets(&compilation_unit, 4, 7);
+        let ghi = Location::with_offsets(&compilation_unit, 8, 11);
+
+        compilation_unit.edit(0..3, "a");
+        let def_1 = Location::with_offsets(&compilation_unit, 2, 5);
+        compilation_unit.edit(3..4, "EE");
+        compilation_unit.edit(10..10, "!");
+        assert_eq!(compilation_unit.text().as_str(), "a dEEf ghi!");
+
+        let offsets = |location: &Location| (location.first_offset(), location.last_offset());
+        assert_eq!(offsets(&bc), (1, 1));
+        assert_eq!(offsets(&def), (2, 6));
+        assert_eq!(offsets(&def_1), (2, 6));
+        assert_eq!(offsets(&ghi), (7, 10));
+
+        // Versions without locations are discarded.
+        assert_eq!(compilation_unit.previous_versions.borrow().len(), 2);
+        drop(def_1);
+        compilation_unit.edit(0..0, " ");
+        assert_eq!(compilation_unit.previous_versions.borrow().len(), 1);
+        assert_eq!(offsets(&ghi), (8, 11));
+    }
 }
\ No newline at end of file
@@ -1,24 +1,28 @@
 use std::cmp::Ordering;
 use std::fmt::Debug;
-use serde::{Serialize, Deserialize};
 use std::rc::Rc;
 use crate::compilation_unit::*;
 
 /// Represents a source location. This location includes
 /// spanning lines and columns and the reference compilation unit.
-#[derive(Clone, Serialize, Deserialize)]
+///
+/// Locations serialize into an empty object unless serialized within
+/// `with_location_serialization()`, and deserialize against
+/// the compilation unit supplied to `with_location_deserialization()`.
+#[derive(Clone)]
 pub struct Location {
     /// The compilation unit that this location belongs to.
-    #[serde(skip)]
     pub(crate) compilation_unit: Rc<CompilationUnit>,
 
     /// First UTF-8 offset.
-    #[serde(skip)]
     pub(crate) first_offset: usize,
 
     /// Last UTF-8 offset.
-    #[serde(skip)]
     pub(crate) last_offset: usize,
+
+    /// Version of the compilation unit's text that the offsets
+    /// refer to. The offsets are mapped through any later edits.
+    pub(crate) version: Rc<TextVersion>,
 }
 
 impl Debug for Location {
@@ -27,10 +31,10 @@ impl Debug for Location {
             "Location(first_line_number={}, first_column={}, first_offset={}, last_line_number={}, last_column={}, last_offset={})",
             self.first_line_number(),
             self.first_column(),
-            self.first_offset,
+            self.first_offset(),
             self.last_line_number(),
             self.last_column(),
-            self.last_offset
+            self.last_offset()
         )
     }
 }
@@ -40,20 +44,20 @@ impl Eq for Location {}
 impl PartialEq for Location {
     fn eq(&self, other: &Self) -> bool {
         Rc::ptr_eq(&self.compilation_unit, &other.compilation_unit) &&
-            self.first_offset == other.first_offset &&
-            self.last_offset == other.last_offset
+            self.first_offset() == other.first_offset() &&
+            self.last_offset() == other.last_offset()
     }
 }
 
 impl Ord for Location {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap_or(Ordering::Equal)
+        self.first_offset().cmp(&other.first_offset())
     }
 }
 
 impl PartialOrd for Location {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.first_offset.partial_cmp(&other.first_offset)
+        Some(self.cmp(other))
     }
 }
 
@@ -68,6 +72,7 @@ impl Location {
             compilation_unit: compilation_unit.clone(),
             first_offset,
             last_offset,
+            version: compilation_unit.version.borrow().clone(),
         }
     }
 
@@ -80,22 +85,14 @@ impl Location {
     /// serves as the first location, while `other` serves as the
     /// last location.
     pub fn combine_with(&self, other: Location) -> Self {
-        Self {
-            compilation_unit: self.compilation_unit.clone(),
-            first_offset: self.first_offset,
-            last_offset: other.last_offset,
-        }
+        Self::with_offsets(&self.compilation_unit, self.first_offset(), other.last_offset())
     }
 
     /// Build a location by combining two locations. `self`
     /// serves as the first location, while the first column and first line
     /// of `other` serve as the last location.
     pub fn combine_with_start_of(&self, other: Location) -> Self {
-        Self {
-            compilation_unit: self.compilation_unit.clone(),
-            first_offset: self.first_offset,
-            last_offset: other.first_offset,
-        }
+        Self::with_offsets(&self.compilation_unit, self.first_offset(), other.first_offset())
     }
 
     /// The compilation unit that this location belongs to.
@@ -105,12 +102,12 @@ impl Location {
 
     /// First line number, counted from one.
     pub fn first_line_number(&self) -> usize {
-        self.compilation_unit.get_line_number(self.first_offset)
+        self.compilation_unit.get_line_number(self.first_offset())
     }
 
     /// Last line number, counted from one.
     pub fn last_line_number(&self) -> usize {
-        self.compilation_unit.get_line_number(self.last_offset)
+        self.compilation_unit.get_line_number(self.last_offset())
     }
 
     /// First line offset, counted from one.
@@ -125,23 +122,23 @@ impl Location {
 
     // The first byte offset of this location.
     pub fn first_offset(&self) -> usize {
-        self.first_offset
+        self.version.map_offset(self.first_offset)
     }
 
     // The last byte offset of this location.
     pub fn last_offset(&self) -> usize {
-        self.last_offset
+        self.version.map_offset(self.last_offset)
     }
 
     /// Zero based first column of the location in code points.
     pub fn first_column(&self) -> usize {
         let line_offset = self.first_line_offset();
-        let target_offset = self.first_offset;
+        let target_offset = self.first_offset();
         if line_offset > target_offset {
             return 0;
         }
         let mut i = 0;
-        for _ in self.compilation_unit.text[line_offset..target_offset].chars() {
+        for _ in self.compilation_unit.text()[line_offset..target_offset].chars() {
             i += 1;
         }
         i
@@ -150,19 +147,24 @@ impl Location {
     /// Zero based last column of the location in code points.
     pub fn last_column(&self) -> usize {
         let line_offset = self.last_line_offset();
-        let target_offset = self.last_offset;
+        let target_offset = self.last_offset();
         if line_offset > target_offset {
             return 0;
         }
         let mut i = 0;
-        for _ in self.compilation_unit.text[line_offset..target_offset].chars() {
+        for _ in self.compilation_unit.text()[line_offset..target_offset].chars() {
             i += 1;
         }
         i
     }
 
     pub fn character_count(&self) -> usize {
-        self.compilation_unit.text[self.first_offset..self.last_offset].chars().count()
+        self.compilation_unit.text()[self.first_offset()..self.last_offset()].chars().count()
+    }
+
+    /// The source text spanned by this location.
+    pub fn text(&self) -> String {
+        self.compilation_unit.text()[self.first_offset()..self.last_offset()].to_owned()
     }
 
     /// Indicates whether a previous location and a next location
@@ -0,0 +1,130 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use serde::{Serialize, Deserialize, Serializer, Deserializer};
+use serde::ser::SerializeStruct;
+use crate::ns::*;
+
+/// Options for serializing source locations.
+///
+/// By default, a `Location` serializes into an empty object. Source locations
+/// are only serialized within a `with_location_serialization()` call.
+#[derive(Clone, Default)]
+pub struct LocationSerialization {
+    /// Whether to include the file path of the compilation unit
+    /// in every serialized location.
+    pub include_file_path: bool,
+}
+
+thread_local! {
+    static SERIALIZATION: RefCell<Option<LocationSerialization>> = const { RefCell::new(None) };
+    static DESERIALIZATION: RefCell<Option<Rc<CompilationUnit>>> = const { RefCell::new(None) };
+}
+
+/// Serializes source locations, including offsets, line numbers and columns,
+/// for the duration of the given function.
+///
+/// # Example
+///
+/// ```ignore
+/// let json = with_location_serialization(LocationSerialization::default(), || {
+///     serde_json::to_string_pretty(&program).unwrap()
+/// });
+/// ```
+pub fn with_location_serialization<T>(options: LocationSerialization, function: impl FnOnce() -> T) -> T {
+    let old = SERIALIZATION.with(|s| s.replace(Some(options)));
+    let result = function();
+    SERIALIZATION.with(|s| s.replace(old));
+    result
+}
+
+/// Deserializes source locations against the given compilation unit
+/// for the duration of the given function.
+///
+/// Locations that do not contain offsets deserialize into the
+/// zero offset.
+///
+/// # Example
+///
+/// ```ignore
+/// let program: Rc<Program> = with_location_deserialization(&compilation_unit, || {
+///     serde_json::from_str(&json).unwrap()
+/// });
+/// ```
+pub fn with_location_deserialization<T>(compilation_unit: &Rc<CompilationUnit>, function: impl FnOnce() -> T) -> T {
+    let old = DESERIALIZATION.with(|d| d.replace(Some(compilation_unit.clone())));
+    let result = function();
+    DESERIALIZATION.with(|d| d.replace(old));
+    result
+}
+
+#[derive(Deserialize)]
+struct SerializedLocation {
+    #[serde(default)]
+    first_offset: usize,
+    #[serde(default)]
+    last_offset: usize,
+}
+
+impl Serialize for Location {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let Some(options) = SERIALIZATION.with(|s| s.borrow().clone()) else {
+            return serializer.serialize_struct("Location", 0)?.end();
+        };
+        let file_path = if options.include_file_path { self.compilation_unit.file_path() } else { None };
+        let mut s = serializer.serialize_struct("Location", if file_path.is_some() { 7 } else { 6 })?;
+        if let Some(file_path) = file_path {
+            s.serialize_field("file_path", &file_path)?;
+        }
+        s.serialize_field("first_offset", &self.first_offset())?;
+        s.serialize_field("last_offset", &self.last_offset())?;
+        s.serialize_field("first_line", &self.first_line_number())?;
+        s.serialize_field("first_column", &self.first_column())?;
+        s.serialize_field("last_line", &self.last_line_number())?;
+        s.serialize_field("last_column", &self.last_column())?;
+        s.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Location {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let SerializedLocation { first_offset, last_offset } = SerializedLocation::deserialize(deserializer)?;
+        let compilation_unit = DESERIALIZATION.with(|d| d.borrow().clone()).unwrap_or_default();
+        let text = compilation_unit.text();
+        for offset in [first_offset, last_offset] {
+            if !text.is_char_boundary(offset) {
+                return Err(serde::de::Error::custom(format!("offset {offset} is out of the compilation unit's bounds")));
+            }
+        }
+        if first_offset > last_offset {
+            return Err(serde::de::Error::custom("first offset is after last offset"));
+        }
+        Ok(Location::with_offsets(&compilation_unit, first_offset, last_offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ns::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn round_trip() {
+        let compilation_unit = CompilationUnit::new(Some("Main.jet".into()), "const x = 10\nconst y = 20".into(), &CompilerOptions::new());
+        let program = ParserFacade::parse_program(&compilation_unit, &SymbolHost::new("")).unwrap();
+        assert!(!serde_json::to_string(&program).unwrap().contains("first_offset"));
+
+        let json = with_location_serialization(LocationSerialization { include_file_path: true }, || {
+            serde_json::to_string(&program).unwrap()
+        });
+        assert!(json.contains(r#""file_path":"Main.jet""#));
+        assert!(json.contains(r#""first_line":2"#));
+
+        let program_1: Rc<Program> = with_location_deserialization(&compilation_unit, || {
+            serde_json::from_str(&json).unwrap()
+        });
+        let location = program_1.directives[1].location();
+        assert!(Rc::ptr_eq(&location.compilation_unit(), &compilation_unit));
+        assert_eq!(location, program.directives[1].location());
+        assert_eq!((location.first_line_number(), location.first_column()), (2, 0));
+    }
+}
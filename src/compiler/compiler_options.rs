@@ -1,2 +1,3 @@
+#[allow(clippy::module_inception)]
 mod compiler_options;
 pub use compiler_options::*;
\ No newline at end of file
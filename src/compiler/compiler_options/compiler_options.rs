@@ -1,9 +1,93 @@
 use std::rc::Rc;
+use crate::ns::*;
 
-pub struct CompilerOptions {}
+/// Options shared by the compilation units of a compilation.
+///
+/// ```
+/// use hydroper_jet_compiler::ns::*;
+/// let compiler_options = Rc::new(CompilerOptions {
+///     warnings_as_errors: true,
+///     configuration_constants: HashMap::from([("air::target".into(), "ios".into())]),
+///     ..default()
+/// });
+/// ```
+pub struct CompilerOptions {
+    /// Strict mode, in which warnings are reported as errors.
+    pub warnings_as_errors: bool,
+    /// Kinds of warnings that are not reported.
+    pub suppressed_warnings: HashSet<DiagnosticKind>,
+    /// Constants tested by configuration directives, mapping a
+    /// qualified name such as `air::target` to a value such as `ios`.
+    pub configuration_constants: HashMap<String, String>,
+    /// Backend that the compilation targets.
+    pub target: CompilerTarget,
+    /// Output directory of the jetpm package manager, used by
+    /// `Embed` expressions of the form `output + "path"`. If `None`, the
+    /// directory given to the `SymbolHost` is used.
+    pub jetpm_output_directory: Option<String>,
+}
+
+impl Default for CompilerOptions {
+    fn default() -> Self {
+        Self {
+            warnings_as_errors: false,
+            suppressed_warnings: HashSet::new(),
+            configuration_constants: HashMap::new(),
+            target: CompilerTarget::JavaScript,
+            jetpm_output_directory: None,
+        }
+    }
+}
 
 impl CompilerOptions {
     pub fn new() -> Rc<Self> {
-        Rc::new(Self {})
+        Rc::new(Self::default())
+    }
+
+    /// Whether a warning of the given kind is reported.
+    pub fn reports_warning(&self, kind: DiagnosticKind) -> bool {
+        !self.suppressed_warnings.contains(&kind)
+    }
+}
+
+/// Backend targetted by a compilation.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum CompilerTarget {
+    /// ECMAScript 2020 modules, generated by `JsGenerator`.
+    JavaScript,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ns::*;
+
+    #[test]
+    fn warning_options() {
+        let source = r#"
+            package p {
+                public function f(): void {
+                    return;
+                    f();
+                }
+            }
+        "#;
+        let verify = |compiler_options: CompilerOptions| {
+            let host = SymbolHost::new("");
+            declare_jet_lang_classes(&host, &["Object", "Boolean", "String", "Number", "Function", "Class", "Array"]);
+            let compilation_unit = CompilationUnit::new(None, source.to_owned(), &Rc::new(compiler_options));
+            let program = ParserFacade::parse_program(&compilation_unit, &host).unwrap();
+            let mut verifier = Verifier::new(&host);
+            verifier.verify_programs(vec![program.clone()]);
+            let diagnostics = compilation_unit.diagnostics().iter().map(|d| (d.id(), d.is_warning(), d.location().first_line_number())).collect::<Vec<_>>();
+            (diagnostics, verifier.invalidated(), compilation_unit.invalidated())
+        };
+
+        assert_eq!(verify(default()), (vec![(DiagnosticKind::UnreachableCode.id(), true, 5)], false, false));
+
+        let suppressed_warnings = HashSet::from([DiagnosticKind::UnreachableCode]);
+        assert_eq!(verify(CompilerOptions { suppressed_warnings: suppressed_warnings.clone(), ..default() }), (vec![], false, false));
+        assert_eq!(verify(CompilerOptions { suppressed_warnings, warnings_as_errors: true, ..default() }), (vec![], false, false));
+
+        assert_eq!(verify(CompilerOptions { warnings_as_errors: true, ..default() }), (vec![(DiagnosticKind::UnreachableCode.id(), false, 5)], true, true));
     }
-}
\ No newline at end of file
+}
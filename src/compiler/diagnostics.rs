@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 mod diagnostics;
 pub use diagnostics::*;
 mod diagnostic_kind;
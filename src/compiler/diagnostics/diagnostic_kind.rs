@@ -1,5 +1,5 @@
 #[repr(i32)]
-#[derive(Eq, PartialEq, Clone, Copy)]
+#[derive(Eq, PartialEq, Hash, Clone, Copy)]
 pub enum DiagnosticKind {
     UnexpectedOrInvalidToken = 1024,
     UnexpectedEnd = 1025,
@@ -69,6 +69,76 @@ pub enum DiagnosticKind {
     UndefinedProperty = 1089,
     IncompatibleFieldKey = 1090,
     MissingPropertyInLiteral = 1091,
+    UnresolvedReference = 1092,
+    UnknownConfigurationConstant = 1093,
+    UnsupportedOperator = 1094,
+    WrongNumberOfArguments = 1095,
+    NotCallable = 1096,
+    MissingArgument = 1097,
+    IncompatibleArgument = 1098,
+    CouldNotInferTypeArgument = 1099,
+    CannotInstantiate = 1100,
+    WrongNumberOfTypeArguments = 1101,
+    DuplicateDefinition = 1102,
+    VoidFunctionMustNotReturnValue = 1103,
+    ReturnValueExpected = 1104,
+    YieldOutsideGenerator = 1105,
+    AwaitOutsideAsynchronousFunction = 1106,
+    NotAClass = 1107,
+    NotAnInterface = 1108,
+    ExtendingFinalClass = 1109,
+    CyclicInheritance = 1110,
+    MustOverrideAMethod = 1111,
+    CannotOverrideTypeParameterizedMethod = 1112,
+    CannotIntroduceTypeParameters = 1113,
+    IncompatibleOverride = 1114,
+    OverridingFinalMethod = 1115,
+    AbstractMethodNotImplemented = 1116,
+    UnimplementedInterfaceMethod = 1117,
+    UnimplementedInterfaceGetter = 1118,
+    UnimplementedInterfaceSetter = 1119,
+    InterfacePropertyMustBeMethod = 1120,
+    InterfacePropertyMustBeVirtualProperty = 1121,
+    WrongInterfaceMethodSignature = 1122,
+    WrongInterfaceGetterSignature = 1123,
+    WrongInterfaceSetterSignature = 1124,
+    WrongInterfaceImplementationVisibility = 1125,
+    NonConformingInterfaceTypeParameters = 1126,
+    UnsupportedEnumRepresentationType = 1127,
+    ConstantExpressionExpected = 1128,
+    EnumMemberValueOutOfRange = 1129,
+    DuplicateEnumMemberValue = 1130,
+    UnreachableCode = 1131,
+    NotAllPathsReturnValue = 1132,
+    VariableUsedBeforeAssignment = 1133,
+    UnsupportedDestructuringType = 1134,
+    DestructuringExceedingTupleElements = 1135,
+    NotIterable = 1136,
+    NotAnErrorType = 1137,
+    NonExhaustiveSwitch = 1138,
+    UnreachableCase = 1139,
+    UnknownRegExpFlag = 1140,
+    DuplicateRegExpFlag = 1141,
+    UnterminatedRegExpGroup = 1142,
+    UnmatchedRegExpParenthesis = 1143,
+    InvalidRegExpGroup = 1144,
+    InvalidRegExpGroupName = 1145,
+    DuplicateRegExpGroupName = 1146,
+    InvalidRegExpGroupReference = 1147,
+    UnterminatedRegExpCharacterClass = 1148,
+    InvalidRegExpCharacterClassRange = 1149,
+    NothingToRepeatInRegExp = 1150,
+    RegExpQuantifierOutOfOrder = 1151,
+    InvalidRegExpEscape = 1152,
+    UnescapedRegExpCharacter = 1153,
+    XmlClosingTagMismatch = 1154,
+    DuplicateXmlAttribute = 1155,
+    UndeclaredXmlNamespacePrefix = 1156,
+    UnsupportedAttributeAccess = 1157,
+    LongConstantOverflow = 1158,
+    IllegalSuperExpression = 1159,
+    GeneratorResultTypeMustBeIterator = 1160,
+    UndefinedPackage = 1161,
 }
 
 impl DiagnosticKind {
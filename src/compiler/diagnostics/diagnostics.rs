@@ -33,7 +33,7 @@ impl Ord for Diagnostic {
 
 impl PartialOrd for Diagnostic {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.location.partial_cmp(&other.location)
+        Some(self.cmp(other))
     }
 }
 
@@ -73,7 +73,7 @@ impl Diagnostic {
     }
 
     pub fn kind(&self) -> DiagnosticKind {
-        self.kind.clone()
+        self.kind
     }
 
     pub fn is_warning(&self) -> bool {
@@ -116,10 +116,8 @@ impl Diagnostic {
 
     pub fn format_message(&self, messages: &HashMap<i32, String>) -> String {
         let mut string_arguments: HashMap<String, String> = hashmap!{};
-        let mut i = 1;
-        for argument in &self.arguments {
-            string_arguments.insert(i.to_string(), self.format_argument(argument.clone()));
-            i += 1;
+        for (i, argument) in self.arguments.iter().enumerate() {
+            string_arguments.insert((i + 1).to_string(), self.format_argument(argument.clone()));
         }
         use late_format::LateFormat;
         let Some(msg) = messages.get(&self.id()) else {
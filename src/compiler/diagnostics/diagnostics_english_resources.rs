@@ -73,6 +73,76 @@ lazy_static! {
         DiagnosticKind::UndefinedProperty.id() => "Access of undefined property '{1}'".into(),
         DiagnosticKind::IncompatibleFieldKey.id() => "Incompatible field key".into(),
         DiagnosticKind::MissingPropertyInLiteral.id() => "Missing property in literal: '{1}'".into(),
+        DiagnosticKind::UnresolvedReference.id() => "Unresolved reference".into(),
+        DiagnosticKind::UnknownConfigurationConstant.id() => "Unknown configuration constant: '{1}'".into(),
+        DiagnosticKind::UnsupportedOperator.id() => "Operator not supported by type '{1}'".into(),
+        DiagnosticKind::WrongNumberOfArguments.id() => "Wrong number of arguments: expected {1}".into(),
+        DiagnosticKind::NotCallable.id() => "Value of type '{1}' is not callable".into(),
+        DiagnosticKind::MissingArgument.id() => "Missing argument for parameter '{1}'".into(),
+        DiagnosticKind::IncompatibleArgument.id() => "Incompatible argument for parameter '{1}': expected '{2}', got '{3}'".into(),
+        DiagnosticKind::CouldNotInferTypeArgument.id() => "Could not infer type argument for '{1}'".into(),
+        DiagnosticKind::CannotInstantiate.id() => "Cannot instantiate type '{1}'".into(),
+        DiagnosticKind::WrongNumberOfTypeArguments.id() => "Wrong number of type arguments: expected {1}".into(),
+        DiagnosticKind::DuplicateDefinition.id() => "'{1}' is already defined".into(),
+        DiagnosticKind::VoidFunctionMustNotReturnValue.id() => "Void function must not return a value".into(),
+        DiagnosticKind::ReturnValueExpected.id() => "Expected a return value of type '{1}'".into(),
+        DiagnosticKind::YieldOutsideGenerator.id() => "'yield' is only allowed in generators".into(),
+        DiagnosticKind::AwaitOutsideAsynchronousFunction.id() => "'await' is only allowed in asynchronous functions".into(),
+        DiagnosticKind::NotAClass.id() => "'{1}' is not a class".into(),
+        DiagnosticKind::NotAnInterface.id() => "'{1}' is not an interface".into(),
+        DiagnosticKind::ExtendingFinalClass.id() => "Cannot extend final class '{1}'".into(),
+        DiagnosticKind::CyclicInheritance.id() => "Cyclic inheritance involving '{1}'".into(),
+        DiagnosticKind::MustOverrideAMethod.id() => "'{1}' must override a method".into(),
+        DiagnosticKind::CannotOverrideTypeParameterizedMethod.id() => "Cannot override type parameterized method '{1}'".into(),
+        DiagnosticKind::CannotIntroduceTypeParameters.id() => "Overriding method '{1}' must not introduce type parameters".into(),
+        DiagnosticKind::IncompatibleOverride.id() => "Incompatible override: expected '{1}', got '{2}'".into(),
+        DiagnosticKind::OverridingFinalMethod.id() => "Cannot override final method '{1}'".into(),
+        DiagnosticKind::AbstractMethodNotImplemented.id() => "Abstract method '{1}' is not implemented".into(),
+        DiagnosticKind::UnimplementedInterfaceMethod.id() => "Method '{1}' of interface '{2}' is not implemented".into(),
+        DiagnosticKind::UnimplementedInterfaceGetter.id() => "Getter '{1}' of interface '{2}' is not implemented".into(),
+        DiagnosticKind::UnimplementedInterfaceSetter.id() => "Setter '{1}' of interface '{2}' is not implemented".into(),
+        DiagnosticKind::InterfacePropertyMustBeMethod.id() => "'{1}' must be a method to implement interface '{2}'".into(),
+        DiagnosticKind::InterfacePropertyMustBeVirtualProperty.id() => "'{1}' must be a getter or setter to implement interface '{2}'".into(),
+        DiagnosticKind::WrongInterfaceMethodSignature.id() => "Method '{1}' must have signature '{2}' to implement interface '{3}'".into(),
+        DiagnosticKind::WrongInterfaceGetterSignature.id() => "Getter '{1}' must have signature '{2}' to implement interface '{3}'".into(),
+        DiagnosticKind::WrongInterfaceSetterSignature.id() => "Setter '{1}' must have signature '{2}' to implement interface '{3}'".into(),
+        DiagnosticKind::WrongInterfaceImplementationVisibility.id() => "'{1}' must be {2} to implement interface '{3}'".into(),
+        DiagnosticKind::NonConformingInterfaceTypeParameters.id() => "Type parameters of '{1}' do not conform to interface '{2}'".into(),
+        DiagnosticKind::UnsupportedEnumRepresentationType.id() => "Unsupported enumeration representation type: '{1}'".into(),
+        DiagnosticKind::ConstantExpressionExpected.id() => "Expected a constant expression".into(),
+        DiagnosticKind::EnumMemberValueOutOfRange.id() => "Value of enumeration member '{1}' is out of the range of '{2}'".into(),
+        DiagnosticKind::DuplicateEnumMemberValue.id() => "Enumeration member '{1}' has the same value as '{2}'".into(),
+        DiagnosticKind::UnreachableCode.id() => "Unreachable code".into(),
+        DiagnosticKind::NotAllPathsReturnValue.id() => "Not all code paths return a value of type '{1}'".into(),
+        DiagnosticKind::VariableUsedBeforeAssignment.id() => "Variable '{1}' is used before being assigned".into(),
+        DiagnosticKind::UnsupportedDestructuringType.id() => "Cannot destructure value of type '{1}'".into(),
+        DiagnosticKind::DestructuringExceedingTupleElements.id() => "Destructuring exceeding tuple elements: '{1}'".into(),
+        DiagnosticKind::NotIterable.id() => "Cannot iterate value of type '{1}'".into(),
+        DiagnosticKind::NotAnErrorType.id() => "'{1}' is not an error class".into(),
+        DiagnosticKind::NonExhaustiveSwitch.id() => "Switch is not exhaustive; missing: {1}".into(),
+        DiagnosticKind::UnreachableCase.id() => "Unreachable case".into(),
+        DiagnosticKind::UnknownRegExpFlag.id() => "Unknown regular expression flag: '{1}'".into(),
+        DiagnosticKind::DuplicateRegExpFlag.id() => "Duplicate regular expression flag: '{1}'".into(),
+        DiagnosticKind::UnterminatedRegExpGroup.id() => "Unterminated group in regular expression".into(),
+        DiagnosticKind::UnmatchedRegExpParenthesis.id() => "Unmatched ')' in regular expression".into(),
+        DiagnosticKind::InvalidRegExpGroup.id() => "Invalid group in regular expression".into(),
+        DiagnosticKind::InvalidRegExpGroupName.id() => "Invalid capture group name in regular expression".into(),
+        DiagnosticKind::DuplicateRegExpGroupName.id() => "Duplicate capture group name: '{1}'".into(),
+        DiagnosticKind::InvalidRegExpGroupReference.id() => "Invalid group reference: '{1}'".into(),
+        DiagnosticKind::UnterminatedRegExpCharacterClass.id() => "Unterminated character class in regular expression".into(),
+        DiagnosticKind::InvalidRegExpCharacterClassRange.id() => "Invalid range in character class".into(),
+        DiagnosticKind::NothingToRepeatInRegExp.id() => "Nothing to repeat in regular expression".into(),
+        DiagnosticKind::RegExpQuantifierOutOfOrder.id() => "Numbers out of order in quantifier".into(),
+        DiagnosticKind::InvalidRegExpEscape.id() => "Invalid escape in regular expression".into(),
+        DiagnosticKind::UnescapedRegExpCharacter.id() => "Character '{1}' must be escaped in a Unicode regular expression".into(),
+        DiagnosticKind::XmlClosingTagMismatch.id() => "Closing tag '{1}' does not match opening tag '{2}'".into(),
+        DiagnosticKind::DuplicateXmlAttribute.id() => "Duplicate attribute '{1}'".into(),
+        DiagnosticKind::UndeclaredXmlNamespacePrefix.id() => "Undeclared namespace prefix '{1}'".into(),
+        DiagnosticKind::UnsupportedAttributeAccess.id() => "Attribute access requires a base of type 'XML', 'XMLList' or '*'".into(),
+        DiagnosticKind::LongConstantOverflow.id() => "Constant expression overflows the range of 'Long'".into(),
+        DiagnosticKind::IllegalSuperExpression.id() => "Illegal super expression".into(),
+        DiagnosticKind::GeneratorResultTypeMustBeIterator.id() => "The result type of a generator must be 'Iterator' or '*', not '{1}'".into(),
+        DiagnosticKind::UndefinedPackage.id() => "Package '{1}' is not defined".into(),
         // DiagnosticKind::K.id() => "".into(),
     };
 }
\ No newline at end of file
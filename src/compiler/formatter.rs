@@ -0,0 +1,7 @@
+//! Source formatter, regenerating Jet source from a parsed `Program`.
+
+#[allow(clippy::module_inception)]
+mod formatter;
+pub use formatter::*;
+mod formatter_options;
+pub use formatter_options::*;
@@ -0,0 +1,1482 @@
+use crate::ns::*;
+
+/// Source formatter, regenerating Jet source from a parsed `Program`.
+///
+/// Comments kept in `CompilationUnit::comments()` are written before the
+/// directive that follows them, or after a directive that ends in
+/// the line where they start. JetDoc comments are written from the
+/// source text of the directives that hold them; meta-data and invalid
+/// nodes are written verbatim.
+///
+/// Reparsing the formatted output produces the same AST as the original
+/// program, given `AttributeOrdering::Preserve`; formatting the output
+/// again produces the same text.
+///
+/// ```ignore
+/// let program = ParserFacade::parse_program(&compilation_unit, &host).unwrap();
+/// let text = Formatter::new(&FormatterOptions::default()).format(&program);
+/// ```
+pub struct Formatter {
+    options: FormatterOptions,
+}
+
+impl Formatter {
+    pub fn new(options: &FormatterOptions) -> Self {
+        Self {
+            options: options.clone(),
+        }
+    }
+
+    /// Formats a program. The resulting lines are separated by
+    /// `\n` and the text, unless empty, ends with a line break.
+    pub fn format(&self, program: &Program) -> String {
+        let compilation_unit = program.location.compilation_unit();
+        let comments = compilation_unit.comments();
+        let mut formatter = ProgramFormatter::new(&self.options, &compilation_unit, comments, false);
+        formatter.write_program(program);
+        formatter.output
+    }
+}
+
+struct ProgramFormatter<'a> {
+    options: &'a FormatterOptions,
+    compilation_unit: Rc<CompilationUnit>,
+    output: String,
+    level: usize,
+    column: usize,
+    at_line_start: bool,
+    /// Whether the formatter measures the width of a first line,
+    /// in which case lists are never broken.
+    measuring: bool,
+    /// Comments not yet written, in source order.
+    comments: Vec<Rc<Comment>>,
+    /// Last source offset written so far.
+    previous_end: usize,
+    /// Whether a blank line may precede the next directive or comment.
+    blank_line_allowed: bool,
+}
+
+impl<'a> ProgramFormatter<'a> {
+    fn new(options: &'a FormatterOptions, compilation_unit: &Rc<CompilationUnit>, comments: Vec<Rc<Comment>>, measuring: bool) -> Self {
+        Self {
+            options,
+            compilation_unit: compilation_unit.clone(),
+            output: String::new(),
+            level: 0,
+            column: 0,
+            at_line_start: true,
+            measuring,
+            comments,
+            previous_end: 0,
+            blank_line_allowed: false,
+        }
+    }
+
+    fn write(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        if self.at_line_start {
+            self.at_line_start = false;
+            let indentation = self.options.indentation.unit().repeat(self.level);
+            self.output.push_str(&indentation);
+            self.column = self.level * self.options.indentation.width();
+        }
+        for ch in text.chars() {
+            if ch == '\n' {
+                self.column = 0;
+            } else {
+                self.column += 1;
+            }
+        }
+        self.output.push_str(text);
+    }
+
+    /// Terminates the current line. The indentation of the next line
+    /// is written lazily.
+    fn line(&mut self) {
+        self.output.push('\n');
+        self.column = 0;
+        self.at_line_start = true;
+    }
+
+    fn current_column(&self) -> usize {
+        if self.at_line_start {
+            self.level * self.options.indentation.width()
+        } else {
+            self.column
+        }
+    }
+
+    /// Width of the first line written by `f`.
+    fn measure(&self, f: impl FnOnce(&mut Self)) -> usize {
+        let mut formatter = ProgramFormatter::new(self.options, &self.compilation_unit, vec![], true);
+        formatter.at_line_start = false;
+        f(&mut formatter);
+        formatter.output.split('\n').next().unwrap().chars().count()
+    }
+
+    /// Writes a comma separated list, breaking it into one item per line
+    /// if it does not fit in the line width.
+    fn write_list<T>(&mut self, open: &str, items: &[T], close: &str, write_item: impl Fn(&mut Self, &T)) {
+        let flat = self.measuring || items.is_empty() || {
+            let width = self.measure(|formatter| formatter.write_flat_list(open, items, close, &write_item));
+            self.current_column() + width <= self.options.line_width
+        };
+        if flat {
+            self.write_flat_list(open, items, close, &write_item);
+            return;
+        }
+        self.write(open);
+        self.line();
+        self.level += 1;
+        for (i, item) in items.iter().enumerate() {
+            write_item(self, item);
+            if i + 1 < items.len() {
+                self.write(",");
+            }
+            self.line();
+        }
+        self.level -= 1;
+        self.write(close);
+    }
+
+    fn write_flat_list<T>(&mut self, open: &str, items: &[T], close: &str, write_item: &impl Fn(&mut Self, &T)) {
+        self.write(open);
+        for (i, item) in items.iter().enumerate() {
+            if i != 0 {
+                self.write(", ");
+            }
+            write_item(self, item);
+        }
+        self.write(close);
+    }
+
+    /// Writes source text as is, discarding the comments that it contains.
+    fn write_verbatim(&mut self, location: &Location) {
+        self.comments.retain(|comment| {
+            let offset = comment.location().first_offset();
+            offset < location.first_offset() || offset >= location.last_offset()
+        });
+        self.write(&location.text().replace("\r\n", "\n"));
+    }
+
+    fn write_program(&mut self, program: &Program) {
+        for package in &program.packages {
+            self.write_package_definition(package);
+        }
+        for directive in &program.directives {
+            self.write_directive_item(directive);
+        }
+        self.write_leading_comments(usize::MAX);
+    }
+
+    fn write_package_definition(&mut self, package: &PackageDefinition) {
+        self.begin_item(package.location.first_offset(), package.jetdoc.as_ref());
+        self.write("package");
+        if !package.name.is_empty() {
+            self.write(" ");
+            self.write(&package.name.iter().map(|name| name.0.clone()).collect::<Vec<_>>().join("."));
+        }
+        self.write_header_block(&package.block);
+        self.end_item(&package.location);
+    }
+
+    fn write_directive_item(&mut self, directive: &Rc<Directive>) {
+        self.begin_item(directive.location().first_offset(), directive.jetdoc().as_ref());
+        self.write_directive(directive);
+        self.end_item(&directive.location());
+    }
+
+    /// Writes the comments, blank line and JetDoc that precede
+    /// a directive. `start` excludes the JetDoc.
+    fn begin_item(&mut self, start: usize, jetdoc: Option<&Rc<JetDoc>>) {
+        let start = jetdoc.map(|jetdoc| jetdoc.location.first_offset() - 3).unwrap_or(start);
+        self.write_leading_comments(start);
+        if let Some(jetdoc) = jetdoc {
+            self.write_blank_line_before(start);
+            self.write_jetdoc_text(&jetdoc.location.text());
+            self.line();
+        } else if let Some((offset, text)) = self.dropped_jetdoc(start) {
+            // JetDoc comments preceding statements are consumed
+            // by the parser without being attached to the statement.
+            self.write_blank_line_before(offset);
+            self.write_jetdoc_text(&text);
+            self.line();
+        } else {
+            self.write_blank_line_before(start);
+        }
+    }
+
+    fn end_item(&mut self, location: &Location) {
+        let end = self.item_end(location);
+        self.write_trailing_comments(end);
+        self.line();
+        self.advance(end);
+    }
+
+    /// Last offset of a directive, excluding the whitespace and
+    /// comments that a missing semicolon leaves in its location.
+    fn item_end(&self, location: &Location) -> usize {
+        let text = self.compilation_unit.text();
+        let first = location.first_offset();
+        let mut end = location.last_offset();
+        loop {
+            end = first + text[first..end].trim_end().len();
+            if let Some(comment) = self.comments.iter().find(|comment| comment.location().last_offset() == end && comment.location().first_offset() >= first) {
+                end = comment.location().first_offset();
+                continue;
+            }
+            if text[first..end].ends_with("*/") {
+                if let Some(i) = text[first..end].rfind("/**") {
+                    if end - (first + i) >= 5 && !text[(first + i + 3)..(end - 2)].contains("*/") {
+                        end = first + i;
+                        continue;
+                    }
+                }
+            }
+            return end;
+        }
+    }
+
+    fn advance(&mut self, offset: usize) {
+        self.previous_end = self.previous_end.max(offset);
+        self.blank_line_allowed = true;
+    }
+
+    /// Preserves a blank line between the previous item and
+    /// the item starting at `offset`.
+    fn write_blank_line_before(&mut self, offset: usize) {
+        if !self.blank_line_allowed || self.measuring || offset <= self.previous_end {
+            return;
+        }
+        let compilation_unit = self.compilation_unit.clone();
+        if compilation_unit.text()[self.previous_end..offset].matches('\n').count() > 1 {
+            self.line();
+        }
+    }
+
+    /// Writes the comments that start before `offset`, each on its own line.
+    fn write_leading_comments(&mut self, offset: usize) {
+        while let Some(comment) = self.comments.first().cloned() {
+            let location = comment.location();
+            if location.first_offset() >= offset {
+                break;
+            }
+            self.comments.remove(0);
+            self.write_blank_line_before(location.first_offset());
+            self.write_comment(&comment);
+            self.line();
+            self.advance(location.last_offset());
+        }
+    }
+
+    /// Writes the comments that follow `end` in the same line.
+    fn write_trailing_comments(&mut self, end: usize) {
+        let compilation_unit = self.compilation_unit.clone();
+        let text = compilation_unit.text();
+        let mut line_end = end;
+        let mut i = 0;
+        while i < self.comments.len() {
+            let location = self.comments[i].location();
+            if location.first_offset() < end {
+                i += 1;
+                continue;
+            }
+            if !text[line_end..location.first_offset()].trim().chars().all(|ch| ch == ';') {
+                break;
+            }
+            let comment = self.comments.remove(i);
+            self.write(" ");
+            self.write_comment(&comment);
+            line_end = location.last_offset();
+            self.advance(line_end);
+            if !comment.multiline() {
+                break;
+            }
+        }
+    }
+
+    fn write_comment(&mut self, comment: &Comment) {
+        let content = comment.content().replace("\r\n", "\n");
+        if comment.multiline() {
+            self.write("/*");
+            // Keep the comment from being read as JetDoc.
+            if content.starts_with('*') {
+                self.write(" ");
+            }
+            self.write(&content);
+            self.write("*/");
+        } else {
+            self.write("//");
+            self.write(content.trim_end());
+        }
+    }
+
+    /// Finds a JetDoc comment that immediately precedes `start` and that
+    /// is not part of the compilation unit comments, returning its offset and
+    /// the text between `/**` and `*/`.
+    fn dropped_jetdoc(&self, start: usize) -> Option<(usize, String)> {
+        if start < self.previous_end {
+            return None;
+        }
+        let text = self.compilation_unit.text();
+        let before = text[self.previous_end..start].trim_end();
+        if !before.ends_with("*/") {
+            return None;
+        }
+        let i = before.rfind("/**")?;
+        if before.len() - i < 5 {
+            return None;
+        }
+        let content = &before[(i + 3)..(before.len() - 2)];
+        if content.contains("*/") {
+            return None;
+        }
+        Some((self.previous_end + i, content.to_owned()))
+    }
+
+    /// Writes a JetDoc comment given the text between `/**` and `*/`.
+    /// Continuation lines are reindented, which does not affect
+    /// the JetDoc content.
+    fn write_jetdoc_text(&mut self, text: &str) {
+        let text = text.replace("\r\n", "\n");
+        let mut lines = text.split('\n').peekable();
+        self.write("/**");
+        self.write(lines.next().unwrap());
+        while let Some(line) = lines.next() {
+            self.line();
+            let line = line.trim_start();
+            if !line.is_empty() || lines.peek().is_none() {
+                self.write(" ");
+                self.write(line);
+            }
+        }
+        self.write("*/");
+    }
+
+    fn write_attributes(&mut self, attributes: &[Attribute], implicit_metadata: Option<&str>) {
+        if let Some(metadata) = implicit_metadata {
+            self.write(metadata);
+            self.line();
+        }
+        for attribute in attributes {
+            if let Attribute::Metadata((_, location)) = attribute {
+                self.write("[");
+                self.write_verbatim(location);
+                self.write("]");
+                self.line();
+            }
+        }
+        let mut keywords: Vec<&Attribute> = attributes.iter().filter(|attribute| !attribute.is_metadata()).collect();
+        if self.options.attribute_ordering == AttributeOrdering::Canonical {
+            keywords.sort_by_key(|attribute| canonical_attribute_order(attribute));
+        }
+        for attribute in keywords {
+            self.write(attribute_keyword(attribute));
+            self.write(" ");
+        }
+    }
+
+    /// Writes the separator between a header and its block.
+    fn write_brace_separator(&mut self) {
+        if self.options.brace_style == BraceStyle::NextLine {
+            self.line();
+        } else {
+            self.write(" ");
+        }
+    }
+
+    /// Writes the separator preceding `else`, `while`, `catch` and `finally`.
+    fn write_clause_separator(&mut self, after_block: bool) {
+        if after_block && self.options.brace_style == BraceStyle::SameLine {
+            self.write(" ");
+        } else {
+            self.line();
+        }
+    }
+
+    fn write_header_block(&mut self, block: &Block) {
+        self.write_brace_separator();
+        self.write_block(block);
+    }
+
+    /// Writes a block from its opening brace to its closing brace.
+    fn write_block(&mut self, block: &Block) {
+        let end = block.location.last_offset();
+        let has_comments = self.comments.first().map(|comment| comment.location().first_offset() < end).unwrap_or(false);
+        if block.directives.is_empty() && !has_comments {
+            self.write("{}");
+            return;
+        }
+        self.write("{");
+        self.line();
+        self.level += 1;
+        self.previous_end = self.previous_end.max(block.location.first_offset());
+        self.blank_line_allowed = false;
+        for directive in &block.directives {
+            self.write_directive_item(directive);
+        }
+        self.write_leading_comments(end);
+        self.level -= 1;
+        self.write("}");
+    }
+
+    /// Writes the body of a statement, returning whether it is a block.
+    fn write_substatement(&mut self, directive: &Rc<Directive>) -> bool {
+        if let Directive::Block(block) = directive.as_ref() {
+            if block.metadata.is_none() {
+                self.write_header_block(block);
+                return true;
+            }
+        }
+        self.line();
+        self.level += 1;
+        self.blank_line_allowed = false;
+        self.begin_item(directive.location().first_offset(), None);
+        self.write_directive(directive);
+        let end = self.item_end(&directive.location());
+        self.write_trailing_comments(end);
+        self.advance(end);
+        self.level -= 1;
+        false
+    }
+
+    fn write_directive(&mut self, directive: &Directive) {
+        match directive {
+            Directive::EmptyStatement(_) => self.write(";"),
+            Directive::ExpressionStatement(statement) => {
+                self.write_expression(&statement.expression);
+                self.write(";");
+            },
+            Directive::SuperStatement(statement) => {
+                self.write("super");
+                self.write_arguments(&statement.arguments);
+                self.write(";");
+            },
+            Directive::Block(block) => {
+                for attribute in block.metadata.iter().flatten() {
+                    if let Attribute::Metadata((_, location)) = attribute {
+                        self.write("[");
+                        self.write_verbatim(location);
+                        self.write("] ");
+                    }
+                }
+                self.write_block(block);
+            },
+            Directive::LabeledStatement(statement) => {
+                self.write(&statement.label.0);
+                self.write(": ");
+                self.write_directive(&statement.substatement);
+            },
+            Directive::IfStatement(statement) => self.write_if_statement(statement),
+            Directive::SwitchStatement(statement) => self.write_switch_statement(statement),
+            Directive::SwitchTypeStatement(statement) => self.write_switch_type_statement(statement),
+            Directive::DoStatement(statement) => {
+                self.write("do");
+                let block = self.write_substatement(&statement.body);
+                self.write_clause_separator(block);
+                self.write("while (");
+                self.write_expression(&statement.test);
+                self.write(");");
+            },
+            Directive::WhileStatement(statement) => {
+                self.write("while (");
+                self.write_expression(&statement.test);
+                self.write(")");
+                self.write_substatement(&statement.body);
+            },
+            Directive::ForStatement(statement) => {
+                self.write("for (");
+                match &statement.init {
+                    Some(ForInitializer::Expression(expression)) => self.write_expression(expression),
+                    Some(ForInitializer::VariableDefinition(definition)) => {
+                        self.write_variable_definition_kind(&definition.kind.0);
+                        self.write_variable_bindings(&definition.bindings);
+                    },
+                    None => {},
+                }
+                self.write(";");
+                if let Some(test) = &statement.test {
+                    self.write(" ");
+                    self.write_expression(test);
+                }
+                self.write(";");
+                if let Some(update) = &statement.update {
+                    self.write(" ");
+                    self.write_expression(update);
+                }
+                self.write(")");
+                self.write_substatement(&statement.body);
+            },
+            Directive::ForInStatement(statement) => {
+                self.write(if statement.each { "for each (" } else { "for (" });
+                match &statement.left {
+                    ForInBinding::Expression(expression) => self.write_expression(expression),
+                    ForInBinding::VariableDefinition(definition) => {
+                        self.write_variable_definition_kind(&definition.kind.0);
+                        self.write_variable_bindings(&definition.bindings);
+                    },
+                }
+                self.write(" in ");
+                self.write_expression(&statement.right);
+                self.write(")");
+                self.write_substatement(&statement.body);
+            },
+            Directive::BreakStatement(statement) => {
+                self.write("break");
+                if let Some(label) = &statement.label {
+                    self.write(" ");
+                    self.write(&label.0);
+                }
+                self.write(";");
+            },
+            Directive::ContinueStatement(statement) => {
+                self.write("continue");
+                if let Some(label) = &statement.label {
+                    self.write(" ");
+                    self.write(&label.0);
+                }
+                self.write(";");
+            },
+            Directive::WithStatement(statement) => {
+                self.write("with (");
+                self.write_expression(&statement.object);
+                self.write(")");
+                self.write_substatement(&statement.body);
+            },
+            Directive::ReturnStatement(statement) => {
+                self.write("return");
+                if let Some(expression) = &statement.expression {
+                    self.write(" ");
+                    self.write_expression(expression);
+                }
+                self.write(";");
+            },
+            Directive::ThrowStatement(statement) => {
+                self.write("throw ");
+                self.write_expression(&statement.expression);
+                self.write(";");
+            },
+            Directive::DefaultXmlNamespaceStatement(statement) => {
+                self.write("default xml namespace = ");
+                self.write_expression(&statement.right);
+                self.write(";");
+            },
+            Directive::TryStatement(statement) => {
+                self.write("try");
+                self.write_header_block(&statement.block);
+                for catch_clause in &statement.catch_clauses {
+                    self.write_clause_separator(true);
+                    self.write("catch (");
+                    self.write_typed_destructuring(&catch_clause.parameter);
+                    self.write(")");
+                    self.write_header_block(&catch_clause.block);
+                }
+                if let Some(finally_clause) = &statement.finally_clause {
+                    self.write_clause_separator(true);
+                    self.write("finally");
+                    self.write_header_block(&finally_clause.block);
+                }
+            },
+            Directive::ConfigurationDirective(directive) => {
+                self.write("configuration");
+                self.write_brace_separator();
+                self.write("{");
+                self.line();
+                self.level += 1;
+                self.write_configuration_subdirective(&directive.directive);
+                self.line();
+                self.level -= 1;
+                self.write("}");
+            },
+            Directive::ImportDirective(directive) => {
+                self.write("import ");
+                self.write_import_target(&directive.alias, &directive.package_name, &directive.import_specifier);
+                self.write(";");
+            },
+            Directive::UseDirective(directive) => {
+                self.write_attributes(&directive.attributes, None);
+                self.write("use ");
+                self.write_import_target(&directive.alias, &directive.package_name, &directive.import_specifier);
+                self.write(";");
+            },
+            Directive::VariableDefinition(definition) => {
+                self.write_attributes(&definition.attributes, None);
+                self.write_variable_definition_kind(&definition.kind.0);
+                self.write_variable_bindings(&definition.bindings);
+                self.write(";");
+            },
+            Directive::FunctionDefinition(definition) => self.write_function_definition(definition),
+            Directive::ClassDefinition(definition) => {
+                self.write_attributes(&definition.attributes, if definition.allow_literal { Some("[Literal]") } else { None });
+                self.write("class ");
+                self.write(&definition.name.0);
+                self.write_type_parameters(&definition.type_parameters);
+                if let Some(extends_clause) = &definition.extends_clause {
+                    self.write(" extends ");
+                    self.write_expression(extends_clause);
+                }
+                if let Some(implements_clause) = &definition.implements_clause {
+                    self.write(" implements ");
+                    self.write_expressions(implements_clause);
+                }
+                self.write_header_block(&definition.block);
+            },
+            Directive::EnumDefinition(definition) => {
+                self.write_attributes(&definition.attributes, if definition.is_set { Some("[Set]") } else { None });
+                self.write("enum ");
+                self.write(&definition.name.0);
+                if let Some(as_clause) = &definition.as_clause {
+                    self.write(" as ");
+                    self.write_expression(as_clause);
+                }
+                self.write_header_block(&definition.block);
+            },
+            Directive::InterfaceDefinition(definition) => {
+                self.write_attributes(&definition.attributes, None);
+                self.write("interface ");
+                self.write(&definition.name.0);
+                self.write_type_parameters(&definition.type_parameters);
+                if let Some(extends_clause) = &definition.extends_clause {
+                    self.write(" extends ");
+                    self.write_expressions(extends_clause);
+                }
+                self.write_header_block(&definition.block);
+            },
+            Directive::TypeDefinition(definition) => {
+                self.write_attributes(&definition.attributes, None);
+                self.write("type ");
+                self.write(&definition.left.0);
+                self.write(" = ");
+                self.write_expression(&definition.right);
+                self.write(";");
+            },
+            Directive::Invalid(directive) => self.write_verbatim(&directive.location),
+        }
+    }
+
+    fn write_if_statement(&mut self, statement: &IfStatement) {
+        self.write("if (");
+        self.write_expression(&statement.test);
+        self.write(")");
+        let block = self.write_substatement(&statement.consequent);
+        if let Some(alternative) = &statement.alternative {
+            self.write_clause_separator(block);
+            self.write("else");
+            if let Directive::IfStatement(alternative) = alternative.as_ref() {
+                self.write(" ");
+                self.write_if_statement(alternative);
+            } else {
+                self.write_substatement(alternative);
+            }
+        }
+    }
+
+    fn write_switch_statement(&mut self, statement: &SwitchStatement) {
+        self.write("switch (");
+        self.write_expression(&statement.discriminant);
+        self.write(")");
+        self.write_brace_separator();
+        let end = statement.location.last_offset();
+        self.write("{");
+        self.line();
+        self.level += 1;
+        self.blank_line_allowed = false;
+        for case in &statement.cases {
+            self.begin_item(case.location.first_offset(), None);
+            for label in &case.labels {
+                match label {
+                    CaseLabel::Case((expression, _)) => {
+                        self.write("case ");
+                        self.write_expression(expression);
+                        self.write(":");
+                    },
+                    CaseLabel::Default(_) => self.write("default:"),
+                }
+                self.line();
+            }
+            self.level += 1;
+            self.blank_line_allowed = false;
+            for directive in &case.directives {
+                self.write_directive_item(directive);
+            }
+            self.level -= 1;
+        }
+        self.write_leading_comments(end);
+        self.level -= 1;
+        self.write("}");
+    }
+
+    fn write_switch_type_statement(&mut self, statement: &SwitchTypeStatement) {
+        self.write("switch type (");
+        self.write_expression(&statement.discriminant);
+        self.write(")");
+        self.write_brace_separator();
+        let end = statement.location.last_offset();
+        self.write("{");
+        self.line();
+        self.level += 1;
+        self.blank_line_allowed = false;
+        for case in &statement.cases {
+            self.begin_item(case.location.first_offset(), None);
+            if let Some(parameter) = &case.parameter {
+                self.write("case (");
+                self.write_typed_destructuring(parameter);
+                self.write(")");
+            } else {
+                self.write("default");
+            }
+            self.write_header_block(&case.block);
+            self.end_item(&case.location);
+        }
+        self.write_leading_comments(end);
+        self.level -= 1;
+        self.write("}");
+    }
+
+    fn write_configuration_subdirective(&mut self, directive: &Directive) {
+        match directive {
+            Directive::IfStatement(statement) => {
+                self.write("if (");
+                self.write_configuration_expression(&statement.test);
+                self.write(")");
+                self.write_brace_separator();
+                self.write_directive(&statement.consequent);
+                if let Some(alternative) = &statement.alternative {
+                    self.write_clause_separator(true);
+                    self.write("else");
+                    if matches!(alternative.as_ref(), Directive::IfStatement(_)) {
+                        self.write(" ");
+                    } else {
+                        self.write_brace_separator();
+                    }
+                    self.write_configuration_subdirective(alternative);
+                }
+            },
+            directive => self.write_directive(directive),
+        }
+    }
+
+    fn write_configuration_expression(&mut self, expression: &Expression) {
+        match expression {
+            Expression::QualifiedIdentifier(QualifiedIdentifier { id: QualifiedIdentifierIdentifier::Id((name, _)), .. }) => {
+                self.write(name);
+            },
+            Expression::Binary(expression) => {
+                self.write_configuration_expression(&expression.left);
+                match expression.operator {
+                    Operator::Equals => self.write("="),
+                    Operator::NotEquals => self.write("!="),
+                    operator => {
+                        self.write(" ");
+                        self.write(binary_operator_text(operator));
+                        self.write(" ");
+                    },
+                }
+                if let Expression::StringLiteral(StringLiteral { value, .. }) = expression.right.as_ref() {
+                    if is_identifier_name(value) {
+                        self.write(value);
+                    } else {
+                        self.write_string_literal(value);
+                    }
+                } else {
+                    self.write_configuration_expression(&expression.right);
+                }
+            },
+            Expression::Paren(expression) => {
+                self.write("(");
+                self.write_configuration_expression(&expression.expression);
+                self.write(")");
+            },
+            Expression::Unary(expression) => {
+                self.write("!");
+                self.write_configuration_expression(&expression.expression);
+            },
+            expression => self.write_expression(expression),
+        }
+    }
+
+    fn write_import_target(&mut self, alias: &Option<(String, Location)>, package_name: &[(String, Location)], import_specifier: &ImportSpecifier) {
+        if let Some(alias) = alias {
+            self.write(&alias.0);
+            self.write(" = ");
+        }
+        for name in package_name {
+            self.write(&name.0);
+            self.write(".");
+        }
+        match import_specifier {
+            ImportSpecifier::Wildcard(_) => self.write("*"),
+            ImportSpecifier::Identifier(name) => self.write(&name.0),
+        }
+    }
+
+    fn write_variable_definition_kind(&mut self, kind: &VariableDefinitionKind) {
+        self.write(match kind {
+            VariableDefinitionKind::Var => "var ",
+            VariableDefinitionKind::Const => "const ",
+        });
+    }
+
+    fn write_variable_bindings(&mut self, bindings: &[Rc<VariableBinding>]) {
+        for (i, binding) in bindings.iter().enumerate() {
+            if i != 0 {
+                self.write(", ");
+            }
+            self.write_typed_destructuring(&binding.destructuring);
+            if let Some(initializer) = &binding.initializer {
+                self.write(" = ");
+                self.write_expression(initializer);
+            }
+        }
+    }
+
+    fn write_typed_destructuring(&mut self, destructuring: &TypedDestructuring) {
+        self.write_expression(&destructuring.destructuring);
+        if let Some(type_annotation) = &destructuring.type_annotation {
+            self.write(": ");
+            self.write_expression(type_annotation);
+        }
+    }
+
+    fn write_type_parameters(&mut self, type_parameters: &Option<Vec<Rc<TypeParameter>>>) {
+        if let Some(type_parameters) = type_parameters {
+            self.write(".<");
+            self.write(&type_parameters.iter().map(|parameter| parameter.name.0.clone()).collect::<Vec<_>>().join(", "));
+            self.write(">");
+        }
+    }
+
+    fn write_function_definition(&mut self, definition: &FunctionDefinition) {
+        self.write_attributes(&definition.attributes, None);
+        self.write("function ");
+        match &definition.name {
+            FunctionName::Identifier(name) |
+            FunctionName::Constructor(name) |
+            FunctionName::Proxy(_, name) => self.write(&name.0),
+            FunctionName::Getter(name) => {
+                self.write("get ");
+                self.write(&name.0);
+            },
+            FunctionName::Setter(name) => {
+                self.write("set ");
+                self.write(&name.0);
+            },
+        }
+        self.write_type_parameters(&definition.type_parameters);
+        self.write_function_common(&definition.common, true);
+        if !matches!(definition.common.body, Some(FunctionBody::Block(_))) {
+            self.write(";");
+        }
+    }
+
+    fn write_function_common(&mut self, common: &FunctionCommon, definition: bool) {
+        self.write_function_signature(&common.signature);
+        match &common.body {
+            Some(FunctionBody::Block(block)) => {
+                if definition {
+                    self.write_header_block(block);
+                } else {
+                    self.write(" ");
+                    self.write_block(block);
+                }
+            },
+            Some(FunctionBody::Expression(expression)) => {
+                self.write(" ");
+                self.write_expression(expression);
+            },
+            None => {},
+        }
+    }
+
+    fn write_function_signature(&mut self, signature: &FunctionSignature) {
+        self.write_list("(", &signature.parameters, ")", |formatter, parameter| formatter.write_parameter(parameter));
+        if let Some(result_type) = &signature.result_type {
+            self.write(": ");
+            self.write_expression(result_type);
+        }
+    }
+
+    fn write_parameter(&mut self, parameter: &Parameter) {
+        if parameter.kind == ParameterKind::Rest {
+            self.write("...");
+        }
+        self.write_expression(&parameter.destructuring.destructuring);
+        // Optional parameters of function types
+        if parameter.kind == ParameterKind::Optional && parameter.default_value.is_none() {
+            self.write("?");
+        }
+        if let Some(type_annotation) = &parameter.destructuring.type_annotation {
+            self.write(": ");
+            self.write_expression(type_annotation);
+        }
+        if let Some(default_value) = &parameter.default_value {
+            self.write(" = ");
+            self.write_expression(default_value);
+        }
+    }
+
+    fn write_arguments(&mut self, arguments: &[Rc<Expression>]) {
+        self.write_list("(", arguments, ")", |formatter, argument| formatter.write_expression(argument));
+    }
+
+    fn write_expressions(&mut self, expressions: &[Rc<Expression>]) {
+        for (i, expression) in expressions.iter().enumerate() {
+            if i != 0 {
+                self.write(", ");
+            }
+            self.write_expression(expression);
+        }
+    }
+
+    fn write_expression(&mut self, expression: &Expression) {
+        match expression {
+            Expression::QualifiedIdentifier(id) => self.write_qualified_identifier(id),
+            Expression::Embed(expression) => {
+                self.write("embed ");
+                self.write_object_initializer(&expression.description);
+            },
+            Expression::Paren(expression) => {
+                self.write("(");
+                self.write_expression(&expression.expression);
+                self.write(")");
+            },
+            Expression::NullLiteral(_) => self.write("null"),
+            Expression::BooleanLiteral(literal) => self.write(if literal.value { "true" } else { "false" }),
+            Expression::NumericLiteral(literal) => self.write(&literal.value),
+            Expression::StringLiteral(literal) => self.write_string_literal(&literal.value),
+            Expression::ThisLiteral(_) => self.write("this"),
+            Expression::RegExpLiteral(literal) => {
+                self.write("/");
+                self.write(&literal.body);
+                self.write("/");
+                self.write(&literal.flags);
+            },
+            Expression::Xml(expression) => self.write_xml_element(&expression.element),
+            Expression::XmlMarkup(expression) => self.write(&expression.markup),
+            Expression::XmlList(expression) => {
+                self.write("<>");
+                self.write_xml_content(&expression.content);
+                self.write("</>");
+            },
+            Expression::ArrayLiteral(literal) => self.write_array_literal(literal),
+            Expression::ObjectInitializer(initializer) => self.write_object_initializer(initializer),
+            Expression::Function(expression) => {
+                self.write("function");
+                if let Some(name) = &expression.name {
+                    self.write(" ");
+                    self.write(&name.0);
+                }
+                self.write_function_common(&expression.common, false);
+            },
+            Expression::ImportMeta(_) => self.write("import.meta"),
+            Expression::New(expression) => {
+                self.write("new ");
+                self.write_expression(&expression.base);
+                if let Some(arguments) = &expression.arguments {
+                    self.write_arguments(arguments);
+                }
+            },
+            Expression::Member(expression) => {
+                self.write_expression(&expression.base);
+                if !matches!(expression.base.as_ref(), Expression::OptionalChainingPlaceholder(_)) {
+                    self.write(".");
+                }
+                self.write_qualified_identifier(&expression.identifier);
+            },
+            Expression::ComputedMember(expression) => {
+                self.write_expression(&expression.base);
+                self.write("[");
+                self.write_expression(&expression.key);
+                self.write("]");
+            },
+            Expression::Descendants(expression) => {
+                self.write_expression(&expression.base);
+                self.write("..");
+                self.write_qualified_identifier(&expression.identifier);
+            },
+            Expression::Filter(expression) => {
+                self.write_expression(&expression.base);
+                self.write(".(");
+                self.write_expression(&expression.test);
+                self.write(")");
+            },
+            Expression::Super(expression) => {
+                self.write("super");
+                if let Some(arguments) = &expression.object {
+                    self.write_arguments(arguments);
+                }
+            },
+            Expression::Call(expression) => {
+                self.write_expression(&expression.base);
+                self.write_arguments(&expression.arguments);
+            },
+            Expression::WithTypeArguments(expression) => {
+                self.write_expression(&expression.base);
+                self.write(".<");
+                self.write_expressions(&expression.arguments);
+                self.write(">");
+            },
+            Expression::Unary(expression) => self.write_unary_expression(expression),
+            Expression::OptionalChaining(expression) => {
+                self.write_expression(&expression.base);
+                self.write("?.");
+                self.write_expression(&expression.expression);
+            },
+            Expression::OptionalChainingPlaceholder(_) => {},
+            Expression::Binary(expression) => {
+                self.write_expression(&expression.left);
+                self.write(" ");
+                self.write(binary_operator_text(expression.operator));
+                self.write(" ");
+                self.write_expression(&expression.right);
+            },
+            Expression::Conditional(expression) => {
+                self.write_expression(&expression.test);
+                self.write(" ? ");
+                self.write_expression(&expression.consequent);
+                self.write(" : ");
+                self.write_expression(&expression.alternative);
+            },
+            Expression::Assignment(expression) => {
+                self.write_expression(&expression.left);
+                self.write(" ");
+                if let Some(compound) = expression.compound {
+                    self.write(binary_operator_text(compound));
+                }
+                self.write("= ");
+                self.write_expression(&expression.right);
+            },
+            Expression::Sequence(expression) => {
+                self.write_expression(&expression.left);
+                self.write(", ");
+                self.write_expression(&expression.right);
+            },
+            Expression::NullableType(expression) => {
+                if matches!(expression.base.as_ref(), Expression::FunctionType(_)) {
+                    self.write("?");
+                    self.write_expression(&expression.base);
+                } else {
+                    self.write_expression(&expression.base);
+                    self.write("?");
+                }
+            },
+            Expression::NonNullableType(expression) => {
+                self.write_expression(&expression.base);
+                self.write("!");
+            },
+            Expression::AnyType(_) => self.write("*"),
+            Expression::VoidType(_) => self.write("void"),
+            Expression::ArrayType(expression) => {
+                self.write("[");
+                self.write_expression(&expression.expression);
+                self.write("]");
+            },
+            Expression::TupleType(expression) => {
+                self.write("[");
+                self.write_expressions(&expression.expressions);
+                self.write("]");
+            },
+            Expression::FunctionType(expression) => {
+                self.write("function");
+                self.write_function_signature(&expression.signature);
+            },
+            Expression::Invalid(expression) => self.write_verbatim(&expression.location),
+        }
+    }
+
+    fn write_qualified_identifier(&mut self, id: &QualifiedIdentifier) {
+        if id.attribute {
+            self.write("@");
+        }
+        if let Some(qualifier) = &id.qualifier {
+            self.write_expression(qualifier);
+            self.write("::");
+        }
+        match &id.id {
+            QualifiedIdentifierIdentifier::Id((name, _)) => self.write(name),
+            QualifiedIdentifierIdentifier::Brackets(expression) => {
+                self.write("[");
+                self.write_expression(expression);
+                self.write("]");
+            },
+        }
+    }
+
+    fn write_unary_expression(&mut self, expression: &UnaryExpression) {
+        let postfix = match expression.operator {
+            Operator::PostIncrement => Some("++"),
+            Operator::PostDecrement => Some("--"),
+            Operator::NonNull => Some("!"),
+            _ => None,
+        };
+        if let Some(postfix) = postfix {
+            self.write_expression(&expression.expression);
+            self.write(postfix);
+            return;
+        }
+        self.write(match expression.operator {
+            Operator::Delete => "delete ",
+            Operator::Void => "void ",
+            Operator::Typeof => "typeof ",
+            Operator::Await => "await ",
+            Operator::Yield => "yield ",
+            Operator::PreIncrement => "++",
+            Operator::PreDecrement => "--",
+            Operator::Positive => "+",
+            Operator::Negative => "-",
+            Operator::BitwiseNot => "~",
+            _ => "!",
+        });
+        // Keep `- -x` from being read as `--x`.
+        if let Expression::Unary(operand) = expression.expression.as_ref() {
+            let plus = [Operator::Positive, Operator::PreIncrement];
+            let minus = [Operator::Negative, Operator::PreDecrement];
+            if (plus.contains(&expression.operator) && plus.contains(&operand.operator))
+            || (minus.contains(&expression.operator) && minus.contains(&operand.operator)) {
+                self.write(" ");
+            }
+        }
+        self.write_expression(&expression.expression);
+    }
+
+    fn write_string_literal(&mut self, value: &str) {
+        let mut text = String::from("\"");
+        for ch in value.chars() {
+            match ch {
+                '\\' => text.push_str("\\\\"),
+                '"' => text.push_str("\\\""),
+                '\n' => text.push_str("\\n"),
+                '\r' => text.push_str("\\r"),
+                '\t' => text.push_str("\\t"),
+                '\x08' => text.push_str("\\b"),
+                '\x0C' => text.push_str("\\f"),
+                '\x0B' => text.push_str("\\v"),
+                ch if ch.is_control() || CharacterValidator::is_line_terminator(ch) => {
+                    text.push_str(&format!("\\u{{{:x}}}", ch as u32));
+                },
+                ch => text.push(ch),
+            }
+        }
+        text.push('"');
+        self.write(&text);
+    }
+
+    fn write_array_literal(&mut self, literal: &ArrayLiteral) {
+        let elements: Vec<&Element> = literal.elements.iter().filter(|element| !matches!(element, Element::Elision)).collect();
+        let elisions = literal.elements.len() - elements.len();
+        if elisions == 0 {
+            self.write_list("[", &elements, "]", |formatter, element| formatter.write_array_element(element));
+            return;
+        }
+        // Elisions only occur at the end of an array literal.
+        self.write("[");
+        for element in &elements {
+            self.write_array_element(element);
+            self.write(", ");
+        }
+        self.write(&",".repeat(elisions));
+        self.write("]");
+    }
+
+    fn write_array_element(&mut self, element: &Element) {
+        match element {
+            Element::Expression(expression) => self.write_expression(expression),
+            Element::Rest((expression, _)) => {
+                self.write("...");
+                self.write_expression(expression);
+            },
+            Element::Elision => {},
+        }
+    }
+
+    fn write_object_initializer(&mut self, initializer: &ObjectInitializer) {
+        self.write_list("{", &initializer.fields, "}", |formatter, field| formatter.write_initializer_field(field));
+    }
+
+    fn write_initializer_field(&mut self, field: &InitializerField) {
+        match field {
+            InitializerField::Field { name, non_null, value } => {
+                match &name.0 {
+                    FieldName::Identifier(name) => self.write(name),
+                    FieldName::Brackets(expression) => {
+                        self.write("[");
+                        self.write_expression(expression);
+                        self.write("]");
+                    },
+                    FieldName::StringLiteral(expression) |
+                    FieldName::NumericLiteral(expression) => self.write_expression(expression),
+                }
+                if *non_null {
+                    self.write("!");
+                }
+                if let Some(value) = value {
+                    self.write(": ");
+                    self.write_expression(value);
+                }
+            },
+            InitializerField::Rest((expression, _)) => {
+                self.write("...");
+                self.write_expression(expression);
+            },
+        }
+    }
+
+    fn write_xml_element(&mut self, element: &XmlElement) {
+        self.write("<");
+        self.write_xml_tag_name(&element.name);
+        for attribute in &element.attributes {
+            self.write(" ");
+            self.write(&attribute.name.0);
+            self.write("=");
+            match &attribute.value {
+                XmlAttributeValue::Value((value, _)) => {
+                    let quote = if value.contains('"') { "'" } else { "\"" };
+                    self.write(quote);
+                    self.write(value);
+                    self.write(quote);
+                },
+                XmlAttributeValue::Expression(expression) => {
+                    self.write("{");
+                    self.write_expression(expression);
+                    self.write("}");
+                },
+            }
+        }
+        if let Some(expression) = &element.attribute_expression {
+            self.write(" {");
+            self.write_expression(expression);
+            self.write("}");
+        }
+        let Some(content) = &element.content else {
+            self.write("/>");
+            return;
+        };
+        self.write(">");
+        self.write_xml_content(content);
+        self.write("</");
+        self.write_xml_tag_name(element.closing_name.as_ref().unwrap_or(&element.name));
+        self.write(">");
+    }
+
+    fn write_xml_tag_name(&mut self, name: &XmlTagName) {
+        match name {
+            XmlTagName::Name((name, _)) => self.write(name),
+            XmlTagName::Expression(expression) => {
+                self.write("{");
+                self.write_expression(expression);
+                self.write("}");
+            },
+        }
+    }
+
+    fn write_xml_content(&mut self, content: &[Rc<XmlElementContent>]) {
+        for node in content {
+            match node.as_ref() {
+                XmlElementContent::XmlText((text, _)) |
+                XmlElementContent::XmlMarkup((text, _)) => self.write(text),
+                XmlElementContent::XmlElement(element) => self.write_xml_element(element),
+                XmlElementContent::Expression(expression) => {
+                    self.write("{");
+                    self.write_expression(expression);
+                    self.write("}");
+                },
+            }
+        }
+    }
+}
+
+fn attribute_keyword(attribute: &Attribute) -> &'static str {
+    match attribute {
+        Attribute::Public(_) => "public",
+        Attribute::Private(_) => "private",
+        Attribute::Protected(_) => "protected",
+        Attribute::Internal(_) => "internal",
+        Attribute::Proxy(_) => "proxy",
+        Attribute::Final(_) => "final",
+        Attribute::Native(_) => "native",
+        Attribute::Static(_) => "static",
+        Attribute::Abstract(_) => "abstract",
+        Attribute::Override(_) => "override",
+        Attribute::Metadata(_) => "",
+    }
+}
+
+fn canonical_attribute_order(attribute: &Attribute) -> usize {
+    match attribute {
+        Attribute::Public(_) | Attribute::Private(_) | Attribute::Protected(_) | Attribute::Internal(_) => 0,
+        Attribute::Static(_) => 1,
+        Attribute::Abstract(_) => 2,
+        Attribute::Override(_) => 3,
+        Attribute::Final(_) => 4,
+        Attribute::Native(_) => 5,
+        Attribute::Proxy(_) => 6,
+        Attribute::Metadata(_) => 7,
+    }
+}
+
+fn binary_operator_text(operator: Operator) -> &'static str {
+    match operator {
+        Operator::Power => "**",
+        Operator::Multiply => "*",
+        Operator::Divide => "/",
+        Operator::Remainder => "%",
+        Operator::Add => "+",
+        Operator::Subtract => "-",
+        Operator::ShiftLeft => "<<",
+        Operator::ShiftRight => ">>",
+        Operator::ShiftRightUnsigned => ">>>",
+        Operator::Lt => "<",
+        Operator::Gt => ">",
+        Operator::Le => "<=",
+        Operator::Ge => ">=",
+        Operator::In => "in",
+        Operator::NotIn => "not in",
+        Operator::Is => "is",
+        Operator::IsNot => "is not",
+        Operator::As => "as",
+        Operator::Equals => "==",
+        Operator::NotEquals => "!=",
+        Operator::StrictEquals => "===",
+        Operator::StrictNotEquals => "!==",
+        Operator::BitwiseAnd => "&",
+        Operator::BitwiseXor => "^",
+        Operator::BitwiseOr => "|",
+        Operator::LogicalAnd => "&&",
+        Operator::LogicalXor => "^^",
+        Operator::LogicalOr => "||",
+        Operator::NullCoalescing => "??",
+        _ => "",
+    }
+}
+
+/// Whether a configuration constant value may be written
+/// without quotes.
+fn is_identifier_name(value: &str) -> bool {
+    let mut chars = value.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    CharacterValidator::is_identifier_start(first)
+        && chars.all(CharacterValidator::is_identifier_part)
+        && !JetReservedWord::test(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(file_path: &str, text: &str) -> Option<Rc<Program>> {
+        let host = SymbolHost::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../../tests/jetpm-target"));
+        let compilation_unit = CompilationUnit::new(Some(file_path.into()), text.into(), &CompilerOptions::new());
+        let program = ParserFacade::parse_program(&compilation_unit, &host)?;
+        if compilation_unit.invalidated() {
+            return None;
+        }
+        Some(program)
+    }
+
+    fn assert_round_trip(file_path: &str, text: &str, options: &FormatterOptions) -> Option<String> {
+        let program = parse(file_path, text)?;
+        let formatter = Formatter::new(options);
+        let formatted = formatter.format(&program);
+        let reparsed = parse(file_path, &formatted).unwrap_or_else(|| panic!("{file_path}: formatted output does not parse:\n{formatted}"));
+        assert_eq!(serde_json::to_string(&program).unwrap(), serde_json::to_string(&reparsed).unwrap(), "{file_path}:\n{formatted}");
+        assert_eq!(formatted, formatter.format(&reparsed), "{file_path}: formatting is not idempotent");
+        Some(formatted)
+    }
+
+    #[test]
+    fn round_trip() {
+        let directory = concat!(env!("CARGO_MANIFEST_DIR"), "/../../tests/parser");
+        let mut count = 0;
+        for entry in std::fs::read_dir(directory).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().map(|extension| extension != "jet").unwrap_or(true) {
+                continue;
+            }
+            let text = std::fs::read_to_string(&path).unwrap();
+            for options in [FormatterOptions::default(), FormatterOptions { brace_style: BraceStyle::NextLine, indentation: FormatterIndentation::Tabs, line_width: 20, ..default() }] {
+                if assert_round_trip(path.to_str().unwrap(), &text, &options).is_some() {
+                    count += 1;
+                }
+            }
+        }
+        assert!(count > 10);
+    }
+
+    #[test]
+    fn comments_and_layout() {
+        let text = concat!(
+            "// Leading\n",
+            "package   p.q{\n",
+            "/**\n       * Documentation.\n       * @private\n       */\n",
+            "  public  final class C extends B implements I,J{\n",
+            "    protected static var x:Number=- -1, y = [1,,] // Trailing\n",
+            "\n\n\n",
+            "    function get z():*{return x??y?.[0]?.(1) }\n",
+            "  }\n",
+            "}\n",
+            "if(a)b++\nelse if(!c){d()}else e.(f)..g\n",
+            "/** Dropped */\nfor each(var k in o)trace(<t a=\"1\">{k}</t>, \"\\u{0}\\n\")\n",
+        );
+        let formatted = assert_round_trip("/src/Sample.jet", text, &default()).unwrap();
+        assert_eq!(formatted, concat!(
+            "// Leading\n",
+            "package p.q {\n",
+            "    /**\n",
+            "     * Documentation.\n",
+            "     * @private\n",
+            "     */\n",
+            "    public final class C extends B implements I, J {\n",
+            "        protected static var x: Number = - -1, y = [1, ,]; // Trailing\n",
+            "\n",
+            "        function get z(): * {\n",
+            "            return x ?? y?.[0]?.(1);\n",
+            "        }\n",
+            "    }\n",
+            "}\n",
+            "if (a)\n",
+            "    b++;\n",
+            "else if (!c) {\n",
+            "    d();\n",
+            "} else\n",
+            "    e.(f)..g;\n",
+            "/** Dropped */\n",
+            "for each (var k in o)\n",
+            "    trace(<t a=\"1\">{k}</t>, \"\\u{0}\\n\");\n",
+        ));
+    }
+
+    #[test]
+    fn options() {
+        let text = "public static class C { function f(a: Number, b: String): void { if (a) { g(a, b) } else { h() } } }";
+        let options = FormatterOptions {
+            indentation: FormatterIndentation::Spaces(2),
+            brace_style: BraceStyle::NextLine,
+            line_width: 30,
+            attribute_ordering: AttributeOrdering::Canonical,
+        };
+        let program = parse("/src/C.jet", text).unwrap();
+        assert_eq!(Formatter::new(&options).format(&program), concat!(
+            "public static class C\n",
+            "{\n",
+            "  function f(\n",
+            "    a: Number,\n",
+            "    b: String\n",
+            "  ): void\n",
+            "  {\n",
+            "    if (a)\n",
+            "    {\n",
+            "      g(a, b);\n",
+            "    }\n",
+            "    else\n",
+            "    {\n",
+            "      h();\n",
+            "    }\n",
+            "  }\n",
+            "}\n",
+        ));
+    }
+}
@@ -0,0 +1,82 @@
+/// Options for the [`Formatter`](crate::formatter::Formatter).
+///
+/// ```
+/// use hydroper_jet_compiler::ns::*;
+/// let options = FormatterOptions {
+///     indentation: FormatterIndentation::Spaces(2),
+///     brace_style: BraceStyle::NextLine,
+///     ..default()
+/// };
+/// ```
+#[derive(Clone)]
+pub struct FormatterOptions {
+    /// Indentation of nested directives.
+    pub indentation: FormatterIndentation,
+    /// Placement of the opening brace of definitions and statements.
+    pub brace_style: BraceStyle,
+    /// Column limit after which argument, parameter, array and object
+    /// initializer lists are written one item per line.
+    pub line_width: usize,
+    /// Order in which keyword attributes are written.
+    pub attribute_ordering: AttributeOrdering,
+}
+
+impl Default for FormatterOptions {
+    fn default() -> Self {
+        Self {
+            indentation: FormatterIndentation::Spaces(4),
+            brace_style: BraceStyle::SameLine,
+            line_width: 100,
+            attribute_ordering: AttributeOrdering::Preserve,
+        }
+    }
+}
+
+/// Indentation unit of the formatter.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum FormatterIndentation {
+    /// Indent by the given number of spaces.
+    Spaces(usize),
+    /// Indent by one tab character.
+    Tabs,
+}
+
+impl FormatterIndentation {
+    /// Number of columns that one indentation level occupies.
+    /// Tabs are counted as four columns.
+    pub fn width(&self) -> usize {
+        match self {
+            Self::Spaces(n) => *n,
+            Self::Tabs => 4,
+        }
+    }
+
+    pub fn unit(&self) -> String {
+        match self {
+            Self::Spaces(n) => " ".repeat(*n),
+            Self::Tabs => "\t".into(),
+        }
+    }
+}
+
+/// Placement of opening braces.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum BraceStyle {
+    /// `class C {`
+    SameLine,
+    /// The opening brace of definitions and statements is written
+    /// on its own line. Function expressions keep it on the same line.
+    NextLine,
+}
+
+/// Order of keyword attributes such as `public` and `static`.
+/// Meta-data is always written before keyword attributes, in its
+/// original order.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum AttributeOrdering {
+    /// Keep the order in which attributes appear in the source.
+    Preserve,
+    /// Visibility first, followed by `static`, `abstract`, `override`,
+    /// `final`, `native` and `proxy`.
+    Canonical,
+}
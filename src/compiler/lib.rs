@@ -2,9 +2,11 @@
 #![feature(try_blocks)]
 
 pub mod ast;
+pub mod codegen;
 pub mod compilation_unit;
 pub mod compiler_options;
 pub mod diagnostics;
+pub mod formatter;
 pub mod operator;
 pub mod parser;
 pub mod symbols;
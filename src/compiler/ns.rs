@@ -1,9 +1,12 @@
 //! The `ns` module is an union of all compiler modules.
 
 pub use crate::ast::*;
+pub use crate::codegen::*;
+pub use crate::codegen::js::*;
 pub use crate::compilation_unit::*;
 pub use crate::compiler_options::*;
 pub use crate::diagnostics::*;
+pub use crate::formatter::*;
 pub use crate::operator::*;
 pub use crate::parser::*;
 pub use crate::symbols::*;
@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 mod operator;
 pub use operator::*;
 mod operator_precedence;
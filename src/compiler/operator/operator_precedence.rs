@@ -42,6 +42,6 @@ impl OperatorPrecedence {
 impl TryFrom<u32> for OperatorPrecedence {
     type Error = ();
     fn try_from(value: u32) -> Result<Self, Self::Error> {
-        if let Some(v) = FromPrimitive::from_u32(value as u32) { Ok(v) } else { Err(()) }
+        if let Some(v) = FromPrimitive::from_u32(value) { Ok(v) } else { Err(()) }
     }
 }
\ No newline at end of file
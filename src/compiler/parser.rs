@@ -4,6 +4,7 @@ mod contexts;
 pub use contexts::*;
 mod jet_reserved_word;
 pub use jet_reserved_word::*;
+#[allow(clippy::module_inception)]
 mod parser;
 pub use parser::*;
 mod parsing_failure;
@@ -38,20 +38,20 @@ impl CharacterValidator {
     }
 
     pub fn is_dec_digit(ch: char) -> bool {
-        ch >= '\x30' && ch <= '\x39'
+        ch.is_ascii_digit()
     }
 
     pub fn is_hex_digit(ch: char) -> bool {
-        CharacterValidator::is_dec_digit(ch) || (ch >= '\x41' && ch <= '\x46') || (ch >= '\x61' && ch <= '\x66')
+        CharacterValidator::is_dec_digit(ch) || ('\x41'..='\x46').contains(&ch) || ('\x61'..='\x66').contains(&ch)
     }
 
     /// Returns the mathematical value of a hexadecimal digit.
     pub fn hex_digit_mv(ch: char) -> Option<u32> {
-        if ch >= 'A' && ch <= 'F' {
+        if ('A'..='F').contains(&ch) {
             Some((ch as u32) - 0x41 + 10)
-        } else if ch >= 'a' && ch <= 'f' {
+        } else if ('a'..='f').contains(&ch) {
             Some((ch as u32) - 0x61 + 10)
-        } else if ch >= '0' && ch <= '9' {
+        } else if ch.is_ascii_digit() {
             Some((ch as u32) - 0x30)
         } else {
             None
@@ -60,7 +60,7 @@ impl CharacterValidator {
 
     /// Returns the mathematical value of a binary digit.
     pub fn bin_digit_mv(ch: char) -> Option<u32> {
-        if ch >= '0' && ch <= '1' {
+        if ('0'..='1').contains(&ch) {
             Some((ch as u32) - 0x30)
         } else {
             None
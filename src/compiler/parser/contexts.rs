@@ -53,12 +53,7 @@ impl ParsingDirectiveContext {
     }
 
     pub fn is_type_block(&self) -> bool {
-        match self {
-            Self::ClassBlock { .. } |
-            Self::InterfaceBlock |
-            Self::EnumBlock => true,
-            _ => false,
-        }
+        matches!(self, Self::ClassBlock { .. } | Self::InterfaceBlock | Self::EnumBlock)
     }
 
     pub fn clone_control(&self) -> Self {
@@ -114,7 +109,7 @@ impl ParsingDirectiveContext {
     }
 
     pub fn resolve_label(&self, label: String) -> Option<ParsingControlContext> {
-        if let Self::WithControl { labels, .. } = &self { labels.get(&label).map(|c| c.clone()) } else { None }
+        if let Self::WithControl { labels, .. } = &self { labels.get(&label).cloned() } else { None }
     }
 
     pub fn is_break_allowed(&self, label: Option<String>) -> bool {
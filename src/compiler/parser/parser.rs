@@ -1,5 +1,6 @@
 use crate::ns::*;
 use lazy_regex::*;
+use std::ops::Range;
 use std::str::FromStr;
 
 pub struct Parser<'input> {
@@ -9,21 +10,50 @@ pub struct Parser<'input> {
     locations: Vec<Location>,
     activations: Vec<ParsingActivation>,
     host: Rc<SymbolHost>,
+    error_tolerant: bool,
 }
 
 impl<'input> Parser<'input> {
     /// Constructs a parser.
     pub fn new(compilation_unit: &'input Rc<CompilationUnit>, host: &Rc<SymbolHost>) -> Self {
+        Self::with_tokenizer(Tokenizer::new(compilation_unit), 0, host)
+    }
+
+    /// Constructs a parser that resumes at `offset` in an already
+    /// parsed compilation unit.
+    pub fn new_at(compilation_unit: &'input Rc<CompilationUnit>, offset: usize, host: &Rc<SymbolHost>) -> Self {
+        Self::with_tokenizer(Tokenizer::new_at(compilation_unit, offset), offset, host)
+    }
+
+    fn with_tokenizer(tokenizer: Tokenizer<'input>, offset: usize, host: &Rc<SymbolHost>) -> Self {
+        let location = Location::with_offset(tokenizer.compilation_unit(), offset);
         Self {
-            tokenizer: Tokenizer::new(compilation_unit),
-            previous_token: (Token::Eof, Location::with_offset(&compilation_unit, 0)),
-            token: (Token::Eof, Location::with_offset(&compilation_unit, 0)),
+            tokenizer,
+            previous_token: (Token::Eof, location.clone()),
+            token: (Token::Eof, location),
             locations: vec![],
             activations: vec![],
             host: host.clone(),
+            error_tolerant: false,
         }
     }
 
+    /// Indicates whether the parser recovers from syntax errors.
+    pub fn error_tolerant(&self) -> bool {
+        self.error_tolerant
+    }
+
+    /// Sets whether the parser recovers from syntax errors.
+    ///
+    /// In error-tolerant mode, a directive that fails to parse is replaced by
+    /// a `Directive::Invalid` node and the parser resynchronizes at the next
+    /// statement, directive or block boundary. A missing expression is replaced by
+    /// an `Expression::Invalid` node. Diagnostics are still
+    /// reported to the compilation unit.
+    pub fn set_error_tolerant(&mut self, value: bool) {
+        self.error_tolerant = value;
+    }
+
     fn compilation_unit(&self) -> &Rc<CompilationUnit> {
         self.tokenizer.compilation_unit()
     }
@@ -76,6 +106,74 @@ impl<'input> Parser<'input> {
         Ok(())
     }
 
+    /// Scans the next token, skipping characters that fail to tokenize.
+    /// Used when recovering from syntax errors.
+    fn next_skipping_failures(&mut self) {
+        while self.next().is_err() {
+            self.tokenizer.skip_character();
+        }
+    }
+
+    /// Skips tokens until a directive boundary: past a semicolon or a balanced block,
+    /// before a closing brace, before end-of-file, or before a directive keyword
+    /// that follows a line break. Skips at least one token if the
+    /// parser has not advanced past `start`.
+    fn skip_to_directive_boundary(&mut self, start: &Location) {
+        if self.token.1.first_offset() == start.first_offset() && !self.peek(Token::Eof) {
+            let opens_block = self.peek(Token::LeftBrace);
+            self.next_skipping_failures();
+            if !opens_block {
+                return self.skip_to_directive_boundary_1(0);
+            }
+            return self.skip_to_directive_boundary_1(1);
+        }
+        self.skip_to_directive_boundary_1(0);
+    }
+
+    fn skip_to_directive_boundary_1(&mut self, mut depth: usize) {
+        loop {
+            match self.token.0 {
+                Token::Eof => {
+                    break;
+                },
+                Token::Semicolon if depth == 0 => {
+                    self.next_skipping_failures();
+                    break;
+                },
+                Token::LeftBrace => {
+                    depth += 1;
+                },
+                Token::RightBrace => {
+                    if depth == 0 {
+                        break;
+                    }
+                    depth -= 1;
+                    if depth == 0 {
+                        self.next_skipping_failures();
+                        break;
+                    }
+                },
+                _ => {
+                    if depth == 0 && self.previous_token.1.line_break(&self.token.1) && self.peek_directive_keyword() {
+                        break;
+                    }
+                },
+            }
+            self.next_skipping_failures();
+        }
+    }
+
+    fn peek_directive_keyword(&self) -> bool {
+        matches!(self.token.0,
+            Token::Var | Token::Const | Token::Function | Token::Class | Token::Interface |
+            Token::Import | Token::Use | Token::Package |
+            Token::Public | Token::Private | Token::Protected | Token::Internal |
+            Token::If | Token::Switch | Token::Do | Token::While | Token::For | Token::With |
+            Token::Break | Token::Continue | Token::Return | Token::Throw | Token::Try)
+        || self.peek_context_keyword("enum")
+        || self.peek_context_keyword("type")
+    }
+
     fn peek(&self, token: Token) -> bool {
         self.token.0 == token
     }
@@ -264,6 +362,11 @@ impl<'input> Parser<'input> {
             Ok(exp)
         } else {
             self.add_syntax_error(&self.token_location(), DiagnosticKind::ExpectedExpression, diagnostic_arguments![Token(self.token.0.clone())]);
+            if self.error_tolerant {
+                return Ok(Rc::new(Expression::Invalid(InvalidExpression {
+                    location: Location::with_offset(self.compilation_unit(), self.token.1.first_offset()),
+                })));
+            }
             Err(ParsingFailure)
         }
     }
@@ -416,7 +519,7 @@ impl<'input> Parser<'input> {
         // a logical AND, XOR or OR operation.
         if operator == Operator::NullCoalescing {
             if let Expression::Unary(UnaryExpression { expression, operator, .. }) = base.as_ref() {
-                if [Operator::LogicalAnd, Operator::LogicalXor, Operator::LogicalOr].contains(&operator) {
+                if [Operator::LogicalAnd, Operator::LogicalXor, Operator::LogicalOr].contains(operator) {
                     self.add_syntax_error(&expression.location(), DiagnosticKind::IllegalNullishCoalescingLeftOperand, vec![]);
                 }
             }
@@ -668,12 +771,17 @@ impl<'input> Parser<'input> {
             Ok(Some(Rc::new(Expression::ThisLiteral(ThisLiteral {
                 location: self.pop_location(),
             }))))
-        } else if let Token::RegExpLiteral { ref body, ref flags } = self.token.0.clone() {
+        // RegExpLiteral, scanned as `/` or `/=`
+        } else if self.peek(Token::Div) || self.peek(Token::DivideAssign) {
             self.mark_location();
+            self.token = self.tokenizer.scan_regexp_literal(self.token_location())?;
+            let Token::RegExpLiteral { body, flags } = self.token.0.clone() else {
+                unreachable!();
+            };
             self.next()?;
             Ok(Some(Rc::new(Expression::RegExpLiteral(RegExpLiteral {
                 location: self.pop_location(),
-                body: body.clone(), flags: flags.clone(),
+                body, flags,
             }))))
         // `@`
         } else if self.peek(Token::Attribute) {
@@ -682,7 +790,7 @@ impl<'input> Parser<'input> {
             Ok(Some(Rc::new(Expression::QualifiedIdentifier(id))))
         // Parentheses
         } else if self.peek(Token::LeftParen) {
-            return Ok(Some(self.parse_paren_list_expr_or_qual_id()?));
+            Ok(Some(self.parse_paren_list_expr_or_qual_id()?))
         // `*`
         } else if self.peek(Token::Times) {
             let id_location = self.token_location();
@@ -858,7 +966,7 @@ impl<'input> Parser<'input> {
                 allow_in,
                 min_precedence: OperatorPrecedence::AssignmentAndOther,
                 ..default()
-            })?.map(|e| FunctionBody::Expression(e))
+            })?.map(FunctionBody::Expression)
         };
 
         // Body is required by function expressions
@@ -1091,7 +1199,7 @@ impl<'input> Parser<'input> {
 
             // EmbedExpression
             if self.peek(Token::LeftBrace) && id == "embed" && self.previous_token.1.character_count() == "embed".len() {
-                return Ok(self.finish_embed_expression(id_location)?);
+                return self.finish_embed_expression(id_location);
             }
 
             let id = Rc::new(Expression::QualifiedIdentifier(QualifiedIdentifier {
@@ -1149,12 +1257,17 @@ impl<'input> Parser<'input> {
             Ok(Rc::new(Expression::ThisLiteral(ThisLiteral {
                 location: self.pop_location(),
             })))
-        } else if let Token::RegExpLiteral { ref body, ref flags } = self.token.0.clone() {
+        // RegExpLiteral, scanned as `/` or `/=`
+        } else if self.peek(Token::Div) || self.peek(Token::DivideAssign) {
             self.mark_location();
+            self.token = self.tokenizer.scan_regexp_literal(self.token_location())?;
+            let Token::RegExpLiteral { body, flags } = self.token.0.clone() else {
+                unreachable!();
+            };
             self.next()?;
             Ok(Rc::new(Expression::RegExpLiteral(RegExpLiteral {
                 location: self.pop_location(),
-                body: body.clone(), flags: flags.clone(),
+                body, flags,
             })))
         // `@`
         } else if self.peek(Token::Attribute) {
@@ -1163,7 +1276,7 @@ impl<'input> Parser<'input> {
             Ok(Rc::new(Expression::QualifiedIdentifier(id)))
         // Parentheses
         } else if self.peek(Token::LeftParen) {
-            return Ok(self.parse_paren_list_expr_or_qual_id()?);
+            self.parse_paren_list_expr_or_qual_id()
         // `*`
         } else if self.peek(Token::Times) {
             let id_location = self.token_location();
@@ -1216,10 +1329,10 @@ impl<'input> Parser<'input> {
         let Expression::ObjectInitializer(descriptor) = descriptor.as_ref() else {
             panic!();
         };
-        return Ok(Rc::new(Expression::Embed(EmbedExpression {
+        Ok(Rc::new(Expression::Embed(EmbedExpression {
             location: self.pop_location(),
             description: descriptor.clone(),
-        })));
+        })))
     }
 
     fn parse_array_initializer(&mut self) -> Result<Rc<Expression>, ParsingFailure> {
@@ -1278,10 +1391,10 @@ impl<'input> Parser<'input> {
 
         self.push_location(&start);
         let element = Rc::new(self.parse_xml_element(start, true)?);
-        return Ok(Rc::new(Expression::Xml(XmlExpression {
+        Ok(Rc::new(Expression::Xml(XmlExpression {
             location: self.pop_location(),
             element,
-        })));
+        })))
     }
 
     /// Parses XMLElement starting from its XMLTagContent.
@@ -1303,14 +1416,13 @@ impl<'input> Parser<'input> {
                 self.consume_and_ie_xml_tag(Token::XmlWhitespace)?;
                 self.expect_and_ie_xml_tag(Token::Assign)?;
                 self.consume_and_ie_xml_tag(Token::XmlWhitespace)?;
-                let value: XmlAttributeValue;
-                if self.consume(Token::LeftBrace)? {
+                let value = if self.consume(Token::LeftBrace)? {
                     let expr = self.parse_expression(ParsingExpressionContext { allow_in: true, min_precedence: OperatorPrecedence::AssignmentAndOther, ..default() })?;
                     self.expect_and_ie_xml_tag(Token::RightBrace)?;
-                    value = XmlAttributeValue::Expression(expr);
+                    XmlAttributeValue::Expression(expr)
                 } else {
-                    value = XmlAttributeValue::Value(self.parse_xml_attribute_value()?);
-                }
+                    XmlAttributeValue::Value(self.parse_xml_attribute_value()?)
+                };
                 attributes.push(Rc::new(XmlAttribute {
                     location: self.pop_location(),
                     name, value
@@ -1323,13 +1435,11 @@ impl<'input> Parser<'input> {
         let mut content: Option<Vec<Rc<XmlElementContent>>> = None;
         let mut closing_name: Option<XmlTagName> = None;
 
-        let is_empty;
-
-        if ends_at_ie_div {
-            is_empty = self.consume(Token::XmlSlashGt)?;
+        let is_empty = if ends_at_ie_div {
+            self.consume(Token::XmlSlashGt)?
         } else {
-            is_empty = self.consume_and_ie_xml_content(Token::XmlSlashGt)?;
-        }
+            self.consume_and_ie_xml_content(Token::XmlSlashGt)?
+        };
 
         if !is_empty {
             self.expect_and_ie_xml_content(Token::Gt)?;
@@ -1358,7 +1468,7 @@ impl<'input> Parser<'input> {
         if let Token::XmlAttributeValue(value) = self.token.0.clone() {
             let location = self.token_location();
             self.next_ie_xml_tag()?;
-            return Ok((value, location));
+            Ok((value, location))
         } else {
             self.add_syntax_error(&self.token_location(), DiagnosticKind::ExpectedXmlAttributeValue, diagnostic_arguments![Token(self.token.0.clone())]);
             Err(ParsingFailure)
@@ -1383,7 +1493,7 @@ impl<'input> Parser<'input> {
         if let Token::XmlName(name) = self.token.0.clone() {
             let name_location = self.token_location();
             self.next_ie_xml_tag()?;
-            return Ok((name, name_location));
+            Ok((name, name_location))
         } else {
             self.add_syntax_error(&self.token_location(), DiagnosticKind::ExpectedXmlName, diagnostic_arguments![Token(self.token.0.clone())]);
             Err(ParsingFailure)
@@ -1429,10 +1539,10 @@ impl<'input> Parser<'input> {
             return Ok(Rc::new(Expression::QualifiedIdentifier(id)));
         }
         self.push_location(&start);
-        return Ok(Rc::new(Expression::Paren(ParenExpression {
+        Ok(Rc::new(Expression::Paren(ParenExpression {
             location: self.pop_location(),
             expression: left,
-        })));
+        })))
     }
 
     /// Parses either a ParenListExpression, (), or a QualifiedIdentifier
@@ -1491,6 +1601,7 @@ impl<'input> Parser<'input> {
                 let ql = self.pop_location();
                 return self.finish_qualified_identifier(attribute, ql, id);
             } else {
+                self.pop_location();
                 let id = QualifiedIdentifier {
                     location: id_location.clone(),
                     attribute,
@@ -1732,9 +1843,9 @@ impl<'input> Parser<'input> {
         } else if self.peek(Token::Times) {
             let location = self.token_location();
             self.next()?;
-            return Ok((Rc::new(Expression::AnyType(AnyTypeExpression {
+            Ok((Rc::new(Expression::AnyType(AnyTypeExpression {
                 location,
-            })), wrap_nullable));
+            })), wrap_nullable))
         // Identifier
         } else {
             let id = self.expect_identifier(false)?;
@@ -1846,47 +1957,50 @@ impl<'input> Parser<'input> {
             if arguments.is_some() {
                 semicolon_inserted = self.parse_semicolon()?;
             }
-            if arguments.is_none() || (!semicolon_inserted && (self.peek(Token::Dot) || self.peek(Token::LeftBracket))) {
-                if !(self.peek(Token::Dot) || self.peek(Token::LeftBracket)) {
-                    self.expect(Token::Dot)?;
-                }
-                self.duplicate_location();
-                // ExpressionStatement (`super`...)
-                let mut expr = Rc::new(Expression::Super(SuperExpression {
-                    location: self.pop_location(),
-                    object: arguments,
-                }));
-                expr = self.parse_subexpressions(expr, ParsingExpressionContext {
-                    allow_in: true,
-                    min_precedence: OperatorPrecedence::List,
-                    ..default()
-                })?;
-                let semicolon_inserted = self.parse_semicolon()?;
-                Ok((Rc::new(Directive::ExpressionStatement(ExpressionStatement {
-                    location: self.pop_location(),
-                    expression: expr,
-                })), semicolon_inserted))
-            } else {
+            match arguments {
                 // SuperStatement
-                let node = Rc::new(Directive::SuperStatement(SuperStatement {
-                    location: self.pop_location(),
-                    arguments: arguments.unwrap(),
-                }));
+                Some(arguments) if semicolon_inserted || !(self.peek(Token::Dot) || self.peek(Token::LeftBracket)) => {
+                    let node = Rc::new(Directive::SuperStatement(SuperStatement {
+                        location: self.pop_location(),
+                        arguments,
+                    }));
 
-                // Check whether super statement is allowed here
-                let allowed_here;
-                if let ParsingDirectiveContext::ConstructorBlock { super_statement_found } = &context {
-                    allowed_here = !super_statement_found.get();
-                    super_statement_found.set(true);
-                } else {
-                    allowed_here = false;
-                }
+                    // Check whether super statement is allowed here
+                    let allowed_here;
+                    if let ParsingDirectiveContext::ConstructorBlock { super_statement_found } = &context {
+                        allowed_here = !super_statement_found.get();
+                        super_statement_found.set(true);
+                    } else {
+                        allowed_here = false;
+                    }
 
-                if !allowed_here {
-                    self.add_syntax_error(&node.location(), DiagnosticKind::NotAllowedHere, diagnostic_arguments![Token(Token::Super)]);
-                }
+                    if !allowed_here {
+                        self.add_syntax_error(&node.location(), DiagnosticKind::NotAllowedHere, diagnostic_arguments![Token(Token::Super)]);
+                    }
 
-                Ok((node, semicolon_inserted))
+                    Ok((node, semicolon_inserted))
+                },
+                arguments => {
+                    if !(self.peek(Token::Dot) || self.peek(Token::LeftBracket)) {
+                        self.expect(Token::Dot)?;
+                    }
+                    self.duplicate_location();
+                    // ExpressionStatement (`super`...)
+                    let mut expr = Rc::new(Expression::Super(SuperExpression {
+                        location: self.pop_location(),
+                        object: arguments,
+                    }));
+                    expr = self.parse_subexpressions(expr, ParsingExpressionContext {
+                        allow_in: true,
+                        min_precedence: OperatorPrecedence::List,
+                        ..default()
+                    })?;
+                    let semicolon_inserted = self.parse_semicolon()?;
+                    Ok((Rc::new(Directive::ExpressionStatement(ExpressionStatement {
+                        location: self.pop_location(),
+                        expression: expr,
+                    })), semicolon_inserted))
+                },
             }
         // EmptyStatement
         } else if self.peek(Token::Semicolon) {
@@ -2013,15 +2127,17 @@ impl<'input> Parser<'input> {
         self.expect(Token::LeftBrace)?;
         let mut directives = vec![];
         let mut semicolon_inserted = false;
-        while !self.peek(Token::RightBrace) {
-            if !directives.is_empty() && !semicolon_inserted {
-                self.expect(Token::Semicolon)?;
-            }
-            let (directive, semicolon_inserted_1) = self.parse_directive(context.clone())?;
+        while !(self.peek(Token::RightBrace) || (self.error_tolerant && self.peek(Token::Eof))) {
+            let expect_semicolon = !directives.is_empty() && !semicolon_inserted;
+            let (directive, semicolon_inserted_1) = self.parse_directive_with_recovery(context.clone(), expect_semicolon)?;
             directives.push(directive);
             semicolon_inserted = semicolon_inserted_1;
         }
-        self.expect(Token::RightBrace)?;
+        if self.error_tolerant && self.peek(Token::Eof) {
+            self.add_syntax_error(&self.token_location(), DiagnosticKind::Expected, diagnostic_arguments![Token(Token::RightBrace), Token(Token::Eof)]);
+        } else {
+            self.expect(Token::RightBrace)?;
+        }
         Ok(Block { 
             location: self.pop_location(),
             metadata,
@@ -2117,11 +2233,9 @@ impl<'input> Parser<'input> {
             }
             let mut directives = vec![];
             semicolon_inserted = false;
-            while !(self.peek(Token::RightBrace) || self.peek(Token::Case) || self.peek(Token::Default)) {
-                if !directives.is_empty() && !semicolon_inserted {
-                    self.expect(Token::Semicolon)?;
-                }
-                let (directive, semicolon_inserted_1) = self.parse_directive(context.clone())?;
+            while !(self.peek(Token::RightBrace) || self.peek(Token::Case) || self.peek(Token::Default) || (self.error_tolerant && self.peek(Token::Eof))) {
+                let expect_semicolon = !directives.is_empty() && !semicolon_inserted;
+                let (directive, semicolon_inserted_1) = self.parse_directive_with_recovery(context.clone(), expect_semicolon)?;
                 directives.push(directive);
                 semicolon_inserted = semicolon_inserted_1;
             }
@@ -2254,9 +2368,12 @@ impl<'input> Parser<'input> {
             None
         };
 
-        if init_variable.is_some() && self.consume(Token::In)? {
-            return self.parse_for_in_statement_with_left_variable(context, init_variable.unwrap());
-        }
+        let init_variable = match init_variable {
+            Some(init_variable) if self.consume(Token::In)? => {
+                return self.parse_for_in_statement_with_left_variable(context, init_variable);
+            },
+            init_variable => init_variable,
+        };
 
         let mut init_exp = if init_variable.is_none() && !self.peek(Token::Semicolon) {
             self.parse_opt_expression(ParsingExpressionContext {
@@ -2268,9 +2385,12 @@ impl<'input> Parser<'input> {
             None
         };
 
-        if init_exp.is_some() && self.consume(Token::In)? {
-            return self.parse_for_in_statement_with_left_exp(context, init_exp.unwrap());
-        }
+        init_exp = match init_exp {
+            Some(init_exp) if self.consume(Token::In)? => {
+                return self.parse_for_in_statement_with_left_exp(context, init_exp);
+            },
+            init_exp => init_exp,
+        };
 
         if init_exp.is_none() && init_variable.is_none() && !self.peek(Token::Semicolon) {
             init_exp = Some(self.parse_expression(ParsingExpressionContext {
@@ -2284,10 +2404,8 @@ impl<'input> Parser<'input> {
 
         let init = if let Some(exp) = init_exp.as_ref() {
             Some(ForInitializer::Expression(exp.clone()))
-        } else if let Some(variable) = init_variable.as_ref() {
-            Some(ForInitializer::VariableDefinition(Rc::new(variable.clone())))
         } else {
-            None
+            init_variable.as_ref().map(|variable| ForInitializer::VariableDefinition(Rc::new(variable.clone())))
         };
 
         self.expect(Token::Semicolon)?;
@@ -2394,14 +2512,13 @@ impl<'input> Parser<'input> {
 
     fn parse_simple_variable_definition(&mut self, allow_in: bool) -> Result<SimpleVariableDefinition, ParsingFailure> {
         self.mark_location();
-        let kind: VariableDefinitionKind;
         let kind_location = self.token_location();
-        if self.consume(Token::Const)? {
-            kind = VariableDefinitionKind::Const;
+        let kind = if self.consume(Token::Const)? {
+            VariableDefinitionKind::Const
         } else {
             self.expect(Token::Var)?;
-            kind = VariableDefinitionKind::Var;
-        }
+            VariableDefinitionKind::Var
+        };
         let mut bindings = vec![Rc::new(self.parse_variable_binding(allow_in)?)];
         while self.consume(Token::Comma)? {
             bindings.push(Rc::new(self.parse_variable_binding(allow_in)?));
@@ -2590,7 +2707,6 @@ impl<'input> Parser<'input> {
             allow_in: true,
             allow_assignment: false,
             min_precedence: OperatorPrecedence::AssignmentAndOther,
-            ..default()
         })?;
 
         let semicolon_inserted = self.parse_semicolon()?;
@@ -2615,7 +2731,7 @@ impl<'input> Parser<'input> {
         if let Token::Identifier(id) = &self.token.0 {
             let id = (id.clone(), self.token_location());
             self.next()?;
-            if self.peek_annotatable_directive_identifier_name() || self.lookbehind_is_annotatable_directive_identifier_name() {
+            if self.lookbehind_is_annotatable_directive_identifier_name() {
                 let mut context1: AnnotatableContext;
                 if ["enum", "type"].contains(&id.0.as_ref()) && id.1.character_count() == id.0.len() {
                     context1 = AnnotatableContext {
@@ -2636,7 +2752,7 @@ impl<'input> Parser<'input> {
                     };
                     self.parse_attribute_identifier_names(&mut context1)?;
                 }
-                return self.parse_annotatable_directive(context1);
+                self.parse_annotatable_directive(context1)
             } else if self.peek(Token::LeftBrace) && &id.0 == "configuration" && id.1.character_count() == "configuration".len() {
                 self.parse_configuration_directive(context, id.1)
             } else {
@@ -2708,7 +2824,7 @@ impl<'input> Parser<'input> {
                 directive_context_keyword: None,
             };
             self.parse_attribute_identifier_names(&mut context)?;
-            return self.parse_annotatable_directive(context);
+            self.parse_annotatable_directive(context)
         } else {
             self.parse_statement(context)
         }
@@ -2718,16 +2834,51 @@ impl<'input> Parser<'input> {
         let mut directives = vec![];
         let mut semicolon = false;
         while !self.peek(Token::Eof) {
-            if !directives.is_empty() && !semicolon {
-                self.expect(Token::Semicolon)?;
-            }
-            let (directive, semicolon_1) = self.parse_directive(context.clone())?;
+            let expect_semicolon = !directives.is_empty() && !semicolon;
+            let (directive, semicolon_1) = self.parse_directive_with_recovery(context.clone(), expect_semicolon)?;
             directives.push(directive);
             semicolon = semicolon_1;
         }
         Ok(directives)
     }
 
+    /// Parses a directive, expecting a semicolon before it if `expect_semicolon` is true.
+    ///
+    /// In error-tolerant mode, a missing semicolon is reported without failing,
+    /// and a directive that fails to parse or does not consume any token results into
+    /// an `Invalid` directive after resynchronizing at the next directive boundary.
+    fn parse_directive_with_recovery(&mut self, context: ParsingDirectiveContext, expect_semicolon: bool) -> Result<(Rc<Directive>, bool), ParsingFailure> {
+        if !self.error_tolerant {
+            if expect_semicolon {
+                self.expect(Token::Semicolon)?;
+            }
+            return self.parse_directive(context);
+        }
+        let locations_length = self.locations.len();
+        let activations_length = self.activations.len();
+        let start = self.token_location();
+        let result: Result<(Rc<Directive>, bool), ParsingFailure> = try {
+            if expect_semicolon && !self.consume(Token::Semicolon)? {
+                self.add_syntax_error(&self.token_location(), DiagnosticKind::Expected, diagnostic_arguments![Token(Token::Semicolon), Token(self.token.0.clone())]);
+            }
+            self.parse_directive(context)?
+        };
+        match result {
+            Ok(result) if self.token.1.first_offset() != start.first_offset() => Ok(result),
+            _ => {
+                self.locations.truncate(locations_length);
+                self.activations.truncate(activations_length);
+                self.skip_to_directive_boundary(&start);
+                let location = if self.previous_token.1.first_offset() < start.first_offset() {
+                    Location::with_offset(self.compilation_unit(), start.first_offset())
+                } else {
+                    start.combine_with(self.previous_token.1.clone())
+                };
+                Ok((Rc::new(Directive::Invalid(InvalidDirective { location })), true))
+            },
+        }
+    }
+
     fn parse_annotatable_directive(&mut self, context: AnnotatableContext) -> Result<(Rc<Directive>, bool), ParsingFailure> {
         if self.consume(Token::Use)? {
             self.parse_use_directive(context)
@@ -2792,7 +2943,7 @@ impl<'input> Parser<'input> {
     fn refine_metadata_entries(&self, list: &Vec<Rc<Expression>>) -> Result<Vec<Rc<PlainMetadataEntry>>, MetadataRefineError> {
         let mut r = Vec::<Rc<PlainMetadataEntry>>::new();
         for entry in list {
-            r.push(self.refine_metadata_entry(&entry)?);
+            r.push(self.refine_metadata_entry(entry)?);
         }
         Ok(r)
     }
@@ -2855,15 +3006,14 @@ impl<'input> Parser<'input> {
         }
     }
 
-    fn refine_metadata_file_value(&self, list: &Vec<Rc<Expression>>) -> Result<PlainMetadataValue, MetadataRefineError> {
+    fn refine_metadata_file_value(&self, list: &[Rc<Expression>]) -> Result<PlainMetadataValue, MetadataRefineError> {
         use file_paths::FlexPath;
         if list.len() != 1 {
             return Err(MetadataRefineError::Syntax);
         }
-        let path: String;
-        match list[0].as_ref() {
+        let path = match list[0].as_ref() {
             Expression::StringLiteral(StringLiteral { value, .. }) => {
-                path = FlexPath::new_native(&self.compilation_unit().file_path().unwrap_or(String::new())).resolve("..").resolve(value).to_string_with_flex_separator();
+                FlexPath::new_native(&self.compilation_unit().file_path().unwrap_or_default()).resolve("..").resolve(value).to_string_with_flex_separator()
             },
             Expression::Binary(BinaryExpression { left, operator, right, .. }) => {
                 if *operator != Operator::Add {
@@ -2878,12 +3028,13 @@ impl<'input> Parser<'input> {
                 let Expression::StringLiteral(StringLiteral { value: right_val, .. }) = right.as_ref() else {
                     return Err(MetadataRefineError::Syntax);
                 };
-                path = FlexPath::from_n_native([self.host.jetpm_output_directory().as_ref(), right_val.as_ref()]).to_string_with_flex_separator();
+                let jetpm_output_directory = self.compilation_unit().compiler_options.jetpm_output_directory.clone().unwrap_or(self.host.jetpm_output_directory());
+                FlexPath::from_n_native([jetpm_output_directory.as_ref(), right_val.as_ref()]).to_string_with_flex_separator()
             },
             _ => {
                 return Err(MetadataRefineError::Syntax);
             },
-        }
+        };
 
         if let Ok(data) = std::fs::read(&path) {
             Ok(PlainMetadataValue::File {
@@ -2996,7 +3147,7 @@ impl<'input> Parser<'input> {
         let semicolon = self.parse_semicolon()?;
         let location = self.pop_location();
 
-        if !(matches!(context, ParsingDirectiveContext::PackageBlock { .. })) {
+        if !(matches!(context, ParsingDirectiveContext::PackageBlock)) {
             self.add_syntax_error(&location, DiagnosticKind::NotAllowedHere, diagnostic_arguments![String("'use'".into()), Token(self.token.0.clone())]);
         }
 
@@ -3075,7 +3226,7 @@ impl<'input> Parser<'input> {
                 Attribute::Private(_) |
                 Attribute::Protected(_) |
                 Attribute::Internal(_) => {
-                    self.verify_visibility(&a, &context);
+                    self.verify_visibility(a, &context);
                 },
                 _ => {
                     // Unallowed attribute
@@ -3172,10 +3323,8 @@ impl<'input> Parser<'input> {
         }
 
         // Interface methods must not contain any annotations except for meta-data.
-        if !attributes.is_empty() && interface_method {
-            if !attributes.last().unwrap().is_metadata() {
-                self.add_syntax_error(&name.location(), DiagnosticKind::FunctionMustNotContainAnnotations, diagnostic_arguments![]);
-            }
+        if interface_method && attributes.last().is_some_and(|a| !a.is_metadata()) {
+            self.add_syntax_error(&name.location(), DiagnosticKind::FunctionMustNotContainAnnotations, diagnostic_arguments![]);
         }
 
         for a in &attributes {
@@ -3214,7 +3363,7 @@ impl<'input> Parser<'input> {
                         // Unallowed visibility in proxy function
                         self.add_syntax_error(&a.location(), DiagnosticKind::UnallowedAttribute, diagnostic_arguments![]);
                     } else {
-                        self.verify_visibility(&a, &context);
+                        self.verify_visibility(a, &context);
                     }
                 },
                 _ => {
@@ -3266,7 +3415,7 @@ impl<'input> Parser<'input> {
                 Attribute::Private(_) |
                 Attribute::Protected(_) |
                 Attribute::Internal(_) => {
-                    self.verify_visibility(&a, &context);
+                    self.verify_visibility(a, &context);
                 },
                 _ => {
                     // Unallowed attribute
@@ -3323,7 +3472,7 @@ impl<'input> Parser<'input> {
                 Attribute::Private(_) |
                 Attribute::Protected(_) |
                 Attribute::Internal(_) => {
-                    self.verify_visibility(&a, &context);
+                    self.verify_visibility(a, &context);
                 },
                 _ => {
                     // Unallowed attribute
@@ -3386,7 +3535,7 @@ impl<'input> Parser<'input> {
                 Attribute::Private(_) |
                 Attribute::Protected(_) |
                 Attribute::Internal(_) => {
-                    self.verify_visibility(&a, &context);
+                    self.verify_visibility(a, &context);
                 },
                 _ => {
                     // Unallowed attribute
@@ -3429,7 +3578,7 @@ impl<'input> Parser<'input> {
                 Attribute::Private(_) |
                 Attribute::Protected(_) |
                 Attribute::Internal(_) => {
-                    self.verify_visibility(&a, &context);
+                    self.verify_visibility(a, &context);
                 },
                 _ => {
                     // Unallowed attribute
@@ -3570,7 +3719,7 @@ impl<'input> Parser<'input> {
             let mut id = id.clone();
             if self.consume(Token::ColonColon)? {
                 let (id_1, _) = self.expect_identifier(true)?;
-                id = id + &"::".to_owned() + &id_1;
+                id = id + "::" + &id_1;
             }
             let id_location = self.pop_location();
             let id = Rc::new(Expression::QualifiedIdentifier(QualifiedIdentifier {
@@ -3672,7 +3821,7 @@ impl<'input> Parser<'input> {
         }
         loop {
             if let Some(a) = self.peek_attribute() {
-                let last_attribute_is_identifier = context.attributes.last().map_or(false, |a| !a.is_metadata());
+                let last_attribute_is_identifier = context.attributes.last().is_some_and(|a| !a.is_metadata());
                 if last_attribute_is_identifier {
                     self.forbid_line_break_before_token();
                 }
@@ -3708,42 +3857,112 @@ impl<'input> Parser<'input> {
         self.mark_location();
         let mut packages = vec![];
         while self.peek(Token::Package) {
-            self.mark_location();
-            let jetdoc = self.parse_jetdoc()?;
-            self.next()?;
-            let mut name = vec![];
+            self.parse_package_definition_with_recovery(&mut packages)?;
+        }
+        let directives = self.parse_directives(ParsingDirectiveContext::TopLevel)?;
+        Ok(Rc::new(Program {
+            location: self.pop_location(),
+            packages,
+            directives,
+        }))
+    }
+
+    /// Parses the top-level packages and directives that start before `end_offset`,
+    /// as part of reparsing a program. Packages are only parsed
+    /// if `packages_allowed` is true.
+    ///
+    /// Returns whether the last directive was terminated.
+    fn parse_program_region(&mut self, end_offset: usize, mut packages_allowed: bool, packages: &mut Vec<Rc<PackageDefinition>>, directives: &mut Vec<Rc<Directive>>) -> Result<bool, ParsingFailure> {
+        let mut semicolon = true;
+        while !self.peek(Token::Eof) && self.token.1.first_offset() < end_offset {
+            if packages_allowed && self.peek(Token::Package) {
+                self.parse_package_definition_with_recovery(packages)?;
+                continue;
+            }
+            packages_allowed = false;
+            let expect_semicolon = !directives.is_empty() && !semicolon;
+            let (directive, semicolon_1) = self.parse_directive_with_recovery(ParsingDirectiveContext::TopLevel, expect_semicolon)?;
+            directives.push(directive);
+            semicolon = semicolon_1;
+        }
+        Ok(semicolon)
+    }
+
+    /// Parses a package definition. In error-tolerant mode, a package definition
+    /// that fails to parse is skipped until the next directive boundary and
+    /// results into an unnamed package containing an `Invalid` directive.
+    fn parse_package_definition_with_recovery(&mut self, packages: &mut Vec<Rc<PackageDefinition>>) -> Result<(), ParsingFailure> {
+        if !self.error_tolerant {
+            packages.push(self.parse_package_definition()?);
+            return Ok(());
+        }
+        let locations_length = self.locations.len();
+        let start = self.token_location();
+        match self.parse_package_definition() {
+            Ok(pckg) => packages.push(pckg),
+            Err(_) => {
+                self.locations.truncate(locations_length);
+                self.skip_to_directive_boundary(&start);
+                let location = start.combine_with(self.previous_token.1.clone());
+                packages.push(Rc::new(PackageDefinition {
+                    location: location.clone(),
+                    jetdoc: None,
+                    name: vec![],
+                    block: Rc::new(Block {
+                        location: location.clone(),
+                        metadata: None,
+                        directives: vec![Rc::new(Directive::Invalid(InvalidDirective { location }))],
+                    }),
+                }));
+            },
+        }
+        Ok(())
+    }
+
+    fn parse_package_definition(&mut self) -> Result<Rc<PackageDefinition>, ParsingFailure> {
+        self.mark_location();
+        let jetdoc = self.parse_jetdoc()?;
+        self.next()?;
+        let mut name = vec![];
+        let result: Result<(), ParsingFailure> = try {
             if let Some(name1) = self.consume_identifier(false)? {
                 name.push(name1.clone());
                 while self.consume(Token::Dot)? {
                     name.push(self.expect_identifier(true)?);
                 }
             }
-            let block = Rc::new(self.parse_block(ParsingDirectiveContext::PackageBlock)?);
-            packages.push(Rc::new(PackageDefinition {
-                location: self.pop_location(),
-                jetdoc,
-                name,
-                block,
-            }));
+        };
+        if self.error_tolerant {
+            // Resynchronize at the package block, so that its
+            // directives are kept despite an invalid package name.
+            if result.is_ok() && !self.peek(Token::LeftBrace) {
+                self.add_syntax_error(&self.token_location(), DiagnosticKind::Expected, diagnostic_arguments![Token(Token::LeftBrace), Token(self.token.0.clone())]);
+            }
+            while !(self.peek(Token::LeftBrace) || self.peek(Token::RightBrace) || self.peek(Token::Eof)) {
+                self.next_skipping_failures();
+            }
+        } else {
+            result?;
         }
-        let directives = self.parse_directives(ParsingDirectiveContext::TopLevel)?;
-        Ok(Rc::new(Program {
+        let block = Rc::new(self.parse_block(ParsingDirectiveContext::PackageBlock)?);
+        Ok(Rc::new(PackageDefinition {
             location: self.pop_location(),
-            packages,
-            directives,
+            jetdoc,
+            name,
+            block,
         }))
     }
 
     pub fn parse_jetdoc(&mut self) -> Result<Option<Rc<JetDoc>>, ParsingFailure> {
         let comments = self.compilation_unit().comments.borrow();
-        let last_comment = comments.last().map(|last_comment| last_comment.clone());
+        let last_comment = comments.last().cloned();
         drop(comments);
         Ok(last_comment.and_then(|comment| {
             if comment.is_jetdoc(&self.token.1) {
                 self.compilation_unit().comments_mut().pop();
                 let location = comment.location();
                 let comment_prefix_length: usize = 3;
-                let location = Location::with_offsets(self.compilation_unit(), location.first_offset + comment_prefix_length, location.last_offset - 2);
+                let location = Location::with_offsets(self.compilation_unit(), location.first_offset() + comment_prefix_length, location.last_offset() - 2);
                 let content = &comment.content.borrow()[1..];
                 let (main_body, tags) = self.parse_jetdoc_content(&location, content);
                 Some(Rc::new(JetDoc {
@@ -3757,7 +3976,7 @@ impl<'input> Parser<'input> {
         }))
     }
 
-    fn parse_jetdoc_content(&mut self, location: &Location, content: &str) -> (Option<(String, Location)>, Vec<(JetDocTag, Location)>) {
+    fn parse_jetdoc_content(&mut self, location: &Location, content: &str) -> JetDocContent {
         let lines = self.split_jetdoc_lines(location, content);
 
         let mut main_body: Option<(String, Location)> = None;
@@ -3899,7 +4118,7 @@ impl<'input> Parser<'input> {
                     if let Some(exp) = ParserFacade::parse_expression(&compilation_unit_2, &self.host) {
                         tags.push((JetDocTag::EventType(exp), location));
                     } else {
-                        self.add_syntax_error(&tag_location, DiagnosticKind::FailedParsingJetDocTag, diagnostic_arguments![String(tag_name.clone())]);
+                        self.add_syntax_error(tag_location, DiagnosticKind::FailedParsingJetDocTag, diagnostic_arguments![String(tag_name.clone())]);
                     }
                 },
 
@@ -3917,7 +4136,7 @@ impl<'input> Parser<'input> {
 
                     // Content must be non empty
                     if regex_is_match!(r"^\s*$", &path) {
-                        self.add_syntax_error(&tag_location, DiagnosticKind::FailedParsingJetDocTag, diagnostic_arguments![String(tag_name.clone())]);
+                        self.add_syntax_error(tag_location, DiagnosticKind::FailedParsingJetDocTag, diagnostic_arguments![String(tag_name.clone())]);
                     }
 
                     tags.push((JetDocTag::Image { path }, location));
@@ -3930,7 +4149,7 @@ impl<'input> Parser<'input> {
 
                     // Content must be non empty
                     if regex_is_match!(r"^\s*$", &text) {
-                        self.add_syntax_error(&tag_location, DiagnosticKind::FailedParsingJetDocTag, diagnostic_arguments![String(tag_name.clone())]);
+                        self.add_syntax_error(tag_location, DiagnosticKind::FailedParsingJetDocTag, diagnostic_arguments![String(tag_name.clone())]);
                     }
 
                     tags.push((JetDocTag::Internal(text), location));
@@ -3955,7 +4174,7 @@ impl<'input> Parser<'input> {
 
                     // Content must be empty
                     if !regex_is_match!(r"^\s*$", &text) {
-                        self.add_syntax_error(&tag_location, DiagnosticKind::FailedParsingJetDocTag, diagnostic_arguments![String(tag_name.clone())]);
+                        self.add_syntax_error(tag_location, DiagnosticKind::FailedParsingJetDocTag, diagnostic_arguments![String(tag_name.clone())]);
                     }
 
                     tags.push((JetDocTag::Private, location));
@@ -3981,7 +4200,7 @@ impl<'input> Parser<'input> {
                         reference = content;
                         display_text = None;
                     }
-                    if let Some(reference) = self.parse_jetdoc_reference(&reference, &tag_location, &tag_name) {
+                    if let Some(reference) = self.parse_jetdoc_reference(&reference, tag_location, tag_name) {
                         tags.push((JetDocTag::See { reference, display_text }, location));
                     }
                 },
@@ -4004,16 +4223,16 @@ impl<'input> Parser<'input> {
                         if let Some(exp) = ParserFacade::parse_type_expression(&compilation_unit_2, &self.host) {
                             tags.push((JetDocTag::Throws { class_reference: exp, description }, location));
                         } else {
-                            self.add_syntax_error(&tag_location, DiagnosticKind::FailedParsingJetDocTag, diagnostic_arguments![String(tag_name.clone())]);
+                            self.add_syntax_error(tag_location, DiagnosticKind::FailedParsingJetDocTag, diagnostic_arguments![String(tag_name.clone())]);
                         }
                     } else {
-                        self.add_syntax_error(&tag_location, DiagnosticKind::FailedParsingJetDocTag, diagnostic_arguments![String(tag_name.clone())]);
+                        self.add_syntax_error(tag_location, DiagnosticKind::FailedParsingJetDocTag, diagnostic_arguments![String(tag_name.clone())]);
                     }
                 },
 
                 // Unrecognized tag
                 _ => {
-                    self.add_syntax_error(&tag_location, DiagnosticKind::UnrecognizedJetDocTag, diagnostic_arguments![String(tag_name.clone())]);
+                    self.add_syntax_error(tag_location, DiagnosticKind::UnrecognizedJetDocTag, diagnostic_arguments![String(tag_name.clone())]);
                 },
             }
         } else if !building_content.is_empty() {
@@ -4027,7 +4246,7 @@ impl<'input> Parser<'input> {
     fn parse_jetdoc_reference(&self, reference: &str, tag_location: &Location, tag_name: &str) -> Option<Rc<JetDocReference>> {
         let split: Vec<&str> = reference.split("#").collect();
         if split.len() > 2 {
-            self.add_syntax_error(&tag_location, DiagnosticKind::FailedParsingJetDocTag, diagnostic_arguments![String(tag_name.to_owned())]);
+            self.add_syntax_error(tag_location, DiagnosticKind::FailedParsingJetDocTag, diagnostic_arguments![String(tag_name.to_owned())]);
             return None;
         }
         let mut base: Option<Rc<Expression>> = None;
@@ -4039,20 +4258,23 @@ impl<'input> Parser<'input> {
             if let Some(exp) = ParserFacade::parse_expression(&compilation_unit_2, &self.host) {
                 base = Some(exp);
             } else {
-                self.add_syntax_error(&tag_location, DiagnosticKind::FailedParsingJetDocTag, diagnostic_arguments![String(tag_name.to_owned())]);
+                self.add_syntax_error(tag_location, DiagnosticKind::FailedParsingJetDocTag, diagnostic_arguments![String(tag_name.to_owned())]);
                 return None;
             }
         }
 
         if base.is_none() && instance_property.is_none() {
-            self.add_syntax_error(&tag_location, DiagnosticKind::FailedParsingJetDocTag, diagnostic_arguments![String(tag_name.to_owned())]);
+            self.add_syntax_error(tag_location, DiagnosticKind::FailedParsingJetDocTag, diagnostic_arguments![String(tag_name.to_owned())]);
             return None;
         }
         Some(Rc::new(JetDocReference { base, instance_property, }))
     }
 }
 
-fn join_jetdoc_content(content: &Vec<(String, Location)>) -> (String, Location) {
+/// The main body and tags of a JetDoc comment.
+type JetDocContent = (Option<(String, Location)>, Vec<(JetDocTag, Location)>);
+
+fn join_jetdoc_content(content: &[(String, Location)]) -> (String, Location) {
     let s: Vec<String> = content.iter().map(|c| c.0.clone()).collect();
     let s = s.join("\n").trim().to_owned();
     let location = content.first().unwrap().1.combine_with(content.last().unwrap().1.clone());
@@ -4113,6 +4335,29 @@ impl ParserFacade {
         }
     }
 
+    /// Parses `Program` until end-of-file in error-tolerant mode.
+    /// Always returns a `Program`, possibly containing `Invalid` nodes;
+    /// syntax errors are reported to the compilation unit.
+    pub fn parse_program_error_tolerant(compilation_unit: &Rc<CompilationUnit>, host: &Rc<SymbolHost>) -> Rc<Program> {
+        let mut parser = Parser::new(compilation_unit, host);
+        parser.set_error_tolerant(true);
+        parser.next_skipping_failures();
+        parser.parse_program().unwrap_or_else(|_| Rc::new(Program {
+            location: Location::with_offsets(compilation_unit, 0, compilation_unit.text().len()),
+            packages: vec![],
+            directives: vec![],
+        }))
+    }
+
+    /// Parses `Directives` until end-of-file in error-tolerant mode.
+    /// Always returns a list of directives, possibly containing `Invalid` nodes.
+    pub fn parse_directives_error_tolerant(compilation_unit: &Rc<CompilationUnit>, host: &Rc<SymbolHost>, context: ParsingDirectiveContext) -> Vec<Rc<Directive>> {
+        let mut parser = Parser::new(compilation_unit, host);
+        parser.set_error_tolerant(true);
+        parser.next_skipping_failures();
+        parser.parse_directives(context).unwrap_or_default()
+    }
+
     /// Parses `ListExpression^allowIn` and expects end-of-file.
     pub fn parse_expression(compilation_unit: &Rc<CompilationUnit>, host: &Rc<SymbolHost>) -> Option<Rc<Expression>> {
         let mut parser = Parser::new(compilation_unit, host);
@@ -4153,4 +4398,284 @@ impl ParserFacade {
             None
         }
     }
+
+    /// Reparses `Program` after its compilation unit's text has been
+    /// changed through `CompilationUnit::edit()`. `program` must be the program
+    /// last parsed from the compilation unit.
+    ///
+    /// Only the top-level packages and directives affected by the edits are
+    /// reparsed; the other `Rc<PackageDefinition>` and `Rc<Directive>` nodes
+    /// are reused, with their locations shifted past the edits, so that
+    /// their `AstToSymbol` entries remain valid. The whole program
+    /// is reparsed if the reparsed region does not connect with
+    /// the reused nodes.
+    ///
+    /// Returns `None` if the compilation unit contains syntax errors. Syntax errors
+    /// from previous attempts are discarded, as `program` contains none.
+    pub fn reparse_program(program: &Rc<Program>, host: &Rc<SymbolHost>) -> Option<Rc<Program>> {
+        let compilation_unit = program.location.compilation_unit();
+        compilation_unit.retain_diagnostics(|d| d.is_warning() || d.is_verify_error());
+        let program = Self::reparse_program_1(program, host, false).ok();
+        if compilation_unit.diagnostics().iter().any(|d| !(d.is_warning() || d.is_verify_error())) { None } else { program }
+    }
+
+    /// Reparses `Program` after its compilation unit's text has been
+    /// changed through `CompilationUnit::edit()`, in error-tolerant mode.
+    /// See `reparse_program()` for how nodes are reused.
+    pub fn reparse_program_error_tolerant(program: &Rc<Program>, host: &Rc<SymbolHost>) -> Rc<Program> {
+        let compilation_unit = program.location.compilation_unit();
+        Self::reparse_program_1(program, host, true).unwrap_or_else(|_| Rc::new(Program {
+            location: Location::with_offsets(&compilation_unit, 0, compilation_unit.text().len()),
+            packages: vec![],
+            directives: vec![],
+        }))
+    }
+
+    fn reparse_program_1(program: &Rc<Program>, host: &Rc<SymbolHost>, error_tolerant: bool) -> Result<Rc<Program>, ParsingFailure> {
+        let compilation_unit = program.location.compilation_unit();
+
+        // Range affected by the edits in the current text
+        let Some(Range { start: dirty_start, end: dirty_end }) = program.location.version.dirty_range() else {
+            return Ok(program.clone());
+        };
+
+        // Top-level items, packages first
+        let items: Vec<(Option<Rc<JetDoc>>, Location)> = program.packages.iter()
+            .map(|pckg| (pckg.jetdoc.clone(), pckg.location.clone()))
+            .chain(program.directives.iter().map(|drtv| (drtv.jetdoc(), drtv.location())))
+            .collect();
+        let package_count = program.packages.len();
+
+        // Reparse the items from `lo` (inclusive) to `hi` (exclusive)
+        let mut lo = items.iter().position(|(_, location)| location.last_offset() >= dirty_start).unwrap_or(items.len());
+        let mut hi = (lo..items.len()).find(|&i| Self::item_start(&items[i]) > dirty_end).unwrap_or(items.len());
+
+        // The first reparsed directive must follow a directive
+        // terminated by a semicolon or line break
+        while lo > package_count && !Self::is_terminated_directive(&compilation_unit, &items[lo - 1].1, items.get(lo).and_then(|item| item.0.as_ref())) {
+            lo -= 1;
+        }
+
+        let comments = compilation_unit.comments();
+        let mut packages = vec![];
+        let mut directives = vec![];
+        let mut result = Self::reparse_program_region(program, host, error_tolerant, &items, lo..hi, &mut packages, &mut directives);
+        if matches!(result, Ok(None)) {
+            // The reparsed region does not connect with the
+            // next item; reparse the whole program.
+            (lo, hi) = (0, items.len());
+            packages.clear();
+            directives.clear();
+            result = Self::reparse_program_region(program, host, error_tolerant, &items, lo..hi, &mut packages, &mut directives);
+        }
+        let location = match result {
+            Ok(location) => location.unwrap(),
+            Err(error) => {
+                *compilation_unit.comments_mut() = comments;
+                return Err(error);
+            },
+        };
+        packages.splice(0..0, program.packages[..lo.min(package_count)].iter().cloned());
+        packages.extend(program.packages[hi.min(package_count)..].iter().cloned());
+        directives.splice(0..0, program.directives[..(lo.max(package_count) - package_count)].iter().cloned());
+        directives.extend(program.directives[(hi.max(package_count) - package_count)..].iter().cloned());
+
+        Ok(Rc::new(Program {
+            location,
+            packages,
+            directives,
+        }))
+    }
+
+    /// Reparses the top-level items in `range` into `packages` and `directives`,
+    /// returning the program location, or `None` if the reparsed
+    /// packages and directives do not connect with the next item.
+    fn reparse_program_region(program: &Rc<Program>, host: &Rc<SymbolHost>, error_tolerant: bool, items: &[(Option<Rc<JetDoc>>, Location)], Range { start: lo, end: hi }: Range<usize>, packages: &mut Vec<Rc<PackageDefinition>>, directives: &mut Vec<Rc<Directive>>) -> Result<Option<Location>, ParsingFailure> {
+        let compilation_unit = program.location.compilation_unit();
+        let text_length = compilation_unit.text().len();
+        let package_count = program.packages.len();
+
+        // The region starts after the last token of the previous item
+        // and ends at the next item, including its JetDoc comment.
+        let start = if lo == 0 { 0 } else { Self::item_end(&compilation_unit, &items[lo - 1].1, items.get(lo).and_then(|item| item.0.as_ref())) };
+        let (end, next_token_offset) = match items.get(hi) {
+            Some(item) => (Self::item_start(item), item.1.first_offset()),
+            None => (text_length, text_length),
+        };
+
+        // Discard the comments and diagnostics within the region
+        let (comments_before, comments_after): (Vec<Rc<Comment>>, Vec<Rc<Comment>>) = compilation_unit.comments().into_iter()
+            .filter(|comment| comment.location().first_offset() < start || comment.location().first_offset() >= end)
+            .partition(|comment| comment.location().first_offset() < start);
+        *compilation_unit.comments_mut() = comments_before;
+        compilation_unit.retain_diagnostics(|d| {
+            let offset = d.location().first_offset();
+            offset < start || (offset >= end && end != text_length)
+        });
+
+        let mut parser = Parser::new_at(&compilation_unit, start, host);
+        parser.set_error_tolerant(error_tolerant);
+        if error_tolerant {
+            parser.next_skipping_failures();
+        } else {
+            parser.next()?;
+        }
+        let first_offset = if lo == 0 { parser.token.1.first_offset() } else { program.location.first_offset() };
+        let semicolon = parser.parse_program_region(end, lo <= package_count, packages, directives)?;
+        let connects = hi == items.len() || (
+            parser.token.1.first_offset() == next_token_offset &&
+            (semicolon || directives.is_empty()) &&
+            (hi >= package_count || directives.is_empty())
+        );
+
+        // The JetDoc comment of the next item has been scanned again
+        let mut comments = compilation_unit.comments_mut();
+        comments.retain(|comment| comment.location().first_offset() < end);
+        comments.extend(comments_after);
+        drop(comments);
+
+        let location = Location::with_offsets(&compilation_unit, first_offset, text_length);
+        Ok(if connects { Some(location) } else { None })
+    }
+
+    /// Offset of a top-level item, including its JetDoc comment.
+    fn item_start((jetdoc, location): &(Option<Rc<JetDoc>>, Location)) -> usize {
+        jetdoc.as_ref().map(|jetdoc| jetdoc.location.first_offset() - 3).unwrap_or(location.first_offset())
+    }
+
+    /// Offset after the last token of a top-level item, skipping trailing
+    /// whitespace and comments, including the JetDoc comment of the next item.
+    fn item_end(compilation_unit: &Rc<CompilationUnit>, location: &Location, next_jetdoc: Option<&Rc<JetDoc>>) -> usize {
+        let text = compilation_unit.text();
+        let mut comments: Vec<(usize, usize)> = compilation_unit.comments().iter()
+            .map(|comment| (comment.location().first_offset(), comment.location().last_offset()))
+            .collect();
+        if let Some(jetdoc) = next_jetdoc {
+            comments.push((jetdoc.location.first_offset() - 3, jetdoc.location.last_offset() + 2));
+        }
+        let first_offset = location.first_offset();
+        let mut offset = location.last_offset();
+        loop {
+            offset = first_offset + text[first_offset..offset].trim_end_matches(|ch| CharacterValidator::is_whitespace(ch) || CharacterValidator::is_line_terminator(ch)).len();
+            match comments.iter().find(|(start, end)| *end == offset && *start >= first_offset) {
+                Some((start, _)) => offset = *start,
+                None => break offset,
+            }
+        }
+    }
+
+    /// Indicates whether a top-level directive is terminated
+    /// by a semicolon or line break.
+    fn is_terminated_directive(compilation_unit: &Rc<CompilationUnit>, location: &Location, next_jetdoc: Option<&Rc<JetDoc>>) -> bool {
+        let end = Self::item_end(compilation_unit, location, next_jetdoc);
+        compilation_unit.text()[..end].ends_with(';') || compilation_unit.get_line_number(end) != location.last_line_number()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ns::*;
+    use std::rc::Rc;
+
+    fn serialize(program: &Rc<Program>) -> String {
+        with_location_serialization(LocationSerialization::default(), || {
+            serde_json::to_string(&(&program.packages, &program.directives)).unwrap()
+        })
+    }
+
+    #[test]
+    fn statement_followed_by_annotatable_directive() {
+        let host = SymbolHost::new("");
+        let compilation_unit = CompilationUnit::new(None, "x\nvar y = 1;\nf()\nfunction g() {}".into(), &CompilerOptions::new());
+        let program = ParserFacade::parse_program(&compilation_unit, &host).unwrap();
+        let directives = program.directives.iter().map(|d| d.as_ref()).collect::<Vec<_>>();
+        assert!(matches!(directives.as_slice(), [
+            Directive::ExpressionStatement(_),
+            Directive::VariableDefinition(_),
+            Directive::ExpressionStatement(_),
+            Directive::FunctionDefinition(_),
+        ]));
+    }
+
+    #[test]
+    fn error_tolerant_package_definitions() {
+        let host = SymbolHost::new("");
+        let compilation_unit = CompilationUnit::new(None, "package p. {\n    var a;\n    function f() { ) }\n    var b;\n}\nvar c;".into(), &CompilerOptions::new());
+        let program = ParserFacade::parse_program_error_tolerant(&compilation_unit, &host);
+        assert!(compilation_unit.invalidated());
+        assert_eq!(program.packages.len(), 1);
+        assert_eq!(program.packages[0].name.iter().map(|name| name.0.as_str()).collect::<Vec<_>>(), ["p"]);
+        let directives = program.packages[0].block.directives.iter().map(|d| d.as_ref()).collect::<Vec<_>>();
+        assert!(matches!(directives.as_slice(), [
+            Directive::VariableDefinition(_),
+            Directive::FunctionDefinition(_),
+            Directive::VariableDefinition(_),
+        ]));
+        assert!(matches!(program.directives[..], [ref d] if matches!(d.as_ref(), Directive::VariableDefinition(_))));
+
+        // A package without a block is replaced by an invalid directive
+        let compilation_unit = CompilationUnit::new(None, "package p".into(), &CompilerOptions::new());
+        let program = ParserFacade::parse_program_error_tolerant(&compilation_unit, &host);
+        assert_eq!(program.packages.len(), 1);
+        assert!(matches!(program.packages[0].block.directives[..], [ref d] if matches!(d.as_ref(), Directive::Invalid(_))));
+    }
+
+    #[test]
+    fn reparse_program() {
+        let host = SymbolHost::new("");
+        let text = "package p {\n}\n/** A. */\nfunction a(): void {\n}\nvar x = 10\n// B\nfunction b(): void {\n}\n";
+        let compilation_unit = CompilationUnit::new(None, text.into(), &CompilerOptions::new());
+        let program = ParserFacade::parse_program(&compilation_unit, &host).unwrap();
+
+        let offset = text.find("10").unwrap();
+        compilation_unit.edit(offset..offset + 2, "100 +\n1");
+        let reparsed = ParserFacade::reparse_program(&program, &host).unwrap();
+        assert!(Rc::ptr_eq(&program.packages[0], &reparsed.packages[0]));
+        assert!(Rc::ptr_eq(&program.directives[0], &reparsed.directives[0]));
+        assert!(!Rc::ptr_eq(&program.directives[1], &reparsed.directives[1]));
+        assert!(Rc::ptr_eq(&program.directives[2], &reparsed.directives[2]));
+        assert!(reparsed.directives[0].jetdoc().is_some());
+        let location = reparsed.directives[2].location();
+        assert_eq!(location.first_offset(), compilation_unit.text().find("function b").unwrap());
+        assert_eq!(location.first_line_number(), 9);
+        assert_eq!(compilation_unit.comments().len(), 1);
+
+        // Syntax errors are reported until fixed
+        let offset = compilation_unit.text().find("1\n").unwrap();
+        compilation_unit.edit(offset..offset + 1, "");
+        assert!(ParserFacade::reparse_program(&reparsed, &host).is_none());
+        compilation_unit.edit(offset..offset, "2");
+        let reparsed_1 = ParserFacade::reparse_program(&reparsed, &host).unwrap();
+        assert!(!compilation_unit.invalidated());
+        assert!(Rc::ptr_eq(&program.directives[2], &reparsed_1.directives[2]));
+
+        let fresh_compilation_unit = CompilationUnit::new(None, compilation_unit.text().to_string(), &CompilerOptions::new());
+        let fresh = ParserFacade::parse_program(&fresh_compilation_unit, &host).unwrap();
+        assert_eq!(serialize(&reparsed_1), serialize(&fresh));
+    }
+
+    #[test]
+    fn reparse_program_matches_parse() {
+        let host = SymbolHost::new("");
+        let directory = concat!(env!("CARGO_MANIFEST_DIR"), "/../../tests/parser");
+        for entry in std::fs::read_dir(directory).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().map(|extension| extension != "jet").unwrap_or(true) {
+                continue;
+            }
+            let text = std::fs::read_to_string(&path).unwrap();
+            let compilation_unit = CompilationUnit::new(None, text.clone(), &CompilerOptions::new());
+            let mut program = ParserFacade::parse_program_error_tolerant(&compilation_unit, &host);
+            let offsets = (0..text.len()).step_by(41).filter(|&offset| text.is_char_boundary(offset)).collect::<Vec<_>>();
+            for (i, &offset) in offsets.iter().enumerate().rev() {
+                let replacement = ["\n", " ", "(", ""][i % 4];
+                let end = if replacement.is_empty() { (offset + 1..=text.len()).find(|&end| text.is_char_boundary(end)).unwrap() } else { offset };
+                compilation_unit.edit(offset..end, replacement);
+                let fresh_compilation_unit = CompilationUnit::new(None, compilation_unit.text().to_string(), &CompilerOptions::new());
+                let fresh = ParserFacade::parse_program_error_tolerant(&fresh_compilation_unit, &host);
+                program = ParserFacade::reparse_program_error_tolerant(&program, &host);
+                assert_eq!(serialize(&program), serialize(&fresh), "{}: edit at {offset}:\n{}", path.display(), compilation_unit.text());
+            }
+        }
+    }
 }
\ No newline at end of file
@@ -4,7 +4,9 @@ use crate::ns::*;
 /// to finish without a resulting node.
 /// 
 /// This parser is intolerant in general,
-/// thus resulting in a `ParsingFailure` for almost any syntax error.
+/// thus resulting in a `ParsingFailure` for almost any syntax error,
+/// unless it is in error-tolerant mode, in which case failures are recovered
+/// at statement, directive and block boundaries.
 #[derive(Copy, Clone, Debug)]
 pub struct ParsingFailure;
 
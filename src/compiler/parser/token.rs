@@ -144,7 +144,7 @@ pub enum Token {
     XmlAttributeValue(String),
 }
 
-impl ToString for Token {
+impl std::fmt::Display for Token {
     /// Converts the token into a readable string.
     ///
     /// The method `Token::to_string` returns the following possible values:
@@ -163,8 +163,8 @@ impl ToString for Token {
     /// * `"XML name"`
     /// * `"XML markup"`
     /// * `"XML attribute value"`
-    fn to_string(&self) -> String {
-        (match self {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
             Token::Eof => "end of program",
             Token::Identifier(_) => "identifier",
             Token::StringLiteral(_) => "string",
@@ -288,7 +288,7 @@ impl ToString for Token {
             Token::XmlName(_) => "XML name",
             Token::XmlMarkup(_) => "XML markup",
             Token::XmlAttributeValue(_) => "XML attribute value",
-        }).into()
+        })
     }
 }
 
@@ -423,7 +423,7 @@ impl Token {
             Self::Protected => Some(Attribute::Protected(location.clone())),
             Self::Internal => Some(Attribute::Internal(location.clone())),
             Self::Identifier(ref name) => {
-                Attribute::from_identifier_name(name, &location)
+                Attribute::from_identifier_name(name, location)
             },
             _ => None,
         }
@@ -1,24 +1,48 @@
 use std::cell::RefCell;
+use std::marker::PhantomData;
 use std::rc::Rc;
 use crate::ns::*;
 
 pub struct Tokenizer<'input> {
     compilation_unit: Rc<CompilationUnit>,
+    /// Text of the compilation unit when the tokenizer was constructed.
+    text: Rc<String>,
     line_number: usize,
-    characters: CharacterReader<'input>,
+    characters: TextReader,
+    records_line_skips: bool,
+    _input: PhantomData<&'input CompilationUnit>,
 }
 
 impl<'input> Tokenizer<'input> {
     /// Constructs a tokenizer.
     pub fn new(compilation_unit: &'input Rc<CompilationUnit>) -> Self {
-        let text: &'input str = compilation_unit.text.as_ref();
         let compilation_unit = compilation_unit.clone();
         assert!(!compilation_unit.already_tokenized.get(), "A CompilationUnit must be tokenized at most once.");
         compilation_unit.already_tokenized.set(true);
+        let text = compilation_unit.text();
         Self {
             compilation_unit,
+            characters: TextReader { text: text.clone(), index: 0 },
+            text,
             line_number: 1,
-            characters: CharacterReader::from(text),
+            records_line_skips: true,
+            _input: PhantomData,
+        }
+    }
+
+    /// Constructs a tokenizer that resumes at `offset` in an already
+    /// tokenized compilation unit, used for reparsing part of its text
+    /// after an edit. Line skips are not recorded, since
+    /// `CompilationUnit::edit()` already updates them.
+    pub fn new_at(compilation_unit: &'input Rc<CompilationUnit>, offset: usize) -> Self {
+        let text = compilation_unit.text();
+        Self {
+            compilation_unit: compilation_unit.clone(),
+            characters: TextReader { text: text.clone(), index: offset },
+            text,
+            line_number: compilation_unit.get_line_number(offset),
+            records_line_skips: false,
+            _input: PhantomData,
         }
     }
 
@@ -26,6 +50,14 @@ impl<'input> Tokenizer<'input> {
         &self.compilation_unit
     }
 
+    /// Skips the current character. Used by the parser to recover
+    /// from a character that failed to tokenize.
+    pub fn skip_character(&mut self) {
+        if !self.consume_line_terminator() {
+            self.characters.next();
+        }
+    }
+
     /// Scans for an *InputElementDiv* token.
     pub fn scan_ie_div(&mut self) -> Result<(Token, Location), ParsingFailure> {
         loop {
@@ -53,43 +85,43 @@ impl<'input> Tokenizer<'input> {
                 // Comma
                 self.characters.next();
                 let location = start.combine_with(self.cursor_location());
-                return Ok((Token::Comma, location));
+                Ok((Token::Comma, location))
             },
             '(' => {
                 // LeftParen
                 self.characters.next();
                 let location = start.combine_with(self.cursor_location());
-                return Ok((Token::LeftParen, location));
+                Ok((Token::LeftParen, location))
             },
             ')' => {
                 // RightParen
                 self.characters.next();
                 let location = start.combine_with(self.cursor_location());
-                return Ok((Token::RightParen, location));
+                Ok((Token::RightParen, location))
             },
             '[' => {
                 // LeftBracket
                 self.characters.next();
                 let location = start.combine_with(self.cursor_location());
-                return Ok((Token::LeftBracket, location));
+                Ok((Token::LeftBracket, location))
             },
             ']' => {
                 // RightBracket
                 self.characters.next();
                 let location = start.combine_with(self.cursor_location());
-                return Ok((Token::RightBracket, location));
+                Ok((Token::RightBracket, location))
             },
             '{' => {
                 // LeftBrace
                 self.characters.next();
                 let location = start.combine_with(self.cursor_location());
-                return Ok((Token::LeftBrace, location));
+                Ok((Token::LeftBrace, location))
             },
             '}' => {
                 // RightBrace
                 self.characters.next();
                 let location = start.combine_with(self.cursor_location());
-                return Ok((Token::RightBrace, location));
+                Ok((Token::RightBrace, location))
             },
             ':' => {
                 self.characters.next();
@@ -101,7 +133,7 @@ impl<'input> Tokenizer<'input> {
                 }
                 // Colon
                 let location = start.combine_with(self.cursor_location());
-                return Ok((Token::Colon, location));
+                Ok((Token::Colon, location))
             },
             '=' => {
                 self.characters.next();
@@ -120,7 +152,7 @@ impl<'input> Tokenizer<'input> {
                 }
                 // Assign
                 let location = start.combine_with(self.cursor_location());
-                return Ok((Token::Assign, location));
+                Ok((Token::Assign, location))
             },
             '!' => {
                 self.characters.next();
@@ -139,7 +171,7 @@ impl<'input> Tokenizer<'input> {
                 }
                 // Exclamation
                 let location = start.combine_with(self.cursor_location());
-                return Ok((Token::Exclamation, location));
+                Ok((Token::Exclamation, location))
             },
             '?' => {
                 self.characters.next();
@@ -164,13 +196,13 @@ impl<'input> Tokenizer<'input> {
                 }
                 // Question
                 let location = start.combine_with(self.cursor_location());
-                return Ok((Token::Question, location));
+                Ok((Token::Question, location))
             },
             ';' => {
                 // Semicolon
                 self.characters.next();
                 let location = start.combine_with(self.cursor_location());
-                return Ok((Token::Semicolon, location));
+                Ok((Token::Semicolon, location))
             },
             '<' => {
                 self.characters.next();
@@ -195,7 +227,7 @@ impl<'input> Tokenizer<'input> {
                 }
                 // Lt
                 let location = start.combine_with(self.cursor_location());
-                return Ok((Token::Lt, location));
+                Ok((Token::Lt, location))
             },
             '>' => {
                 self.characters.next();
@@ -225,14 +257,14 @@ impl<'input> Tokenizer<'input> {
                     return Ok((Token::UnsignedRightShift, location));
                 }
                 // RightShift
-                if ch == '<' {
+                if ch == '>' {
                     self.characters.next();
                     let location = start.combine_with(self.cursor_location());
                     return Ok((Token::RightShift, location));
                 }
                 // Gt
                 let location = start.combine_with(self.cursor_location());
-                return Ok((Token::Gt, location));
+                Ok((Token::Gt, location))
             },
             '@' => {
                 // Attribute
@@ -241,7 +273,7 @@ impl<'input> Tokenizer<'input> {
                     return Ok(token);
                 }
                 let location = start.combine_with(self.cursor_location());
-                return Ok((Token::Attribute, location));
+                Ok((Token::Attribute, location))
             },
             '+' => {
                 self.characters.next();
@@ -260,7 +292,7 @@ impl<'input> Tokenizer<'input> {
                 }
                 // Plus
                 let location = start.combine_with(self.cursor_location());
-                return Ok((Token::Plus, location));
+                Ok((Token::Plus, location))
             },
             '-' => {
                 self.characters.next();
@@ -279,7 +311,7 @@ impl<'input> Tokenizer<'input> {
                 }
                 // Minus
                 let location = start.combine_with(self.cursor_location());
-                return Ok((Token::Minus, location));
+                Ok((Token::Minus, location))
             },
             '*' => {
                 self.characters.next();
@@ -304,7 +336,7 @@ impl<'input> Tokenizer<'input> {
                 }
                 // Times
                 let location = start.combine_with(self.cursor_location());
-                return Ok((Token::Times, location));
+                Ok((Token::Times, location))
             },
             '/' => {
                 self.characters.next();
@@ -317,7 +349,7 @@ impl<'input> Tokenizer<'input> {
                 }
                 // Div
                 let location = start.combine_with(self.cursor_location());
-                return Ok((Token::Div, location));
+                Ok((Token::Div, location))
             },
             '%' => {
                 self.characters.next();
@@ -330,7 +362,7 @@ impl<'input> Tokenizer<'input> {
                 }
                 // Remainder
                 let location = start.combine_with(self.cursor_location());
-                return Ok((Token::Remainder, location));
+                Ok((Token::Remainder, location))
             },
             '&' => {
                 self.characters.next();
@@ -355,7 +387,7 @@ impl<'input> Tokenizer<'input> {
                 }
                 // BitwiseAnd
                 let location = start.combine_with(self.cursor_location());
-                return Ok((Token::BitwiseAnd, location));
+                Ok((Token::BitwiseAnd, location))
             },
             '^' => {
                 self.characters.next();
@@ -380,7 +412,7 @@ impl<'input> Tokenizer<'input> {
                 }
                 // BitwiseXor
                 let location = start.combine_with(self.cursor_location());
-                return Ok((Token::BitwiseXor, location));
+                Ok((Token::BitwiseXor, location))
             },
             '|' => {
                 self.characters.next();
@@ -405,21 +437,21 @@ impl<'input> Tokenizer<'input> {
                 }
                 // BitwiseOr
                 let location = start.combine_with(self.cursor_location());
-                return Ok((Token::BitwiseOr, location));
+                Ok((Token::BitwiseOr, location))
             },
             '~' => {
                 // BitwiseNot
                 self.characters.next();
                 let location = start.combine_with(self.cursor_location());
-                return Ok((Token::BitwiseNot, location));
+                Ok((Token::BitwiseNot, location))
             },
             _ => {
                 if self.characters.has_remaining() {
                     self.add_unexpected_error();
-                    return Err(ParsingFailure);
+                    Err(ParsingFailure)
                 // Eof
                 } else {
-                    return Ok((Token::Eof, start))
+                    Ok((Token::Eof, start))
                 }
             },
         }
@@ -428,7 +460,8 @@ impl<'input> Tokenizer<'input> {
     /// Scans regular expression after a `/` or `/=` token has been scanned by
     /// `scan_ie_div`.
     pub fn scan_regexp_literal(&mut self, start: Location) -> Result<(Token, Location), ParsingFailure> {
-        let mut body = String::new();
+        // The `=` of a `/=` token begins the body.
+        let mut body = self.text[(start.first_offset() + 1)..self.characters.index()].to_owned();
         loop {
             let ch = self.characters.peek_or_zero();
             if ch == '/' {
@@ -498,13 +531,17 @@ impl<'input> Tokenizer<'input> {
         if ch == '\x0D' && self.characters.peek_at_or_zero(1) == '\x0A' {
             self.characters.skip_count_in_place(2);
             self.line_number += 1;
-            self.compilation_unit.push_line_skip(self.line_number, self.characters.index());
+            if self.records_line_skips {
+                self.compilation_unit.push_line_skip(self.line_number, self.characters.index());
+            }
             return true;
         }
         if CharacterValidator::is_line_terminator(ch) {
             self.characters.next();
             self.line_number += 1;
-            self.compilation_unit.push_line_skip(self.line_number, self.characters.index());
+            if self.records_line_skips {
+                self.compilation_unit.push_line_skip(self.line_number, self.characters.index());
+            }
             return true;
         }
         false
@@ -527,7 +564,7 @@ impl<'input> Tokenizer<'input> {
 
             self.compilation_unit.comments.borrow_mut().push(Rc::new(Comment {
                 multiline: false,
-                content: RefCell::new(self.compilation_unit.text[(location.first_offset() + 2)..location.last_offset()].to_owned()),
+                content: RefCell::new(self.text[(location.first_offset() + 2)..location.last_offset()].to_owned()),
                 location: RefCell::new(location),
             }));
 
@@ -562,7 +599,7 @@ impl<'input> Tokenizer<'input> {
 
             self.compilation_unit.comments.borrow_mut().push(Rc::new(Comment {
                 multiline: true,
-                content: RefCell::new(self.compilation_unit.text[(location.first_offset() + 2)..(location.last_offset() - 2)].to_owned()),
+                content: RefCell::new(self.text[(location.first_offset() + 2)..(location.last_offset() - 2)].to_owned()),
                 location: RefCell::new(location),
             }));
 
@@ -659,7 +696,7 @@ impl<'input> Tokenizer<'input> {
         }
         self.characters.next();
         let location = start.combine_with(self.cursor_location());
-        let r = u32::from_str_radix(&self.compilation_unit.text[(start.first_offset + 2)..(location.last_offset - 1)], 16);
+        let r = u32::from_str_radix(&self.text[(start.first_offset() + 2)..(location.last_offset() - 1)], 16);
         let Ok(r) = r else {
             self.compilation_unit.add_diagnostic(Diagnostic::new_syntax_error(&location, DiagnosticKind::UnexpectedOrInvalidToken, vec![]));
             return Err(ParsingFailure);
@@ -768,7 +805,7 @@ impl<'input> Tokenizer<'input> {
         self.unallow_numeric_suffix();
 
         let location = start.combine_with(self.cursor_location());
-        let string = self.compilation_unit.text[location.first_offset..location.last_offset].to_owned();
+        let string = self.text[location.first_offset()..location.last_offset()].to_owned();
 
         Ok(Some((Token::NumericLiteral(string), location)))
     }
@@ -786,7 +823,7 @@ impl<'input> Tokenizer<'input> {
         self.unallow_numeric_suffix();
 
         let location = start.combine_with(self.cursor_location());
-        let s = self.compilation_unit.text[location.first_offset..location.last_offset].to_owned();
+        let s = self.text[location.first_offset()..location.last_offset()].to_owned();
         Ok(Some((Token::NumericLiteral(s), location)))
     }
 
@@ -803,7 +840,7 @@ impl<'input> Tokenizer<'input> {
         self.unallow_numeric_suffix();
 
         let location = start.combine_with(self.cursor_location());
-        let s = self.compilation_unit.text[location.first_offset..location.last_offset].to_owned();
+        let s = self.text[location.first_offset()..location.last_offset()].to_owned();
         Ok(Some((Token::NumericLiteral(s), location)))
     }
 
@@ -1061,7 +1098,7 @@ impl<'input> Tokenizer<'input> {
                 self.characters.next();
             }
             let location = start.combine_with(self.cursor_location());
-            let name = self.compilation_unit.text[location.first_offset..location.last_offset].to_owned();
+            let name = self.text[location.first_offset()..location.last_offset()].to_owned();
             return Ok((Token::XmlName(name), location));
         }
 
@@ -1116,7 +1153,7 @@ impl<'input> Tokenizer<'input> {
                     self.add_unexpected_error();
                     return Err(ParsingFailure)
                 }
-                let value = self.compilation_unit.text[(start.first_offset + 1)..self.cursor_location().first_offset].to_owned();
+                let value = self.text[(start.first_offset() + 1)..self.cursor_location().first_offset()].to_owned();
                 self.characters.next();
                 
                 let location = start.combine_with(self.cursor_location());
@@ -1188,7 +1225,7 @@ impl<'input> Tokenizer<'input> {
                 }
 
                 let location = start.combine_with(self.cursor_location());
-                let content = self.compilation_unit.text[location.first_offset..location.last_offset].to_owned();
+                let content = self.text[location.first_offset()..location.last_offset()].to_owned();
                 Ok((Token::XmlText(content), location))
             },
         }
@@ -1214,7 +1251,7 @@ impl<'input> Tokenizer<'input> {
             }
 
             let location = start.combine_with(self.cursor_location());
-            let content = self.compilation_unit.text[location.first_offset..location.last_offset].to_owned();
+            let content = self.text[location.first_offset()..location.last_offset()].to_owned();
 
             return Ok(Some((Token::XmlMarkup(content), location)));
         }
@@ -1237,7 +1274,7 @@ impl<'input> Tokenizer<'input> {
             }
 
             let location = start.combine_with(self.cursor_location());
-            let content = self.compilation_unit.text[location.first_offset..location.last_offset].to_owned();
+            let content = self.text[location.first_offset()..location.last_offset()].to_owned();
 
             return Ok(Some((Token::XmlMarkup(content), location)));
         }
@@ -1260,7 +1297,7 @@ impl<'input> Tokenizer<'input> {
             }
 
             let location = start.combine_with(self.cursor_location());
-            let content = self.compilation_unit.text[location.first_offset..location.last_offset].to_owned();
+            let content = self.text[location.first_offset()..location.last_offset()].to_owned();
 
             return Ok(Some((Token::XmlMarkup(content), location)));
         }
@@ -1269,6 +1306,62 @@ impl<'input> Tokenizer<'input> {
     }
 }
 
+/// Reads the characters of a shared text, with the same
+/// operations as `CharacterReader`.
+#[derive(Clone)]
+struct TextReader {
+    text: Rc<String>,
+    index: usize,
+}
+
+impl TextReader {
+    fn has_remaining(&self) -> bool {
+        self.index < self.text.len()
+    }
+
+    fn reached_end(&self) -> bool {
+        !self.has_remaining()
+    }
+
+    fn skip_in_place(&mut self) {
+        self.next();
+    }
+
+    fn skip_count_in_place(&mut self, count: usize) {
+        for _ in 0..count {
+            if self.next().is_none() {
+                break;
+            }
+        }
+    }
+
+    fn index(&self) -> usize {
+        self.index
+    }
+
+    fn peek_or_zero(&self) -> char {
+        self.peek_at_or_zero(0)
+    }
+
+    fn peek_at_or_zero(&self, index: usize) -> char {
+        self.text[self.index..].chars().nth(index).unwrap_or('\x00')
+    }
+
+    fn peek_seq(&self, num_code_points: u64) -> String {
+        self.text[self.index..].chars().take(num_code_points as usize).collect()
+    }
+}
+
+impl Iterator for TextReader {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let ch = self.text[self.index..].chars().next()?;
+        self.index += ch.len_utf8();
+        Some(ch)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::ns::*;
@@ -1,9 +1,16 @@
 use crate::ns::*;
-use num_traits::{FromPrimitive, One, Zero};
+use num_traits::{FromPrimitive, One, Pow, ToPrimitive, Zero};
 use num_bigint::BigInt;
-use std::ops::{BitXor, BitOr};
+use std::ops::{Add, BitAnd, BitXor, BitOr, Div, Mul, Neg, Not, Rem, Shl, Shr, Sub};
 
-#[derive(Clone, PartialEq)]
+/// A numeric constant of one of the numeric types.
+///
+/// Binary operations require both operands to be of the same
+/// variant and panic otherwise. Operations over `Long` wrap on
+/// overflow, which `ConstantEvaluator` reports instead; integer
+/// division by zero panics, so callers must check for a zero
+/// divisor first.
+#[derive(Clone, PartialEq, PartialOrd)]
 pub enum AbstractRangeNumber {
     Single(f32),
     Number(f64),
@@ -17,11 +24,11 @@ impl BitXor for AbstractRangeNumber {
         match self {
             Self::Single(v) => {
                 let Self::Single(rhs) = rhs else { panic!(); };
-                Self::Single(f32::from_u32(unsafe { v.to_int_unchecked::<u32>() } ^ unsafe { rhs.to_int_unchecked::<u32>() }).unwrap_or(0.0))
+                Self::Single((to_int32(v as f64) ^ to_int32(rhs as f64)) as f32)
             },
             Self::Number(v) => {
                 let Self::Number(rhs) = rhs else { panic!(); };
-                Self::Number(f64::from_u32(unsafe { v.to_int_unchecked::<u32>() } ^ unsafe { rhs.to_int_unchecked::<u32>() }).unwrap_or(0.0))
+                Self::Number((to_int32(v) ^ to_int32(rhs)) as f64)
             },
             Self::BigInt(v) => {
                 let Self::BigInt(ref rhs) = rhs else { panic!(); };
@@ -41,11 +48,11 @@ impl BitOr for AbstractRangeNumber {
         match self {
             Self::Single(v) => {
                 let Self::Single(rhs) = rhs else { panic!(); };
-                Self::Single(f32::from_u32(unsafe { v.to_int_unchecked::<u32>() } | unsafe { rhs.to_int_unchecked::<u32>() }).unwrap_or(0.0))
+                Self::Single((to_int32(v as f64) | to_int32(rhs as f64)) as f32)
             },
             Self::Number(v) => {
                 let Self::Number(rhs) = rhs else { panic!(); };
-                Self::Number(f64::from_u32(unsafe { v.to_int_unchecked::<u32>() } | unsafe { rhs.to_int_unchecked::<u32>() }).unwrap_or(0.0))
+                Self::Number((to_int32(v) | to_int32(rhs)) as f64)
             },
             Self::BigInt(v) => {
                 let Self::BigInt(ref rhs) = rhs else { panic!(); };
@@ -59,6 +66,239 @@ impl BitOr for AbstractRangeNumber {
     }
 }
 
+impl BitAnd for AbstractRangeNumber {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self::Output {
+        match self {
+            Self::Single(v) => {
+                let Self::Single(rhs) = rhs else { panic!(); };
+                Self::Single((to_int32(v as f64) & to_int32(rhs as f64)) as f32)
+            },
+            Self::Number(v) => {
+                let Self::Number(rhs) = rhs else { panic!(); };
+                Self::Number((to_int32(v) & to_int32(rhs)) as f64)
+            },
+            Self::BigInt(v) => {
+                let Self::BigInt(ref rhs) = rhs else { panic!(); };
+                Self::BigInt(v & rhs)
+            },
+            Self::Long(v) => {
+                let Self::Long(rhs) = rhs else { panic!(); };
+                Self::Long(v & rhs)
+            },
+        }
+    }
+}
+
+impl Add for AbstractRangeNumber {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        match self {
+            Self::Single(v) => {
+                let Self::Single(rhs) = rhs else { panic!(); };
+                Self::Single(v + rhs)
+            },
+            Self::Number(v) => {
+                let Self::Number(rhs) = rhs else { panic!(); };
+                Self::Number(v + rhs)
+            },
+            Self::BigInt(v) => {
+                let Self::BigInt(rhs) = rhs else { panic!(); };
+                Self::BigInt(v + rhs)
+            },
+            Self::Long(v) => {
+                let Self::Long(rhs) = rhs else { panic!(); };
+                Self::Long(v.wrapping_add(rhs))
+            },
+        }
+    }
+}
+
+impl Sub for AbstractRangeNumber {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        match self {
+            Self::Single(v) => {
+                let Self::Single(rhs) = rhs else { panic!(); };
+                Self::Single(v - rhs)
+            },
+            Self::Number(v) => {
+                let Self::Number(rhs) = rhs else { panic!(); };
+                Self::Number(v - rhs)
+            },
+            Self::BigInt(v) => {
+                let Self::BigInt(rhs) = rhs else { panic!(); };
+                Self::BigInt(v - rhs)
+            },
+            Self::Long(v) => {
+                let Self::Long(rhs) = rhs else { panic!(); };
+                Self::Long(v.wrapping_sub(rhs))
+            },
+        }
+    }
+}
+
+impl Mul for AbstractRangeNumber {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        match self {
+            Self::Single(v) => {
+                let Self::Single(rhs) = rhs else { panic!(); };
+                Self::Single(v * rhs)
+            },
+            Self::Number(v) => {
+                let Self::Number(rhs) = rhs else { panic!(); };
+                Self::Number(v * rhs)
+            },
+            Self::BigInt(v) => {
+                let Self::BigInt(rhs) = rhs else { panic!(); };
+                Self::BigInt(v * rhs)
+            },
+            Self::Long(v) => {
+                let Self::Long(rhs) = rhs else { panic!(); };
+                Self::Long(v.wrapping_mul(rhs))
+            },
+        }
+    }
+}
+
+impl Div for AbstractRangeNumber {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        match self {
+            Self::Single(v) => {
+                let Self::Single(rhs) = rhs else { panic!(); };
+                Self::Single(v / rhs)
+            },
+            Self::Number(v) => {
+                let Self::Number(rhs) = rhs else { panic!(); };
+                Self::Number(v / rhs)
+            },
+            Self::BigInt(v) => {
+                let Self::BigInt(rhs) = rhs else { panic!(); };
+                Self::BigInt(v / rhs)
+            },
+            Self::Long(v) => {
+                let Self::Long(rhs) = rhs else { panic!(); };
+                Self::Long(v.wrapping_div(rhs))
+            },
+        }
+    }
+}
+
+impl Rem for AbstractRangeNumber {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self::Output {
+        match self {
+            Self::Single(v) => {
+                let Self::Single(rhs) = rhs else { panic!(); };
+                Self::Single(v % rhs)
+            },
+            Self::Number(v) => {
+                let Self::Number(rhs) = rhs else { panic!(); };
+                Self::Number(v % rhs)
+            },
+            Self::BigInt(v) => {
+                let Self::BigInt(rhs) = rhs else { panic!(); };
+                Self::BigInt(v % rhs)
+            },
+            Self::Long(v) => {
+                let Self::Long(rhs) = rhs else { panic!(); };
+                Self::Long(v.wrapping_rem(rhs))
+            },
+        }
+    }
+}
+
+impl Shl for AbstractRangeNumber {
+    type Output = Self;
+    fn shl(self, rhs: Self) -> Self::Output {
+        match self {
+            Self::Single(v) => {
+                let Self::Single(rhs) = rhs else { panic!(); };
+                Self::Single(to_int32(v as f64).wrapping_shl(to_int32(rhs as f64) as u32) as f32)
+            },
+            Self::Number(v) => {
+                let Self::Number(rhs) = rhs else { panic!(); };
+                Self::Number(to_int32(v).wrapping_shl(to_int32(rhs) as u32) as f64)
+            },
+            Self::BigInt(v) => {
+                let Self::BigInt(rhs) = rhs else { panic!(); };
+                let rhs = rhs.to_i64().unwrap_or(0);
+                Self::BigInt(if rhs < 0 { v >> rhs.unsigned_abs() } else { v << rhs })
+            },
+            Self::Long(v) => {
+                let Self::Long(rhs) = rhs else { panic!(); };
+                Self::Long(v.wrapping_shl(rhs as u32))
+            },
+        }
+    }
+}
+
+impl Shr for AbstractRangeNumber {
+    type Output = Self;
+    fn shr(self, rhs: Self) -> Self::Output {
+        match self {
+            Self::Single(v) => {
+                let Self::Single(rhs) = rhs else { panic!(); };
+                Self::Single(to_int32(v as f64).wrapping_shr(to_int32(rhs as f64) as u32) as f32)
+            },
+            Self::Number(v) => {
+                let Self::Number(rhs) = rhs else { panic!(); };
+                Self::Number(to_int32(v).wrapping_shr(to_int32(rhs) as u32) as f64)
+            },
+            Self::BigInt(v) => {
+                let Self::BigInt(rhs) = rhs else { panic!(); };
+                let rhs = rhs.to_i64().unwrap_or(0);
+                Self::BigInt(if rhs < 0 { v << rhs.unsigned_abs() } else { v >> rhs })
+            },
+            Self::Long(v) => {
+                let Self::Long(rhs) = rhs else { panic!(); };
+                Self::Long(v.wrapping_shr(rhs as u32))
+            },
+        }
+    }
+}
+
+impl Neg for AbstractRangeNumber {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        match self {
+            Self::Single(v) => Self::Single(-v),
+            Self::Number(v) => Self::Number(-v),
+            Self::BigInt(v) => Self::BigInt(-v),
+            Self::Long(v) => Self::Long(v.wrapping_neg()),
+        }
+    }
+}
+
+impl Not for AbstractRangeNumber {
+    type Output = Self;
+    fn not(self) -> Self::Output {
+        match self {
+            Self::Single(v) => Self::Single(!to_int32(v as f64) as f32),
+            Self::Number(v) => Self::Number(!to_int32(v) as f64),
+            Self::BigInt(v) => Self::BigInt(!v),
+            Self::Long(v) => Self::Long(!v),
+        }
+    }
+}
+
+/// Converts a floating point to an unsigned 32-bit integer as in the
+/// ECMAScript `ToUint32` operation.
+fn to_uint32(v: f64) -> u32 {
+    if !v.is_finite() {
+        return 0;
+    }
+    v.trunc().rem_euclid(4_294_967_296.0) as u32
+}
+
+/// Converts a floating point to a 32-bit integer as in the
+/// ECMAScript `ToInt32` operation.
+fn to_int32(v: f64) -> i32 {
+    to_uint32(v) as i32
+}
+
 impl AbstractRangeNumber {
     pub fn zero(type_symbol: &Symbol, host: &SymbolHost) -> Self {
         if type_symbol == &host.number_type() {
@@ -166,11 +406,11 @@ impl AbstractRangeNumber {
         match self {
             Self::Single(v) => {
                 let Self::Single(rhs) = rhs else { panic!(); };
-                (unsafe { v.to_int_unchecked::<u32>() } & unsafe { rhs.to_int_unchecked::<u32>() } != 0)
+                to_uint32(*v as f64) & to_uint32(*rhs as f64) != 0
             },
             Self::Number(v) => {
                 let Self::Number(rhs) = rhs else { panic!(); };
-                (unsafe { v.to_int_unchecked::<u32>() } & unsafe { rhs.to_int_unchecked::<u32>() } != 0)
+                to_uint32(*v) & to_uint32(*rhs) != 0
             },
             Self::BigInt(v) => {
                 let Self::BigInt(ref rhs) = rhs else { panic!(); };
@@ -187,11 +427,11 @@ impl AbstractRangeNumber {
         // Based on https://stackoverflow.com/a/600306
         match self {
             Self::Single(v) => {
-                let v = unsafe { v.to_int_unchecked::<u32>() };
+                let v = to_uint32(*v as f64);
                 (v != 0) && ((v & (v - 1)) == 0)
             },
             Self::Number(v) => {
-                let v = unsafe { v.to_int_unchecked::<u32>() };
+                let v = to_uint32(*v);
                 (v != 0) && ((v & (v - 1)) == 0)
             },
             Self::BigInt(v) => {
@@ -238,7 +478,7 @@ impl AbstractRangeNumber {
                 Self::Single(v) => Self::Long(unsafe { v.to_int_unchecked() }),
                 Self::Number(v) => Self::Long(unsafe { v.to_int_unchecked() }),
                 Self::BigInt(v) => Self::Long(v.try_into().unwrap_or(0)),
-                Self::Long(v) => Self::Long((*v).try_into().unwrap_or(0)),
+                Self::Long(v) => Self::Long(*v),
             }
         } else if target_type == &big_int_type {
             match self {
@@ -252,6 +492,49 @@ impl AbstractRangeNumber {
         }
     }
 
+    /// Raises to a power. A negative exponent results
+    /// into zero for integer types.
+    pub fn power(&self, rhs: &Self) -> Self {
+        match self {
+            Self::Single(v) => {
+                let Self::Single(rhs) = rhs else { panic!(); };
+                Self::Single(v.powf(*rhs))
+            },
+            Self::Number(v) => {
+                let Self::Number(rhs) = rhs else { panic!(); };
+                Self::Number(v.powf(*rhs))
+            },
+            Self::BigInt(v) => {
+                let Self::BigInt(rhs) = rhs else { panic!(); };
+                Self::BigInt(rhs.to_u32().map(|rhs| Pow::pow(v, rhs)).unwrap_or(BigInt::zero()))
+            },
+            Self::Long(v) => {
+                let Self::Long(rhs) = rhs else { panic!(); };
+                Self::Long(u32::try_from(*rhs).map(|rhs| v.wrapping_pow(rhs)).unwrap_or(0))
+            },
+        }
+    }
+
+    /// Performs an unsigned right shift. For `BigInt`,
+    /// this is equivalent to a signed right shift.
+    pub fn shift_right_unsigned(&self, rhs: &Self) -> Self {
+        match self {
+            Self::Single(v) => {
+                let Self::Single(rhs) = rhs else { panic!(); };
+                Self::Single(to_uint32(*v as f64).wrapping_shr(to_int32(*rhs as f64) as u32) as f32)
+            },
+            Self::Number(v) => {
+                let Self::Number(rhs) = rhs else { panic!(); };
+                Self::Number(to_uint32(*v).wrapping_shr(to_int32(*rhs) as u32) as f64)
+            },
+            Self::BigInt(_) => self.clone() >> rhs.clone(),
+            Self::Long(v) => {
+                let Self::Long(rhs) = rhs else { panic!(); };
+                Self::Long((*v as u64).wrapping_shr(*rhs as u32) as i64)
+            },
+        }
+    }
+
     pub fn is_nan(&self) -> bool {
         match self {
             Self::Number(f) => f.is_nan(),
@@ -275,4 +558,24 @@ impl AbstractRangeNumber {
             _ => false,
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let n = |v: f64| AbstractRangeNumber::Number(v);
+        let l = |v: i64| AbstractRangeNumber::Long(v);
+        assert!(n(3.0) + n(4.5) == n(7.5));
+        assert!(n(7.0) % n(4.0) == n(3.0));
+        assert!(n(-1.0).shift_right_unsigned(&n(28.0)) == n(15.0));
+        assert!(n(4_294_967_297.0) << n(1.0) == n(2.0));
+        assert!(!n(0.0) == n(-1.0));
+        assert!(l(i64::MAX) + l(1) == l(i64::MIN));
+        assert!(l(2).power(&l(10)) == l(1024));
+        assert!(l(1) << l(4) == l(16));
+        assert!(n(2.0) < n(3.0) && l(-1) < l(0));
+    }
 }
\ No newline at end of file
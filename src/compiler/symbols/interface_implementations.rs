@@ -16,13 +16,13 @@ impl<'a> InterfaceImplementations<'a> {
             interface.throw_if_unresolved().map_err(|_| DeferVerificationError)?;
 
             for (name, item) in interface.prototype(self.0).borrow().iter() {
-                let implementor_item = implementor.prototype(self.0).get(name);
+                let implementor_item = self.lookup_implementor_item(implementor, name)?;
 
                 if implementor_item.is_some() && implementor_item.clone().unwrap().visibility() != expected_visibility {
                     log.push(InterfaceImplementationLog::WrongVisibility { name: name.clone(), expected_visibility });
                 }
 
-                if implementor_item.is_none() {
+                let Some(implementor_item) = implementor_item else {
                     if item.is_virtual_property() {
                         if item.getter(self.0).is_some() && !item.getter(self.0).unwrap().is_optional_interface_method() {
                             log.push(InterfaceImplementationLog::UnimplementedGetter { name: name.clone() });
@@ -33,9 +33,11 @@ impl<'a> InterfaceImplementations<'a> {
                     } else if !item.is_optional_interface_method() {
                         log.push(InterfaceImplementationLog::UnimplementedMethod { name: name.clone() });
                     }
+                    continue;
+                };
+
                 // Verify accessors
-                } else if item.is_virtual_property() {
-                    let implementor_item = implementor_item.unwrap();
+                if item.is_virtual_property() {
                     if !implementor_item.is_virtual_property() {
                         log.push(InterfaceImplementationLog::PropertyMustBeVirtualProperty { name: name.clone() });
                     } else {
@@ -75,7 +77,6 @@ impl<'a> InterfaceImplementations<'a> {
                     }
                 // Verify regular method
                 } else {
-                    let implementor_item = implementor_item.unwrap();
                     if !implementor_item.is_function() {
                         log.push(InterfaceImplementationLog::PropertyMustBeMethod { name: name.clone() });
                     }
@@ -119,6 +120,18 @@ impl<'a> InterfaceImplementations<'a> {
         Ok(log)
     }
 
+    /// Looks up an instance property of the implementor,
+    /// including properties inherited from base classes.
+    fn lookup_implementor_item(&mut self, implementor: &Symbol, name: &String) -> Result<Option<Symbol>, DeferVerificationError> {
+        for class in implementor.descending_class_hierarchy(self.0).collect::<Vec<_>>() {
+            class.throw_if_unresolved()?;
+            if let Some(item) = class.prototype(self.0).get(name) {
+                return Ok(Some(item));
+            }
+        }
+        Ok(None)
+    }
+
     fn conforming_type_parameters(&mut self, type_parameters_1: &SharedArray<Symbol>, type_parameters_2: Option<SharedArray<Symbol>>) -> bool {
         if type_parameters_2.is_none() {
             return false;
@@ -143,11 +143,11 @@ impl<'a> MethodOverriding<'a> {
         }
 
         let base_parameters = base_signature.parameters();
-        let mut base_parameters = base_parameters.iter();
+        let base_parameters = base_parameters.iter();
         let subtype_parameters = subtype_signature.parameters();
         let mut subtype_parameters = subtype_parameters.iter();
 
-        while let Some(base_param) = base_parameters.next() {
+        for base_param in base_parameters {
             let subtype_param = subtype_parameters.next();
             if subtype_param.is_none() {
                 return false;
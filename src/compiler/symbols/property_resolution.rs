@@ -18,7 +18,7 @@ impl SemanticPropertyKey {
             },
             Self::Number(d) => {
                 let number_type = host.number_type();
-                host.factory().create_number_constant(AbstractRangeNumber::Number(d.clone()), &number_type)
+                host.factory().create_number_constant(AbstractRangeNumber::Number(*d), &number_type)
             },
             Self::Value(s) => s.clone(),
         }
@@ -152,7 +152,7 @@ impl<'a> PropertyResolution<'a> {
                                 // Throw if unresolved
                                 prop.property_static_type(self.0).throw_if_unresolved().map_err(|_| PropertyResolutionError::DeferVerification)?;
 
-                                return Ok(Some(self.0.factory().create_instance_reference_value(&base, &prop)));
+                                return Ok(Some(self.0.factory().create_instance_reference_value(base, &prop)));
                             }
                         }
                     } else if base_type.is_interface_type() {
@@ -165,7 +165,7 @@ impl<'a> PropertyResolution<'a> {
                                 // Throw if unresolved
                                 prop.property_static_type(self.0).throw_if_unresolved().map_err(|_| PropertyResolutionError::DeferVerification)?;
 
-                                return Ok(Some(self.0.factory().create_instance_reference_value(&base, &prop)));
+                                return Ok(Some(self.0.factory().create_instance_reference_value(base, &prop)));
                             }
                         }
                     }
@@ -186,18 +186,18 @@ impl<'a> PropertyResolution<'a> {
 
                     let proxy_key_type = proxy_signature.parameters().get(0).unwrap().static_type.clone();
                     if key_type.is_equals_or_subtype_of(&proxy_key_type, self.0) {
-                        return Ok(Some(self.0.factory().create_proxy_reference_value(&base, &proxy)));
+                        return Ok(Some(self.0.factory().create_proxy_reference_value(base, &proxy)));
                     }
                 }
             }
 
             // 6.4. If key is a Number constant value and base is of a tuple type
-            if number_key.is_some() && base_type.is_tuple_type() {
-                let index: usize = unsafe { number_key.unwrap().to_int_unchecked() };
+            if let Some(number_key) = number_key.filter(|_| base_type.is_tuple_type()) {
+                let index: usize = unsafe { number_key.to_int_unchecked() };
                 if index >= base_type.element_types().length() {
                     return Ok(None);
                 }
-                return Ok(Some(self.0.factory().create_tuple_reference_value(&base, index)));
+                return Ok(Some(self.0.factory().create_tuple_reference_value(base, index)));
             }
 
             return Ok(None);
@@ -279,7 +279,7 @@ impl<'a> PropertyResolution<'a> {
         }
 
         // 11. Return undefined
-        return Ok(None);
+        Ok(None)
     }
 
     pub fn resolve_scope_property(&mut self, base: &Symbol, qual: Option<Symbol>, key: SemanticPropertyKey, disamb: PropertyDisambiguation) -> Result<Option<Symbol>, PropertyResolutionError> {
@@ -340,10 +340,12 @@ impl<'a> PropertyResolution<'a> {
         // 9. If base is a package scope
         if base.is_package_scope() {
             amb = self.resolve_property_with_disambiguation(&base.package(), qual.clone(), key.clone(), disamb)?;
-            if r.is_some() {
-                return Err(PropertyResolutionError::AmbiguousReference { name: string_key.clone().unwrap() });
+            if amb.is_some() {
+                if r.is_some() {
+                    return Err(PropertyResolutionError::AmbiguousReference { name: string_key.clone().unwrap() });
+                }
+                r = amb;
             }
-            r = amb;
         }
 
         // 10. If qual is undefined and key is a String constant
@@ -361,22 +363,34 @@ impl<'a> PropertyResolution<'a> {
             }
         }
 
-        // 11. For each op in base[[OpenPackages]]
-        for p in base.packages().iter() {
+        // 11. If qual is undefined and key is a String constant
+        // naming a package alias
+        if qual.is_none() && string_key.is_some() {
+            if let Some(p) = base.package_aliases().get(&string_key.clone().unwrap()) {
+                if r.is_some() {
+                    return Err(PropertyResolutionError::AmbiguousReference { name: string_key.clone().unwrap() });
+                }
+                r = Some(p);
+            }
+        }
+
+        // 12. For each op in base[[OpenPackages]]
+        for p in base.open_packages().iter() {
             amb = self.resolve_property_with_disambiguation(&p, qual.clone(), key.clone(), disamb)?;
-            if r.is_some() {
-                return Err(PropertyResolutionError::AmbiguousReference { name: string_key.clone().unwrap() });
+            if amb.is_some() {
+                if r.is_some() {
+                    return Err(PropertyResolutionError::AmbiguousReference { name: string_key.clone().unwrap() });
+                }
+                r = amb;
             }
-            r = amb;
         }
 
-        // 12. If r is undefined and base[[ParentScope]] is not undefined
-        let parent_scope = base.parent_scope();
-        if r.is_none() && parent_scope.is_some() {
-            return self.resolve_scope_property(&parent_scope.unwrap(), qual, key, disamb);
+        // 13. If r is undefined and base[[ParentScope]] is not undefined
+        if let Some(parent_scope) = base.parent_scope().filter(|_| r.is_none()) {
+            return self.resolve_scope_property(&parent_scope, qual, key, disamb);
         }
 
-        // 13. Return r
+        // 14. Return r
         Ok(r)
     }
 }
\ No newline at end of file
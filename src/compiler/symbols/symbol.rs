@@ -70,10 +70,7 @@ impl Symbol {
     }
 
     pub fn is_origin_class_type(&self) -> bool {
-        match self.0.upgrade().unwrap().as_ref() {
-            SymbolKind::Type(TypeKind::ClassType(_)) => true,
-            _ => false,
-        }
+        matches!(self.0.upgrade().unwrap().as_ref(), SymbolKind::Type(TypeKind::ClassType(_)))
     }
 
     pub fn is_enum_type(&self) -> bool {
@@ -89,10 +86,7 @@ impl Symbol {
     }
 
     pub fn is_origin_interface_type(&self) -> bool {
-        match self.0.upgrade().unwrap().as_ref() {
-            SymbolKind::Type(TypeKind::InterfaceType(_)) => true,
-            _ => false,
-        }
+        matches!(self.0.upgrade().unwrap().as_ref(), SymbolKind::Type(TypeKind::InterfaceType(_)))
     }
 
     pub fn is_function_type(&self) -> bool {
@@ -444,6 +438,10 @@ impl Symbol {
         let mut r: Vec<String> = vec![];
         let mut p = Some(self.clone());
         while let Some(p1) = p {
+            // Properties of scopes have no qualified name beyond their own.
+            if p1.is_scope() {
+                break;
+            }
             let name = p1.name();
             if !name.is_empty() {
                 r.insert(0, name);
@@ -750,31 +748,31 @@ impl Symbol {
         let symbol = self.0.upgrade().unwrap();
         match symbol.as_ref() {
             SymbolKind::Type(TypeKind::ClassType(data)) => {
-                data.parent_definition.replace(value.map(|v| v.clone()));
+                data.parent_definition.replace(value.cloned());
             },
             SymbolKind::Type(TypeKind::EnumType(data)) => {
-                data.parent_definition.replace(value.map(|v| v.clone()));
+                data.parent_definition.replace(value.cloned());
             },
             SymbolKind::Type(TypeKind::InterfaceType(data)) => {
-                data.parent_definition.replace(value.map(|v| v.clone()));
+                data.parent_definition.replace(value.cloned());
             },
             SymbolKind::Alias(data) => {
-                data.parent_definition.replace(value.map(|v| v.clone()));
+                data.parent_definition.replace(value.cloned());
             },
             SymbolKind::Package(data) => {
-                data.parent_definition.replace(value.map(|v| v.clone()));
+                data.parent_definition.replace(value.cloned());
             },
             SymbolKind::PackageSet(data) => {
-                data.parent_definition.replace(value.map(|v| v.clone()));
+                data.parent_definition.replace(value.cloned());
             },
             SymbolKind::VariableProperty(data) => {
-                data.parent_definition.replace(value.map(|v| v.clone()));
+                data.parent_definition.replace(value.cloned());
             },
             SymbolKind::VirtualProperty(data) => {
-                data.parent_definition.replace(value.map(|v| v.clone()));
+                data.parent_definition.replace(value.cloned());
             },
             SymbolKind::Function(data) => {
-                data.parent_definition.replace(value.map(|v| v.clone()));
+                data.parent_definition.replace(value.cloned());
             },
             _ => panic!(),
         }
@@ -792,10 +790,7 @@ impl Symbol {
                     return Some(r.clone());
                 }
                 let r = data.origin.extends_class(host);
-                if r.is_none() {
-                    return None;
-                }
-                let r = r.unwrap();
+                let r = r?;
                 if r.is_unresolved() {
                     return Some(r.clone());
                 }
@@ -811,7 +806,7 @@ impl Symbol {
         let symbol = self.0.upgrade().unwrap();
         match symbol.as_ref() {
             SymbolKind::Type(TypeKind::ClassType(data)) => {
-                data.extends_class.replace(value.map(|c| c.clone()));
+                data.extends_class.replace(value.cloned());
             },
             _ => panic!(),
         }
@@ -839,7 +834,7 @@ impl Symbol {
         match symbol.as_ref() {
             SymbolKind::Type(TypeKind::EnumType(data)) => {
                 let EnumTypeData { ref representation_type, .. } = data.as_ref();
-                representation_type.replace(value.map(|t| t.clone()));
+                representation_type.replace(value.cloned());
             },
             _ => panic!(),
         }
@@ -859,13 +854,13 @@ impl Symbol {
         let symbol = self.0.upgrade().unwrap();
         match symbol.as_ref() {
             SymbolKind::Type(TypeKind::ClassType(data)) => {
-                data.type_parameters.replace(value.map(|p| p.clone()));
+                data.type_parameters.replace(value.cloned());
             },
             SymbolKind::Type(TypeKind::InterfaceType(data)) => {
-                data.type_parameters.replace(value.map(|p| p.clone()));
+                data.type_parameters.replace(value.cloned());
             },
             SymbolKind::Function(data) => {
-                data.type_parameters.replace(value.map(|p| p.clone()));
+                data.type_parameters.replace(value.cloned());
             },
             _ => panic!(),
         }
@@ -888,7 +883,7 @@ impl Symbol {
                     return r.clone();
                 }
                 let r: SharedMap<String, Symbol> = data.origin.static_properties(host).borrow().iter().map(|(name, p)| {
-                    let p = TypeSubstitution(host).execute(&p, &data.origin.type_parameters().unwrap(), &data.substitute_types);
+                    let p = TypeSubstitution(host).execute(p, &data.origin.type_parameters().unwrap(), &data.substitute_types);
                     (name.clone(), p)
                 }).collect();
                 static_properties.replace(Some(r.clone()));
@@ -919,7 +914,7 @@ impl Symbol {
                     return r.clone();
                 }
                 let r: SharedMap<String, Symbol> = data.origin.prototype(host).borrow().iter().map(|(name, p)| {
-                    let p = TypeSubstitution(host).execute(&p, &data.origin.type_parameters().unwrap(), &data.substitute_types);
+                    let p = TypeSubstitution(host).execute(p, &data.origin.type_parameters().unwrap(), &data.substitute_types);
                     (name.clone(), p)
                 }).collect();
                 prototype.replace(Some(r.clone()));
@@ -946,7 +941,7 @@ impl Symbol {
                     return r.clone();
                 }
                 let r: SharedMap<ProxyKind, Symbol> = data.origin.proxies(host).borrow().iter().map(|(kind, p)| {
-                    let p = TypeSubstitution(host).execute(&p, &data.origin.type_parameters().unwrap(), &data.substitute_types);
+                    let p = TypeSubstitution(host).execute(p, &data.origin.type_parameters().unwrap(), &data.substitute_types);
                     (*kind, p)
                 }).collect();
                 proxies.replace(Some(r.clone()));
@@ -1007,10 +1002,7 @@ impl Symbol {
                     return Some(r.clone());
                 }
                 let r = data.origin.constructor_function(host);
-                if r.is_none() {
-                    return None;
-                }
-                let r = r.unwrap();
+                let r = r?;
                 let r = TypeSubstitution(host).execute(&r, &data.origin.type_parameters().unwrap(), &data.substitute_types);
                 data.constructor_function.replace(Some(r.clone()));
                 Some(r)
@@ -1024,7 +1016,33 @@ impl Symbol {
         match symbol.as_ref() {
             SymbolKind::Type(TypeKind::ClassType(data)) => {
                 let ClassTypeData { ref constructor_function, .. } = data.as_ref();
-                constructor_function.replace(value.map(|f| f.clone()));
+                constructor_function.replace(value.cloned());
+            },
+            _ => panic!(),
+        }
+    }
+
+    /// Indicates whether a class or interface is marked with the `[Limited]`
+    /// meta-data, in which case its direct subclasses or implementors are
+    /// collected into `limited_subclasses()` or `limited_implementors()`.
+    pub fn is_limited(&self) -> bool {
+        let symbol = self.0.upgrade().unwrap();
+        match symbol.as_ref() {
+            SymbolKind::Type(TypeKind::ClassType(data)) => data.flags.borrow().contains(ClassTypeFlags::IS_LIMITED),
+            SymbolKind::Type(TypeKind::InterfaceType(data)) => data.is_limited.get(),
+            SymbolKind::Type(TypeKind::TypeAfterExplicitTypeSubstitution(data)) => data.origin.is_limited(),
+            _ => panic!(),
+        }
+    }
+
+    pub fn set_is_limited(&self, value: bool) {
+        let symbol = self.0.upgrade().unwrap();
+        match symbol.as_ref() {
+            SymbolKind::Type(TypeKind::ClassType(data)) => {
+                data.flags.borrow_mut().set(ClassTypeFlags::IS_LIMITED, value);
+            },
+            SymbolKind::Type(TypeKind::InterfaceType(data)) => {
+                data.is_limited.set(value);
             },
             _ => panic!(),
         }
@@ -1194,6 +1212,72 @@ impl Symbol {
         }
     }
 
+    pub fn location(&self) -> Option<Location> {
+        let symbol = self.0.upgrade().unwrap();
+        match symbol.as_ref() {
+            SymbolKind::Type(TypeKind::ClassType(data)) => {
+                let ClassTypeData { ref location, .. } = data.as_ref();
+                location.borrow().clone()
+            },
+            SymbolKind::Type(TypeKind::EnumType(data)) => {
+                let EnumTypeData { ref location, .. } = data.as_ref();
+                location.borrow().clone()
+            },
+            SymbolKind::Type(TypeKind::InterfaceType(data)) => {
+                let InterfaceTypeData { ref location, .. } = data.as_ref();
+                location.borrow().clone()
+            },
+            SymbolKind::Type(TypeKind::TypeAfterExplicitTypeSubstitution(data)) => data.origin.location(),
+            SymbolKind::Alias(data) => data.location.borrow().clone(),
+            SymbolKind::Package(data) => data.location.borrow().clone(),
+            SymbolKind::PackageSet(data) => data.location.borrow().clone(),
+            SymbolKind::VariableProperty(data) => data.location.borrow().clone(),
+            SymbolKind::VariablePropertyAfterIndirectTypeSubstitution(data) => data.origin.location(),
+            SymbolKind::VirtualProperty(data) => data.location.borrow().clone(),
+            SymbolKind::VirtualPropertyAfterIndirectTypeSubstitution(data) => data.origin.location(),
+            SymbolKind::Function(data) => data.location.borrow().clone(),
+            SymbolKind::FunctionAfterExplicitOrIndirectTypeSubstitution(data) => data.origin.location(),
+            _ => panic!(),
+        }
+    }
+
+    pub fn set_location(&self, value: Option<Location>) {
+        let symbol = self.0.upgrade().unwrap();
+        match symbol.as_ref() {
+            SymbolKind::Type(TypeKind::ClassType(data)) => {
+                let ClassTypeData { ref location, .. } = data.as_ref();
+                location.replace(value);
+            },
+            SymbolKind::Type(TypeKind::EnumType(data)) => {
+                let EnumTypeData { ref location, .. } = data.as_ref();
+                location.replace(value);
+            },
+            SymbolKind::Type(TypeKind::InterfaceType(data)) => {
+                let InterfaceTypeData { ref location, .. } = data.as_ref();
+                location.replace(value);
+            },
+            SymbolKind::Alias(data) => {
+                data.location.replace(value);
+            },
+            SymbolKind::Package(data) => {
+                data.location.replace(value);
+            },
+            SymbolKind::PackageSet(data) => {
+                data.location.replace(value);
+            },
+            SymbolKind::VariableProperty(data) => {
+                data.location.replace(value);
+            },
+            SymbolKind::VirtualProperty(data) => {
+                data.location.replace(value);
+            },
+            SymbolKind::Function(data) => {
+                data.location.replace(value);
+            },
+            _ => panic!(),
+        }
+    }
+
     pub fn parameters(&self) -> SharedArray<Rc<ParameterOfFunctionType>> {
         let symbol = self.0.upgrade().unwrap();
         match symbol.as_ref() {
@@ -1288,7 +1372,7 @@ impl Symbol {
         let symbol = self.0.upgrade().unwrap();
         match symbol.as_ref() {
             SymbolKind::Type(TypeKind::AnyType) |
-            SymbolKind::Type(TypeKind::VoidType) => { return true; },
+            SymbolKind::Type(TypeKind::VoidType) => true,
             _ => {
                 if self.is_type() {
                     return false;
@@ -1303,7 +1387,7 @@ impl Symbol {
         let symbol = self.0.upgrade().unwrap();
         match symbol.as_ref() {
             SymbolKind::Type(TypeKind::AnyType) |
-            SymbolKind::Type(TypeKind::NullableType(_)) => { return true; },
+            SymbolKind::Type(TypeKind::NullableType(_)) => true,
             _ => {
                 if self.is_type() {
                     return false;
@@ -1389,7 +1473,7 @@ impl Symbol {
         if self.is_alias() {
             return self.alias_of().resolve_alias();
         }
-        return self.clone();
+        self.clone()
     }
 
     pub fn resolve_property(&self, qual: Option<Symbol>, key: SemanticPropertyKey, host: &SymbolHost) -> Result<Option<Symbol>, PropertyResolutionError> {
@@ -1562,11 +1646,11 @@ impl Symbol {
         }
     }
 
-    pub fn write_only(&self, host: &SymbolHost) -> bool {
+    pub fn write_only(&self) -> bool {
         let symbol = self.0.upgrade().unwrap();
         match symbol.as_ref() {
             SymbolKind::VirtualProperty(data) => data.getter.borrow().is_none(),
-            SymbolKind::VirtualPropertyAfterIndirectTypeSubstitution(data) => data.origin.write_only(host),
+            SymbolKind::VirtualPropertyAfterIndirectTypeSubstitution(data) => data.origin.write_only(),
             SymbolKind::Value(_, Some(data)) => {
                 match data.as_ref() {
                     ValueKind::Reference(data) => {
@@ -1574,16 +1658,16 @@ impl Symbol {
                             ReferenceValueKind::Type { .. } => false,
                             ReferenceValueKind::Xml { .. } => false,
                             ReferenceValueKind::Dynamic { .. } => false,
-                            ReferenceValueKind::Static { property, .. } => property.write_only(host),
-                            ReferenceValueKind::Instance { property, .. } => property.write_only(host),
+                            ReferenceValueKind::Static { property, .. } => property.write_only(),
+                            ReferenceValueKind::Instance { property, .. } => property.write_only(),
                             ReferenceValueKind::Proxy { .. } => false,
                             ReferenceValueKind::Tuple { .. } => false,
-                            ReferenceValueKind::Scope { property, .. } => property.write_only(host),
+                            ReferenceValueKind::Scope { property, .. } => property.write_only(),
                             ReferenceValueKind::DynamicScope { .. } => false,
-                            ReferenceValueKind::Package { property, .. } => property.write_only(host),
+                            ReferenceValueKind::Package { property, .. } => property.write_only(),
                         }
                     }
-                    _ => true,
+                    _ => false,
                 }
             },
             _ => false,
@@ -1658,7 +1742,7 @@ impl Symbol {
         let symbol = self.0.upgrade().unwrap();
         match symbol.as_ref() {
             SymbolKind::VariableProperty(data) => {
-                data.constant_initializer.replace(value.map(|v| v.clone()));
+                data.constant_initializer.replace(value.cloned());
             },
             _ => panic!(),
         }
@@ -1698,7 +1782,7 @@ impl Symbol {
         let symbol = self.0.upgrade().unwrap();
         match symbol.as_ref() {
             SymbolKind::VirtualProperty(data) => {
-                data.getter.replace(value.map(|v| v.clone()));
+                data.getter.replace(value.cloned());
             },
             _ => panic!(),
         }
@@ -1728,7 +1812,7 @@ impl Symbol {
         let symbol = self.0.upgrade().unwrap();
         match symbol.as_ref() {
             SymbolKind::VirtualProperty(data) => {
-                data.setter.replace(value.map(|v| v.clone()));
+                data.setter.replace(value.cloned());
             },
             _ => panic!(),
         }
@@ -1748,7 +1832,7 @@ impl Symbol {
                 if r.is_unresolved() {
                     return r.clone();
                 }
-                let r = TypeSubstitution(host).execute(&r, &data.explicit_or_indirect_substitute_types, &data.explicit_or_indirect_substitute_types);
+                let r = TypeSubstitution(host).execute(&r, &data.explicit_or_indirect_type_parameters, &data.explicit_or_indirect_substitute_types);
                 data.signature.replace(Some(r.clone()));
                 r
             },
@@ -1776,10 +1860,7 @@ impl Symbol {
                     return Some(r.clone());
                 }
                 let r = data.origin.of_virtual_property(host);
-                if r.is_none() {
-                    return None;
-                }
-                let r = TypeSubstitution(host).execute(&r.unwrap(), &data.explicit_or_indirect_substitute_types, &data.explicit_or_indirect_substitute_types);
+                let r = TypeSubstitution(host).execute(&r?, &data.explicit_or_indirect_type_parameters, &data.explicit_or_indirect_substitute_types);
                 data.of_virtual_property.replace(Some(r.clone()));
                 Some(r)
             },
@@ -1791,7 +1872,7 @@ impl Symbol {
         let symbol = self.0.upgrade().unwrap();
         match symbol.as_ref() {
             SymbolKind::Function(data) => {
-                data.of_virtual_property.replace(value.map(|v| v.clone()));
+                data.of_virtual_property.replace(value.cloned());
             },
             _ => panic!(),
         }
@@ -1807,7 +1888,7 @@ impl Symbol {
                     return r.clone();
                 }
                 let r = data.origin.overriden_by(host);
-                let r: SharedArray<Symbol> = r.iter().map(|r| TypeSubstitution(host).execute(&r, &data.explicit_or_indirect_substitute_types, &data.explicit_or_indirect_substitute_types)).collect();
+                let r: SharedArray<Symbol> = r.iter().map(|r| TypeSubstitution(host).execute(&r, &data.explicit_or_indirect_type_parameters, &data.explicit_or_indirect_substitute_types)).collect();
                 data.overriden_by.replace(Some(r.clone()));
                 r
             },
@@ -1824,10 +1905,7 @@ impl Symbol {
                     return Some(r.clone());
                 }
                 let r = data.origin.overrides_method(host);
-                if r.is_none() {
-                    return None;
-                }
-                let r = TypeSubstitution(host).execute(&r.unwrap(), &data.explicit_or_indirect_substitute_types, &data.explicit_or_indirect_substitute_types);
+                let r = TypeSubstitution(host).execute(&r?, &data.explicit_or_indirect_type_parameters, &data.explicit_or_indirect_substitute_types);
                 data.overrides_method.replace(Some(r.clone()));
                 Some(r)
             },
@@ -1860,7 +1938,7 @@ impl Symbol {
         let symbol = self.0.upgrade().unwrap();
         match symbol.as_ref() {
             SymbolKind::Scope(data, _) => {
-                data.parent_scope.replace(value.map(|v| v.clone()));
+                data.parent_scope.replace(value.cloned());
             },
             _ => panic!(),
         }
@@ -1936,7 +2014,25 @@ impl Symbol {
         let symbol = self.0.upgrade().unwrap();
         match symbol.as_ref() {
             SymbolKind::Scope(_, Some(ScopeKind::Activation(data))) => {
-                data.this.replace(value.map(|v| v.clone()));
+                data.this.replace(value.cloned());
+            },
+            _ => panic!(),
+        }
+    }
+
+    pub fn default_xml_namespace(&self) -> Option<Symbol> {
+        let symbol = self.0.upgrade().unwrap();
+        match symbol.as_ref() {
+            SymbolKind::Scope(_, Some(ScopeKind::Activation(data))) => data.default_xml_namespace.borrow().clone(),
+            _ => panic!(),
+        }
+    }
+
+    pub fn set_default_xml_namespace(&self, value: Option<&Symbol>) {
+        let symbol = self.0.upgrade().unwrap();
+        match symbol.as_ref() {
+            SymbolKind::Scope(_, Some(ScopeKind::Activation(data))) => {
+                data.default_xml_namespace.replace(value.cloned());
             },
             _ => panic!(),
         }
@@ -1985,7 +2081,7 @@ impl Symbol {
         match symbol.as_ref() {
             SymbolKind::Value(_, Some(data)) => {
                 match data.as_ref() {
-                    ValueKind::Constant(ConstantKind::Char(v)) => v.clone(),
+                    ValueKind::Constant(ConstantKind::Char(v)) => *v,
                     _ => panic!(),
                 }
             },
@@ -1998,7 +2094,7 @@ impl Symbol {
         match symbol.as_ref() {
             SymbolKind::Value(_, Some(data)) => {
                 match data.as_ref() {
-                    ValueKind::Constant(ConstantKind::Boolean(v)) => v.clone(),
+                    ValueKind::Constant(ConstantKind::Boolean(v)) => *v,
                     _ => panic!(),
                 }
             },
@@ -2025,7 +2121,7 @@ impl Symbol {
         match symbol.as_ref() {
             SymbolKind::Value(_, Some(data)) => {
                 match data.as_ref() {
-                    ValueKind::Conversion(data) => data.relationship.clone(),
+                    ValueKind::Conversion(data) => data.relationship,
                     _ => panic!(),
                 }
             },
@@ -2038,7 +2134,7 @@ impl Symbol {
         match symbol.as_ref() {
             SymbolKind::Value(_, Some(data)) => {
                 match data.as_ref() {
-                    ValueKind::Conversion(data) => data.optional.clone(),
+                    ValueKind::Conversion(data) => data.optional,
                     _ => panic!(),
                 }
             },
@@ -2165,7 +2261,7 @@ impl Symbol {
                 match data.as_ref() {
                     ValueKind::Reference(data) => {
                         match data.as_ref() {
-                            ReferenceValueKind::Tuple { index, .. } => index.clone(),
+                            ReferenceValueKind::Tuple { index, .. } => *index,
                             _ => panic!(),
                         }
                     },
@@ -2179,17 +2275,18 @@ impl Symbol {
     pub fn property_is_visible(&self, scope: &Symbol, host: &SymbolHost) -> bool {
         let mut prop = self.clone();
         if prop.is_value() {
-            if prop.is_static_reference_value() {
-                prop = prop.property();
-            } else if prop.is_instance_reference_value() {
-                prop = prop.property();
-            } else if prop.is_package_reference_value() {
+            if prop.is_static_reference_value() || prop.is_instance_reference_value() || prop.is_package_reference_value() {
                 prop = prop.property();
             } else {
                 return true;
             }
         }
 
+        // Packages, such as package aliases, are always visible.
+        if prop.is_package() {
+            return true;
+        }
+
         match prop.visibility() {
             Visibility::Public => true,
             Visibility::Internal => {
@@ -2211,7 +2308,7 @@ impl Symbol {
                         return true;
                     }
                 }
-                return false;
+                false
             },
             Visibility::Private => {
                 let mut t: Option<Symbol> = None;
@@ -2232,7 +2329,7 @@ impl Symbol {
                         return true;
                     }
                 }
-                return false;
+                false
             },
             Visibility::Protected => {
                 let mut t: Option<Symbol> = None;
@@ -2253,7 +2350,7 @@ impl Symbol {
                         return true;
                     }
                 }
-                return false;
+                false
             },
         }
     }
@@ -2307,7 +2404,7 @@ impl Symbol {
         } else if self.is_tuple_type() {
             return vec![host.object_type()];
         }
-        return vec![];
+        vec![]
     }
 
     pub fn expect_type(&self) -> Result<Symbol, ExpectedTypeError> {
@@ -2349,6 +2446,12 @@ impl Symbol {
 
     /// The internal *PropertyStaticType*() function.
     pub fn property_static_type(&self, host: &SymbolHost) -> Symbol {
+        if self.is_alias() {
+            return self.resolve_alias().property_static_type(host);
+        }
+        if self.is_unresolved() {
+            return self.clone();
+        }
         if self.is_variable_property() || self.is_virtual_property() {
             return self.static_type(host);
         }
@@ -2356,7 +2459,7 @@ impl Symbol {
             return self.signature(host);
         }
         assert!(self.is_type());
-        return host.class_type();
+        host.class_type()
     }
 
     /// Throws `DeferVerificationError` error if the symbol is `Unresolved`.
@@ -2385,31 +2488,25 @@ impl Symbol {
 
     /// The internal *WrapPropertyReference*() function.
     pub fn wrap_property_reference(&self, host: &SymbolHost) -> Symbol {
-        if self.is_type() && (self.is_void_type() || self.is_any_type() || self.is_function_type() || self.is_tuple_type() || self.is_nullable_type()) {
-            return host.factory().create_type_as_reference_value(&self);
+        if self.is_type() && (self.is_void_type() || self.is_any_type() || self.is_function_type() || self.is_tuple_type() || self.is_nullable_type() || self.is_type_parameter_type()) {
+            return host.factory().create_type_as_reference_value(self);
         }
         let parent = self.parent_definition().unwrap();
         if parent.is_class_type() || parent.is_enum_type() {
-            return host.factory().create_static_reference_value(&parent, &self);
+            return host.factory().create_static_reference_value(&parent, self);
         }
         if parent.is_package() {
-            return host.factory().create_package_reference_value(&parent, &self);
+            return host.factory().create_package_reference_value(&parent, self);
         }
-        assert!(self.is_scope());
-        return host.factory().create_scope_reference_value(&parent, &self);
+        assert!(parent.is_scope());
+        host.factory().create_scope_reference_value(&parent, self)
     }
 
     pub fn is_floating_point_type_of_wider_range_than(&self, other: &Symbol, host: &SymbolHost) -> bool {
         let number_type = host.number_type();
         let single_type = host.single_type();
 
-        if self == &number_type {
-            other == &single_type
-        } else if self == &single_type {
-            false
-        } else {
-            false
-        }
+        self == &number_type && other == &single_type
     }
 
     pub fn is_integer_type_of_wider_range_than(&self, other: &Symbol, host: &SymbolHost) -> bool {
@@ -2452,7 +2549,7 @@ impl Symbol {
             let params = map_type.type_parameters().unwrap();
             Ok(Some((params.get(0).unwrap(), params.get(1).unwrap())))
         } else if self.type_after_substitution_has_origin(&map_type) {
-            let sub = map_type.substitute_types();
+            let sub = self.substitute_types();
             Ok(Some((sub.get(0).unwrap(), sub.get(1).unwrap())))
         } else {
             Ok(None)
@@ -2501,13 +2598,43 @@ impl Symbol {
         }
     }
 
-    pub fn find_activation(&self) -> Option<Symbol> {
+    /// Local variables whose static type is narrowed within the scope,
+    /// mapped to their narrowed types.
+    pub fn narrowed_types(&self) -> SharedMap<Symbol, Symbol> {
+        let symbol = self.0.upgrade().unwrap();
+        match symbol.as_ref() {
+            SymbolKind::Scope(data, _) => data.narrowed_types.clone(),
+            _ => panic!(),
+        }
+    }
+
+    /// Finds the narrowed type of a local variable, looking up
+    /// enclosing scopes up to the innermost activation.
+    pub fn find_narrowed_type(&self, variable: &Symbol) -> Option<Symbol> {
+        for scope in self.descending_scope_hierarchy() {
+            if let Some(t) = scope.narrowed_types().get(variable) {
+                return Some(t);
+            }
+            if scope.is_activation_scope() {
+                break;
+            }
+        }
+        None
+    }
+
+    /// Discards narrowings of a local variable up to the
+    /// innermost activation, as when the variable is assigned.
+    pub fn clear_narrowed_type(&self, variable: &Symbol) {
         for scope in self.descending_scope_hierarchy() {
+            scope.narrowed_types().remove(variable);
             if scope.is_activation_scope() {
-                return Some(scope);
+                break;
             }
         }
-        return None
+    }
+
+    pub fn find_activation(&self) -> Option<Symbol> {
+        self.descending_scope_hierarchy().find(|scope| scope.is_activation_scope())
     }
 
     pub fn property_has_capture(&self, property: &Symbol) -> bool {
@@ -2553,7 +2680,7 @@ impl Symbol {
     ///
     /// `check_property_has_capture` performs the following action:
     /// 
-    /// ```
+    /// ```ignore
     /// let current_scope = self;
     /// if reference.is_scope_reference_value() && reference.base().find_activation().unwrap() != current_scope.find_activation().unwrap() {
     ///     reference.base().find_activation().unwrap().set_property_has_capture(reference.property(), true);
@@ -2621,10 +2748,10 @@ impl Symbol {
     }
 }
 
-impl ToString for Symbol {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for Symbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let symbol = self.0.upgrade().unwrap();
-        match symbol.as_ref() {
+        let string = match symbol.as_ref() {
             SymbolKind::Type(TypeKind::AnyType) => "*".into(),
             SymbolKind::Type(TypeKind::VoidType) => "void".into(),
             SymbolKind::Type(TypeKind::ClassType(_)) |
@@ -2642,26 +2769,26 @@ impl ToString for Symbol {
                 for p_1 in ft.parameters.iter() {
                     match p_1.kind {
                         ParameterKind::Required => {
-                            p.push(format!("{}: {}", p_1.name, p_1.static_type.to_string()));
+                            p.push(format!("{}: {}", p_1.name, p_1.static_type));
                         },
                         ParameterKind::Optional => {
-                            p.push(format!("{}?: {}", p_1.name, p_1.static_type.to_string()));
+                            p.push(format!("{}?: {}", p_1.name, p_1.static_type));
                         },
                         ParameterKind::Rest => {
-                            p.push(format!("...{}: {}", p_1.name, p_1.static_type.to_string()));
+                            p.push(format!("...{}: {}", p_1.name, p_1.static_type));
                         },
                     }
                 }
-                format!("function({}): {}", p.join(", "), ft.result_type.to_string())
+                format!("function({}): {}", p.join(", "), ft.result_type)
             },
             SymbolKind::Type(TypeKind::TupleType(tt)) => {
                 format!("[{}]", tt.element_types.iter().map(|t| t.to_string()).collect::<Vec<String>>().join(", "))
             },
             SymbolKind::Type(TypeKind::NullableType(base)) => {
                 if base.is_function_type() {
-                    format!("?{}", base.to_string())
+                    format!("?{}", base)
                 } else {
-                    format!("{}?", base.to_string())
+                    format!("{}?", base)
                 }
             },
             SymbolKind::Type(TypeKind::TypeParameterType(_)) => self.name(),
@@ -2680,7 +2807,8 @@ impl ToString for Symbol {
             SymbolKind::VirtualPropertyAfterIndirectTypeSubstitution(data) => data.origin.fully_qualified_name(),
             SymbolKind::FunctionAfterExplicitOrIndirectTypeSubstitution(data) => data.origin.fully_qualified_name(),
             _ => panic!(),
-        }
+        };
+        f.write_str(&string)
     }
 }
 
@@ -2785,6 +2913,7 @@ pub(crate) struct ClassTypeData {
     pub limited_subclasses: SharedArray<Symbol>,
     pub plain_metadata: SharedArray<Rc<PlainMetadata>>,
     pub jetdoc: RefCell<Option<Rc<JetDoc>>>,
+    pub location: RefCell<Option<Location>>,
 }
 
 pub(crate) struct EnumTypeData {
@@ -2800,6 +2929,7 @@ pub(crate) struct EnumTypeData {
     pub enumeration_members: SharedMap<String, AbstractRangeNumber>,
     pub plain_metadata: SharedArray<Rc<PlainMetadata>>,
     pub jetdoc: RefCell<Option<Rc<JetDoc>>>,
+    pub location: RefCell<Option<Location>>,
 }
 
 pub(crate) struct InterfaceTypeData {
@@ -2809,9 +2939,11 @@ pub(crate) struct InterfaceTypeData {
     pub extends_interfaces: SharedArray<Symbol>,
     pub type_parameters: RefCell<Option<SharedArray<Symbol>>>,
     pub prototype: SharedMap<String, Symbol>,
+    pub is_limited: Cell<bool>,
     pub limited_implementors: SharedArray<Symbol>,
     pub plain_metadata: SharedArray<Rc<PlainMetadata>>,
     pub jetdoc: RefCell<Option<Rc<JetDoc>>>,
+    pub location: RefCell<Option<Location>>,
 }
 
 pub(crate) struct FunctionTypeData {
@@ -2847,6 +2979,7 @@ bitflags! {
         const IS_STATIC = 0b00000010;
         const IS_ABSTRACT = 0b00000100;
         const ALLOW_LITERAL = 0b00001000;
+        const IS_LIMITED = 0b00010000;
     }
 }
 
@@ -2857,6 +2990,7 @@ pub(crate) struct AliasData {
     pub parent_definition: RefCell<Option<Symbol>>,
     pub plain_metadata: SharedArray<Rc<PlainMetadata>>,
     pub jetdoc: RefCell<Option<Rc<JetDoc>>>,
+    pub location: RefCell<Option<Location>>,
 }
 
 pub(crate) struct PackageData {
@@ -2866,6 +3000,7 @@ pub(crate) struct PackageData {
     pub redirect_packages: SharedArray<Symbol>,
     pub subpackages: SharedMap<String, Symbol>,
     pub jetdoc: RefCell<Option<Rc<JetDoc>>>,
+    pub location: RefCell<Option<Location>>,
 }
 
 pub(crate) struct PackageSetData {
@@ -2874,6 +3009,7 @@ pub(crate) struct PackageSetData {
     pub visibility: Cell<Visibility>,
     pub packages: SharedArray<Symbol>,
     pub jetdoc: RefCell<Option<Rc<JetDoc>>>,
+    pub location: RefCell<Option<Location>>,
 }
 
 pub(crate) struct VariablePropertyData {
@@ -2884,6 +3020,7 @@ pub(crate) struct VariablePropertyData {
     pub read_only: Cell<bool>,
     pub constant_initializer: RefCell<Option<Symbol>>,
     pub jetdoc: RefCell<Option<Rc<JetDoc>>>,
+    pub location: RefCell<Option<Location>>,
     pub plain_metadata: SharedArray<Rc<PlainMetadata>>,
 }
 
@@ -2902,6 +3039,7 @@ pub(crate) struct VirtualPropertyData {
     pub getter: RefCell<Option<Symbol>>,
     pub setter: RefCell<Option<Symbol>>,
     pub jetdoc: RefCell<Option<Rc<JetDoc>>>,
+    pub location: RefCell<Option<Location>>,
 }
 
 pub(crate) struct VirtualPropertyAfterIndirectTypeSubstitutionData {
@@ -2924,6 +3062,7 @@ pub(crate) struct FunctionSymbolData {
     pub overriden_by: SharedArray<Symbol>,
     pub overrides_method: RefCell<Option<Symbol>>,
     pub jetdoc: RefCell<Option<Rc<JetDoc>>>,
+    pub location: RefCell<Option<Location>>,
     pub plain_metadata: SharedArray<Rc<PlainMetadata>>,
     pub activation_scope: RefCell<Option<Symbol>>,
 }
@@ -2960,12 +3099,14 @@ pub(crate) struct ScopeData {
     pub open_packages: SharedArray<Symbol>,
     pub package_aliases: SharedMap<String, Symbol>,
     pub local_variable_scope_count: Cell<usize>,
+    pub narrowed_types: SharedMap<Symbol, Symbol>,
 }
 
 pub(crate) struct ActivationScopeData {
     pub function: Symbol,
     pub this: RefCell<Option<Symbol>>,
     pub property_has_capture: RefCell<Option<SharedArray<Symbol>>>,
+    pub default_xml_namespace: RefCell<Option<Symbol>>,
 }
 
 pub(crate) enum ScopeKind {
@@ -3172,6 +3313,8 @@ impl Deref for VoidType {
 /// * `set_is_static()`
 /// * `allow_literal()`
 /// * `set_allow_literal()`
+/// * `is_limited()`
+/// * `set_is_limited()`
 /// * `implements()` — Implements list of the class.
 /// * `name()` — Unqualified name.
 /// * `parent_definition()`
@@ -3192,6 +3335,8 @@ impl Deref for VoidType {
 /// * `set_visibility()`
 /// * `jetdoc()`
 /// * `set_jetdoc()`
+/// * `location()`
+/// * `set_location()`
 /// * `includes_undefined()` — Returns `false`.
 /// * `includes_null()` — Returns `false`.
 /// * `property_is_visible()`
@@ -3231,6 +3376,8 @@ impl Deref for ClassType {
 /// * `set_visibility()`
 /// * `jetdoc()`
 /// * `set_jetdoc()`
+/// * `location()`
+/// * `set_location()`
 /// * `includes_undefined()` — Returns `false`.
 /// * `includes_null()` — Returns `false`.
 /// * `property_is_visible()`
@@ -3260,12 +3407,16 @@ impl Deref for EnumType {
 /// * `type_parameters()`
 /// * `set_type_parameters()`
 /// * `prototype()`
+/// * `is_limited()`
+/// * `set_is_limited()`
 /// * `limited_implementors()`
 /// * `plain_metadata()`
 /// * `visibility()`
 /// * `set_visibility()`
 /// * `jetdoc()`
 /// * `set_jetdoc()`
+/// * `location()`
+/// * `set_location()`
 /// * `includes_undefined()` — Returns `false`.
 /// * `includes_null()` — Returns `false`.
 /// * `property_is_visible()`
@@ -3417,6 +3568,7 @@ impl Deref for TypeParameterType {
 /// * `plain_metadata()`
 /// * `visibility()`
 /// * `jetdoc()`
+/// * `location()`
 /// * `includes_undefined()` — Returns `false`.
 /// * `includes_null()` — Returns `false`.
 /// * `property_is_visible()`
@@ -3448,6 +3600,8 @@ impl Deref for TypeAfterExplicitTypeSubstitution {
 /// * `plain_metadata()`
 /// * `jetdoc()`
 /// * `set_jetdoc()`
+/// * `location()`
+/// * `set_location()`
 #[derive(Clone, Hash, PartialEq, Eq)]
 pub struct Alias(pub Symbol);
 
@@ -3474,6 +3628,8 @@ impl Deref for Alias {
 /// * `subpackages()`
 /// * `jetdoc()`
 /// * `set_jetdoc()`
+/// * `location()`
+/// * `set_location()`
 #[derive(Clone, Hash, PartialEq, Eq)]
 pub struct Package(pub Symbol);
 
@@ -3500,6 +3656,8 @@ impl Deref for Package {
 /// * `packages()`
 /// * `jetdoc()`
 /// * `set_jetdoc()`
+/// * `location()`
+/// * `set_location()`
 #[derive(Clone, Hash, PartialEq, Eq)]
 pub struct PackageSet(pub Symbol);
 
@@ -3533,6 +3691,8 @@ impl Deref for PackageSet {
 /// * `plain_metadata()`
 /// * `jetdoc()`
 /// * `set_jetdoc()`
+/// * `location()`
+/// * `set_location()`
 #[derive(Clone, Hash, PartialEq, Eq)]
 pub struct VariableProperty(pub Symbol);
 
@@ -3563,6 +3723,7 @@ impl Deref for VariableProperty {
 /// * `read_only()`
 /// * `plain_metadata()`
 /// * `jetdoc()`
+/// * `location()`
 #[derive(Clone, Hash, PartialEq, Eq)]
 pub struct VariablePropertyAfterIndirectTypeSubstitution(pub Symbol);
 
@@ -3596,6 +3757,8 @@ impl Deref for VariablePropertyAfterIndirectTypeSubstitution {
 /// * `set_setter()`
 /// * `jetdoc()`
 /// * `set_jetdoc()`
+/// * `location()`
+/// * `set_location()`
 #[derive(Clone, Hash, PartialEq, Eq)]
 pub struct VirtualProperty(pub Symbol);
 
@@ -3628,6 +3791,7 @@ impl Deref for VirtualProperty {
 /// * `getter()`
 /// * `setter()`
 /// * `jetdoc()`
+/// * `location()`
 #[derive(Clone, Hash, PartialEq, Eq)]
 pub struct VirtualPropertyAfterIndirectTypeSubstitution(pub Symbol);
 
@@ -3654,6 +3818,8 @@ impl Deref for VirtualPropertyAfterIndirectTypeSubstitution {
 /// * `set_visibility()`
 /// * `jetdoc()`
 /// * `set_jetdoc()`
+/// * `location()`
+/// * `set_location()`
 /// * `plain_metadata()`
 /// * `is_generator()`
 /// * `is_async()`
@@ -3707,6 +3873,7 @@ impl Deref for FunctionSymbol {
 /// * `parent_definition()`
 /// * `visibility()`
 /// * `jetdoc()`
+/// * `location()`
 /// * `plain_metadata()`
 /// * `is_generator()`
 /// * `is_async()`
@@ -3838,6 +4005,8 @@ impl Deref for FilterOperatorScope {
 /// * `property_has_capture()` — Indicates whether an activation's property has been captured by a subsequent activation.
 ///   Properties range from the activation scope to the innermost scope of an activation.
 /// * `set_property_has_capture()`
+/// * `default_xml_namespace()` — The value assigned by the last `default xml namespace` statement verified within the activation.
+/// * `set_default_xml_namespace()`
 #[derive(Clone, Hash, PartialEq, Eq)]
 pub struct ActivationScope(pub Symbol);
 
@@ -4467,4 +4636,32 @@ mod tests {
         let p = host.factory().create_package(["y", "n"]);
         println!("{}", p.fully_qualified_name());
     }
+
+    #[test]
+    fn write_only_values() {
+        let host = SymbolHost::new("");
+        let s = host.factory().create_string_constant("s".into(), &host.any_type());
+        assert!(!s.write_only());
+
+        let t = host.factory().create_class_type("C".into());
+        let p = host.factory().create_virtual_property("x".into());
+        let r = host.factory().create_static_reference_value(&t, &p);
+        assert!(r.write_only());
+    }
+
+    #[test]
+    fn resolving_open_packages() {
+        let host = SymbolHost::new("");
+        let p = host.factory().create_package(["y", "n"]);
+        let x = host.factory().create_variable_property("x".into(), false, &host.any_type());
+        x.set_parent_definition(Some(&p));
+        x.set_visibility(Visibility::Public);
+        p.properties(&host).set("x".into(), x.clone());
+
+        let scope = host.factory().create_scope();
+        scope.open_packages().push(p);
+        let r = scope.resolve_property(None, SemanticPropertyKey::String("x".into()), &host).unwrap().unwrap();
+        assert!(r.is_scope_reference_value() || r.is_package_reference_value());
+        assert!(r.property() == x);
+    }
 }
\ No newline at end of file
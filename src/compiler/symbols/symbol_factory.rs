@@ -37,6 +37,7 @@ impl<'a> SymbolFactory<'a> {
             limited_subclasses: SharedArray::new(),
             plain_metadata: SharedArray::new(),
             jetdoc: RefCell::new(None),
+            location: RefCell::new(None),
         })))))
     }
 
@@ -54,6 +55,7 @@ impl<'a> SymbolFactory<'a> {
             enumeration_members: SharedMap::new(),
             plain_metadata: SharedArray::new(),
             jetdoc: RefCell::new(None),
+            location: RefCell::new(None),
         })))))
     }
 
@@ -65,9 +67,11 @@ impl<'a> SymbolFactory<'a> {
             extends_interfaces: SharedArray::new(),
             type_parameters: RefCell::new(None),
             prototype: SharedMap::new(),
+            is_limited: Cell::new(false),
             limited_implementors: SharedArray::new(),
             plain_metadata: SharedArray::new(),
             jetdoc: RefCell::new(None),
+            location: RefCell::new(None),
         })))))
     }
 
@@ -85,10 +89,10 @@ impl<'a> SymbolFactory<'a> {
             if result_type != ft.result_type() {
                 continue 'ft;
             }
-            let mut parameters_1 = parameters.iter();
+            let parameters_1 = parameters.iter();
             let parameters_2 = ft.parameters();
             let mut parameters_2 = parameters_2.iter();
-            while let Some(param_1) = parameters_1.next() {
+            for param_1 in parameters_1 {
                 let param_2 = parameters_2.next().unwrap();
                 if !(param_1.kind == param_2.kind && param_1.name == param_2.name && param_1.static_type == param_2.static_type) {
                     continue 'ft;
@@ -118,10 +122,10 @@ impl<'a> SymbolFactory<'a> {
             tuple_types.insert(element_count, vec![]);
         }
         'tt: for tt in collection.unwrap() {
-            let mut element_types_1 = element_types.iter();
+            let element_types_1 = element_types.iter();
             let element_types_2 = tt.element_types();
             let mut element_types_2 = element_types_2.iter();
-            while let Some(e_1) = element_types_1.next() {
+            for e_1 in element_types_1 {
                 let e_2 = element_types_2.next().unwrap();
                 if e_1 != &e_2 {
                     continue 'tt;
@@ -170,17 +174,17 @@ impl<'a> SymbolFactory<'a> {
 
         let mut taets_list = self.host.taets.borrow_mut();
 
-        let mut list = taets_list.get(&origin);
+        let mut list = taets_list.get(origin);
         let empty_list = vec![];
         if list.is_none() {
             list = Some(&empty_list);
             taets_list.insert(origin.clone(), vec![]);
         }
         'taets: for taets in list.unwrap() {
-            let mut substitute_types_1 = substitute_types.iter();
+            let substitute_types_1 = substitute_types.iter();
             let substitute_types_2 = taets.substitute_types();
             let mut substitute_types_2 = substitute_types_2.iter();
-            while let Some(substitute_type_1) = substitute_types_1.next() {
+            for substitute_type_1 in substitute_types_1 {
                 let substitute_type_2 = substitute_types_2.next().unwrap();
                 if substitute_type_1 != substitute_type_2 {
                     continue 'taets;
@@ -202,7 +206,7 @@ impl<'a> SymbolFactory<'a> {
             list_of_to_proxies: RefCell::new(None),
         })))));
 
-        let list = taets_list.get_mut(&origin).unwrap();
+        let list = taets_list.get_mut(origin).unwrap();
         list.push(taets.clone());
 
         taets
@@ -216,6 +220,7 @@ impl<'a> SymbolFactory<'a> {
             parent_definition: RefCell::new(None),
             plain_metadata: SharedArray::new(),
             jetdoc: RefCell::new(None),
+            location: RefCell::new(None),
         }))))
     }
 
@@ -245,6 +250,7 @@ impl<'a> SymbolFactory<'a> {
                     redirect_packages: SharedArray::new(),
                     subpackages: SharedMap::new(),
                     jetdoc: RefCell::new(None),
+                    location: RefCell::new(None),
                 }))));
                 result.subpackages().set(name_1, result_1.clone());
                 result = result_1;
@@ -260,6 +266,7 @@ impl<'a> SymbolFactory<'a> {
             packages,
             visibility: Cell::new(Visibility::Internal),
             jetdoc: RefCell::new(None),
+            location: RefCell::new(None),
         }))))
     }
 
@@ -273,6 +280,7 @@ impl<'a> SymbolFactory<'a> {
             parent_definition: RefCell::new(None),
             plain_metadata: SharedArray::new(),
             jetdoc: RefCell::new(None),
+            location: RefCell::new(None),
         }))))
     }
 
@@ -298,10 +306,10 @@ impl<'a> SymbolFactory<'a> {
             base_list.insert(indirect_type_parameters.clone(), vec![]);
         }
         'vapaits: for vapaits in list.unwrap() {
-            let mut substitute_types_1 = indirect_substitute_types.iter();
+            let substitute_types_1 = indirect_substitute_types.iter();
             let substitute_types_2 = vapaits.indirect_substitute_types();
             let mut substitute_types_2 = substitute_types_2.iter();
-            while let Some(substitute_type_1) = substitute_types_1.next() {
+            for substitute_type_1 in substitute_types_1 {
                 let substitute_type_2 = substitute_types_2.next().unwrap();
                 if substitute_type_1 != substitute_type_2 {
                     continue 'vapaits;
@@ -317,7 +325,7 @@ impl<'a> SymbolFactory<'a> {
             static_type: RefCell::new(None),
         }))));
 
-        let list = vapaits_list.get_mut(origin).unwrap().get_mut(&indirect_type_parameters).unwrap();
+        let list = vapaits_list.get_mut(origin).unwrap().get_mut(indirect_type_parameters).unwrap();
         list.push(vapaits.clone());
 
         vapaits
@@ -332,6 +340,7 @@ impl<'a> SymbolFactory<'a> {
             setter: RefCell::new(None),
             parent_definition: RefCell::new(None),
             jetdoc: RefCell::new(None),
+            location: RefCell::new(None),
         }))))
     }
 
@@ -357,10 +366,10 @@ impl<'a> SymbolFactory<'a> {
             base_list.insert(indirect_type_parameters.clone(), vec![]);
         }
         'vipaits: for vipaits in list.unwrap() {
-            let mut substitute_types_1 = indirect_substitute_types.iter();
+            let substitute_types_1 = indirect_substitute_types.iter();
             let substitute_types_2 = vipaits.indirect_substitute_types();
             let mut substitute_types_2 = substitute_types_2.iter();
-            while let Some(substitute_type_1) = substitute_types_1.next() {
+            for substitute_type_1 in substitute_types_1 {
                 let substitute_type_2 = substitute_types_2.next().unwrap();
                 if substitute_type_1 != substitute_type_2 {
                     continue 'vipaits;
@@ -378,7 +387,7 @@ impl<'a> SymbolFactory<'a> {
             setter: RefCell::new(None),
         }))));
 
-        let list = vipaits_list.get_mut(origin).unwrap().get_mut(&indirect_type_parameters).unwrap();
+        let list = vipaits_list.get_mut(origin).unwrap().get_mut(indirect_type_parameters).unwrap();
         list.push(vipaits.clone());
 
         vipaits
@@ -391,6 +400,7 @@ impl<'a> SymbolFactory<'a> {
             parent_definition: RefCell::new(None),
             plain_metadata: SharedArray::new(),
             jetdoc: RefCell::new(None),
+            location: RefCell::new(None),
             flags: RefCell::new(FunctionSymbolFlags::empty()),
             signature: RefCell::new(signature.clone()),
             type_parameters: RefCell::new(None),
@@ -408,25 +418,13 @@ impl<'a> SymbolFactory<'a> {
 
         let mut faeoits_list = self.host.faeoits.borrow_mut();
 
-        let mut base_list = faeoits_list.get_mut(origin);
-        let mut empty_base_list = HashMap::<SharedArray<Symbol>, Vec<Symbol>>::new();
-        if base_list.is_none() {
-            base_list = Some(&mut empty_base_list);
-            faeoits_list.insert(origin.clone(), HashMap::new());
-        }
-        let base_list = base_list.unwrap();
-
-        let mut list = base_list.get(explicit_or_indirect_type_parameters);
-        let empty_list = vec![];
-        if list.is_none() {
-            list = Some(&empty_list);
-            base_list.insert(explicit_or_indirect_type_parameters.clone(), vec![]);
-        }
-        'faeoits: for faeoits in list.unwrap() {
-            let mut substitute_types_1 = explicit_or_indirect_substitute_types.iter();
+        let list = faeoits_list.entry(origin.clone()).or_default()
+            .entry(explicit_or_indirect_type_parameters.clone()).or_default();
+        'faeoits: for faeoits in list.iter() {
+            let substitute_types_1 = explicit_or_indirect_substitute_types.iter();
             let substitute_types_2 = faeoits.explicit_or_indirect_substitute_types();
             let mut substitute_types_2 = substitute_types_2.iter();
-            while let Some(substitute_type_1) = substitute_types_1.next() {
+            for substitute_type_1 in substitute_types_1 {
                 let substitute_type_2 = substitute_types_2.next().unwrap();
                 if substitute_type_1 != substitute_type_2 {
                     continue 'faeoits;
@@ -446,7 +444,7 @@ impl<'a> SymbolFactory<'a> {
             is_overriding: Cell::new(origin.is_overriding()),
         }))));
 
-        let list = faeoits_list.get_mut(origin).unwrap().get_mut(&explicit_or_indirect_type_parameters).unwrap();
+        let list = faeoits_list.get_mut(origin).unwrap().get_mut(explicit_or_indirect_type_parameters).unwrap();
         list.push(faeoits.clone());
 
         faeoits
@@ -460,6 +458,7 @@ impl<'a> SymbolFactory<'a> {
             open_packages: SharedArray::new(),
             package_aliases: SharedMap::new(),
             local_variable_scope_count: Cell::new(0),
+            narrowed_types: SharedMap::new(),
         }), None)))
     }
 
@@ -471,6 +470,7 @@ impl<'a> SymbolFactory<'a> {
             open_packages: SharedArray::new(),
             package_aliases: SharedMap::new(),
             local_variable_scope_count: Cell::new(0),
+            narrowed_types: SharedMap::new(),
         }), Some(ScopeKind::With {
             object: object.clone(),
         }))))
@@ -484,6 +484,7 @@ impl<'a> SymbolFactory<'a> {
             open_packages: SharedArray::new(),
             package_aliases: SharedMap::new(),
             local_variable_scope_count: Cell::new(0),
+            narrowed_types: SharedMap::new(),
         }), Some(ScopeKind::FilterOperator {
             base: base.clone(),
         }))))
@@ -497,10 +498,12 @@ impl<'a> SymbolFactory<'a> {
             open_packages: SharedArray::new(),
             package_aliases: SharedMap::new(),
             local_variable_scope_count: Cell::new(0),
+            narrowed_types: SharedMap::new(),
         }), Some(ScopeKind::Activation(Rc::new(ActivationScopeData {
             function: function.clone(),
             this: RefCell::new(None),
             property_has_capture: RefCell::new(None),
+            default_xml_namespace: RefCell::new(None),
         }))))))
     }
 
@@ -512,6 +515,7 @@ impl<'a> SymbolFactory<'a> {
             open_packages: SharedArray::new(),
             package_aliases: SharedMap::new(),
             local_variable_scope_count: Cell::new(0),
+            narrowed_types: SharedMap::new(),
         }), Some(ScopeKind::Class {
             class: class.clone(),
         }))))
@@ -525,6 +529,7 @@ impl<'a> SymbolFactory<'a> {
             open_packages: SharedArray::new(),
             package_aliases: SharedMap::new(),
             local_variable_scope_count: Cell::new(0),
+            narrowed_types: SharedMap::new(),
         }), Some(ScopeKind::Enum {
             class: class.clone(),
         }))))
@@ -538,6 +543,7 @@ impl<'a> SymbolFactory<'a> {
             open_packages: SharedArray::new(),
             package_aliases: SharedMap::new(),
             local_variable_scope_count: Cell::new(0),
+            narrowed_types: SharedMap::new(),
         }), Some(ScopeKind::Interface {
             interface: interface.clone(),
         }))))
@@ -551,6 +557,7 @@ impl<'a> SymbolFactory<'a> {
             open_packages: SharedArray::new(),
             package_aliases: SharedMap::new(),
             local_variable_scope_count: Cell::new(0),
+            narrowed_types: SharedMap::new(),
         }), Some(ScopeKind::Package {
             package: package.clone(),
         }))))
@@ -2,6 +2,10 @@ use crate::ns::*;
 use std::cell::RefCell;
 use std::rc::Rc;
 
+/// Interned symbols after type substitution, by origin and
+/// by the list of substituted type parameters.
+pub(crate) type SubstitutionCache = RefCell<HashMap<Symbol, HashMap<SharedArray<Symbol>, Vec<Symbol>>>>;
+
 pub struct SymbolHost {
     pub(crate) arena: Arena<SymbolKind>,
     pub(crate) unresolved: Symbol,
@@ -21,11 +25,11 @@ pub struct SymbolHost {
     /// Types after explicit type substitution.
     pub(crate) taets: RefCell<HashMap<Symbol, Vec<Symbol>>>,
     /// Variable properties after indirect type substitution.
-    pub(crate) vapaits: RefCell<HashMap<Symbol, HashMap<SharedArray<Symbol>, Vec<Symbol>>>>,
+    pub(crate) vapaits: SubstitutionCache,
     /// Virtual properties after indirect type substitution.
-    pub(crate) vipaits: RefCell<HashMap<Symbol, HashMap<SharedArray<Symbol>, Vec<Symbol>>>>,
+    pub(crate) vipaits: SubstitutionCache,
     /// Functions after explicit or indirect type substitution.
-    pub(crate) faeoits: RefCell<HashMap<Symbol, HashMap<SharedArray<Symbol>, Vec<Symbol>>>>,
+    pub(crate) faeoits: SubstitutionCache,
 
     pub(crate) top_level_package: Symbol,
     pub(crate) jet_lang_package: RefCell<Option<Symbol>>,
@@ -48,6 +52,7 @@ pub struct SymbolHost {
     pub(crate) byte_array_type: RefCell<Option<Symbol>>,
     pub(crate) reg_exp_type: RefCell<Option<Symbol>>,
     pub(crate) iterator_type: RefCell<Option<Symbol>>,
+    pub(crate) error_type: RefCell<Option<Symbol>>,
 
     pub(crate) infinity_constant: RefCell<Option<Symbol>>,
     pub(crate) nan_constant: RefCell<Option<Symbol>>,
@@ -69,6 +74,7 @@ impl SymbolHost {
             redirect_packages: SharedArray::new(),
             subpackages: SharedMap::new(),
             jetdoc: RefCell::new(None),
+            location: RefCell::new(None),
         }))));
 
         let import_meta = Symbol(arena.allocate(SymbolKind::Value(ValueData {
@@ -122,6 +128,7 @@ impl SymbolHost {
             byte_array_type: RefCell::new(None),
             reg_exp_type: RefCell::new(None),
             iterator_type: RefCell::new(None),
+            error_type: RefCell::new(None),
 
             infinity_constant: RefCell::new(None),
             nan_constant: RefCell::new(None),
@@ -130,7 +137,7 @@ impl SymbolHost {
         })
     }
 
-    pub fn factory(&self) -> SymbolFactory {
+    pub fn factory(&self) -> SymbolFactory<'_> {
         SymbolFactory { host: self }
     }
 
@@ -448,6 +455,19 @@ impl SymbolHost {
         }
     }
 
+    /// The `jet.lang.Error` class, possibly `Unresolved`.
+    pub fn error_type(&self) -> Symbol {
+        if let Some(r) = self.error_type.borrow().as_ref() {
+            return r.clone();
+        }
+        if let Some(r) = self.lookup_at_jet_lang("Error") {
+            self.error_type.replace(Some(r.clone()));
+            r
+        } else {
+            self.unresolved()
+        }
+    }
+
     /// The `jet.lang.Infinity` constant, possibly `Unresolved`.
     pub fn infinity_constant(&self) -> Symbol {
         if let Some(r) = self.infinity_constant.borrow().as_ref() {
@@ -518,10 +538,8 @@ impl SymbolHost {
         }
         let mut r = HashMap::<String, String>::new();
         if let Ok(iterator) = dotenvy::dotenv_iter() {
-            for item in iterator {
-                if let Ok((key, value)) = item {
-                    r.insert(key, value);
-                }
+            for (key, value) in iterator.flatten() {
+                r.insert(key, value);
             }
         }
         let r = Rc::new(r);
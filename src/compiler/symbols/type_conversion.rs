@@ -84,8 +84,6 @@ impl<'a> TypeConversions<'a> {
                 return Some(self.0.factory().create_number_constant(v, target_type));
             }
 
-            let number_type = self.0.number_type();
-            let nullable_number_type = self.0.factory().create_nullable_type(&number_type);
 
             // NaN constant to NI or NI?
             if value.number_value().is_nan() && self.0.is_integer_type(&target_type.non_null_type()) {
@@ -214,7 +212,7 @@ impl<'a> TypeConversions<'a> {
                     continue;
                 }
                 let result_type = signature.result_type();
-                if result_type.is_equals_or_subtype_of(&target_type, self.0) {
+                if result_type.is_equals_or_subtype_of(target_type, self.0) {
                     return Some(self.0.factory().create_conversion_value(value, TypeConversionRelationship::ThroughToProxy, optional, target_type));
                 }
             }
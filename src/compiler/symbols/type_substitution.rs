@@ -19,7 +19,7 @@ pub struct TypeSubstitution<'a>(pub &'a SymbolHost);
 impl<'a> TypeSubstitution<'a> {
     pub fn execute(&mut self, symbol: &Symbol, type_parameters: &SharedArray<Symbol>, substitute_types: &SharedArray<Symbol>) -> Symbol {
         if symbol.is_unresolved() {
-            return symbol.clone();
+            symbol.clone()
         } else if symbol.is_type() {
             if symbol.is_function_type() {
                 let result_type = symbol.result_type().type_substitution(self.0, type_parameters, substitute_types);
@@ -38,12 +38,12 @@ impl<'a> TypeSubstitution<'a> {
                 let new_substitute_types: SharedArray<Symbol> = symbol.substitute_types().iter().map(|t| t.type_substitution(self.0, type_parameters, substitute_types)).collect();
                 return self.0.factory().create_type_after_explicit_type_substitution(&symbol.origin(), &new_substitute_types);
             } else if symbol.is_type_parameter_type() {
-                let i = type_parameters.index_of(&symbol);
+                let i = type_parameters.index_of(symbol);
                 if let Some(i) = i {
                     return substitute_types.get(i).unwrap();
                 }
             }
-            return symbol.clone();
+            symbol.clone()
         } else if symbol.is_variable_property() {
             self.0.factory().create_variable_property_after_indirect_type_substitution(symbol, type_parameters, substitute_types)
         } else if symbol.is_virtual_property() {
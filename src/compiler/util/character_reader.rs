@@ -35,7 +35,7 @@ impl<'a> CharacterReader<'a> {
 
     /// Returns the current index in the string.
     pub fn index(&self) -> usize {
-        self.clone().char_indices.next().map_or(0, |(i, _)| i)
+        self.char_indices.offset()
     }
 
     /// Returns the next code point. If there are no code points
@@ -34,13 +34,19 @@ impl<T> Hash for SharedArray<T> {
     }
 }
 
+impl<T> Default for SharedArray<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> SharedArray<T> {
     pub fn new() -> Self {
         Self(Rc::new(RefCell::new(vec![])))
     }
 
     pub fn get(&self, index: usize) -> Option<T> where T: Clone {
-        self.0.borrow().get(index).map(|v| v.clone())
+        self.0.borrow().get(index).cloned()
     }
 
     pub fn set(&mut self, index: usize, value: T) where T: Clone {
@@ -74,9 +80,9 @@ impl<T> SharedArray<T> {
         self.0.borrow_mut().push(value);
     }
 
-    pub fn iter(&self) -> SharedArrayIterator<T> where T: Clone {
+    pub fn iter(&self) -> SharedArrayIterator<'_, T> where T: Clone {
         SharedArrayIterator {
-            array: &self,
+            array: self,
             index: 0,
         }
     }
@@ -47,13 +47,19 @@ impl<K, V> Hash for SharedMap<K, V> {
     }
 }
 
+impl<K, V> Default for SharedMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<K, V> SharedMap<K, V> {
     pub fn new() -> Self {
         Self(Rc::new(RefCell::new(HashMap::new())))
     }
 
     pub fn get(&self, key: &K) -> Option<V> where K: Eq + Hash, V: Clone {
-        self.0.borrow().get(key).map(|v| v.clone())
+        self.0.borrow().get(key).cloned()
     }
 
     pub fn set(&mut self, key: K, value: V) where K: Eq + Hash {
@@ -80,7 +86,7 @@ impl<K, V> SharedMap<K, V> {
         r
     }
 
-    pub fn borrow(&self) -> std::cell::Ref<HashMap<K, V>> {
+    pub fn borrow(&self) -> std::cell::Ref<'_, HashMap<K, V>> {
         self.0.borrow()
     }
 }
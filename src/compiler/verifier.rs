@@ -1,5 +1,17 @@
 use crate::ns::*;
 
+mod configuration_evaluator;
+pub use configuration_evaluator::*;
+mod constant_evaluator;
+pub use constant_evaluator::*;
+mod control_flow;
+pub use control_flow::*;
+mod narrowing;
+pub use narrowing::*;
+mod destructuring;
+mod regexp_validator;
+pub use regexp_validator::*;
+
 /// Jet verifier.
 ///
 /// `Verifier` is both a type checker, a symbol solver and strictness verifier,
@@ -25,7 +37,7 @@ use crate::ns::*;
 /// Such methods may alter the `parent_scope()` field of the scope to use the enclosing
 /// scope as the parent.
 ///
-/// ```
+/// ```ignore
 /// verifier.enter_scope(&scope);
 /// verifier.exit_scope();
 /// ```
@@ -54,6 +66,9 @@ impl Verifier {
                 invalidated: false,
                 deferred_counter: 0,
                 scope: host.root_scope(),
+                configuration_blocks: HashMap::new(),
+                optional_chaining_bases: vec![],
+                verifying_parameter_defaults: false,
             },
         }
     }
@@ -77,7 +92,18 @@ impl Verifier {
         }
         self.verifier.reset_state();
 
-        todo_here();
+        for program in &programs {
+            self.verifier.declare_program_scopes(program);
+        }
+
+        for phase in VerifierPhase::LIST {
+            for program in &programs {
+                self.verifier.verify_program_directives(program, phase);
+            }
+            self.verifier.verify_deferred_directives(phase);
+        }
+
+        self.verifier.finish_deferred_verification();
     }
 
     /// Verifies an expression. Returns `None` if verification failed.
@@ -91,7 +117,16 @@ impl Verifier {
         }
         self.verifier.reset_state();
 
-        ()
+        let result = self.verifier.verify_expression(exp, context);
+        self.verifier.finish_deferred_verification();
+
+        match result {
+            Ok(result) => result,
+            Err(DeferVerificationError) => {
+                self.verifier.add_verify_error(&exp.location(), DiagnosticKind::UnresolvedReference, diagnostic_arguments![]);
+                None
+            },
+        }
     }
 
     pub fn enter_scope(&mut self, scope: &Symbol) {
@@ -107,25 +142,22 @@ pub(crate) struct VerifierVerifier {
     pub host: Rc<SymbolHost>,
     pub ast_to_symbol: Rc<AstToSymbol>,
     /// List of (phase, scope, directive).
-    pub deferred_directives: Vec<(usize, Symbol, Rc<Directive>)>,
+    pub deferred_directives: Vec<(VerifierPhase, Symbol, Rc<Directive>)>,
     /// List of (phase, scope, common).
-    pub deferred_function_commons: Vec<(usize, Symbol, Rc<FunctionCommon>)>,
+    pub deferred_function_commons: Vec<(VerifierPhase, Symbol, Rc<FunctionCommon>)>,
     invalidated: bool,
     pub deferred_counter: usize,
     pub scope: Symbol,
+    /// Blocks selected by configuration directives.
+    pub configuration_blocks: HashMap<AstAsKey<Rc<Directive>>, Option<Rc<Directive>>>,
+    /// Non-null bases of the optional chaining operations being verified.
+    pub optional_chaining_bases: Vec<Symbol>,
+    /// Whether the default values of the parameters of the innermost
+    /// function are being verified, which belong to no function body.
+    pub verifying_parameter_defaults: bool,
 }
 
 impl VerifierVerifier {
-    pub fn ast_to_symbol(&self) -> &Rc<AstToSymbol> {
-        &self.ast_to_symbol
-    }
-
-    /// Indicates whether a syntax or verify error occurred while
-    /// verifying.
-    pub fn invalidated(&self) -> bool {
-        self.invalidated
-    }
-
     fn reset_state(&mut self) {
         self.deferred_counter = 0;
         self.deferred_directives.clear();
@@ -142,8 +174,12 @@ impl VerifierVerifier {
         self.invalidated = true;
     }
 
-    pub fn add_warning(&self, location: &Location, kind: DiagnosticKind, arguments: Vec<DiagnosticArgument>) {
-        location.compilation_unit().add_diagnostic(Diagnostic::new_warning(location, kind, arguments));
+    pub fn add_warning(&mut self, location: &Location, kind: DiagnosticKind, arguments: Vec<DiagnosticArgument>) {
+        let compilation_unit = location.compilation_unit();
+        if compilation_unit.compiler_options.warnings_as_errors && compilation_unit.compiler_options.reports_warning(kind) {
+            self.invalidated = true;
+        }
+        compilation_unit.add_diagnostic(Diagnostic::new_warning(location, kind, arguments));
     }
 
     pub fn enter_scope(&mut self, scope: &Symbol) {
@@ -159,59 +195,140 @@ impl VerifierVerifier {
     }
 
     pub fn verify_expression(&mut self, exp: &Rc<Expression>, context: &ExpressionVerifyContext) -> Result<Option<Symbol>, DeferVerificationError> {
-        let pre_result = self.ast_to_symbol.get(exp);
-        if let Some(pre_result) = pre_result {
-            return Ok(Some(pre_result));
+        if self.ast_to_symbol.has(exp) {
+            return Ok(self.ast_to_symbol.get(exp));
         }
-        let result: Option<Symbol>;
-        match exp.as_ref() {
+        let result = match exp.as_ref() {
             Expression::QualifiedIdentifier(id) => {
-                result = id.verify_as_exp(self, &context)?;
+                id.verify_as_exp(self, context)?
             },
             Expression::Embed(emb) => {
-                result = emb.verify(self, &context)?;
+                emb.verify(self, context)?
             },
             Expression::Paren(paren_exp) => {
-                result = self.verify_expression(&paren_exp.expression, &context)?;
+                self.verify_expression(&paren_exp.expression, context)?
             },
             Expression::NullLiteral(nl) => {
-                result = nl.verify(self, &context)?;
+                nl.verify(self, context)?
             },
             Expression::BooleanLiteral(bl) => {
-                result = Some(self.host.factory().create_boolean_constant(bl.value, &self.host.boolean_type()));
+                Some(self.host.factory().create_boolean_constant(bl.value, &self.host.boolean_type()))
             },
             Expression::NumericLiteral(nl) => {
-                result = nl.verify(self, &context)?;
+                nl.verify(self, context)?
             },
             Expression::StringLiteral(sl) => {
-                result = sl.verify(self, &context)?;
+                sl.verify(self, context)?
             },
             Expression::ThisLiteral(tl) => {
-                result = tl.verify(self)?;
+                tl.verify(self)?
             },
             Expression::RegExpLiteral(rl) => {
-                result = rl.verify(self)?;
+                rl.verify(self)?
             },
             Expression::Xml(xml) => {
                 xml.element.verify(self)?;
-                result = Some(self.host.factory().create_value(&self.host.xml_type()));
+                xml.element.verify_markup(self, Some(&[]));
+                Some(self.host.factory().create_value(&self.host.xml_type()))
             },
-            Expression::XmlMarkup(xml) => {
-                result = Some(self.host.factory().create_value(&self.host.xml_type()));
+            Expression::XmlMarkup(_) => {
+                Some(self.host.factory().create_value(&self.host.xml_type()))
             },
             Expression::XmlList(xml) => {
                 for content in &xml.content {
                     content.verify(self)?;
                 }
-                result = Some(self.host.factory().create_value(&self.host.xml_list_type()));
+                for content in &xml.content {
+                    if let XmlElementContent::XmlElement(el) = content.as_ref() {
+                        el.verify_markup(self, Some(&[]));
+                    }
+                }
+                Some(self.host.factory().create_value(&self.host.xml_list_type()))
             },
             Expression::ArrayLiteral(al) => {
-                result = al.verify(self, context)?;
+                al.verify(self, context)?
             },
             Expression::ObjectInitializer(oi) => {
-                result = oi.verify(self, context)?;
+                oi.verify(self, context)?
             },
-        }
+            Expression::Conditional(ce) => {
+                ce.verify(self, context)?
+            },
+            Expression::Unary(ue) => {
+                ue.verify(self, context)?
+            },
+            Expression::Binary(be) => {
+                be.verify(self, context)?
+            },
+            Expression::Assignment(ae) => {
+                ae.verify(self)?
+            },
+            Expression::Call(ce) => {
+                ce.verify(self)?
+            },
+            Expression::New(ne) => {
+                ne.verify(self)?
+            },
+            Expression::WithTypeArguments(e) => {
+                e.verify(self)?
+            },
+            Expression::Member(m) => {
+                m.verify(self, context)?
+            },
+            Expression::ComputedMember(m) => {
+                m.verify(self, context)?
+            },
+            Expression::Descendants(d) => {
+                d.verify(self)?
+            },
+            Expression::Filter(f) => {
+                f.verify(self)?
+            },
+            Expression::Function(f) => {
+                f.verify(self, context)?
+            },
+            Expression::AnyType(_) => {
+                Some(self.host.factory().create_type_as_reference_value(&self.host.any_type()))
+            },
+            Expression::VoidType(_) => {
+                Some(self.host.factory().create_type_as_reference_value(&self.host.void_type()))
+            },
+            Expression::NullableType(t) => {
+                t.verify(self)?
+            },
+            Expression::NonNullableType(t) => {
+                t.verify(self)?
+            },
+            Expression::ArrayType(t) => {
+                t.verify(self)?
+            },
+            Expression::TupleType(t) => {
+                t.verify(self)?
+            },
+            Expression::FunctionType(t) => {
+                t.verify(self)?
+            },
+            Expression::ImportMeta(_) => {
+                Some(self.host.import_meta())
+            },
+            Expression::Super(se) => {
+                se.verify(self)?
+            },
+            Expression::OptionalChaining(oc) => {
+                oc.verify(self)?
+            },
+            Expression::OptionalChainingPlaceholder(_) => {
+                self.optional_chaining_bases.last().cloned()
+            },
+            Expression::Sequence(se) => {
+                self.verify_expression(&se.left, &default())?;
+                self.verify_expression(&se.right, context)?
+            },
+            // A syntax error has already been reported.
+            Expression::Invalid(_) => {
+                None
+            },
+        };
 
         self.ast_to_symbol.set(exp, result.clone());
 
@@ -222,7 +339,7 @@ impl VerifierVerifier {
 
         match context.mode {
             VerifyMode::Read => {
-                if result.write_only(&self.host) {
+                if result.write_only() {
                     self.add_verify_error(&exp.location(), DiagnosticKind::ReferenceIsWriteOnly, diagnostic_arguments![]);
                 }
             },
@@ -241,6 +358,450 @@ impl VerifierVerifier {
         Ok(Some(result))
     }
 
+    /// Creates the package scopes of a program and its top-level
+    /// activation scope, whose function has the signature `function(): void`.
+    fn declare_program_scopes(&mut self, program: &Rc<Program>) {
+        for pckg in &program.packages {
+            let package = self.host.factory().create_package(pckg.name.iter().map(|name| name.0.as_ref()));
+            if pckg.jetdoc.is_some() {
+                package.set_jetdoc(pckg.jetdoc.clone());
+            }
+            if package.location().is_none() {
+                package.set_location(pckg.name.last().map(|name| name.1.clone()));
+            }
+            let scope = self.host.factory().create_package_scope(&package);
+            scope.set_parent_scope(Some(&self.scope));
+            self.ast_to_symbol.set(&pckg.block, Some(scope));
+        }
+
+        let signature = self.host.factory().create_function_type(vec![], self.host.void_type());
+        let function = self.host.factory().create_function(String::new(), &signature);
+        let activation = self.host.factory().create_activation_scope(&function);
+        activation.set_parent_scope(Some(&self.scope));
+        function.set_activation_scope(Some(activation.clone()));
+        self.ast_to_symbol.set(program, Some(activation));
+    }
+
+    fn verify_program_directives(&mut self, program: &Rc<Program>, phase: VerifierPhase) {
+        for pckg in &program.packages {
+            let scope = self.ast_to_symbol.get(&pckg.block).unwrap();
+            self.enter_scope(&scope);
+            self.verify_directives(&pckg.block.directives, phase);
+            self.exit_scope();
+        }
+        let activation = self.ast_to_symbol.get(program).unwrap();
+        self.enter_scope(&activation);
+        self.verify_directives(&program.directives, phase);
+        self.exit_scope();
+    }
+
+    /// Verifies a list of directives at a given phase. Directives that
+    /// throw `DeferVerificationError` are queued in `deferred_directives`
+    /// together with the current scope and are skipped until they
+    /// are resolved.
+    pub fn verify_directives(&mut self, list: &[Rc<Directive>], phase: VerifierPhase) {
+        for drtv in list {
+            if self.is_deferred_directive(drtv) {
+                continue;
+            }
+            if self.verify_directive(drtv, phase).is_err() {
+                self.deferred_counter += 1;
+                self.deferred_directives.push((phase, self.scope.clone(), drtv.clone()));
+            }
+        }
+    }
+
+    pub fn verify_directive(&mut self, drtv: &Rc<Directive>, phase: VerifierPhase) -> Result<(), DeferVerificationError> {
+        match drtv.as_ref() {
+            Directive::Block(block) => {
+                let scope = self.node_scope(drtv);
+                self.enter_scope(&scope);
+                self.verify_directives(&block.directives, phase);
+                self.exit_scope();
+            },
+            Directive::ExpressionStatement(expstmt) => {
+                if phase == VerifierPhase::Bodies {
+                    self.verify_expression(&expstmt.expression, &default())?;
+                }
+            },
+            Directive::IfStatement(ifstmt) => {
+                ifstmt.verify(self, phase)?;
+            },
+            Directive::SwitchStatement(swstmt) => {
+                swstmt.verify(self, drtv, phase)?;
+            },
+            Directive::SwitchTypeStatement(swstmt) => {
+                swstmt.verify(self, phase)?;
+            },
+            Directive::DoStatement(dostmt) => {
+                dostmt.verify(self, phase)?;
+            },
+            Directive::WhileStatement(whilestmt) => {
+                whilestmt.verify(self, phase)?;
+            },
+            Directive::ForStatement(forstmt) => {
+                forstmt.verify(self, phase)?;
+            },
+            Directive::ForInStatement(forstmt) => {
+                forstmt.verify(self, phase)?;
+            },
+            Directive::LabeledStatement(lstmt) => {
+                self.verify_directives(std::slice::from_ref(&lstmt.substatement), phase);
+            },
+            Directive::WithStatement(withstmt) => {
+                withstmt.verify(self, drtv, phase)?;
+            },
+            Directive::TryStatement(trystmt) => {
+                trystmt.verify(self, phase)?;
+            },
+            Directive::ThrowStatement(throwstmt) => {
+                if phase == VerifierPhase::Bodies {
+                    self.verify_expression(&throwstmt.expression, &default())?;
+                }
+            },
+            Directive::SuperStatement(superstmt) => {
+                if phase == VerifierPhase::Bodies {
+                    superstmt.verify(self)?;
+                }
+            },
+            Directive::DefaultXmlNamespaceStatement(dxns) => {
+                if phase == VerifierPhase::Bodies {
+                    dxns.verify(self)?;
+                }
+            },
+            Directive::ReturnStatement(retstmt) => {
+                if phase == VerifierPhase::Bodies {
+                    retstmt.verify(self)?;
+                }
+            },
+            Directive::VariableDefinition(defn) => {
+                defn.verify(self, phase)?;
+            },
+            Directive::FunctionDefinition(defn) => {
+                defn.verify(self, drtv, phase)?;
+            },
+            Directive::ClassDefinition(defn) => {
+                defn.verify(self, drtv, phase)?;
+            },
+            Directive::EnumDefinition(defn) => {
+                defn.verify(self, drtv, phase)?;
+            },
+            Directive::InterfaceDefinition(defn) => {
+                defn.verify(self, drtv, phase)?;
+            },
+            Directive::TypeDefinition(defn) => {
+                defn.verify(self, drtv, phase)?;
+            },
+            Directive::ImportDirective(impdrtv) => {
+                impdrtv.verify(self, phase);
+            },
+            Directive::UseDirective(usedrtv) => {
+                usedrtv.verify(self, drtv, phase);
+            },
+            // The directives of the selected block belong to the enclosing scope.
+            Directive::ConfigurationDirective(cfg) => {
+                if let Some(block) = self.select_configuration_block(drtv, cfg) {
+                    if let Directive::Block(block) = block.as_ref() {
+                        self.verify_directives(&block.directives, phase);
+                    }
+                }
+            },
+            _ => {},
+        }
+        Ok(())
+    }
+
+    /// Returns the scope mapped from a block or another node
+    /// that introduces a scope, creating it if necessary.
+    pub(crate) fn node_scope<T>(&mut self, node: &Rc<T>) -> Symbol where AstToSymbol: AstToSymbolAccessor<T> {
+        if let Some(scope) = self.ast_to_symbol.get(node) {
+            return scope;
+        }
+        let scope = self.host.factory().create_scope();
+        self.ast_to_symbol.set(node, Some(scope.clone()));
+        scope
+    }
+
+    /// Verifies a block that is not a directive, such as
+    /// the block of a `try` statement, within its own scope.
+    pub(crate) fn verify_block(&mut self, block: &Rc<Block>, phase: VerifierPhase) {
+        let scope = self.node_scope(block);
+        self.enter_scope(&scope);
+        self.verify_directives(&block.directives, phase);
+        self.exit_scope();
+    }
+
+    /// Evaluates a configuration directive once, reporting
+    /// unknown configuration constants.
+    fn select_configuration_block(&mut self, drtv: &Rc<Directive>, cfg: &ConfigurationDirective) -> Option<Rc<Directive>> {
+        if let Some(block) = self.configuration_blocks.get(&AstAsKey(drtv.clone())) {
+            return block.clone();
+        }
+        let mut log = vec![];
+        let block = ConfigurationEvaluator(&self.host).select(cfg, &mut log);
+        for UnknownConfigurationConstant { name, location } in log {
+            self.add_warning(&location, DiagnosticKind::UnknownConfigurationConstant, diagnostic_arguments![String(name)]);
+        }
+        self.configuration_blocks.insert(AstAsKey(drtv.clone()), block.clone());
+        block
+    }
+
+    fn is_deferred_directive(&self, drtv: &Rc<Directive>) -> bool {
+        self.deferred_directives.iter().any(|(_, _, drtv_1)| Rc::ptr_eq(drtv, drtv_1))
+    }
+
+    /// Retries deferred directives up to the given phase until no further
+    /// progress is made. A directive that succeeds at its deferred phase is
+    /// carried through the subsequent phases up to `phase`.
+    ///
+    /// Returns `true` if any deferred directive made progress.
+    fn verify_deferred_directives(&mut self, phase: VerifierPhase) -> bool {
+        let mut progressed = false;
+        loop {
+            let mut progressed_1 = false;
+            for (drtv_phase, scope, drtv) in self.deferred_directives.clone() {
+                // The directive may have been resolved by an enclosing directive.
+                let Some(i) = self.deferred_directives.iter().position(|(_, _, drtv_1)| Rc::ptr_eq(&drtv, drtv_1)) else {
+                    continue;
+                };
+                if drtv_phase > phase {
+                    continue;
+                }
+                self.deferred_directives.remove(i);
+
+                let k = self.scope.clone();
+                self.scope = scope.clone();
+                let mut failed_phase: Option<VerifierPhase> = None;
+                for phase_1 in VerifierPhase::LIST {
+                    if phase_1 < drtv_phase || phase_1 > phase {
+                        continue;
+                    }
+                    if self.verify_directive(&drtv, phase_1).is_err() {
+                        failed_phase = Some(phase_1);
+                        break;
+                    }
+                }
+                self.scope = k;
+
+                if let Some(failed_phase) = failed_phase {
+                    self.deferred_counter += 1;
+                    if failed_phase > drtv_phase {
+                        progressed_1 = true;
+                    }
+                    self.deferred_directives.push((failed_phase, scope, drtv));
+                } else {
+                    progressed_1 = true;
+                }
+            }
+            if !progressed_1 {
+                break;
+            }
+            progressed = true;
+        }
+        progressed
+    }
+
+    /// Queues a function body for verification after the directives
+    /// enclosing it. `scope` is the activation scope of the function.
+    pub fn defer_function_common(&mut self, phase: VerifierPhase, scope: &Symbol, common: &Rc<FunctionCommon>) {
+        if self.deferred_function_commons.iter().any(|(_, _, common_1)| Rc::ptr_eq(common, common_1)) {
+            return;
+        }
+        self.deferred_function_commons.push((phase, scope.clone(), common.clone()));
+    }
+
+    /// Verifies queued function bodies until no further progress is made.
+    ///
+    /// Returns `true` if any function body was verified.
+    fn verify_deferred_function_commons(&mut self) -> bool {
+        let mut progressed = false;
+        loop {
+            let mut progressed_1 = false;
+            for (common_phase, scope, common) in self.deferred_function_commons.clone() {
+                let Some(i) = self.deferred_function_commons.iter().position(|(_, _, common_1)| Rc::ptr_eq(&common, common_1)) else {
+                    continue;
+                };
+                self.deferred_function_commons.remove(i);
+
+                let k = self.scope.clone();
+                self.scope = scope.clone();
+                let result = self.verify_function_body(&common);
+                self.scope = k;
+
+                if result.is_err() {
+                    self.deferred_counter += 1;
+                    self.deferred_function_commons.push((common_phase, scope, common));
+                } else {
+                    progressed_1 = true;
+                }
+            }
+            if !progressed_1 {
+                break;
+            }
+            progressed = true;
+        }
+        progressed
+    }
+
+    fn verify_function_body(&mut self, common: &Rc<FunctionCommon>) -> Result<(), DeferVerificationError> {
+        let function = self.ast_to_symbol.get(common).unwrap();
+        let signature = function.signature(&self.host);
+        self.verifying_parameter_defaults = true;
+        let result = self.verify_parameter_defaults(common, &signature);
+        self.verifying_parameter_defaults = false;
+        result?;
+        if function.is_generator() {
+            self.verify_generator_result_type(common, &signature)?;
+        }
+        match &common.body {
+            Some(FunctionBody::Expression(exp)) => {
+                let result_type = signature.result_type();
+                if function.is_generator() || function.is_async() || result_type.is_any_type() || result_type.is_void_type() {
+                    self.verify_expression(exp, &default())?;
+                } else {
+                    self.limit_expression_type(exp, &result_type)?;
+                }
+            },
+            Some(FunctionBody::Block(block)) => {
+                for phase in VerifierPhase::LIST {
+                    self.verify_directives(&block.directives, phase);
+                }
+                self.verify_control_flow(&function, &signature, common, block);
+            },
+            None => {},
+        }
+        Ok(())
+    }
+
+    fn verify_parameter_defaults(&mut self, common: &Rc<FunctionCommon>, signature: &Symbol) -> Result<(), DeferVerificationError> {
+        for (parameter, parameter_1) in common.signature.parameters.iter().zip(signature.parameters().iter()) {
+            if let Some(default_value) = &parameter.default_value {
+                self.limit_expression_type(default_value, &parameter_1.static_type)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Reports a generator whose declared result type is
+    /// neither `*` nor an `Iterator`.
+    fn verify_generator_result_type(&mut self, common: &Rc<FunctionCommon>, signature: &Symbol) -> Result<(), DeferVerificationError> {
+        let result_type = signature.result_type();
+        if result_type.is_any_type() {
+            return Ok(());
+        }
+        let iterator_type = self.host.iterator_type();
+        iterator_type.throw_if_unresolved()?;
+        let origin = if result_type.is_type_after_explicit_type_substitution() { result_type.origin() } else { result_type.clone() };
+        if !origin.is_equals_or_subtype_of(&iterator_type, &self.host) {
+            let location = common.signature.result_type.as_ref().map_or(common.signature.location.clone(), |t| t.location());
+            self.add_verify_error(&location, DiagnosticKind::GeneratorResultTypeMustBeIterator, diagnostic_arguments![Symbol(result_type)]);
+        }
+        Ok(())
+    }
+
+    /// Reports unreachable statements, reads of local variables before
+    /// definite assignment and paths falling off the end of a function
+    /// that must return a value.
+    fn verify_control_flow(&mut self, function: &Symbol, signature: &Symbol, common: &Rc<FunctionCommon>, block: &Block) {
+        let mut log = vec![];
+        let graph = ControlFlowGraph::build(&self.host, &block.directives, &mut log);
+        graph.verify_definite_assignment(&mut log);
+        for issue in log {
+            match issue {
+                ControlFlowIssue::UnreachableCode(location) => {
+                    self.add_warning(&location, DiagnosticKind::UnreachableCode, diagnostic_arguments![]);
+                },
+                ControlFlowIssue::UseBeforeAssignment { name, location } => {
+                    self.add_verify_error(&location, DiagnosticKind::VariableUsedBeforeAssignment, diagnostic_arguments![String(name)]);
+                },
+            }
+        }
+        let result_type = signature.result_type();
+        if graph.falls_off_end() && !(function.is_generator() || function.is_async() || result_type.is_any_type() || result_type.is_void_type()) {
+            self.add_verify_error(&common.location, DiagnosticKind::NotAllPathsReturnValue, diagnostic_arguments![Symbol(result_type)]);
+        }
+    }
+
+    /// Verifies the remaining function bodies and deferred directives
+    /// until a fixed point is reached, then reports anything
+    /// that is still deferred as an unresolved reference.
+    fn finish_deferred_verification(&mut self) {
+        loop {
+            let progressed_1 = self.verify_deferred_function_commons();
+            let progressed_2 = self.verify_deferred_directives(VerifierPhase::Bodies);
+            if !(progressed_1 || progressed_2) {
+                break;
+            }
+        }
+
+        for (_, _, drtv) in std::mem::take(&mut self.deferred_directives) {
+            self.add_verify_error(&drtv.location(), DiagnosticKind::UnresolvedReference, diagnostic_arguments![]);
+        }
+        for (_, _, common) in std::mem::take(&mut self.deferred_function_commons) {
+            self.add_verify_error(&common.location, DiagnosticKind::UnresolvedReference, diagnostic_arguments![]);
+        }
+    }
+
+    /// Returns the definition that receives a definition declared
+    /// in the current scope, together with the properties
+    /// it is declared into.
+    pub fn definition_destination(&self, is_static: bool) -> (Symbol, SharedMap<String, Symbol>) {
+        let scope = &self.scope;
+        if scope.is_package_scope() {
+            let package = scope.package();
+            let properties = package.properties(&self.host);
+            (package, properties)
+        } else if scope.is_class_scope() || scope.is_enum_scope() {
+            let class = scope.class();
+            let properties = if is_static { class.static_properties(&self.host) } else { class.prototype(&self.host) };
+            (class, properties)
+        } else if scope.is_interface_scope() {
+            let interface = scope.interface();
+            let properties = interface.prototype(&self.host);
+            (interface, properties)
+        } else {
+            (scope.clone(), scope.properties(&self.host))
+        }
+    }
+
+    /// Looks up a package by its fully qualified name, reporting
+    /// an error if it is not defined.
+    pub(crate) fn resolve_package_name(&mut self, name: &[(String, Location)]) -> Option<Symbol> {
+        let mut package = self.host.top_level_package();
+        for (name_1, _) in name {
+            let Some(package_1) = package.subpackages().get(name_1) else {
+                let location = name.first().unwrap().1.combine_with(name.last().unwrap().1.clone());
+                let name = name.iter().map(|name| name.0.clone()).collect::<Vec<_>>().join(".");
+                self.add_verify_error(&location, DiagnosticKind::UndefinedPackage, diagnostic_arguments![String(name)]);
+                return None;
+            };
+            package = package_1;
+        }
+        Some(package)
+    }
+
+    /// Looks up a definition of a package, including definitions
+    /// of the packages it redirects to through `public use`,
+    /// reporting an error if it is not defined.
+    pub(crate) fn resolve_package_property(&mut self, package: &Symbol, name: &(String, Location)) -> Option<Symbol> {
+        let r = Self::find_package_property(&self.host, package, &name.0, &mut vec![]);
+        if r.is_none() {
+            self.add_verify_error(&name.1, DiagnosticKind::UndefinedProperty, diagnostic_arguments![String(name.0.clone())]);
+        }
+        r
+    }
+
+    fn find_package_property(host: &SymbolHost, package: &Symbol, name: &String, visited: &mut Vec<Symbol>) -> Option<Symbol> {
+        if visited.contains(package) {
+            return None;
+        }
+        visited.push(package.clone());
+        if let Some(r) = package.properties(host).get(name) {
+            return Some(r);
+        }
+        package.redirect_packages().iter().find_map(|p| Self::find_package_property(host, &p, name, visited))
+    }
+
     pub fn verify_type_expression(&mut self, exp: &Rc<Expression>) -> Result<Option<Symbol>, DeferVerificationError> {
         let v = self.verify_expression(exp, &ExpressionVerifyContext { ..default() })?;
         if v.is_none() {
@@ -270,7 +831,7 @@ impl VerifierVerifier {
         let got_type = v.static_type(&self.host);
         let v = TypeConversions(&self.host).implicit_conversion(&v, limit_type, false);
         if v.is_none() {
-            self.add_verify_error(&exp.location(), DiagnosticKind::IncompatibleTypes, diagnostic_arguments![Symbol(got_type), Symbol(limit_type.clone())]);
+            self.add_verify_error(&exp.location(), DiagnosticKind::IncompatibleTypes, diagnostic_arguments![Symbol(limit_type.clone()), Symbol(got_type)]);
             self.ast_to_symbol.set(exp, None);
             return Ok(None);
         }
@@ -278,6 +839,48 @@ impl VerifierVerifier {
         self.ast_to_symbol.set(exp, Some(v.clone()));
         Ok(Some(v))
     }
+
+    /// Verifies the test of a statement. The test must be
+    /// convertible to `Boolean`, or else be of a nullable type
+    /// or of the `*` type, which are tested for truthiness.
+    pub fn verify_condition(&mut self, exp: &Rc<Expression>) -> Result<Option<Symbol>, DeferVerificationError> {
+        let Some(v) = self.verify_expression(exp, &default())? else {
+            return Ok(None);
+        };
+        let t = v.static_type(&self.host);
+        t.throw_if_unresolved()?;
+        let boolean_type = self.host.boolean_type();
+        boolean_type.throw_if_unresolved()?;
+        if t.is_any_type() || t.is_nullable_type() || TypeConversions(&self.host).implicit_conversion(&v, &boolean_type, false).is_some() {
+            return Ok(Some(v));
+        }
+        self.add_verify_error(&exp.location(), DiagnosticKind::IncompatibleTypes, diagnostic_arguments![Symbol(boolean_type), Symbol(t)]);
+        Ok(None)
+    }
+}
+
+/// Phase of directive verification. Directives are verified once per
+/// phase, in order, so that definitions may refer to each other
+/// regardless of the order in which they appear.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum VerifierPhase {
+    /// Definitions are declared in their enclosing scope.
+    Declaration,
+    /// Inherited types of classes, enumerations and interfaces are resolved.
+    Heritage,
+    /// Variable types and function signatures are resolved.
+    Signatures,
+    /// Remaining directives and statements are verified.
+    Bodies,
+}
+
+impl VerifierPhase {
+    pub const LIST: [VerifierPhase; 4] = [
+        VerifierPhase::Declaration,
+        VerifierPhase::Heritage,
+        VerifierPhase::Signatures,
+        VerifierPhase::Bodies,
+    ];
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -304,4 +907,60 @@ impl Default for ExpressionVerifyContext {
             preceded_by_negative: false,
         }
     }
+}
+
+/// Declares public classes of the given names in the `jet.lang` package,
+/// standing in for the built-in classes that tests depend on.
+#[cfg(test)]
+pub(crate) fn declare_jet_lang_classes(host: &Rc<SymbolHost>, names: &[&str]) {
+    let jet_lang = host.jet_lang_package();
+    for name in names {
+        let t = host.factory().create_class_type((*name).to_owned());
+        t.set_parent_definition(Some(&jet_lang));
+        t.set_visibility(Visibility::Public);
+        jet_lang.properties(host).set((*name).to_owned(), t);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ns::*;
+
+    #[test]
+    fn expression_kinds() {
+        let host = SymbolHost::new("");
+        declare_jet_lang_classes(&host, &["Object", "Boolean", "String", "Number", "Function", "Class", "Array"]);
+
+        let source = r#"
+            package p {
+                public class B {
+                    public var v: Number = 0;
+                }
+                public class C extends B {
+                    public function f(): Number {
+                        return super.v;
+                    }
+                }
+                public function g(c: ?C): ?Number {
+                    const n: Number = (c, 10);
+                    const e = import.meta.env;
+                    const k: Number = c?.v;
+                    return c?.v;
+                }
+                public function h(): void {
+                    super.v;
+                }
+            }
+        "#;
+        let compilation_unit = CompilationUnit::new(None, source.to_owned(), &CompilerOptions::new());
+        let program = ParserFacade::parse_program(&compilation_unit, &host).unwrap();
+        let mut verifier = Verifier::new(&host);
+        verifier.verify_programs(vec![program.clone()]);
+
+        let diagnostics = compilation_unit.diagnostics().iter().map(|d| (d.id(), d.location().first_line_number())).collect::<Vec<_>>();
+        assert_eq!(diagnostics, [
+            (DiagnosticKind::IncompatibleTypes.id(), 14),
+            (DiagnosticKind::IllegalSuperExpression.id(), 18),
+        ]);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,136 @@
+use crate::ns::*;
+
+/// Evaluates configuration directives, as in:
+///
+/// ```jet
+/// configuration {
+///     if (air::target=ios) {
+///         // Directives
+///     } else {
+///         // Directives
+///     }
+/// }
+/// ```
+///
+/// Constants are looked up in the `CompilerOptions` of the directive's
+/// compilation unit and then in the JetPM constants of the host.
+/// A constant tested without a value, as in `if (debug)`, is true if its
+/// value is `"true"`. Unknown constants have no value and are reported
+/// in the log of the evaluation.
+pub struct ConfigurationEvaluator<'a>(pub &'a SymbolHost);
+
+/// Unknown constant found while evaluating a configuration test.
+pub struct UnknownConfigurationConstant {
+    pub name: String,
+    pub location: Location,
+}
+
+impl<'a> ConfigurationEvaluator<'a> {
+    /// Returns the value of a configuration constant.
+    pub fn constant(&self, compiler_options: &CompilerOptions, name: &str) -> Option<String> {
+        compiler_options.configuration_constants.get(name).cloned().or_else(|| self.0.jetpm_constants().get(&name.to_owned()))
+    }
+
+    /// Selects the block of a configuration directive, or `None` if no
+    /// branch is selected.
+    pub fn select(&self, drtv: &ConfigurationDirective, log: &mut Vec<UnknownConfigurationConstant>) -> Option<Rc<Directive>> {
+        self.select_subdirective(&drtv.directive, log)
+    }
+
+    fn select_subdirective(&self, drtv: &Rc<Directive>, log: &mut Vec<UnknownConfigurationConstant>) -> Option<Rc<Directive>> {
+        match drtv.as_ref() {
+            Directive::IfStatement(IfStatement { test, consequent, alternative, .. }) => {
+                if self.evaluate(test, log) {
+                    Some(consequent.clone())
+                } else {
+                    alternative.as_ref().and_then(|alternative| self.select_subdirective(alternative, log))
+                }
+            },
+            _ => Some(drtv.clone()),
+        }
+    }
+
+    /// Evaluates a configuration test.
+    pub fn evaluate(&self, exp: &Rc<Expression>, log: &mut Vec<UnknownConfigurationConstant>) -> bool {
+        match exp.as_ref() {
+            Expression::QualifiedIdentifier(_) => {
+                self.constant_at(exp, log).is_some_and(|value| value == "true")
+            },
+            Expression::Binary(BinaryExpression { operator, left, right, .. }) => {
+                // Both operands are evaluated so that every unknown
+                // constant is logged.
+                match operator {
+                    Operator::LogicalAnd => self.evaluate(left, log) & self.evaluate(right, log),
+                    Operator::LogicalOr => self.evaluate(left, log) | self.evaluate(right, log),
+                    Operator::Equals | Operator::NotEquals => {
+                        let Expression::StringLiteral(StringLiteral { value, .. }) = right.as_ref() else {
+                            return false;
+                        };
+                        let equals = self.constant_at(left, log).is_some_and(|value_1| &value_1 == value);
+                        if *operator == Operator::Equals { equals } else { !equals }
+                    },
+                    _ => false,
+                }
+            },
+            Expression::Unary(UnaryExpression { operator: Operator::LogicalNot, expression, .. }) => {
+                !self.evaluate(expression, log)
+            },
+            Expression::Paren(ParenExpression { expression, .. }) => self.evaluate(expression, log),
+            _ => false,
+        }
+    }
+
+    fn constant_at(&self, exp: &Rc<Expression>, log: &mut Vec<UnknownConfigurationConstant>) -> Option<String> {
+        let (name, location) = exp.to_identifier_name()?;
+        let value = self.constant(&location.compilation_unit().compiler_options, &name);
+        if value.is_none() {
+            log.push(UnknownConfigurationConstant { name, location });
+        }
+        value
+    }
+
+    /// Replaces configuration directives in a list by the directives
+    /// of their selected blocks, ignoring unknown constants.
+    pub fn expand_directives(&self, list: &[Rc<Directive>]) -> Vec<Rc<Directive>> {
+        let mut result = vec![];
+        for drtv in list {
+            if let Directive::ConfigurationDirective(cfg) = drtv.as_ref() {
+                if let Some(block) = self.select(cfg, &mut vec![]) {
+                    if let Directive::Block(block) = block.as_ref() {
+                        result.extend(self.expand_directives(&block.directives));
+                    }
+                }
+            } else {
+                result.push(drtv.clone());
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evaluation() {
+        let host = SymbolHost::new("");
+        let compiler_options = Rc::new(CompilerOptions {
+            configuration_constants: HashMap::from([("air::target".into(), "ios".into()), ("debug".into(), "true".into())]),
+            ..default()
+        });
+        let compilation_unit = CompilationUnit::new(None, "configuration { if (air::target=android || !debug && air::unknown!=x) {} else if ((debug)) { f() } }".into(), &compiler_options);
+        let program = ParserFacade::parse_program(&compilation_unit, &host).unwrap();
+        let Directive::ConfigurationDirective(cfg) = program.directives[0].as_ref() else {
+            panic!();
+        };
+        let mut log = vec![];
+        let block = ConfigurationEvaluator(&host).select(cfg, &mut log).unwrap();
+        assert!(matches!(block.as_ref(), Directive::Block(Block { directives, .. }) if directives.len() == 1));
+        assert_eq!(log.iter().map(|c| c.name.clone()).collect::<Vec<_>>(), vec!["air::unknown".to_owned()]);
+
+        Verifier::new(&host).verify_programs(vec![program]);
+        let diagnostics = compilation_unit.diagnostics();
+        assert!(diagnostics.iter().any(|d| d.is_warning() && d.kind() == DiagnosticKind::UnknownConfigurationConstant));
+    }
+}
@@ -0,0 +1,274 @@
+use crate::ns::*;
+
+/// Evaluates operations over compile-time constants.
+///
+/// Operands are the symbols of verified expressions, with numeric
+/// operands already converted to a common type. An operation whose
+/// operands are constants results into a constant of the operation's
+/// result type; otherwise the evaluation results into `None`.
+///
+/// The verifier folds constant expressions through the evaluator,
+/// and backends use `ConstantEvaluator::evaluate` to obtain the
+/// constant denoted by a verified expression:
+///
+/// ```ignore
+/// if let Some(constant) = ConstantEvaluator(&host).evaluate(&exp, &ast_to_symbol) {
+///     // Emit constant
+/// }
+/// ```
+pub struct ConstantEvaluator<'a>(pub &'a SymbolHost);
+
+/// Error resulting from a constant evaluation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConstantEvaluationError {
+    /// An operation over `Long` constants exceeds the range of `Long`.
+    LongOverflow,
+}
+
+impl<'a> ConstantEvaluator<'a> {
+    /// Returns the constant denoted by a verified expression, if any.
+    pub fn evaluate(&self, exp: &Rc<Expression>, ast_to_symbol: &AstToSymbol) -> Option<Symbol> {
+        self.constant_of(&ast_to_symbol.get(exp)?)
+    }
+
+    /// Returns the constant denoted by a value: either the value itself
+    /// or the constant initializer of a referenced read-only variable.
+    pub fn constant_of(&self, value: &Symbol) -> Option<Symbol> {
+        if value.is_constant() {
+            return Some(value.clone());
+        }
+        if value.is_reference_value() && (value.is_static_reference_value() || value.is_instance_reference_value() || value.is_scope_reference_value() || value.is_package_reference_value()) {
+            let p = value.property();
+            if p.is_origin_variable_property() && p.read_only(self.0) {
+                return p.constant_initializer();
+            }
+        }
+        None
+    }
+
+    /// Evaluates the positive, negative, bitwise NOT and logical NOT operators.
+    pub fn unary(&self, operator: Operator, operand: &Symbol) -> Result<Option<Symbol>, ConstantEvaluationError> {
+        let host = self.0;
+        match operator {
+            Operator::LogicalNot if operand.is_boolean_constant() => {
+                Ok(Some(host.factory().create_boolean_constant(!operand.boolean_value(), &host.boolean_type())))
+            },
+            Operator::Positive | Operator::Negative | Operator::BitwiseNot if operand.is_number_constant() => {
+                let n = operand.number_value();
+                let n = match (operator, n) {
+                    (Operator::Positive, n) => n,
+                    (Operator::Negative, AbstractRangeNumber::Long(v)) => {
+                        AbstractRangeNumber::Long(v.checked_neg().ok_or(ConstantEvaluationError::LongOverflow)?)
+                    },
+                    (Operator::Negative, n) => -n,
+                    (_, n) => !n,
+                };
+                Ok(Some(host.factory().create_number_constant(n, &operand.static_type(host))))
+            },
+            _ => Ok(None),
+        }
+    }
+
+    /// Evaluates a binary operator: arithmetic, bitwise, shift, relational,
+    /// equality and logical operators, string concatenation and set
+    /// enumeration operations.
+    pub fn binary(&self, operator: Operator, left: &Symbol, right: &Symbol) -> Result<Option<Symbol>, ConstantEvaluationError> {
+        let host = self.0;
+        match operator {
+            Operator::Equals | Operator::StrictEquals |
+            Operator::NotEquals | Operator::StrictNotEquals => {
+                let Some(equals) = ConstantEvaluator::equals(left, right) else {
+                    return Ok(None);
+                };
+                let v = if [Operator::Equals, Operator::StrictEquals].contains(&operator) { equals } else { !equals };
+                Ok(Some(host.factory().create_boolean_constant(v, &host.boolean_type())))
+            },
+            Operator::Lt | Operator::Gt | Operator::Le | Operator::Ge => {
+                let ordering = if left.is_number_constant() && right.is_number_constant() {
+                    // NaN is unordered and compares false.
+                    let Some(o) = left.number_value().partial_cmp(&right.number_value()) else {
+                        return Ok(Some(host.factory().create_boolean_constant(false, &host.boolean_type())));
+                    };
+                    o
+                } else if left.is_string_constant() && right.is_string_constant() {
+                    left.string_value().cmp(&right.string_value())
+                } else if left.is_char_constant() && right.is_char_constant() {
+                    left.char_value().cmp(&right.char_value())
+                } else {
+                    return Ok(None);
+                };
+                let v = match operator {
+                    Operator::Lt => ordering.is_lt(),
+                    Operator::Gt => ordering.is_gt(),
+                    Operator::Le => ordering.is_le(),
+                    _ => ordering.is_ge(),
+                };
+                Ok(Some(host.factory().create_boolean_constant(v, &host.boolean_type())))
+            },
+            Operator::LogicalAnd | Operator::LogicalOr | Operator::LogicalXor => {
+                if !(left.is_boolean_constant() && right.is_boolean_constant()) {
+                    return Ok(None);
+                }
+                let (l, r) = (left.boolean_value(), right.boolean_value());
+                let v = match operator {
+                    Operator::LogicalAnd => l && r,
+                    Operator::LogicalOr => l || r,
+                    _ => l != r,
+                };
+                Ok(Some(host.factory().create_boolean_constant(v, &host.boolean_type())))
+            },
+            Operator::Add if left.is_string_constant() || right.is_string_constant() => {
+                let (Some(l), Some(r)) = (ConstantEvaluator::string_value(left), ConstantEvaluator::string_value(right)) else {
+                    return Ok(None);
+                };
+                Ok(Some(host.factory().create_string_constant(l + &r, &host.string_type())))
+            },
+            _ => {
+                if left.is_number_constant() && right.is_number_constant() {
+                    let Some(v) = ConstantEvaluator::fold_numbers(operator, left.number_value(), right.number_value())? else {
+                        return Ok(None);
+                    };
+                    return Ok(Some(host.factory().create_number_constant(v, &left.static_type(host))));
+                }
+                let set_operator = [Operator::BitwiseAnd, Operator::BitwiseXor, Operator::BitwiseOr].contains(&operator);
+                if set_operator && left.is_enum_constant() && right.is_enum_constant() {
+                    let Some(v) = ConstantEvaluator::fold_numbers(operator, left.number_value(), right.number_value())? else {
+                        return Ok(None);
+                    };
+                    return Ok(Some(host.factory().create_enum_constant(v, &left.static_type(host))));
+                }
+                Ok(None)
+            },
+        }
+    }
+
+    /// Evaluates the conditional operator, whose branches have
+    /// already been converted to the result type.
+    pub fn conditional(&self, test: &Symbol, consequent: &Symbol, alternative: &Symbol) -> Option<Symbol> {
+        if !test.is_boolean_constant() {
+            return None;
+        }
+        let v = if test.boolean_value() { consequent } else { alternative };
+        v.is_constant().then(|| v.clone())
+    }
+
+    /// Compares two constants of the same kind.
+    pub fn equals(left: &Symbol, right: &Symbol) -> Option<bool> {
+        if (left.is_number_constant() && right.is_number_constant()) || (left.is_enum_constant() && right.is_enum_constant()) {
+            Some(left.number_value() == right.number_value())
+        } else if left.is_string_constant() && right.is_string_constant() {
+            Some(left.string_value() == right.string_value())
+        } else if left.is_char_constant() && right.is_char_constant() {
+            Some(left.char_value() == right.char_value())
+        } else if left.is_boolean_constant() && right.is_boolean_constant() {
+            Some(left.boolean_value() == right.boolean_value())
+        } else if (left.is_null_constant() || left.is_undefined_constant()) && (right.is_null_constant() || right.is_undefined_constant()) {
+            Some(left.is_null_constant() == right.is_null_constant())
+        } else {
+            None
+        }
+    }
+
+    fn string_value(value: &Symbol) -> Option<String> {
+        if value.is_string_constant() {
+            Some(value.string_value())
+        } else if value.is_char_constant() {
+            Some(value.char_value().to_string())
+        } else {
+            None
+        }
+    }
+
+    fn fold_numbers(operator: Operator, left: AbstractRangeNumber, right: AbstractRangeNumber) -> Result<Option<AbstractRangeNumber>, ConstantEvaluationError> {
+        if let (AbstractRangeNumber::Long(l), AbstractRangeNumber::Long(r)) = (&left, &right) {
+            let (l, r) = (*l, *r);
+            let checked = match operator {
+                Operator::Add => Some(l.checked_add(r)),
+                Operator::Subtract => Some(l.checked_sub(r)),
+                Operator::Multiply => Some(l.checked_mul(r)),
+                Operator::Divide if r != 0 => Some(l.checked_div(r)),
+                Operator::Remainder if r != 0 => Some(l.checked_rem(r)),
+                Operator::Power if r >= 0 => Some(u32::try_from(r).ok().and_then(|r| l.checked_pow(r))),
+                // Negative shift counts and counts of 64 or more overflow.
+                Operator::ShiftLeft => Some(u32::try_from(r).ok().and_then(|r| l.checked_shl(r))),
+                Operator::ShiftRight => Some(u32::try_from(r).ok().and_then(|r| l.checked_shr(r))),
+                Operator::ShiftRightUnsigned => Some(u32::try_from(r).ok().and_then(|r| (l as u64).checked_shr(r)).map(|v| v as i64)),
+                _ => None,
+            };
+            if let Some(v) = checked {
+                return v.map(|v| Some(AbstractRangeNumber::Long(v))).ok_or(ConstantEvaluationError::LongOverflow);
+            }
+        }
+        let integer = matches!(left, AbstractRangeNumber::Long(_) | AbstractRangeNumber::BigInt(_));
+        Ok(match operator {
+            Operator::Add => Some(left + right),
+            Operator::Subtract => Some(left - right),
+            Operator::Multiply => Some(left * right),
+            // Integer division by zero is left to run time.
+            Operator::Divide | Operator::Remainder if integer && right.is_zero() => None,
+            Operator::Divide => Some(left / right),
+            Operator::Remainder => Some(left % right),
+            Operator::Power => Some(left.power(&right)),
+            Operator::ShiftLeft => Some(left << right),
+            Operator::ShiftRight => Some(left >> right),
+            Operator::ShiftRightUnsigned => Some(left.shift_right_unsigned(&right)),
+            Operator::BitwiseAnd => Some(left & right),
+            Operator::BitwiseXor => Some(left ^ right),
+            Operator::BitwiseOr => Some(left | right),
+            _ => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ns::*;
+
+    #[test]
+    fn constant_evaluation() {
+        let host = SymbolHost::new("");
+        declare_jet_lang_classes(&host, &["Object", "Boolean", "String", "Number", "Long", "Function", "Class", "Array"]);
+
+        let source = r#"
+            package p {
+                [Set]
+                public enum E {
+                    const A
+                    const B
+                    const C
+                }
+                public const X: Number = 2 * 3;
+                public const Y: String = X > 5 ? "big" : "small";
+                public const Z: E = E.A | E.C;
+                public const L: Long = 0x7FFF_FFFF_FFFF_FFFF;
+                public const M: Long = L + 1;
+                public const N: Long = L % -1 + (-0x8000_0000_0000_0000 % -1);
+                public const O: Long = 1 << 64;
+                public const Q: Long = L >> -1;
+                public const R: Long = 1 << 62 | -16 >>> 60;
+                public const S: Long = L % 10 + 7 % 0;
+            }
+        "#;
+        let compilation_unit = CompilationUnit::new(None, source.to_owned(), &CompilerOptions::new());
+        let program = ParserFacade::parse_program(&compilation_unit, &host).unwrap();
+        let mut verifier = Verifier::new(&host);
+        verifier.verify_programs(vec![program.clone()]);
+
+        let diagnostics = compilation_unit.diagnostics().iter().map(|d| (d.id(), d.location().first_line_number())).collect::<Vec<_>>();
+        assert_eq!(diagnostics, [
+            (DiagnosticKind::LongConstantOverflow.id(), 13),
+            (DiagnosticKind::LongConstantOverflow.id(), 14),
+            (DiagnosticKind::LongConstantOverflow.id(), 15),
+            (DiagnosticKind::LongConstantOverflow.id(), 16),
+        ]);
+
+        let p = host.factory().create_package(["p"]);
+        let constant = |name: &str| p.properties(&host).get(&name.to_owned()).unwrap().constant_initializer().unwrap();
+        assert!(constant("X").number_value() == AbstractRangeNumber::Number(6.0));
+        assert!(constant("Y").string_value() == "big");
+        assert!(constant("Z").is_enum_constant() && constant("Z").number_value() == AbstractRangeNumber::Number(5.0));
+        assert!(p.properties(&host).get(&"M".to_owned()).unwrap().constant_initializer().is_none());
+        assert!(constant("R").number_value() == AbstractRangeNumber::Long((1 << 62) | 15));
+        assert!(p.properties(&host).get(&"S".to_owned()).unwrap().constant_initializer().is_none());
+    }
+}
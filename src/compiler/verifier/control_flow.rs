@@ -0,0 +1,948 @@
+use crate::ns::*;
+
+/// Control-flow graph of a list of directives, such as the body
+/// of a function.
+///
+/// Statements are split into basic blocks of `ControlFlowItem`s connected
+/// by their successors. Control enters at `ControlFlowGraph::ENTRY` and
+/// reaches `ControlFlowGraph::END` either by a `return` statement or by
+/// falling off the end of the directives; `throw` statements leave
+/// the graph without reaching any block.
+///
+/// Building a graph logs statements that cannot be reached:
+///
+/// ```ignore
+/// let mut log = vec![];
+/// let graph = ControlFlowGraph::build(&host, &block.directives, &mut log);
+/// if graph.falls_off_end() {
+///     // Missing return
+/// }
+/// graph.verify_definite_assignment(&mut log);
+/// ```
+pub struct ControlFlowGraph {
+    blocks: Vec<ControlFlowBlock>,
+    falls_off_end: bool,
+}
+
+/// Basic block of a `ControlFlowGraph`.
+#[derive(Default)]
+pub struct ControlFlowBlock {
+    pub items: Vec<ControlFlowItem>,
+    pub successors: Vec<usize>,
+}
+
+/// Operation performed within a basic block.
+pub enum ControlFlowItem {
+    /// Evaluation of an expression.
+    Expression(Rc<Expression>),
+    /// Declaration of a variable, evaluating its initializer if any.
+    VariableBinding(Rc<VariableBinding>),
+    /// Assignment of a value to a destructuring pattern, as in
+    /// a `for..in` binding or a `catch` parameter.
+    Pattern(Rc<Expression>),
+}
+
+/// Issue found while analysing control flow.
+pub enum ControlFlowIssue {
+    /// Statement that can never be executed.
+    UnreachableCode(Location),
+    /// Read of a local variable that may not have been assigned.
+    UseBeforeAssignment {
+        name: String,
+        location: Location,
+    },
+}
+
+impl ControlFlowGraph {
+    pub const ENTRY: usize = 0;
+    pub const END: usize = 1;
+
+    pub fn build(host: &SymbolHost, directives: &[Rc<Directive>], log: &mut Vec<ControlFlowIssue>) -> Self {
+        let mut builder = ControlFlowBuilder {
+            host,
+            blocks: vec![ControlFlowBlock::default(), ControlFlowBlock::default()],
+            current: Some(Self::ENTRY),
+            jump_targets: vec![],
+            suppress_unreachable: false,
+            log,
+        };
+        builder.build_directives(directives);
+        let last = builder.current;
+        builder.goto(Self::END);
+        let mut graph = Self { blocks: builder.blocks, falls_off_end: false };
+        graph.falls_off_end = last.is_some_and(|last| graph.reachable_blocks()[last]);
+        graph
+    }
+
+    pub fn blocks(&self) -> &[ControlFlowBlock] {
+        &self.blocks
+    }
+
+    /// Indicates whether control may reach the end of the directives
+    /// without a `return` or `throw` statement.
+    pub fn falls_off_end(&self) -> bool {
+        self.falls_off_end
+    }
+
+    /// Returns whether each block is reachable from `ENTRY`.
+    pub fn reachable_blocks(&self) -> Vec<bool> {
+        let mut reachable = vec![false; self.blocks.len()];
+        let mut stack = vec![Self::ENTRY];
+        while let Some(i) = stack.pop() {
+            if reachable[i] {
+                continue;
+            }
+            reachable[i] = true;
+            stack.extend(self.blocks[i].successors.iter().copied());
+        }
+        reachable
+    }
+
+    /// Logs reads of local variables that are not definitely assigned
+    /// on every path reaching them.
+    ///
+    /// Only variables declared exactly once in the graph are tracked,
+    /// so that shadowing declarations in nested blocks do not interfere
+    /// with each other. Nested functions are not analysed, since
+    /// they may be called at any time.
+    pub fn verify_definite_assignment(&self, log: &mut Vec<ControlFlowIssue>) {
+        let mut declarations: Vec<(String, Rc<VariableBinding>)> = vec![];
+        for block in &self.blocks {
+            for item in &block.items {
+                if let ControlFlowItem::VariableBinding(binding) = item {
+                    let mut names = vec![];
+                    binding.destructuring.destructuring.pattern_names(&mut names);
+                    for name in names {
+                        if !declarations.iter().any(|(name_1, binding_1)| *name_1 == name && Rc::ptr_eq(binding, binding_1)) {
+                            declarations.push((name, binding.clone()));
+                        }
+                    }
+                }
+            }
+        }
+        let analysis = DefiniteAssignment {
+            tracked: declarations.iter()
+                .filter(|(name, _)| declarations.iter().filter(|(name_1, _)| name == name_1).count() == 1)
+                .map(|(name, _)| name.clone()).collect(),
+            assigned: default(),
+        };
+        if analysis.tracked.is_empty() {
+            return;
+        }
+
+        // Each entry holds the variables that may be unassigned
+        // on entry to the block, or `None` if the block is not reached.
+        let mut entry_states: Vec<Option<HashSet<String>>> = vec![None; self.blocks.len()];
+        entry_states[Self::ENTRY] = Some(HashSet::new());
+        let mut worklist = vec![Self::ENTRY];
+        let mut reads = vec![];
+        while let Some(i) = worklist.pop() {
+            let mut state = entry_states[i].clone().unwrap();
+            for item in &self.blocks[i].items {
+                analysis.item(item, &mut state, &mut reads);
+            }
+            reads.clear();
+            for &successor in &self.blocks[i].successors {
+                let changed = match &mut entry_states[successor] {
+                    Some(state_1) => {
+                        let len = state_1.len();
+                        state_1.extend(state.iter().cloned());
+                        state_1.len() != len
+                    },
+                    entry_state @ None => {
+                        *entry_state = Some(state.clone());
+                        true
+                    },
+                };
+                if changed && !worklist.contains(&successor) {
+                    worklist.push(successor);
+                }
+            }
+        }
+
+        // Items may be repeated across blocks, as for `finally` clauses,
+        // so each read is reported once.
+        let mut reported: Vec<(String, Location)> = vec![];
+        for (i, block) in self.blocks.iter().enumerate() {
+            let Some(mut state) = entry_states[i].clone() else {
+                continue;
+            };
+            for item in &block.items {
+                analysis.item(item, &mut state, &mut reads);
+            }
+            for read in reads.drain(..) {
+                if !reported.contains(&read) {
+                    reported.push(read);
+                }
+            }
+        }
+        reported.sort_by(|(_, a), (_, b)| a.cmp(b));
+        for (name, location) in reported {
+            log.push(ControlFlowIssue::UseBeforeAssignment { name, location });
+        }
+    }
+
+    /// Returns the names of the variables that the directives and
+    /// expressions may assign on any path, either directly or through
+    /// destructuring, such as the body and update expression of a loop.
+    /// Nested functions are not analysed.
+    pub fn assigned_names(host: &SymbolHost, directives: &[Rc<Directive>], expressions: &[Rc<Expression>]) -> HashSet<String> {
+        let graph = Self::build(host, directives, &mut vec![]);
+        let analysis = DefiniteAssignment {
+            tracked: HashSet::new(),
+            assigned: default(),
+        };
+        let mut state = HashSet::new();
+        for block in &graph.blocks {
+            for item in &block.items {
+                analysis.item(item, &mut state, &mut vec![]);
+            }
+        }
+        for exp in expressions {
+            analysis.expression(exp, &mut state, &mut vec![]);
+        }
+        analysis.assigned.into_inner()
+    }
+}
+
+struct ControlFlowBuilder<'a> {
+    host: &'a SymbolHost,
+    blocks: Vec<ControlFlowBlock>,
+    /// Block receiving the next items, or `None` after
+    /// an unconditional jump.
+    current: Option<usize>,
+    jump_targets: Vec<JumpTarget>,
+    /// Set while building the copy of a `finally` clause that
+    /// runs after abrupt completions, so that its statements
+    /// are not reported twice.
+    suppress_unreachable: bool,
+    log: &'a mut Vec<ControlFlowIssue>,
+}
+
+/// Statement that a `break` or `continue` statement may jump out of.
+struct JumpTarget {
+    labels: Vec<String>,
+    /// Indicates whether an unlabeled `break` targets this statement.
+    breakable: bool,
+    break_block: usize,
+    continue_block: Option<usize>,
+}
+
+impl<'a> ControlFlowBuilder<'a> {
+    fn new_block(&mut self) -> usize {
+        self.blocks.push(ControlFlowBlock::default());
+        self.blocks.len() - 1
+    }
+
+    fn edge(&mut self, from: usize, to: usize) {
+        if !self.blocks[from].successors.contains(&to) {
+            self.blocks[from].successors.push(to);
+        }
+    }
+
+    /// Flows from the current block into `to`, if control
+    /// reaches the current point.
+    fn goto(&mut self, to: usize) {
+        if let Some(from) = self.current {
+            self.edge(from, to);
+        }
+    }
+
+    /// Returns the current block, starting a block without
+    /// predecessors if control does not reach the current point.
+    fn current_block(&mut self) -> usize {
+        if let Some(block) = self.current {
+            return block;
+        }
+        let block = self.new_block();
+        self.current = Some(block);
+        block
+    }
+
+    fn push_item(&mut self, item: ControlFlowItem) {
+        let block = self.current_block();
+        self.blocks[block].items.push(item);
+    }
+
+    fn push_expression(&mut self, exp: &Rc<Expression>) {
+        self.push_item(ControlFlowItem::Expression(exp.clone()));
+    }
+
+    /// Continues at a join block, unless no block flows into it.
+    fn continue_at(&mut self, block: usize) {
+        let reached = self.blocks.iter().any(|block_1| block_1.successors.contains(&block));
+        self.current = if reached { Some(block) } else { None };
+    }
+
+    fn build_directives(&mut self, list: &[Rc<Directive>]) {
+        for drtv in list {
+            if self.current.is_none() && Self::is_statement(drtv) {
+                if !self.suppress_unreachable {
+                    self.log.push(ControlFlowIssue::UnreachableCode(drtv.location()));
+                }
+                self.current_block();
+            }
+            self.build_directive(drtv, vec![]);
+        }
+    }
+
+    /// Indicates whether a directive is executed where it appears,
+    /// unlike definitions and directives such as `import`.
+    fn is_statement(drtv: &Rc<Directive>) -> bool {
+        !matches!(drtv.as_ref(),
+            Directive::EmptyStatement(_) |
+            Directive::ConfigurationDirective(_) |
+            Directive::ImportDirective(_) |
+            Directive::UseDirective(_) |
+            Directive::FunctionDefinition(_) |
+            Directive::ClassDefinition(_) |
+            Directive::EnumDefinition(_) |
+            Directive::InterfaceDefinition(_) |
+            Directive::TypeDefinition(_) |
+            Directive::Invalid(_))
+    }
+
+    /// Indicates whether a loop condition is the `true` literal,
+    /// in which case the loop is only left by jumps.
+    fn is_true_literal(exp: &Rc<Expression>) -> bool {
+        match exp.as_ref() {
+            Expression::BooleanLiteral(literal) => literal.value,
+            Expression::Paren(paren) => Self::is_true_literal(&paren.expression),
+            _ => false,
+        }
+    }
+
+    fn build_directive(&mut self, drtv: &Rc<Directive>, mut labels: Vec<String>) {
+        match drtv.as_ref() {
+            Directive::LabeledStatement(stmt) => {
+                labels.push(stmt.label.0.clone());
+                self.build_directive(&stmt.substatement, labels);
+                return;
+            },
+            Directive::DoStatement(_) |
+            Directive::WhileStatement(_) |
+            Directive::ForStatement(_) |
+            Directive::ForInStatement(_) |
+            Directive::SwitchStatement(_) |
+            Directive::SwitchTypeStatement(_) => {},
+            _ => if !labels.is_empty() {
+                let after = self.new_block();
+                self.jump_targets.push(JumpTarget { labels, breakable: false, break_block: after, continue_block: None });
+                self.build_directive(drtv, vec![]);
+                self.jump_targets.pop();
+                self.goto(after);
+                self.continue_at(after);
+                return;
+            },
+        }
+
+        match drtv.as_ref() {
+            Directive::ExpressionStatement(stmt) => {
+                self.push_expression(&stmt.expression);
+            },
+            Directive::SuperStatement(stmt) => {
+                for argument in &stmt.arguments {
+                    self.push_expression(argument);
+                }
+            },
+            Directive::DefaultXmlNamespaceStatement(stmt) => {
+                self.push_expression(&stmt.right);
+            },
+            Directive::VariableDefinition(defn) => {
+                for binding in &defn.bindings {
+                    self.push_item(ControlFlowItem::VariableBinding(binding.clone()));
+                }
+            },
+            Directive::Block(block) => {
+                self.build_directives(&block.directives);
+            },
+            Directive::ConfigurationDirective(cfg) => {
+                // Unknown constants are reported by the verifier.
+                if let Some(block) = ConfigurationEvaluator(self.host).select(cfg, &mut vec![]) {
+                    if let Directive::Block(block) = block.as_ref() {
+                        self.build_directives(&block.directives);
+                    }
+                }
+            },
+            Directive::WithStatement(stmt) => {
+                self.push_expression(&stmt.object);
+                self.build_directive(&stmt.body, vec![]);
+            },
+            Directive::IfStatement(stmt) => {
+                self.push_expression(&stmt.test);
+                let test = self.current_block();
+                let after = self.new_block();
+
+                let consequent = self.new_block();
+                self.edge(test, consequent);
+                self.current = Some(consequent);
+                self.build_directive(&stmt.consequent, vec![]);
+                self.goto(after);
+
+                if let Some(alternative_drtv) = &stmt.alternative {
+                    let alternative = self.new_block();
+                    self.edge(test, alternative);
+                    self.current = Some(alternative);
+                    self.build_directive(alternative_drtv, vec![]);
+                    self.goto(after);
+                } else {
+                    self.edge(test, after);
+                }
+                self.continue_at(after);
+            },
+            Directive::WhileStatement(stmt) => {
+                let header = self.new_block();
+                self.goto(header);
+                self.current = Some(header);
+                self.push_expression(&stmt.test);
+                let body = self.new_block();
+                let after = self.new_block();
+                self.edge(header, body);
+                if !Self::is_true_literal(&stmt.test) {
+                    self.edge(header, after);
+                }
+                self.build_loop_body(&stmt.body, labels, body, after, header);
+                self.goto(header);
+                self.continue_at(after);
+            },
+            Directive::DoStatement(stmt) => {
+                let body = self.new_block();
+                let test = self.new_block();
+                let after = self.new_block();
+                self.goto(body);
+                self.build_loop_body(&stmt.body, labels, body, after, test);
+                self.goto(test);
+                self.continue_at(test);
+                if self.current.is_some() {
+                    self.push_expression(&stmt.test);
+                    self.edge(test, body);
+                    if !Self::is_true_literal(&stmt.test) {
+                        self.edge(test, after);
+                    }
+                }
+                self.continue_at(after);
+            },
+            Directive::ForStatement(stmt) => {
+                match &stmt.init {
+                    Some(ForInitializer::Expression(exp)) => {
+                        self.push_expression(exp);
+                    },
+                    Some(ForInitializer::VariableDefinition(defn)) => {
+                        for binding in &defn.bindings {
+                            self.push_item(ControlFlowItem::VariableBinding(binding.clone()));
+                        }
+                    },
+                    None => {},
+                }
+                let header = self.new_block();
+                self.goto(header);
+                self.current = Some(header);
+                if let Some(test) = &stmt.test {
+                    self.push_expression(test);
+                }
+                let body = self.new_block();
+                let update = self.new_block();
+                let after = self.new_block();
+                self.edge(header, body);
+                if stmt.test.as_ref().is_some_and(|test| !Self::is_true_literal(test)) {
+                    self.edge(header, after);
+                }
+                self.build_loop_body(&stmt.body, labels, body, after, update);
+                self.goto(update);
+                self.continue_at(update);
+                if self.current.is_some() {
+                    if let Some(exp) = &stmt.update {
+                        self.push_expression(exp);
+                    }
+                    self.edge(update, header);
+                }
+                self.continue_at(after);
+            },
+            Directive::ForInStatement(stmt) => {
+                self.push_expression(&stmt.right);
+                let header = self.new_block();
+                let body = self.new_block();
+                let after = self.new_block();
+                self.goto(header);
+                self.edge(header, body);
+                self.edge(header, after);
+                match &stmt.left {
+                    ForInBinding::Expression(exp) => {
+                        self.blocks[body].items.push(ControlFlowItem::Pattern(exp.clone()));
+                    },
+                    ForInBinding::VariableDefinition(defn) => {
+                        for binding in &defn.bindings {
+                            self.blocks[body].items.push(ControlFlowItem::VariableBinding(binding.clone()));
+                            self.blocks[body].items.push(ControlFlowItem::Pattern(binding.destructuring.destructuring.clone()));
+                        }
+                    },
+                }
+                self.build_loop_body(&stmt.body, labels, body, after, header);
+                self.goto(header);
+                self.continue_at(after);
+            },
+            Directive::SwitchStatement(stmt) => {
+                self.push_expression(&stmt.discriminant);
+                let after = self.new_block();
+                self.jump_targets.push(JumpTarget { labels, breakable: true, break_block: after, continue_block: None });
+                let mut test = self.current_block();
+                let mut fallthrough: Option<usize> = None;
+                let mut has_default = false;
+                for case in &stmt.cases {
+                    self.current = Some(test);
+                    for label in &case.labels {
+                        match label {
+                            CaseLabel::Case((exp, _)) => self.push_expression(exp),
+                            CaseLabel::Default(_) => has_default = true,
+                        }
+                    }
+                    test = self.current_block();
+                    let body = self.new_block();
+                    self.edge(test, body);
+                    if let Some(fallthrough) = fallthrough {
+                        self.edge(fallthrough, body);
+                    }
+                    self.current = Some(body);
+                    self.build_directives(&case.directives);
+                    fallthrough = self.current;
+                }
+                self.current = fallthrough;
+                self.goto(after);
+                if !has_default {
+                    self.edge(test, after);
+                }
+                self.jump_targets.pop();
+                self.continue_at(after);
+            },
+            Directive::SwitchTypeStatement(stmt) => {
+                self.push_expression(&stmt.discriminant);
+                let test = self.current_block();
+                let after = self.new_block();
+                self.jump_targets.push(JumpTarget { labels, breakable: true, break_block: after, continue_block: None });
+                let mut has_default = false;
+                for case in &stmt.cases {
+                    let body = self.new_block();
+                    self.edge(test, body);
+                    self.current = Some(body);
+                    if let Some(parameter) = &case.parameter {
+                        self.push_item(ControlFlowItem::Pattern(parameter.destructuring.clone()));
+                    } else {
+                        has_default = true;
+                    }
+                    self.build_directives(&case.block.directives);
+                    self.goto(after);
+                }
+                if !has_default {
+                    self.edge(test, after);
+                }
+                self.jump_targets.pop();
+                self.continue_at(after);
+            },
+            Directive::TryStatement(stmt) => {
+                self.build_try_statement(stmt);
+            },
+            Directive::BreakStatement(stmt) => {
+                let target = if let Some((label, _)) = &stmt.label {
+                    self.jump_targets.iter().rev().find(|target| target.labels.contains(label))
+                } else {
+                    self.jump_targets.iter().rev().find(|target| target.breakable)
+                };
+                if let Some(block) = target.map(|target| target.break_block) {
+                    self.goto(block);
+                }
+                self.current = None;
+            },
+            Directive::ContinueStatement(stmt) => {
+                let target = if let Some((label, _)) = &stmt.label {
+                    self.jump_targets.iter().rev().find(|target| target.labels.contains(label) && target.continue_block.is_some())
+                } else {
+                    self.jump_targets.iter().rev().find(|target| target.continue_block.is_some())
+                };
+                if let Some(block) = target.and_then(|target| target.continue_block) {
+                    self.goto(block);
+                }
+                self.current = None;
+            },
+            Directive::ReturnStatement(stmt) => {
+                if let Some(exp) = &stmt.expression {
+                    self.push_expression(exp);
+                }
+                self.goto(ControlFlowGraph::END);
+                self.current = None;
+            },
+            Directive::ThrowStatement(stmt) => {
+                self.push_expression(&stmt.expression);
+                self.current = None;
+            },
+            _ => {},
+        }
+    }
+
+    /// Builds the body of a loop starting at `body`, where `break`
+    /// jumps to `after` and `continue` jumps to `continue_block`.
+    fn build_loop_body(&mut self, drtv: &Rc<Directive>, labels: Vec<String>, body: usize, after: usize, continue_block: usize) {
+        self.jump_targets.push(JumpTarget { labels, breakable: true, break_block: after, continue_block: Some(continue_block) });
+        self.current = Some(body);
+        self.build_directive(drtv, vec![]);
+        self.jump_targets.pop();
+    }
+
+    /// Builds a `try` statement. An exception may be thrown before
+    /// any statement of the `try` block completes, thus catch clauses
+    /// are reached from the start of the statement.
+    ///
+    /// The `finally` clause is built twice: once following normal
+    /// completion of the `try` block and catch clauses, continuing
+    /// after the statement, and once following an exception,
+    /// after which control does not continue.
+    fn build_try_statement(&mut self, stmt: &TryStatement) {
+        let start = self.current_block();
+        let mut completions = vec![];
+
+        let block = self.new_block();
+        self.edge(start, block);
+        self.current = Some(block);
+        self.build_directives(&stmt.block.directives);
+        completions.extend(self.current);
+
+        for catch_clause in &stmt.catch_clauses {
+            let block = self.new_block();
+            self.edge(start, block);
+            self.current = Some(block);
+            self.push_item(ControlFlowItem::Pattern(catch_clause.parameter.destructuring.clone()));
+            self.build_directives(&catch_clause.block.directives);
+            completions.extend(self.current);
+        }
+
+        let Some(finally_clause) = &stmt.finally_clause else {
+            let after = self.new_block();
+            for completion in completions {
+                self.edge(completion, after);
+            }
+            self.continue_at(after);
+            return;
+        };
+
+        let abrupt = self.new_block();
+        self.edge(start, abrupt);
+        self.current = Some(abrupt);
+        self.suppress_unreachable = !completions.is_empty();
+        self.build_directives(&finally_clause.block.directives);
+        self.suppress_unreachable = false;
+
+        if completions.is_empty() {
+            self.current = None;
+            return;
+        }
+        let block = self.new_block();
+        for completion in completions {
+            self.edge(completion, block);
+        }
+        self.current = Some(block);
+        self.build_directives(&finally_clause.block.directives);
+    }
+}
+
+/// Definite assignment analysis over the items of a graph.
+struct DefiniteAssignment {
+    tracked: HashSet<String>,
+    /// Names assigned by the analysed items on any path.
+    assigned: RefCell<HashSet<String>>,
+}
+
+impl DefiniteAssignment {
+    /// Applies an item to the set of variables that may be unassigned,
+    /// collecting reads of such variables.
+    fn item(&self, item: &ControlFlowItem, state: &mut HashSet<String>, reads: &mut Vec<(String, Location)>) {
+        match item {
+            ControlFlowItem::Expression(exp) => {
+                self.expression(exp, state, reads);
+            },
+            ControlFlowItem::VariableBinding(binding) => {
+                let mut names = vec![];
+                binding.destructuring.destructuring.pattern_names(&mut names);
+                state.extend(names.iter().filter(|name| self.tracked.contains(*name)).cloned());
+                if let Some(init) = &binding.initializer {
+                    self.expression(init, state, reads);
+                    for name in names {
+                        state.remove(&name);
+                        self.assigned.borrow_mut().insert(name);
+                    }
+                }
+            },
+            ControlFlowItem::Pattern(exp) => {
+                self.assign_pattern(exp, state);
+            },
+        }
+    }
+
+    fn assign_pattern(&self, exp: &Rc<Expression>, state: &mut HashSet<String>) {
+        let mut names = vec![];
+        exp.pattern_names(&mut names);
+        for name in names {
+            state.remove(&name);
+            self.assigned.borrow_mut().insert(name);
+        }
+    }
+
+    /// Evaluates an expression whose evaluation may be skipped,
+    /// such as the right operand of `&&`.
+    fn conditional_expression(&self, exp: &Rc<Expression>, state: &HashSet<String>, reads: &mut Vec<(String, Location)>) {
+        let mut state_1 = state.clone();
+        self.expression(exp, &mut state_1, reads);
+    }
+
+    fn expression(&self, exp: &Rc<Expression>, state: &mut HashSet<String>, reads: &mut Vec<(String, Location)>) {
+        match exp.as_ref() {
+            Expression::QualifiedIdentifier(id) => {
+                self.qualified_identifier(id, state, reads);
+            },
+            Expression::Paren(exp) => {
+                self.expression(&exp.expression, state, reads);
+            },
+            Expression::Xml(exp) => {
+                self.xml_element(&exp.element, state, reads);
+            },
+            Expression::XmlList(exp) => {
+                self.xml_content(&exp.content, state, reads);
+            },
+            Expression::ArrayLiteral(exp) => {
+                for element in &exp.elements {
+                    match element {
+                        Element::Expression(exp) => self.expression(exp, state, reads),
+                        Element::Rest((exp, _)) => self.expression(exp, state, reads),
+                        Element::Elision => {},
+                    }
+                }
+            },
+            Expression::ObjectInitializer(exp) => {
+                for field in &exp.fields {
+                    match field.as_ref() {
+                        InitializerField::Field { name, value, .. } => {
+                            if let FieldName::Brackets(exp) = &name.0 {
+                                self.expression(exp, state, reads);
+                            }
+                            if let Some(value) = value {
+                                self.expression(value, state, reads);
+                            } else if let FieldName::Identifier(name_1) = &name.0 {
+                                if state.contains(name_1) {
+                                    reads.push((name_1.clone(), name.1.clone()));
+                                }
+                            }
+                        },
+                        InitializerField::Rest((exp, _)) => self.expression(exp, state, reads),
+                    }
+                }
+            },
+            Expression::New(exp) => {
+                self.expression(&exp.base, state, reads);
+                for argument in exp.arguments.iter().flatten() {
+                    self.expression(argument, state, reads);
+                }
+            },
+            Expression::Member(exp) => {
+                self.expression(&exp.base, state, reads);
+            },
+            Expression::ComputedMember(exp) => {
+                self.expression(&exp.base, state, reads);
+                self.expression(&exp.key, state, reads);
+            },
+            Expression::Descendants(exp) => {
+                self.expression(&exp.base, state, reads);
+            },
+            // The test of a filter is evaluated against each XML node.
+            Expression::Filter(exp) => {
+                self.expression(&exp.base, state, reads);
+            },
+            Expression::Super(exp) => {
+                for argument in exp.object.iter().flatten() {
+                    self.expression(argument, state, reads);
+                }
+            },
+            Expression::Call(exp) => {
+                self.expression(&exp.base, state, reads);
+                for argument in &exp.arguments {
+                    self.expression(argument, state, reads);
+                }
+            },
+            Expression::WithTypeArguments(exp) => {
+                self.expression(&exp.base, state, reads);
+            },
+            Expression::Unary(exp) => {
+                self.expression(&exp.expression, state, reads);
+            },
+            Expression::OptionalChaining(exp) => {
+                self.expression(&exp.base, state, reads);
+                self.conditional_expression(&exp.expression, state, reads);
+            },
+            Expression::Binary(exp) => {
+                self.expression(&exp.left, state, reads);
+                if matches!(exp.operator, Operator::LogicalAnd | Operator::LogicalOr | Operator::NullCoalescing) {
+                    self.conditional_expression(&exp.right, state, reads);
+                } else {
+                    self.expression(&exp.right, state, reads);
+                }
+            },
+            Expression::Conditional(exp) => {
+                self.expression(&exp.test, state, reads);
+                let mut state_1 = state.clone();
+                self.expression(&exp.consequent, &mut state_1, reads);
+                self.expression(&exp.alternative, state, reads);
+                state.extend(state_1);
+            },
+            Expression::Assignment(exp) => {
+                let is_pattern = exp.compound.is_none() && (
+                    exp.left.to_identifier_name().is_some() ||
+                    matches!(exp.left.as_ref(), Expression::ArrayLiteral(_) | Expression::ObjectInitializer(_)));
+                if is_pattern {
+                    self.expression(&exp.right, state, reads);
+                    self.assign_pattern(&exp.left, state);
+                } else {
+                    self.expression(&exp.left, state, reads);
+                    self.expression(&exp.right, state, reads);
+                }
+            },
+            Expression::Sequence(exp) => {
+                self.expression(&exp.left, state, reads);
+                self.expression(&exp.right, state, reads);
+            },
+            // Function bodies are analysed separately.
+            Expression::Function(_) => {},
+            _ => {},
+        }
+    }
+
+    fn qualified_identifier(&self, id: &QualifiedIdentifier, state: &mut HashSet<String>, reads: &mut Vec<(String, Location)>) {
+        if let Some(qualifier) = &id.qualifier {
+            self.expression(qualifier, state, reads);
+        }
+        if let QualifiedIdentifierIdentifier::Brackets(exp) = &id.id {
+            self.expression(exp, state, reads);
+        }
+        if let Some((name, location)) = id.to_identifier_name() {
+            if state.contains(&name) {
+                reads.push((name, location));
+            }
+        }
+    }
+
+    fn xml_element(&self, element: &XmlElement, state: &mut HashSet<String>, reads: &mut Vec<(String, Location)>) {
+        if let XmlTagName::Expression(exp) = &element.name {
+            self.expression(exp, state, reads);
+        }
+        for attribute in &element.attributes {
+            if let XmlAttributeValue::Expression(exp) = &attribute.value {
+                self.expression(exp, state, reads);
+            }
+        }
+        if let Some(exp) = &element.attribute_expression {
+            self.expression(exp, state, reads);
+        }
+        if let Some(content) = &element.content {
+            self.xml_content(content, state, reads);
+        }
+    }
+
+    fn xml_content(&self, content: &[Rc<XmlElementContent>], state: &mut HashSet<String>, reads: &mut Vec<(String, Location)>) {
+        for content_1 in content {
+            match content_1.as_ref() {
+                XmlElementContent::XmlElement(element) => self.xml_element(element, state, reads),
+                XmlElementContent::Expression(exp) => self.expression(exp, state, reads),
+                _ => {},
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ns::*;
+
+    #[test]
+    fn control_flow() {
+        let host = SymbolHost::new("");
+        declare_jet_lang_classes(&host, &["Object", "Boolean", "String", "Number", "Function", "Class", "Array"]);
+
+        let source = r#"
+            function f(a: Boolean): Number {
+                if (a) {
+                    return 1;
+                }
+            }
+            function g(a: Boolean): Number {
+                if (a) {
+                    return 1;
+                } else {
+                    throw 0;
+                }
+            }
+            function h(): Number {
+                while (true) {}
+            }
+            function i(): Number {
+                return 0;
+                1;
+                2;
+            }
+            function j(a: Boolean): Number {
+                l: while (true) {
+                    if (a) {
+                        break l;
+                    }
+                }
+            }
+            function k(): Number {
+                try {
+                    return 0;
+                } finally {}
+            }
+        "#;
+        let compilation_unit = CompilationUnit::new(None, source.to_owned(), &CompilerOptions::new());
+        let program = ParserFacade::parse_program(&compilation_unit, &host).unwrap();
+        let mut verifier = Verifier::new(&host);
+        verifier.verify_programs(vec![program.clone()]);
+
+        let mut diagnostics = compilation_unit.diagnostics().iter().map(|d| (d.id(), d.location().first_line_number())).collect::<Vec<_>>();
+        diagnostics.sort();
+        assert_eq!(diagnostics, [
+            (DiagnosticKind::UnreachableCode.id(), 19),
+            (DiagnosticKind::NotAllPathsReturnValue.id(), 2),
+            (DiagnosticKind::NotAllPathsReturnValue.id(), 22),
+        ]);
+
+        let source = r#"
+            function f(a: Boolean) {
+                var x;
+                const y = x;
+                if (a) {
+                    x = 0;
+                } else {
+                    x = 1;
+                }
+                var z;
+                a && (z = 0);
+                for (var w in x) {
+                    z = w;
+                }
+                trace(x, y, z);
+            }
+        "#;
+        let compilation_unit = CompilationUnit::new(None, source.to_owned(), &CompilerOptions::new());
+        let program = ParserFacade::parse_program(&compilation_unit, &host).unwrap();
+        let Directive::FunctionDefinition(defn) = program.directives[0].as_ref() else {
+            panic!();
+        };
+        let Some(FunctionBody::Block(block)) = &defn.common.body else {
+            panic!();
+        };
+        let mut log = vec![];
+        let graph = ControlFlowGraph::build(&host, &block.directives, &mut log);
+        graph.verify_definite_assignment(&mut log);
+        assert!(graph.falls_off_end());
+        let reads = log.iter().map(|issue| match issue {
+            ControlFlowIssue::UseBeforeAssignment { name, location } => (name.clone(), location.first_line_number()),
+            ControlFlowIssue::UnreachableCode(_) => panic!(),
+        }).collect::<Vec<_>>();
+        assert_eq!(reads, [("x".to_owned(), 4), ("z".to_owned(), 15)]);
+    }
+}
@@ -0,0 +1,312 @@
+use crate::ns::*;
+
+/// Destination of the variables declared by a destructuring pattern.
+struct DestructuringDeclaration<'a> {
+    read_only: bool,
+    properties: &'a SharedMap<String, Symbol>,
+    parent_definition: &'a Symbol,
+    visibility: Visibility,
+}
+
+impl VerifierVerifier {
+    /// Declares the variables bound by a typed destructuring into
+    /// `properties`. The static type is that of the type annotation,
+    /// or else `context_type`, or else `*`.
+    ///
+    /// Patterns that have already been assigned a symbol or `None`
+    /// at `ast_to_symbol` are skipped, thus a deferred destructuring
+    /// may be verified again. If `parent_definition` is a scope,
+    /// the variables shadow previous variables of the same name.
+    pub(crate) fn verify_typed_destructuring(&mut self, tdst: &TypedDestructuring, read_only: bool, properties: &SharedMap<String, Symbol>, parent_definition: &Symbol, visibility: Visibility, context_type: Option<&Symbol>) -> Result<(), DeferVerificationError> {
+        let static_type = if let Some(annotation) = &tdst.type_annotation {
+            self.verify_type_expression(annotation)?.unwrap_or(self.host.any_type())
+        } else {
+            context_type.cloned().unwrap_or(self.host.any_type())
+        };
+        static_type.throw_if_unresolved()?;
+        let declaration = DestructuringDeclaration {
+            read_only,
+            properties,
+            parent_definition,
+            visibility,
+        };
+        self.verify_destructuring(&tdst.destructuring, &static_type, Some(&declaration))
+    }
+
+    /// Verifies the left-hand side of a destructuring assignment
+    /// whose right-hand side is of type `static_type`.
+    pub(crate) fn verify_assignment_destructuring(&mut self, exp: &Rc<Expression>, static_type: &Symbol) -> Result<(), DeferVerificationError> {
+        self.verify_destructuring(exp, static_type, None)
+    }
+
+    fn verify_destructuring(&mut self, exp: &Rc<Expression>, static_type: &Symbol, declaration: Option<&DestructuringDeclaration>) -> Result<(), DeferVerificationError> {
+        match exp.as_ref() {
+            Expression::Unary(UnaryExpression { operator: Operator::NonNull, expression, .. }) => {
+                if self.ast_to_symbol.has(exp) {
+                    return Ok(());
+                }
+                let non_null_type = static_type.non_null_type();
+                self.verify_destructuring(expression, &non_null_type, declaration)?;
+                self.ast_to_symbol.set(exp, Some(self.host.factory().create_value(&non_null_type)));
+                Ok(())
+            },
+            Expression::ArrayLiteral(literal) => {
+                if self.ast_to_symbol.has(exp) {
+                    return Ok(());
+                }
+                self.verify_array_destructuring(literal, static_type, declaration)?;
+                self.ast_to_symbol.set(exp, Some(self.host.factory().create_value(static_type)));
+                Ok(())
+            },
+            Expression::ObjectInitializer(initializer) => {
+                if self.ast_to_symbol.has(exp) {
+                    return Ok(());
+                }
+                self.verify_object_destructuring(initializer, static_type, declaration)?;
+                self.ast_to_symbol.set(exp, Some(self.host.factory().create_value(static_type)));
+                Ok(())
+            },
+            // Default value
+            Expression::Assignment(AssignmentExpression { left, right, compound: None, .. }) => {
+                self.limit_expression_type(right, static_type)?;
+                self.verify_destructuring(left, static_type, declaration)
+            },
+            _ => {
+                if let Some(declaration) = declaration {
+                    if self.ast_to_symbol.has(exp) {
+                        return Ok(());
+                    }
+                    let Some((name, location)) = exp.to_identifier_name() else {
+                        self.ast_to_symbol.set(exp, None);
+                        return Ok(());
+                    };
+                    let variable = self.declare_destructuring_variable(&name, &location, static_type, declaration);
+                    self.ast_to_symbol.set(exp, variable);
+                    return Ok(());
+                }
+                let Some(target) = self.verify_expression(exp, &ExpressionVerifyContext { mode: VerifyMode::Write, ..default() })? else {
+                    return Ok(());
+                };
+                let target_type = target.static_type(&self.host);
+                target_type.throw_if_unresolved()?;
+                let value = self.host.factory().create_value(static_type);
+                if TypeConversions(&self.host).implicit_conversion(&value, &target_type, false).is_none() {
+                    self.add_verify_error(&exp.location(), DiagnosticKind::IncompatibleTypes, diagnostic_arguments![Symbol(target_type), Symbol(static_type.clone())]);
+                    self.narrow_assignment(exp, None);
+                } else {
+                    self.narrow_assignment(exp, Some(static_type));
+                }
+                Ok(())
+            },
+        }
+    }
+
+    /// Declares a variable, returning `None` if it is a duplicate.
+    fn declare_destructuring_variable(&mut self, name: &str, location: &Location, static_type: &Symbol, declaration: &DestructuringDeclaration) -> Option<Symbol> {
+        let mut properties = declaration.properties.clone();
+        if let Some(previous) = properties.get(&name.to_owned()) {
+            // Declared by an earlier attempt of a deferred destructuring
+            if previous.is_variable_property() && previous.location().as_ref() == Some(location) {
+                return Some(previous);
+            }
+            if !declaration.parent_definition.is_scope() {
+                self.add_verify_error(location, DiagnosticKind::DuplicateDefinition, diagnostic_arguments![String(name.to_owned())]);
+                return None;
+            }
+        }
+        let variable = self.host.factory().create_variable_property(name.to_owned(), declaration.read_only, static_type);
+        variable.set_location(Some(location.clone()));
+        variable.set_visibility(declaration.visibility);
+        variable.set_parent_definition(Some(declaration.parent_definition));
+        properties.set(name.to_owned(), variable.clone());
+        Some(variable)
+    }
+
+    fn verify_array_destructuring(&mut self, literal: &ArrayLiteral, static_type: &Symbol, declaration: Option<&DestructuringDeclaration>) -> Result<(), DeferVerificationError> {
+        let host = self.host.clone();
+        if static_type.is_nullable_type() {
+            self.add_verify_error(&literal.location, DiagnosticKind::AccessingPropertyOfNullableBase, diagnostic_arguments![Symbol(static_type.clone())]);
+        }
+        let t = static_type.non_null_type();
+
+        // Tuples are destructured by position.
+        if t.is_tuple_type() {
+            let element_types = t.element_types();
+            if literal.elements.len() > element_types.length() {
+                self.add_verify_error(&literal.location, DiagnosticKind::DestructuringExceedingTupleElements, diagnostic_arguments![Symbol(t.clone())]);
+            }
+            for (i, element) in literal.elements.iter().enumerate() {
+                let element_type = element_types.get(i).unwrap_or(host.any_type());
+                match element {
+                    Element::Elision => {},
+                    Element::Expression(exp) => {
+                        self.verify_destructuring(exp, &element_type, declaration)?;
+                    },
+                    Element::Rest((exp, location)) => {
+                        self.add_verify_error(location, DiagnosticKind::CannotUseTypeInRest, diagnostic_arguments![Symbol(t.clone())]);
+                        self.verify_destructuring(exp, &host.any_type(), declaration)?;
+                    },
+                }
+            }
+            return Ok(());
+        }
+
+        let (element_type, rest_type) = if t.is_any_type() {
+            (t.clone(), t.clone())
+        } else if let Some(element_type) = t.array_element_type(&host)? {
+            (element_type, t.clone())
+        } else {
+            self.add_verify_error(&literal.location, DiagnosticKind::UnsupportedDestructuringType, diagnostic_arguments![Symbol(static_type.clone())]);
+            (host.any_type(), host.any_type())
+        };
+        for element in &literal.elements {
+            match element {
+                Element::Elision => {},
+                Element::Expression(exp) => {
+                    self.verify_destructuring(exp, &element_type, declaration)?;
+                },
+                Element::Rest((exp, _)) => {
+                    self.verify_destructuring(exp, &rest_type, declaration)?;
+                },
+            }
+        }
+        Ok(())
+    }
+
+    /// Verifies an object destructuring. Fields are dynamic for the `*`
+    /// and `Object` types, take the value type of a `Map`, and are
+    /// otherwise resolved as properties of the destructured type.
+    fn verify_object_destructuring(&mut self, initializer: &ObjectInitializer, static_type: &Symbol, declaration: Option<&DestructuringDeclaration>) -> Result<(), DeferVerificationError> {
+        let host = self.host.clone();
+        let object_type = host.object_type();
+        object_type.throw_if_unresolved()?;
+        let t = static_type.non_null_type();
+
+        let field_type: Option<Symbol> = if t.is_any_type() || t == object_type {
+            Some(host.any_type())
+        } else {
+            t.map_key_value_types(&host)?.map(|(_, v)| v)
+        };
+        if field_type.is_some() && static_type.is_nullable_type() {
+            self.add_verify_error(&initializer.location, DiagnosticKind::AccessingPropertyOfNullableBase, diagnostic_arguments![Symbol(static_type.clone())]);
+        }
+        let base = host.factory().create_value(static_type);
+
+        for field in &initializer.fields {
+            match field.as_ref() {
+                InitializerField::Rest((exp, _)) => {
+                    let rest_type = if field_type.is_some() { t.clone() } else { host.any_type() };
+                    self.verify_destructuring(exp, &rest_type, declaration)?;
+                },
+                InitializerField::Field { name, non_null, value } => {
+                    let key = match &name.0 {
+                        FieldName::Identifier(name) => Some(SemanticPropertyKey::String(name.clone())),
+                        FieldName::StringLiteral(exp) => {
+                            let Expression::StringLiteral(literal) = exp.as_ref() else {
+                                unreachable!();
+                            };
+                            Some(SemanticPropertyKey::String(literal.value.clone()))
+                        },
+                        FieldName::NumericLiteral(exp) => {
+                            self.verify_expression(exp, &default())?.filter(|v| v.is_number_constant()).map(SemanticPropertyKey::Value)
+                        },
+                        FieldName::Brackets(exp) => {
+                            self.verify_expression(exp, &default())?.map(SemanticPropertyKey::Value)
+                        },
+                    };
+
+                    let mut value_type = if let Some(field_type) = &field_type {
+                        field_type.clone()
+                    } else if let Some(key) = key {
+                        let r = QualifiedIdentifier::verify_property_resolution(self, &name.1, &base, None, key, PropertyDisambiguation::Default, &default())?;
+                        r.map(|r| r.static_type(&host)).unwrap_or(host.any_type())
+                    } else {
+                        host.any_type()
+                    };
+                    value_type.throw_if_unresolved()?;
+                    if *non_null {
+                        value_type = value_type.non_null_type();
+                    }
+
+                    if let Some(value) = value {
+                        self.verify_destructuring(value, &value_type, declaration)?;
+                    } else if let FieldName::Identifier(name_1) = &name.0 {
+                        if let Some(declaration) = declaration {
+                            self.declare_destructuring_variable(name_1, &name.1, &value_type, declaration);
+                        } else {
+                            self.verify_shorthand_assignment(name_1, &name.1, &value_type)?;
+                        }
+                    }
+                },
+            }
+        }
+        Ok(())
+    }
+
+    /// Verifies the assignment of a shorthand field such as `x`
+    /// in `{ x } = o`.
+    fn verify_shorthand_assignment(&mut self, name: &str, location: &Location, value_type: &Symbol) -> Result<(), DeferVerificationError> {
+        let scope = self.scope.clone();
+        let Some(target) = QualifiedIdentifier::verify_property_resolution(self, location, &scope, None, SemanticPropertyKey::String(name.to_owned()), PropertyDisambiguation::Default, &default())? else {
+            return Ok(());
+        };
+        if target.read_only(&self.host) {
+            self.add_verify_error(location, DiagnosticKind::ReferenceIsReadOnly, diagnostic_arguments![]);
+        }
+        if target.is_scope_reference_value() && target.property().is_variable_property() {
+            scope.clear_narrowed_type(&target.property());
+        }
+        let target_type = target.static_type(&self.host);
+        target_type.throw_if_unresolved()?;
+        let value = self.host.factory().create_value(value_type);
+        if TypeConversions(&self.host).implicit_conversion(&value, &target_type, false).is_none() {
+            self.add_verify_error(location, DiagnosticKind::IncompatibleTypes, diagnostic_arguments![Symbol(target_type), Symbol(value_type.clone())]);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ns::*;
+
+    #[test]
+    fn destructuring() {
+        let host = SymbolHost::new("");
+        declare_jet_lang_classes(&host, &["Object", "Boolean", "String", "Number", "Function", "Class", "Array", "Map"]);
+
+        let source = r#"
+            package p {
+                public class C {
+                    public var x: Number = 0;
+                }
+                public const k = 10;
+                public var k: Number;
+                public function f({ x, y }: C, [a, b]: [Number, String], w: [Number, String]): void {
+                    const n: Number = x;
+                    var s: String = b;
+                    s = a;
+                    const [t, u, v] = w;
+                    n = 1;
+                    var o: * = null;
+                    var { p } = o;
+                    [s] = w;
+                }
+            }
+        "#;
+        let compilation_unit = CompilationUnit::new(None, source.to_owned(), &CompilerOptions::new());
+        let program = ParserFacade::parse_program(&compilation_unit, &host).unwrap();
+        let mut verifier = Verifier::new(&host);
+        verifier.verify_programs(vec![program.clone()]);
+
+        let diagnostics = compilation_unit.diagnostics().iter().map(|d| (d.id(), d.location().first_line_number())).collect::<Vec<_>>();
+        assert_eq!(diagnostics, [
+            (DiagnosticKind::DuplicateDefinition.id(), 7),
+            (DiagnosticKind::UndefinedProperty.id(), 8),
+            (DiagnosticKind::IncompatibleTypes.id(), 11),
+            (DiagnosticKind::DestructuringExceedingTupleElements.id(), 12),
+            (DiagnosticKind::ReferenceIsReadOnly.id(), 13),
+            (DiagnosticKind::IncompatibleTypes.id(), 16),
+        ]);
+    }
+}
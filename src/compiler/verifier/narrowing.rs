@@ -0,0 +1,295 @@
+use crate::ns::*;
+
+/// Narrowings of local variable types implied by a condition. Each
+/// narrowing maps a variable property to its narrowed type; later
+/// narrowings of the same variable take precedence.
+#[derive(Default)]
+pub struct ConditionNarrowing {
+    /// Narrowings that hold where the condition is true.
+    pub when_true: Vec<(Symbol, Symbol)>,
+    /// Narrowings that hold where the condition is false.
+    pub when_false: Vec<(Symbol, Symbol)>,
+}
+
+impl VerifierVerifier {
+    /// Computes the narrowings implied by an already verified condition.
+    ///
+    /// Local variables are narrowed when compared against `null`, tested
+    /// for truthiness or tested with the `is` operator, including
+    /// through the `!`, `&&` and `||` operators.
+    pub(crate) fn condition_narrowing(&self, exp: &Rc<Expression>) -> ConditionNarrowing {
+        match exp.as_ref() {
+            Expression::Paren(paren) => self.condition_narrowing(&paren.expression),
+            Expression::Unary(UnaryExpression { operator: Operator::LogicalNot, expression, .. }) => {
+                let narrowing = self.condition_narrowing(expression);
+                ConditionNarrowing {
+                    when_true: narrowing.when_false,
+                    when_false: narrowing.when_true,
+                }
+            },
+            Expression::Binary(binexp) => match binexp.operator {
+                Operator::LogicalAnd => {
+                    let mut when_true = self.condition_narrowing(&binexp.left).when_true;
+                    when_true.extend(self.condition_narrowing(&binexp.right).when_true);
+                    ConditionNarrowing { when_true, when_false: vec![] }
+                },
+                Operator::LogicalOr => {
+                    let mut when_false = self.condition_narrowing(&binexp.left).when_false;
+                    when_false.extend(self.condition_narrowing(&binexp.right).when_false);
+                    ConditionNarrowing { when_true: vec![], when_false }
+                },
+                Operator::Equals | Operator::StrictEquals |
+                Operator::NotEquals | Operator::StrictNotEquals => {
+                    let operand = if Self::is_null_literal(&binexp.right) {
+                        &binexp.left
+                    } else if Self::is_null_literal(&binexp.left) {
+                        &binexp.right
+                    } else {
+                        return default();
+                    };
+                    let narrowings: Vec<_> = self.non_null_narrowing(operand).into_iter().collect();
+                    if matches!(binexp.operator, Operator::Equals | Operator::StrictEquals) {
+                        ConditionNarrowing { when_true: vec![], when_false: narrowings }
+                    } else {
+                        ConditionNarrowing { when_true: narrowings, when_false: vec![] }
+                    }
+                },
+                Operator::Is | Operator::IsNot => {
+                    let Some((variable, _)) = self.narrowable_variable(&binexp.left) else {
+                        return default();
+                    };
+                    let Some(t) = self.ast_to_symbol.get(&binexp.right).filter(|t| t.is_type() && !t.is_any_type()) else {
+                        return default();
+                    };
+                    if binexp.operator == Operator::Is {
+                        ConditionNarrowing { when_true: vec![(variable, t)], when_false: vec![] }
+                    } else {
+                        ConditionNarrowing { when_true: vec![], when_false: vec![(variable, t)] }
+                    }
+                },
+                _ => default(),
+            },
+            _ => ConditionNarrowing {
+                when_true: self.non_null_narrowing(exp).into_iter().collect(),
+                when_false: vec![],
+            },
+        }
+    }
+
+    /// Returns the local variable referenced by an already verified
+    /// expression, together with its current static type.
+    pub(crate) fn narrowable_variable(&self, exp: &Rc<Expression>) -> Option<(Symbol, Symbol)> {
+        if let Expression::Paren(paren) = exp.as_ref() {
+            return self.narrowable_variable(&paren.expression);
+        }
+        exp.to_identifier_name()?;
+        let v = self.ast_to_symbol.get(exp)?;
+        if !v.is_scope_reference_value() {
+            return None;
+        }
+        let variable = v.property();
+        if !variable.is_variable_property() {
+            return None;
+        }
+        Some((variable, v.static_type(&self.host)))
+    }
+
+    fn non_null_narrowing(&self, exp: &Rc<Expression>) -> Option<(Symbol, Symbol)> {
+        let (variable, t) = self.narrowable_variable(exp)?;
+        let non_null_type = t.non_null_type();
+        if non_null_type == t {
+            return None;
+        }
+        Some((variable, non_null_type))
+    }
+
+    fn is_null_literal(exp: &Rc<Expression>) -> bool {
+        match exp.as_ref() {
+            Expression::NullLiteral(_) => true,
+            Expression::Paren(paren) => Self::is_null_literal(&paren.expression),
+            _ => false,
+        }
+    }
+
+    /// Applies narrowings to a scope.
+    pub(crate) fn narrow_scope(scope: &Symbol, narrowings: &[(Symbol, Symbol)]) {
+        for (variable, t) in narrowings {
+            scope.narrowed_types().set(variable.clone(), t.clone());
+        }
+    }
+
+    /// Verifies part of an expression, such as the right operand
+    /// of `&&`, where the given narrowings hold. The part is verified
+    /// in its own scope, since it may not be evaluated and hence
+    /// narrowings from its assignments must not outlive it.
+    pub(crate) fn verify_narrowed<T>(&mut self, narrowings: &[(Symbol, Symbol)], verify: impl FnOnce(&mut Self) -> T) -> T {
+        let scope = self.host.factory().create_scope();
+        Self::narrow_scope(&scope, narrowings);
+        self.enter_scope(&scope);
+        let result = verify(self);
+        self.exit_scope();
+        result
+    }
+
+    /// Verifies a substatement, such as a branch of `if`, where the
+    /// given narrowings hold. A block substatement is narrowed
+    /// through its own scope.
+    pub(crate) fn verify_narrowed_substatement(&mut self, drtv: &Rc<Directive>, narrowings: &[(Symbol, Symbol)], phase: VerifierPhase) {
+        if matches!(drtv.as_ref(), Directive::Block(_)) {
+            let scope = self.node_scope(drtv);
+            Self::narrow_scope(&scope, narrowings);
+            self.verify_directives(std::slice::from_ref(drtv), phase);
+        } else {
+            self.verify_narrowed(narrowings, |verifier| verifier.verify_directives(std::slice::from_ref(drtv), phase));
+        }
+    }
+
+    /// Updates the narrowing of a local variable assigned by an already
+    /// verified target, once the assigned value has been verified. The
+    /// variable is narrowed to its non-null type if the value is of
+    /// a non-null type, and otherwise is no longer narrowed.
+    pub(crate) fn narrow_assignment(&self, target: &Rc<Expression>, value_type: Option<&Symbol>) {
+        let Some((variable, t)) = self.narrowable_variable(target) else {
+            return;
+        };
+        self.scope.clear_narrowed_type(&variable);
+        let non_null_type = t.non_null_type();
+        let non_null_value = value_type.is_some_and(|t| !(t.is_nullable_type() || t.is_any_type() || t.is_void_type()));
+        if non_null_type != t && non_null_value {
+            self.scope.narrowed_types().set(variable, non_null_type);
+        }
+    }
+
+    /// Discards the narrowings of the local variables that the body
+    /// or update expression of a loop may assign, since later
+    /// iterations observe these assignments.
+    pub(crate) fn clear_loop_narrowings(&self, body: &Rc<Directive>, update: Option<&Rc<Expression>>) {
+        let names = ControlFlowGraph::assigned_names(&self.host, std::slice::from_ref(body), update.map(std::slice::from_ref).unwrap_or_default());
+        if names.is_empty() {
+            return;
+        }
+        for scope in self.scope.descending_scope_hierarchy() {
+            Self::clear_scope_narrowings(&scope, |variable| names.contains(&variable.name()));
+            if scope.is_activation_scope() {
+                break;
+            }
+        }
+    }
+
+    /// Discards the narrowings of a scope whose variables satisfy a predicate.
+    pub(crate) fn clear_scope_narrowings(scope: &Symbol, predicate: impl Fn(&Symbol) -> bool) {
+        let mut narrowed_types = scope.narrowed_types();
+        let variables: Vec<Symbol> = narrowed_types.borrow().keys().filter(|variable| predicate(variable)).cloned().collect();
+        for variable in variables {
+            narrowed_types.remove(&variable);
+        }
+    }
+
+    /// Indicates whether control may flow past a statement,
+    /// rather than always leaving by a jump, `return` or `throw`.
+    pub(crate) fn completes_normally(&self, drtv: &Rc<Directive>) -> bool {
+        ControlFlowGraph::build(&self.host, std::slice::from_ref(drtv), &mut vec![]).falls_off_end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ns::*;
+
+    #[test]
+    fn null_narrowing() {
+        let host = SymbolHost::new("");
+        declare_jet_lang_classes(&host, &["Object", "Boolean", "String", "Number", "Function", "Class", "Array"]);
+
+        let source = r#"
+            package p {
+                public class C {
+                    public function f(): void {}
+                    public function g(): Boolean { return true }
+                }
+                public function a(x: C?): void {
+                    x.f();
+                    if (x != null) {
+                        x.f();
+                    }
+                    if (x) {
+                        x.f();
+                    } else {
+                        x.f();
+                    }
+                    x != null && x.g();
+                    x == null || x.g();
+                    if (!(x === null)) x.f();
+                    if (x == null) {
+                        return;
+                    }
+                    x.f();
+                }
+                public function b(x: Object?): void {
+                    if (x is C) {
+                        x.f();
+                    }
+                    switch type (x) {
+                        case (y: C) {
+                            x.f();
+                        }
+                    }
+                }
+                public class N {
+                    public var v: Number = 0;
+                    public var next: N? = null;
+                }
+                public function c(x: N?, t: Boolean): void {
+                    if (x == null) return;
+                    x = x.next;
+                    x.v;
+                    x = new N();
+                    x.v;
+                    if (t) x = null;
+                    x.v;
+                }
+                public function d(x: N?, t: Boolean): void {
+                    var n: Number = 0;
+                    if (x == null) return;
+                    while (t) {
+                        n += x.v;
+                        x = null;
+                    }
+                }
+                public function e(x: N?, t: Boolean): void {
+                    var n: Number = 0;
+                    if (x == null) return;
+                    do {
+                        n += x.v;
+                        x = null;
+                    } while (t);
+                }
+                public function f(x: N?, t: Boolean): void {
+                    if (x == null) return;
+                    for (var i: Number = 0; i < 10; i++) {
+                        x.v;
+                    }
+                    for (;;x = x.next) {
+                        x.v;
+                    }
+                }
+            }
+        "#;
+        let compilation_unit = CompilationUnit::new(None, source.to_owned(), &CompilerOptions::new());
+        let program = ParserFacade::parse_program(&compilation_unit, &host).unwrap();
+        let mut verifier = Verifier::new(&host);
+        verifier.verify_programs(vec![program.clone()]);
+
+        let diagnostics = compilation_unit.diagnostics().iter().map(|d| (d.id(), d.location().first_line_number())).collect::<Vec<_>>();
+        assert_eq!(diagnostics, [
+            (DiagnosticKind::AccessingPropertyOfNullableBase.id(), 8),
+            (DiagnosticKind::AccessingPropertyOfNullableBase.id(), 15),
+            (DiagnosticKind::AccessingPropertyOfNullableBase.id(), 42),
+            (DiagnosticKind::AccessingPropertyOfNullableBase.id(), 46),
+            (DiagnosticKind::AccessingPropertyOfNullableBase.id(), 52),
+            (DiagnosticKind::AccessingPropertyOfNullableBase.id(), 60),
+            (DiagnosticKind::AccessingPropertyOfNullableBase.id(), 69),
+            (DiagnosticKind::AccessingPropertyOfNullableBase.id(), 70),
+        ]);
+    }
+}
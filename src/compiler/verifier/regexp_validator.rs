@@ -0,0 +1,574 @@
+use crate::ns::*;
+use std::ops::Range;
+
+/// Issue found in a regular expression literal by `RegExpValidator`.
+pub struct RegExpIssue {
+    pub kind: DiagnosticKind,
+    pub arguments: Vec<DiagnosticArgument>,
+    /// Range of UTF-8 offsets relative to the start of the literal,
+    /// where offset 0 is the opening slash.
+    pub range: Range<usize>,
+}
+
+/// Syntax checker for regular expression literals.
+///
+/// Patterns follow the ECMAScript pattern grammar; without the `u` or `v`
+/// flag, the web compatibility extensions to the grammar are accepted,
+/// such as unescaped braces and identity escapes. Back references to
+/// groups that do not exist are reported in either mode.
+///
+/// ```ignore
+/// for issue in RegExpValidator::validate(&literal.body, &literal.flags) {
+///     // Report issue
+/// }
+/// ```
+pub struct RegExpValidator {
+    chars: Vec<(usize, char)>,
+    body_length: usize,
+    index: usize,
+    unicode: bool,
+    unicode_sets: bool,
+    capture_count: u64,
+    group_names: Vec<String>,
+    back_references: Vec<(u64, Range<usize>)>,
+    named_back_references: Vec<(String, Range<usize>)>,
+    issues: Vec<RegExpIssue>,
+}
+
+impl RegExpValidator {
+    /// Flags accepted after a regular expression literal.
+    pub const FLAGS: &'static str = "dgimsuvy";
+
+    /// Validates the body and flags of a regular expression literal,
+    /// as given by the tokenizer.
+    pub fn validate(body: &str, flags: &str) -> Vec<RegExpIssue> {
+        let mut validator = Self {
+            chars: body.char_indices().collect(),
+            body_length: body.len(),
+            index: 0,
+            unicode: flags.contains('u') || flags.contains('v'),
+            unicode_sets: flags.contains('v'),
+            capture_count: 0,
+            group_names: vec![],
+            back_references: vec![],
+            named_back_references: vec![],
+            issues: vec![],
+        };
+        validator.pattern();
+        validator.validate_references();
+        validator.issues.sort_by_key(|issue| issue.range.start);
+        validator.validate_flags(flags);
+        validator.issues
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.peek_at(0)
+    }
+
+    fn peek_at(&self, n: usize) -> Option<char> {
+        self.chars.get(self.index + n).map(|(_, ch)| *ch)
+    }
+
+    fn eat(&mut self, ch: char) -> bool {
+        if self.peek() == Some(ch) {
+            self.index += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Offset of the current character relative to the start of the literal.
+    fn offset(&self) -> usize {
+        self.chars.get(self.index).map_or(self.body_length, |(i, _)| *i) + 1
+    }
+
+    fn report(&mut self, kind: DiagnosticKind, range: Range<usize>, arguments: Vec<DiagnosticArgument>) {
+        self.issues.push(RegExpIssue { kind, arguments, range });
+    }
+
+    /// Reports an issue spanning from `start` to the current character.
+    fn report_from(&mut self, kind: DiagnosticKind, start: usize, arguments: Vec<DiagnosticArgument>) {
+        let end = self.offset().max(start + 1);
+        self.report(kind, start..end, arguments);
+    }
+
+    fn validate_flags(&mut self, flags: &str) {
+        let start = self.body_length + 2;
+        let mut seen = String::new();
+        for (i, ch) in flags.char_indices() {
+            let range = (start + i)..(start + i + ch.len_utf8());
+            if !Self::FLAGS.contains(ch) {
+                self.report(DiagnosticKind::UnknownRegExpFlag, range, diagnostic_arguments![String(ch.to_string())]);
+            } else if seen.contains(ch) {
+                self.report(DiagnosticKind::DuplicateRegExpFlag, range, diagnostic_arguments![String(ch.to_string())]);
+            } else {
+                seen.push(ch);
+            }
+        }
+    }
+
+    fn validate_references(&mut self) {
+        for (number, range) in std::mem::take(&mut self.back_references) {
+            if number > self.capture_count {
+                self.report(DiagnosticKind::InvalidRegExpGroupReference, range, diagnostic_arguments![String(number.to_string())]);
+            }
+        }
+        // Without the `u` flag and named groups, `\k` is an identity escape.
+        if self.unicode || !self.group_names.is_empty() {
+            for (name, range) in std::mem::take(&mut self.named_back_references) {
+                if !self.group_names.contains(&name) {
+                    self.report(DiagnosticKind::InvalidRegExpGroupReference, range, diagnostic_arguments![String(name)]);
+                }
+            }
+        }
+    }
+
+    fn pattern(&mut self) {
+        loop {
+            self.disjunction();
+            if self.peek() == Some(')') {
+                let start = self.offset();
+                self.index += 1;
+                self.report_from(DiagnosticKind::UnmatchedRegExpParenthesis, start, vec![]);
+                continue;
+            }
+            break;
+        }
+    }
+
+    fn disjunction(&mut self) {
+        loop {
+            while let Some(ch) = self.peek() {
+                if ch == '|' || ch == ')' {
+                    break;
+                }
+                self.term();
+            }
+            if !self.eat('|') {
+                break;
+            }
+        }
+    }
+
+    fn term(&mut self) {
+        let start = self.offset();
+        let quantifiable = match self.peek().unwrap() {
+            '^' | '$' => {
+                self.index += 1;
+                false
+            },
+            '\\' if matches!(self.peek_at(1), Some('b' | 'B')) => {
+                self.index += 2;
+                false
+            },
+            '(' => self.group(),
+            '[' => {
+                self.character_class();
+                true
+            },
+            '\\' => {
+                self.atom_escape();
+                true
+            },
+            '*' | '+' | '?' => {
+                self.index += 1;
+                self.eat('?');
+                self.report_from(DiagnosticKind::NothingToRepeatInRegExp, start, vec![]);
+                return;
+            },
+            '{' => {
+                if self.braced_quantifier() {
+                    self.eat('?');
+                    self.report_from(DiagnosticKind::NothingToRepeatInRegExp, start, vec![]);
+                    return;
+                }
+                self.index += 1;
+                if self.unicode {
+                    self.report_from(DiagnosticKind::UnescapedRegExpCharacter, start, diagnostic_arguments![String("{".into())]);
+                }
+                true
+            },
+            ch @ ('}' | ']') => {
+                self.index += 1;
+                if self.unicode {
+                    self.report_from(DiagnosticKind::UnescapedRegExpCharacter, start, diagnostic_arguments![String(ch.to_string())]);
+                }
+                true
+            },
+            _ => {
+                self.index += 1;
+                true
+            },
+        };
+        self.quantifier(quantifiable);
+    }
+
+    fn quantifier(&mut self, quantifiable: bool) {
+        let start = self.offset();
+        let is_quantifier = match self.peek() {
+            Some('*' | '+' | '?') => {
+                self.index += 1;
+                true
+            },
+            Some('{') => self.braced_quantifier(),
+            _ => false,
+        };
+        if !is_quantifier {
+            return;
+        }
+        self.eat('?');
+        if !quantifiable {
+            self.report_from(DiagnosticKind::NothingToRepeatInRegExp, start, vec![]);
+        }
+    }
+
+    /// Consumes a quantifier of the form `{n}`, `{n,}` or `{n,m}`,
+    /// reporting bounds out of order. Returns `false`, consuming nothing,
+    /// if the characters at the cursor do not form such a quantifier.
+    fn braced_quantifier(&mut self) -> bool {
+        let start_index = self.index;
+        let start = self.offset();
+        self.index += 1;
+        let Some(min) = self.decimal_digits() else {
+            self.index = start_index;
+            return false;
+        };
+        let mut max = Some(min);
+        if self.eat(',') {
+            max = self.decimal_digits();
+        }
+        if !self.eat('}') {
+            self.index = start_index;
+            return false;
+        }
+        if max.is_some_and(|max| max < min) {
+            self.report_from(DiagnosticKind::RegExpQuantifierOutOfOrder, start, vec![]);
+        }
+        true
+    }
+
+    fn decimal_digits(&mut self) -> Option<u64> {
+        let mut value: Option<u64> = None;
+        while let Some(digit) = self.peek().and_then(|ch| ch.to_digit(10)) {
+            value = Some(value.unwrap_or(0).saturating_mul(10).saturating_add(digit.into()));
+            self.index += 1;
+        }
+        value
+    }
+
+    /// Consumes a group, returning whether it may be quantified.
+    fn group(&mut self) -> bool {
+        let start = self.offset();
+        self.index += 1;
+        let mut quantifiable = true;
+        if self.eat('?') {
+            match self.peek() {
+                Some(':') => {
+                    self.index += 1;
+                },
+                Some('=' | '!') => {
+                    self.index += 1;
+                    quantifiable = !self.unicode;
+                },
+                Some('<') if matches!(self.peek_at(1), Some('=' | '!')) => {
+                    self.index += 2;
+                    quantifiable = false;
+                },
+                Some('<') => {
+                    self.index += 1;
+                    self.group_name();
+                    self.capture_count += 1;
+                },
+                _ => {
+                    self.report_from(DiagnosticKind::InvalidRegExpGroup, start, vec![]);
+                },
+            }
+        } else {
+            self.capture_count += 1;
+        }
+        self.disjunction();
+        if !self.eat(')') {
+            self.report(DiagnosticKind::UnterminatedRegExpGroup, start..(start + 1), vec![]);
+        }
+        quantifiable
+    }
+
+    /// Consumes a group name after `(?<`, including the closing `>`.
+    fn group_name(&mut self) {
+        let start = self.offset();
+        let name = self.name_until_closing_angle();
+        let end = self.offset();
+        let Some(name) = name.filter(|name| Self::is_valid_group_name(name)) else {
+            self.report_from(DiagnosticKind::InvalidRegExpGroupName, start, vec![]);
+            return;
+        };
+        if self.group_names.contains(&name) {
+            self.report(DiagnosticKind::DuplicateRegExpGroupName, start..(end - 1), diagnostic_arguments![String(name)]);
+        } else {
+            self.group_names.push(name);
+        }
+    }
+
+    /// Consumes characters up to and including `>`, returning them
+    /// without the `>`, or `None` if a `)` or the end of the pattern
+    /// comes first.
+    fn name_until_closing_angle(&mut self) -> Option<String> {
+        let mut name = String::new();
+        while let Some(ch) = self.peek() {
+            if ch == ')' {
+                break;
+            }
+            self.index += 1;
+            if ch == '>' {
+                return Some(name);
+            }
+            name.push(ch);
+        }
+        None
+    }
+
+    fn is_valid_group_name(name: &str) -> bool {
+        let mut chars = name.chars();
+        chars.next().is_some_and(CharacterValidator::is_identifier_start)
+            && chars.all(CharacterValidator::is_identifier_part)
+    }
+
+    fn atom_escape(&mut self) {
+        let start = self.offset();
+        self.index += 1;
+        match self.peek() {
+            None => {
+                self.report_from(DiagnosticKind::InvalidRegExpEscape, start, vec![]);
+            },
+            Some('1'..='9') => {
+                let number = self.decimal_digits().unwrap();
+                self.back_references.push((number, start..self.offset()));
+            },
+            Some('k') => {
+                self.index += 1;
+                if self.eat('<') {
+                    match self.name_until_closing_angle() {
+                        Some(name) => self.named_back_references.push((name, start..self.offset())),
+                        None => if self.unicode {
+                            self.report_from(DiagnosticKind::InvalidRegExpGroupName, start, vec![]);
+                        },
+                    }
+                } else if self.unicode {
+                    self.report_from(DiagnosticKind::InvalidRegExpEscape, start, vec![]);
+                }
+            },
+            Some(_) => {
+                self.character_escape(start, false);
+            },
+        }
+    }
+
+    /// Consumes the escape after a backslash, returning the code point
+    /// it denotes, or `None` for a character class escape such as `\d`.
+    fn character_escape(&mut self, start: usize, in_class: bool) -> Option<u32> {
+        let ch = self.peek()?;
+        self.index += 1;
+        match ch {
+            'd' | 'D' | 's' | 'S' | 'w' | 'W' => None,
+            'p' | 'P' if self.unicode => {
+                if !(self.eat('{') && self.name_until_closing_brace()) {
+                    self.report_from(DiagnosticKind::InvalidRegExpEscape, start, vec![]);
+                }
+                None
+            },
+            'f' => Some(0x0C),
+            'n' => Some(0x0A),
+            'r' => Some(0x0D),
+            't' => Some(0x09),
+            'v' => Some(0x0B),
+            'b' if in_class => Some(0x08),
+            'c' => {
+                if let Some(letter) = self.peek().filter(|ch| ch.is_ascii_alphabetic()) {
+                    self.index += 1;
+                    return Some(letter as u32 % 32);
+                }
+                if self.unicode {
+                    self.report_from(DiagnosticKind::InvalidRegExpEscape, start, vec![]);
+                }
+                // Without the `u` flag, `\c` denotes a backslash followed by `c`.
+                self.index -= 1;
+                Some('\\' as u32)
+            },
+            '0' if !self.peek().is_some_and(|ch| ch.is_ascii_digit()) => Some(0),
+            '0'..='9' => {
+                if self.unicode {
+                    self.report_from(DiagnosticKind::InvalidRegExpEscape, start, vec![]);
+                }
+                // Legacy octal escape
+                let mut value = ch.to_digit(8).unwrap_or(ch as u32);
+                for _ in 0..2 {
+                    let Some(digit) = self.peek().and_then(|ch| ch.to_digit(8)).filter(|_| value < 0o40) else {
+                        break;
+                    };
+                    value = value * 8 + digit;
+                    self.index += 1;
+                }
+                Some(value)
+            },
+            'x' => {
+                let value = self.hex_digits(2);
+                if value.is_none() && self.unicode {
+                    self.report_from(DiagnosticKind::InvalidRegExpEscape, start, vec![]);
+                }
+                Some(value.unwrap_or('x' as u32))
+            },
+            'u' => {
+                let value = self.unicode_escape();
+                if value.is_none() && self.unicode {
+                    self.report_from(DiagnosticKind::InvalidRegExpEscape, start, vec![]);
+                }
+                Some(value.unwrap_or('u' as u32))
+            },
+            _ => {
+                let allowed = Self::is_syntax_character(ch) || ch == '/' || (in_class && ch == '-');
+                if self.unicode && !allowed {
+                    self.report_from(DiagnosticKind::InvalidRegExpEscape, start, vec![]);
+                }
+                Some(ch as u32)
+            },
+        }
+    }
+
+    /// Consumes a non-empty property name up to and including `}`.
+    fn name_until_closing_brace(&mut self) -> bool {
+        let mut empty = true;
+        while let Some(ch) = self.peek() {
+            if !(ch.is_ascii_alphanumeric() || ch == '_' || ch == '=') {
+                break;
+            }
+            empty = false;
+            self.index += 1;
+        }
+        !empty && self.eat('}')
+    }
+
+    fn unicode_escape(&mut self) -> Option<u32> {
+        if self.unicode && self.peek() == Some('{') {
+            let start_index = self.index;
+            self.index += 1;
+            let mut value: u32 = 0;
+            let mut count = 0;
+            while let Some(digit) = self.peek().and_then(|ch| ch.to_digit(16)) {
+                value = value.saturating_mul(16).saturating_add(digit);
+                count += 1;
+                self.index += 1;
+            }
+            if count != 0 && value <= 0x10FFFF && self.eat('}') {
+                return Some(value);
+            }
+            self.index = start_index;
+            return None;
+        }
+        self.hex_digits(4)
+    }
+
+    fn hex_digits(&mut self, count: usize) -> Option<u32> {
+        let mut value = 0;
+        for i in 0..count {
+            value = value * 16 + self.peek_at(i)?.to_digit(16)?;
+        }
+        self.index += count;
+        Some(value)
+    }
+
+    fn character_class(&mut self) {
+        let start = self.offset();
+        self.index += 1;
+        self.eat('^');
+        loop {
+            let Some(ch) = self.peek() else {
+                self.report(DiagnosticKind::UnterminatedRegExpCharacterClass, start..(start + 1), vec![]);
+                return;
+            };
+            if ch == ']' {
+                self.index += 1;
+                return;
+            }
+            // With the `v` flag, classes nest and support set operations,
+            // whose operands are not checked as ranges.
+            if self.unicode_sets {
+                if ch == '[' {
+                    self.character_class();
+                } else {
+                    self.class_atom();
+                }
+                continue;
+            }
+            let range_start = self.offset();
+            let from = self.class_atom();
+            if self.peek() == Some('-') && self.peek_at(1).is_some_and(|ch| ch != ']') {
+                self.index += 1;
+                let to = self.class_atom();
+                match (from, to) {
+                    (Some(from), Some(to)) => if from > to {
+                        self.report_from(DiagnosticKind::InvalidRegExpCharacterClassRange, range_start, vec![]);
+                    },
+                    _ => if self.unicode {
+                        self.report_from(DiagnosticKind::InvalidRegExpCharacterClassRange, range_start, vec![]);
+                    },
+                }
+            }
+        }
+    }
+
+    /// Consumes a character of a class, returning the code point
+    /// it denotes, or `None` for a character class escape.
+    fn class_atom(&mut self) -> Option<u32> {
+        let start = self.offset();
+        let ch = self.peek()?;
+        self.index += 1;
+        if ch != '\\' {
+            return Some(ch as u32);
+        }
+        if self.peek().is_none() {
+            self.report_from(DiagnosticKind::InvalidRegExpEscape, start, vec![]);
+            return Some(ch as u32);
+        }
+        self.character_escape(start, true)
+    }
+
+    fn is_syntax_character(ch: char) -> bool {
+        "^$\\.*+?()[]{}|".contains(ch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ns::*;
+
+    fn issues(body: &str, flags: &str) -> Vec<(i32, std::ops::Range<usize>)> {
+        RegExpValidator::validate(body, flags).into_iter().map(|issue| (issue.kind.id(), issue.range)).collect()
+    }
+
+    #[test]
+    fn validate() {
+        assert!(issues(r"^(?<year>\d{4})-(\d{2})\k<year>\2[a-z\-]+?$", "gimsuy").is_empty());
+        assert_eq!(issues(r"a{,1}]\q\8(?=x)*", ""), [
+            (DiagnosticKind::InvalidRegExpGroupReference.id(), 9..11),
+        ]);
+        assert_eq!(issues(r"(a|b", "gg"), [
+            (DiagnosticKind::UnterminatedRegExpGroup.id(), 1..2),
+            (DiagnosticKind::DuplicateRegExpFlag.id(), 7..8),
+        ]);
+        assert_eq!(issues(r"a)*", "x"), [
+            (DiagnosticKind::UnmatchedRegExpParenthesis.id(), 2..3),
+            (DiagnosticKind::NothingToRepeatInRegExp.id(), 3..4),
+            (DiagnosticKind::UnknownRegExpFlag.id(), 5..6),
+        ]);
+        assert_eq!(issues(r"[z-a]x{3,1}\2", ""), [
+            (DiagnosticKind::InvalidRegExpCharacterClassRange.id(), 2..5),
+            (DiagnosticKind::RegExpQuantifierOutOfOrder.id(), 7..12),
+            (DiagnosticKind::InvalidRegExpGroupReference.id(), 12..14),
+        ]);
+        assert_eq!(issues(r"[a\q", "u"), [
+            (DiagnosticKind::UnterminatedRegExpCharacterClass.id(), 1..2),
+            (DiagnosticKind::InvalidRegExpEscape.id(), 3..5),
+        ]);
+    }
+}
@@ -0,0 +1,122 @@
+use clap::{Parser, ValueEnum};
+use file_paths::FlexPath;
+use std::{env, fs, process::ExitCode};
+use hydroper_jet_compiler::ns::*;
+
+/// Formats Jet source files.
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Arguments {
+    /// Source files to format.
+    #[arg(required = true)]
+    source_paths: Vec<String>,
+
+    /// Writes the formatted source back to the source files
+    /// instead of the standard output.
+    #[arg(short, long)]
+    write: bool,
+
+    /// Lists the source files that are not formatted, failing if
+    /// there is any.
+    #[arg(long)]
+    check: bool,
+
+    /// Number of spaces per indentation level.
+    #[arg(long, default_value_t = 4)]
+    indent_size: usize,
+
+    /// Indents with tabs instead of spaces.
+    #[arg(long)]
+    tabs: bool,
+
+    /// Placement of opening braces.
+    #[arg(long, value_enum, default_value_t = BraceStyleArgument::SameLine)]
+    brace_style: BraceStyleArgument,
+
+    /// Column limit after which lists are written one item per line.
+    #[arg(long, default_value_t = 100)]
+    line_width: usize,
+
+    /// Order of keyword attributes.
+    #[arg(long, value_enum, default_value_t = AttributeOrderingArgument::Preserve)]
+    attribute_ordering: AttributeOrderingArgument,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, ValueEnum)]
+enum BraceStyleArgument {
+    SameLine,
+    NextLine,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, ValueEnum)]
+enum AttributeOrderingArgument {
+    Preserve,
+    Canonical,
+}
+
+fn main() -> ExitCode {
+    let arguments = Arguments::parse();
+    let options = FormatterOptions {
+        indentation: if arguments.tabs { FormatterIndentation::Tabs } else { FormatterIndentation::Spaces(arguments.indent_size) },
+        brace_style: match arguments.brace_style {
+            BraceStyleArgument::SameLine => BraceStyle::SameLine,
+            BraceStyleArgument::NextLine => BraceStyle::NextLine,
+        },
+        line_width: arguments.line_width,
+        attribute_ordering: match arguments.attribute_ordering {
+            AttributeOrderingArgument::Preserve => AttributeOrdering::Preserve,
+            AttributeOrderingArgument::Canonical => AttributeOrdering::Canonical,
+        },
+    };
+    let formatter = Formatter::new(&options);
+    let mut success = true;
+
+    for source_path in &arguments.source_paths {
+        let source_path = FlexPath::from_n_native([env::current_dir().unwrap().to_string_lossy().into_owned().as_ref(), source_path.as_ref()]).to_string_with_flex_separator();
+        let jetpm_target_path = FlexPath::new_native(&source_path).resolve("../jetpm-target").to_string_with_flex_separator();
+        let source_content = match fs::read_to_string(&source_path) {
+            Ok(content) => content,
+            Err(error) => {
+                eprintln!("{source_path}: {error}");
+                success = false;
+                continue;
+            },
+        };
+        let host = SymbolHost::new(&jetpm_target_path);
+        let compilation_unit = CompilationUnit::new(Some(source_path.clone()), source_content.clone(), &CompilerOptions::new());
+        let program = ParserFacade::parse_program(&compilation_unit, &host);
+
+        // Refuse to format sources with syntax errors
+        let Some(program) = program.filter(|_| !compilation_unit.invalidated()) else {
+            compilation_unit.sort_diagnostics();
+            for diagnostic in compilation_unit.diagnostics() {
+                eprintln!("{}", diagnostic.format_english());
+            }
+            success = false;
+            continue;
+        };
+
+        let mut formatted = formatter.format(&program);
+        if source_content.contains("\r\n") {
+            formatted = formatted.replace("\r\n", "\n").replace('\n', "\r\n");
+        }
+
+        if arguments.check {
+            if formatted != source_content {
+                println!("{source_path}");
+                success = false;
+            }
+        } else if arguments.write {
+            if formatted != source_content {
+                if let Err(error) = fs::write(&source_path, formatted) {
+                    eprintln!("{source_path}: {error}");
+                    success = false;
+                }
+            }
+        } else {
+            print!("{formatted}");
+        }
+    }
+
+    if success { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+}
@@ -0,0 +1,44 @@
+//! Conversions between Jet source locations and LSP positions.
+//!
+//! Jet locations count UTF-8 offsets, while LSP positions count lines
+//! from zero and characters in UTF-16 code units.
+
+use hydroper_jet_compiler::ns::*;
+use lsp_types::{Position, Range};
+
+pub fn range(location: &Location) -> Range {
+    Range {
+        start: position(location, location.first_offset(), location.first_line_number(), location.first_line_offset()),
+        end: position(location, location.last_offset(), location.last_line_number(), location.last_line_offset()),
+    }
+}
+
+fn position(location: &Location, offset: usize, line_number: usize, line_offset: usize) -> Position {
+    let text = location.compilation_unit().text();
+    let character = text.get(line_offset.min(offset)..offset).map_or(0, |s| s.encode_utf16().count());
+    Position {
+        line: (line_number - 1) as u32,
+        character: character as u32,
+    }
+}
+
+/// Converts an LSP position into an UTF-8 offset of the given text.
+/// Positions past the end of a line are clamped to the end of that line.
+pub fn offset(text: &str, position: Position) -> usize {
+    let mut line_offset = 0;
+    for _ in 0..position.line {
+        let rest = &text[line_offset..];
+        let Some(i) = rest.find(['\r', '\n']) else {
+            return text.len();
+        };
+        line_offset += i + if rest[i..].starts_with("\r\n") { 2 } else { 1 };
+    }
+    let mut character = 0;
+    for (i, ch) in text[line_offset..].char_indices() {
+        if character >= position.character as usize || ch == '\r' || ch == '\n' {
+            return line_offset + i;
+        }
+        character += ch.len_utf16();
+    }
+    text.len()
+}
@@ -0,0 +1,37 @@
+use std::env;
+use std::error::Error;
+use file_paths::FlexPath;
+use lsp_server::Connection;
+use lsp_types::{HoverProviderCapability, InitializeParams, OneOf, ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind};
+
+mod conversion;
+mod server;
+use server::Server;
+
+fn main() -> Result<(), Box<dyn Error + Sync + Send>> {
+    let (connection, io_threads) = Connection::stdio();
+
+    let capabilities = serde_json::to_value(ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        definition_provider: Some(OneOf::Left(true)),
+        ..Default::default()
+    })?;
+    let params: InitializeParams = serde_json::from_value(connection.initialize(capabilities)?)?;
+
+    // The workspace sources and the JetPM output directory are resolved
+    // from the first workspace folder, or from the current directory.
+    let workspace_path = params.workspace_folders
+        .and_then(|folders| folders.first().and_then(|folder| folder.uri.to_file_path().ok()))
+        .unwrap_or(env::current_dir()?);
+    let jetpm_target_path = FlexPath::new_native(&workspace_path.to_string_lossy()).resolve("jetpm-target").to_string_with_flex_separator();
+
+    let mut server = Server::new(&jetpm_target_path);
+    server.load_workspace(&workspace_path);
+    server.run(&connection)?;
+
+    // Dropping the connection lets the I/O threads finish.
+    drop(connection);
+    io_threads.join()?;
+    Ok(())
+}
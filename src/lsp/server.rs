@@ -0,0 +1,435 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::rc::Rc;
+use hydroper_jet_compiler::ns::*;
+use lsp_server::{Connection, Message, Notification, Request, RequestId, Response};
+use lsp_types::notification::{DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, Notification as _, PublishDiagnostics};
+use lsp_types::request::{GotoDefinition, HoverRequest, Request as _};
+use lsp_types::{
+    DiagnosticSeverity, DidChangeTextDocumentParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
+    GotoDefinitionParams, GotoDefinitionResponse, Hover, HoverContents, HoverParams, MarkupContent, MarkupKind,
+    PublishDiagnosticsParams, TextDocumentPositionParams, Url,
+};
+use crate::conversion;
+
+/// Declarations of the `jet.lang` package that the verifier relies on.
+const JET_LANG_SOURCE: &str = include_str!("jet.lang.jet");
+
+/// Language server over the sources of a workspace.
+///
+/// Every change to the open documents parses the `jet.lang` declarations,
+/// the workspace sources and the open documents in error-tolerant mode
+/// and verifies them together using a new symbol host. An open document
+/// takes the place of its saved workspace source.
+pub struct Server {
+    jetpm_output_directory: String,
+    /// Saved sources of the workspace.
+    workspace_sources: BTreeMap<Url, String>,
+    documents: BTreeMap<Url, String>,
+    /// Documents closed since diagnostics were last published.
+    closed_documents: Vec<Url>,
+    analysis: Option<Analysis>,
+}
+
+struct Analysis {
+    host: Rc<SymbolHost>,
+    verifier: Verifier,
+    /// Compilation units of the open documents and of the workspace sources.
+    compilation_units: Vec<(Url, Rc<CompilationUnit>)>,
+}
+
+impl Server {
+    pub fn new(jetpm_output_directory: &str) -> Self {
+        Self {
+            jetpm_output_directory: jetpm_output_directory.to_owned(),
+            workspace_sources: BTreeMap::new(),
+            documents: BTreeMap::new(),
+            closed_documents: vec![],
+            analysis: None,
+        }
+    }
+
+    /// Loads the `.jet` sources under a workspace directory, excluding
+    /// the JetPM output directory and hidden directories.
+    pub fn load_workspace(&mut self, directory: &Path) {
+        self.workspace_sources.clear();
+        self.load_workspace_directory(directory);
+    }
+
+    fn load_workspace_directory(&mut self, directory: &Path) {
+        let Ok(entries) = fs::read_dir(directory) else {
+            return;
+        };
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if path.is_dir() {
+                let hidden = path.file_name().is_some_and(|name| name.to_string_lossy().starts_with('.'));
+                if !hidden && path != Path::new(&self.jetpm_output_directory) {
+                    self.load_workspace_directory(&path);
+                }
+            } else if path.extension().is_some_and(|extension| extension == "jet") {
+                if let (Ok(uri), Ok(text)) = (Url::from_file_path(&path), fs::read_to_string(&path)) {
+                    self.workspace_sources.insert(uri, text);
+                }
+            }
+        }
+    }
+
+    pub fn run(&mut self, connection: &Connection) -> Result<(), Box<dyn Error + Sync + Send>> {
+        for message in &connection.receiver {
+            match message {
+                Message::Request(request) => {
+                    if connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    let response = self.handle_request(request);
+                    connection.sender.send(Message::Response(response))?;
+                },
+                Message::Notification(notification) => {
+                    if self.handle_notification(notification)? {
+                        self.analyze();
+                        self.publish_diagnostics(connection)?;
+                    }
+                },
+                Message::Response(_) => {},
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_request(&self, request: Request) -> Response {
+        let id = request.id.clone();
+        match request.method.as_str() {
+            HoverRequest::METHOD => {
+                respond(id, serde_json::from_value(request.params).map(|params: HoverParams| self.hover(&params.text_document_position_params)))
+            },
+            GotoDefinition::METHOD => {
+                respond(id, serde_json::from_value(request.params).map(|params: GotoDefinitionParams| self.definition(&params.text_document_position_params)))
+            },
+            _ => Response::new_err(id, lsp_server::ErrorCode::MethodNotFound as i32, format!("Unsupported request: {}", request.method)),
+        }
+    }
+
+    /// Updates the open documents, returning whether they have changed.
+    fn handle_notification(&mut self, notification: Notification) -> Result<bool, serde_json::Error> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params: DidOpenTextDocumentParams = serde_json::from_value(notification.params)?;
+                self.documents.insert(params.text_document.uri, params.text_document.text);
+            },
+            DidChangeTextDocument::METHOD => {
+                let params: DidChangeTextDocumentParams = serde_json::from_value(notification.params)?;
+                // Documents are synchronized in full.
+                if let Some(change) = params.content_changes.into_iter().last() {
+                    self.documents.insert(params.text_document.uri, change.text);
+                }
+            },
+            DidCloseTextDocument::METHOD => {
+                let params: DidCloseTextDocumentParams = serde_json::from_value(notification.params)?;
+                let uri = params.text_document.uri;
+                self.documents.remove(&uri);
+                // The document may have been saved before closing.
+                if self.workspace_sources.contains_key(&uri) {
+                    if let Some(text) = uri.to_file_path().ok().and_then(|path| fs::read_to_string(path).ok()) {
+                        self.workspace_sources.insert(uri.clone(), text);
+                    }
+                }
+                self.closed_documents.push(uri);
+            },
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn analyze(&mut self) {
+        let host = SymbolHost::new(&self.jetpm_output_directory);
+        let compiler_options = CompilerOptions::new();
+        let jet_lang = CompilationUnit::new(None, JET_LANG_SOURCE.into(), &compiler_options);
+        let mut programs = vec![ParserFacade::parse_program_error_tolerant(&jet_lang, &host)];
+        let mut compilation_units = vec![];
+        let workspace_sources = self.workspace_sources.iter().filter(|(uri, _)| !self.documents.contains_key(uri));
+        for (uri, text) in self.documents.iter().chain(workspace_sources) {
+            let file_path = uri.to_file_path().map_or(uri.to_string(), |path| path.to_string_lossy().into_owned());
+            let compilation_unit = CompilationUnit::new(Some(file_path), text.clone(), &compiler_options);
+            programs.push(ParserFacade::parse_program_error_tolerant(&compilation_unit, &host));
+            compilation_units.push((uri.clone(), compilation_unit));
+        }
+        let mut verifier = Verifier::new(&host);
+
+        // Last resort against verifier bugs: the panic is logged and
+        // the symbols verified until then are kept.
+        if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| verifier.verify_programs(programs))) {
+            let message = payload.downcast_ref::<&str>().map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            eprintln!("jet-lsp: verifier panicked: {message}");
+        }
+
+        self.analysis = Some(Analysis { host, verifier, compilation_units });
+    }
+
+    fn publish_diagnostics(&mut self, connection: &Connection) -> Result<(), Box<dyn Error + Sync + Send>> {
+        let mut published = vec![];
+        for uri in self.closed_documents.drain(..) {
+            published.push(PublishDiagnosticsParams::new(uri, vec![], None));
+        }
+        if let Some(analysis) = &self.analysis {
+            // Diagnostics are published for the open documents only.
+            for (uri, compilation_unit) in analysis.compilation_units.iter().filter(|(uri, _)| self.documents.contains_key(uri)) {
+                compilation_unit.sort_diagnostics();
+                let diagnostics: Vec<lsp_types::Diagnostic> = compilation_unit.diagnostics().iter().map(|diagnostic| lsp_types::Diagnostic {
+                    range: conversion::range(&diagnostic.location()),
+                    severity: Some(if diagnostic.is_warning() { DiagnosticSeverity::WARNING } else { DiagnosticSeverity::ERROR }),
+                    code: Some(lsp_types::NumberOrString::Number(diagnostic.id())),
+                    source: Some("jet".into()),
+                    message: diagnostic.format_message_english(),
+                    ..Default::default()
+                }).collect();
+                published.push(PublishDiagnosticsParams::new(uri.clone(), diagnostics, None));
+            }
+        }
+        for params in published {
+            let notification = Notification::new(PublishDiagnostics::METHOD.into(), params);
+            connection.sender.send(Message::Notification(notification))?;
+        }
+        Ok(())
+    }
+
+    /// Returns the innermost expression at a position that
+    /// has been assigned a symbol.
+    fn symbol_at(&self, params: &TextDocumentPositionParams) -> Option<(&Analysis, Rc<Expression>, Symbol)> {
+        let analysis = self.analysis.as_ref()?;
+        let (_, compilation_unit) = analysis.compilation_units.iter().find(|(uri, _)| *uri == params.text_document.uri)?;
+        let offset = conversion::offset(&compilation_unit.text(), params.position);
+        let (exp, symbol) = analysis.verifier.ast_to_symbol().expression_at(compilation_unit, offset)?;
+        Some((analysis, exp, symbol))
+    }
+
+    fn hover(&self, params: &TextDocumentPositionParams) -> Option<Hover> {
+        let (analysis, exp, symbol) = self.symbol_at(params)?;
+        let definition = definition_of(&symbol);
+        let mut value = format!("```jet\n{}\n```", describe(&analysis.host, &symbol, definition.as_ref()));
+        if let Some(jetdoc) = definition.and_then(|d| d.jetdoc()) {
+            value.push_str("\n\n");
+            value.push_str(&format_jetdoc(&jetdoc));
+        }
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent { kind: MarkupKind::Markdown, value }),
+            range: Some(conversion::range(&exp.location())),
+        })
+    }
+
+    fn definition(&self, params: &TextDocumentPositionParams) -> Option<GotoDefinitionResponse> {
+        let (analysis, _, symbol) = self.symbol_at(params)?;
+        let location = definition_of(&symbol)?.location()?;
+        let compilation_unit = location.compilation_unit();
+        let uri = analysis.compilation_units.iter()
+            .find(|(_, cu)| Rc::ptr_eq(cu, &compilation_unit))
+            .map(|(uri, _)| uri.clone())
+            .or_else(|| Url::from_file_path(compilation_unit.file_path()?).ok())?;
+        Some(GotoDefinitionResponse::Scalar(lsp_types::Location {
+            uri,
+            range: conversion::range(&location),
+        }))
+    }
+}
+
+fn respond<T: serde::Serialize>(id: RequestId, result: Result<T, serde_json::Error>) -> Response {
+    match result {
+        Ok(result) => Response::new_ok(id, result),
+        Err(error) => Response::new_err(id, lsp_server::ErrorCode::InvalidParams as i32, error.to_string()),
+    }
+}
+
+/// Returns the definition that a symbol refers to, if it
+/// is one that carries a location and JetDoc comment.
+fn definition_of(symbol: &Symbol) -> Option<Symbol> {
+    let symbol = if symbol.is_type_as_reference_value() {
+        symbol.referenced_type()
+    } else if symbol.is_static_reference_value() || symbol.is_instance_reference_value()
+        || symbol.is_scope_reference_value() || symbol.is_package_reference_value() {
+        symbol.property()
+    } else {
+        symbol.clone()
+    };
+    if symbol.is_class_type() || symbol.is_enum_type() || symbol.is_interface_type()
+        || symbol.is_type_after_explicit_type_substitution() || symbol.is_alias()
+        || symbol.is_package() || symbol.is_package_set() || symbol.is_variable_property()
+        || symbol.is_virtual_property() || symbol.is_function() {
+        Some(symbol)
+    } else {
+        None
+    }
+}
+
+/// Describes a symbol in Jet syntax.
+fn describe(host: &SymbolHost, symbol: &Symbol, definition: Option<&Symbol>) -> String {
+    let Some(definition) = definition else {
+        return if symbol.is_value() {
+            describe_type(&symbol.static_type(host))
+        } else if symbol.is_type() {
+            describe_type(symbol)
+        } else {
+            "*".into()
+        };
+    };
+    if definition.is_class_type() {
+        format!("class {}", definition)
+    } else if definition.is_enum_type() {
+        format!("enum {}", definition)
+    } else if definition.is_interface_type() {
+        format!("interface {}", definition)
+    } else if definition.is_package() {
+        format!("package {}", definition)
+    } else if definition.is_variable_property() {
+        let keyword = if definition.read_only(host) { "const" } else { "var" };
+        format!("{keyword} {}: {}", definition.name(), describe_type(&definition.static_type(host)))
+    } else if definition.is_virtual_property() {
+        format!("{}: {}", definition.name(), describe_type(&definition.static_type(host)))
+    } else if definition.is_function() {
+        let signature = describe_type(&definition.signature(host));
+        format!("function {}{}", definition.name(), signature.strip_prefix("function").unwrap_or(&signature))
+    } else {
+        definition.to_string()
+    }
+}
+
+fn describe_type(symbol: &Symbol) -> String {
+    if symbol.is_unresolved() { "*".into() } else { symbol.to_string() }
+}
+
+fn format_jetdoc(jetdoc: &JetDoc) -> String {
+    let mut paragraphs = vec![];
+    if let Some((body, _)) = &jetdoc.main_body {
+        paragraphs.push(body.clone());
+    }
+    for (tag, _) in &jetdoc.tags {
+        match tag {
+            JetDocTag::Param { name, description } => {
+                paragraphs.push(format!("*@param* `{name}` — {description}"));
+            },
+            JetDocTag::Return(description) => {
+                paragraphs.push(format!("*@return* — {description}"));
+            },
+            JetDocTag::Deprecated { message } => {
+                paragraphs.push(format!("*@deprecated*{}", message.as_ref().map_or(String::new(), |m| format!(" — {m}"))));
+            },
+            JetDocTag::Example(example) => {
+                paragraphs.push(format!("*@example*\n```jet\n{example}\n```"));
+            },
+            _ => {},
+        }
+    }
+    paragraphs.join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, process};
+    use lsp_types::{Position, TextDocumentContentChangeEvent, TextDocumentIdentifier, TextDocumentItem, VersionedTextDocumentIdentifier};
+
+    fn uri() -> Url {
+        Url::parse("file:///a.jet").unwrap()
+    }
+
+    fn open(server: &mut Server, text: &str) -> bool {
+        let params = DidOpenTextDocumentParams {
+            text_document: TextDocumentItem::new(uri(), "jet".into(), 0, text.into()),
+        };
+        server.handle_notification(Notification::new(DidOpenTextDocument::METHOD.into(), params)).unwrap()
+    }
+
+    fn position(line: u32, character: u32) -> TextDocumentPositionParams {
+        TextDocumentPositionParams::new(TextDocumentIdentifier::new(uri()), Position::new(line, character))
+    }
+
+    #[test]
+    fn notifications() {
+        let mut server = Server::new("");
+        assert!(open(&mut server, "var x = 1;"));
+        assert_eq!(server.documents[&uri()], "var x = 1;");
+
+        let params = DidChangeTextDocumentParams {
+            text_document: VersionedTextDocumentIdentifier::new(uri(), 1),
+            content_changes: vec![
+                TextDocumentContentChangeEvent { range: None, range_length: None, text: "var x = 2;".into() },
+                TextDocumentContentChangeEvent { range: None, range_length: None, text: "var x = 3;".into() },
+            ],
+        };
+        assert!(server.handle_notification(Notification::new(DidChangeTextDocument::METHOD.into(), params)).unwrap());
+        assert_eq!(server.documents[&uri()], "var x = 3;");
+
+        let params = DidCloseTextDocumentParams { text_document: TextDocumentIdentifier::new(uri()) };
+        assert!(server.handle_notification(Notification::new(DidCloseTextDocument::METHOD.into(), params)).unwrap());
+        assert!(server.documents.is_empty());
+        assert_eq!(server.closed_documents, [uri()]);
+
+        assert!(!server.handle_notification(Notification::new("$/unknown".into(), ())).unwrap());
+        assert!(server.handle_notification(Notification::new(DidOpenTextDocument::METHOD.into(), 10)).is_err());
+    }
+
+    #[test]
+    fn symbols_at_positions() {
+        let mut server = Server::new("");
+        open(&mut server, "package p {\n    public class C {}\n    public function f(c: C): void {\n        c;\n    }\n}\n");
+        server.analyze();
+
+        let (analysis, exp, symbol) = server.symbol_at(&position(3, 8)).unwrap();
+        assert!(analysis.compilation_units[0].1.diagnostics().is_empty());
+        assert_eq!(conversion::range(&exp.location()), lsp_types::Range::new(Position::new(3, 8), Position::new(3, 9)));
+        let definition = definition_of(&symbol);
+        assert_eq!(describe(&analysis.host, &symbol, definition.as_ref()), "var c: p.C");
+
+        let (_, _, symbol) = server.symbol_at(&position(2, 26)).unwrap();
+        let definition = definition_of(&symbol);
+        assert_eq!(describe(&analysis.host, &symbol, definition.as_ref()), "class p.C");
+
+        assert!(server.symbol_at(&position(0, 0)).is_none());
+    }
+
+    #[test]
+    fn workspace_sources() {
+        let directory = env::temp_dir().join(format!("jet-lsp-workspace-{}", process::id()));
+        fs::create_dir_all(directory.join("jetpm-target")).unwrap();
+        fs::write(directory.join("b.jet"), "package q {\n    public class D {}\n}\n").unwrap();
+        fs::write(directory.join("jetpm-target").join("c.jet"), "package q {\n    public class D {}\n}\n").unwrap();
+
+        let mut server = Server::new(&directory.join("jetpm-target").to_string_lossy());
+        server.load_workspace(&directory);
+        fs::remove_dir_all(&directory).unwrap();
+        let b_uri = Url::from_file_path(directory.join("b.jet")).unwrap();
+        assert_eq!(server.workspace_sources.keys().collect::<Vec<_>>(), [&b_uri]);
+
+        open(&mut server, "package p {\n    import q.D;\n    public function f(d: D): void {}\n}\n");
+        server.analyze();
+        let analysis = server.analysis.as_ref().unwrap();
+        assert!(analysis.compilation_units.iter().all(|(_, cu)| cu.diagnostics().is_empty()));
+
+        let Some(GotoDefinitionResponse::Scalar(location)) = server.definition(&position(2, 26)) else {
+            panic!("expected a definition");
+        };
+        assert_eq!(location.uri, b_uri);
+        assert_eq!(location.range, lsp_types::Range::new(Position::new(1, 17), Position::new(1, 18)));
+    }
+
+    #[test]
+    fn jetdoc_formatting() {
+        let compilation_unit = CompilationUnit::new(None, String::new(), &CompilerOptions::new());
+        let location = Location::with_offset(&compilation_unit, 0);
+        let jetdoc = JetDoc {
+            location: location.clone(),
+            main_body: Some(("Main body.".into(), location.clone())),
+            tags: vec![
+                (JetDocTag::Param { name: "x".into(), description: "Some value.".into() }, location.clone()),
+                (JetDocTag::Return("Result.".into()), location.clone()),
+                (JetDocTag::Deprecated { message: None }, location.clone()),
+                (JetDocTag::Example("f(1)".into()), location.clone()),
+                (JetDocTag::Private, location.clone()),
+            ],
+        };
+        assert_eq!(format_jetdoc(&jetdoc), "Main body.\n\n*@param* `x` — Some value.\n\n*@return* — Result.\n\n*@deprecated*\n\n*@example*\n```jet\nf(1)\n```");
+    }
+}
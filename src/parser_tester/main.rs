@@ -12,6 +12,19 @@ struct Arguments {
 
     #[arg(short, long)]
     file_log: bool,
+
+    /// Recovers from syntax errors, always producing a program.
+    #[arg(short, long)]
+    error_tolerant: bool,
+
+    /// Includes source locations in the AST JSON output.
+    #[arg(short, long)]
+    locations: bool,
+
+    /// Includes the source path in every location of the AST JSON output.
+    /// Implies `--locations`.
+    #[arg(long)]
+    location_file_paths: bool,
 }
 
 fn main() -> io::Result<()> {
@@ -27,9 +40,20 @@ fn main() -> io::Result<()> {
     let source_content = fs::read_to_string(&source_path)?;
     let host = SymbolHost::new(&jetpm_target_path);
     let compilation_unit = CompilationUnit::new(Some(source_path), source_content, &CompilerOptions::new());
-    if let Some(program) = ParserFacade::parse_program(&compilation_unit, &host) {
+    let program = if arguments.error_tolerant {
+        Some(ParserFacade::parse_program_error_tolerant(&compilation_unit, &host))
+    } else {
+        ParserFacade::parse_program(&compilation_unit, &host)
+    };
+    if let Some(program) = program {
         if arguments.file_log {
-            fs::write(&source_path_ast_json, serde_json::to_string_pretty(&program).unwrap())?;
+            let json = if arguments.locations || arguments.location_file_paths {
+                let options = LocationSerialization { include_file_path: arguments.location_file_paths };
+                with_location_serialization(options, || serde_json::to_string_pretty(&program).unwrap())
+            } else {
+                serde_json::to_string_pretty(&program).unwrap()
+            };
+            fs::write(&source_path_ast_json, json)?;
         } else {
             println!("Jet program successfuly parsed.");
         }